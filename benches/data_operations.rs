@@ -30,6 +30,8 @@ fn create_store_with_data(num_modules: usize, num_developers: usize) -> Store {
                     ModuleStatus::Completed
                 },
                 progress_score: (i * 25) as u8 % 100,
+                depends_on: Vec::new(),
+                notes: String::new(),
             })
             .collect(),
         developers: (0..num_developers)
@@ -38,6 +40,7 @@ fn create_store_with_data(num_modules: usize, num_developers: usize) -> Store {
                 name: format!("Developer {}", i),
             })
             .collect(),
+        status_history: Vec::new(),
     };
 
     store.projects.push(project);
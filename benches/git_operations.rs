@@ -127,6 +127,47 @@ fn bench_list_changes(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark listing changes when many tracked files are modified, so every
+/// change carries a diff preview
+fn bench_list_changes_modified(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list_changes_modified");
+
+    for file_count in [10, 100, 500].iter() {
+        let temp_dir = create_test_repo_with_commits(1);
+        let repo_path = temp_dir.path();
+        let repo = git2::Repository::open(repo_path).expect("Failed to open repo");
+
+        // Commit the files, then modify every one of them
+        let mut index = repo.index().expect("Failed to get index");
+        for i in 0..*file_count {
+            let name = format!("file{}.txt", i);
+            fs::write(repo_path.join(&name), format!("content {}\n", i))
+                .expect("Failed to write file");
+            index.add_path(std::path::Path::new(&name)).ok();
+        }
+        index.write().expect("Failed to write index");
+        let tree_id = index.write_tree().expect("Failed to write tree");
+        let tree = repo.find_tree(tree_id).expect("Failed to find tree");
+        let sig =
+            git2::Signature::now("Test User", "test@example.com").expect("Failed to create sig");
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "Add files", &tree, &[&head])
+            .expect("Failed to commit");
+        for i in 0..*file_count {
+            let file = repo_path.join(format!("file{}.txt", i));
+            fs::write(&file, format!("modified {}\n", i)).expect("Failed to write file");
+        }
+
+        let client = GitClient::discover(repo_path).expect("Failed to create client");
+        group.bench_with_input(
+            BenchmarkId::from_parameter(file_count),
+            file_count,
+            |b, _| b.iter(|| client.list_changes().expect("Failed to list changes")),
+        );
+    }
+    group.finish();
+}
+
 /// Benchmark getting commit history with varying commit counts
 fn bench_get_commit_history(c: &mut Criterion) {
     let mut group = c.benchmark_group("get_commit_history");
@@ -157,6 +198,34 @@ fn bench_get_commit_history(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark path-filtered history: summarising every commit vs a pathspec revwalk
+fn bench_file_history(c: &mut Criterion) {
+    let mut group = c.benchmark_group("file_history");
+
+    for commit_count in [10, 50, 100].iter() {
+        let temp_dir = create_test_repo_with_commits(*commit_count);
+        let client = GitClient::discover(temp_dir.path()).expect("Failed to create client");
+
+        group.bench_with_input(
+            BenchmarkId::new("naive", commit_count),
+            commit_count,
+            |b, _| {
+                b.iter(|| client.file_history(black_box("file.txt"), black_box(usize::MAX)));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("revwalk_pathspec", commit_count),
+            commit_count,
+            |b, _| {
+                b.iter(|| {
+                    client.path_history(black_box("file.txt"), None, None, black_box(usize::MAX))
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
 /// Benchmark listing branches
 fn bench_list_branches(c: &mut Criterion) {
     let temp_dir = create_test_repo_with_commits(1);
@@ -259,7 +328,9 @@ criterion_group!(
     bench_discover,
     bench_head_branch,
     bench_list_changes,
+    bench_list_changes_modified,
     bench_get_commit_history,
+    bench_file_history,
     bench_list_branches,
     bench_stage_file,
     bench_unstage_file,
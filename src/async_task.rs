@@ -1,7 +1,7 @@
 //! Async task management for background Git operations
 //!
 //! This module provides a simple background task executor that runs Git operations
-//! (fetch, push, pull) in separate threads without blocking the UI event loop.
+//! (fetch, push, pull, clone) in separate threads without blocking the UI event loop.
 //!
 //! # Architecture
 //!
@@ -31,11 +31,22 @@
 //! }
 //! ```
 
-use std::{path::PathBuf, thread};
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
 
 use crossbeam::channel::{unbounded, Receiver, Sender};
 
-use crate::{git, git::GitClient};
+use crate::{
+    git,
+    git::{GitClient, GitClientOptions, TransferProgress},
+};
+
+/// How often a running clone reports its transfer progress
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
 
 /// Result of a Git operation
 pub type OpResult = Result<String, String>;
@@ -47,12 +58,22 @@ pub struct OperationResult {
     pub result: OpResult,
 }
 
+/// Transfer progress of an operation that is still running
+#[derive(Debug, Clone)]
+pub struct ProgressUpdate {
+    pub op: GitOperation,
+    pub progress: TransferProgress,
+}
+
 /// Git operations that can be performed asynchronously
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GitOperation {
     Fetch(String), // remote name
     Push(String),  // remote name
     Pull(String),  // remote name
+    FetchAll,
+    /// Clone a URL into a new directory; spawned with that directory as workdir
+    Clone(String, PathBuf),
 }
 
 /// Task manager for background Git operations
@@ -61,30 +82,45 @@ pub enum GitOperation {
 pub struct TaskManager {
     sender: Sender<OperationResult>,
     receiver: Receiver<OperationResult>,
+    progress_sender: Sender<ProgressUpdate>,
+    progress_receiver: Receiver<ProgressUpdate>,
     pending: usize,
+    /// Applied to the client each operation opens, e.g. to prune on fetch
+    options: GitClientOptions,
 }
 
 impl TaskManager {
     /// Create a new task manager
     pub fn new() -> Self {
         let (sender, receiver) = unbounded();
+        let (progress_sender, progress_receiver) = unbounded();
         Self {
             sender,
             receiver,
+            progress_sender,
+            progress_receiver,
             pending: 0,
+            options: GitClientOptions::default(),
         }
     }
 
+    /// Set the options operations spawned from now on run with
+    pub fn set_client_options(&mut self, options: GitClientOptions) {
+        self.options = options;
+    }
+
     /// Spawn a background Git operation
     ///
     /// Returns immediately; result can be polled with `try_recv()`
     pub fn spawn_operation(&mut self, workdir: PathBuf, op: GitOperation) {
         self.pending += 1;
         let sender = self.sender.clone();
+        let progress = self.progress_sender.clone();
+        let options = self.options;
 
         thread::spawn(move || {
             let op_clone = op.clone();
-            let result = run_git_operation(&workdir, &op_clone);
+            let result = run_git_operation(&workdir, &op_clone, options, &progress);
 
             // Send result back to main thread
             let _ = sender.send(OperationResult {
@@ -112,6 +148,11 @@ impl TaskManager {
         }
     }
 
+    /// Latest transfer progress reported since the last call, if any
+    pub fn try_recv_progress(&mut self) -> Option<ProgressUpdate> {
+        self.progress_receiver.try_iter().last()
+    }
+
     /// Get number of pending operations
     pub fn pending_count(&self) -> usize {
         self.pending
@@ -129,30 +170,108 @@ impl Default for TaskManager {
     }
 }
 
-fn run_git_operation(workdir: &PathBuf, op: &GitOperation) -> OpResult {
-    let client = match GitClient::discover(workdir) {
-        Ok(client) => client,
-        Err(e) => {
-            return Err(git::GitClient::explain_error(&e));
-        }
+fn run_git_operation(
+    workdir: &PathBuf,
+    op: &GitOperation,
+    options: GitClientOptions,
+    progress: &Sender<ProgressUpdate>,
+) -> OpResult {
+    // Opened lazily: there is no repository until a clone has made one
+    let open = || {
+        let mut client =
+            GitClient::discover(workdir).map_err(|e| git::GitClient::explain_error(&e))?;
+        client.set_options(options);
+        Ok::<_, String>(client)
     };
 
     match op {
-        GitOperation::Fetch(remote) => client
+        GitOperation::Fetch(remote) => open()?
             .fetch(remote)
             .map(|count| format!("Fetched {} objects from {}", count, remote))
             .map_err(|e| git::GitClient::explain_error(&e)),
-        GitOperation::Push(remote) => client
+        GitOperation::FetchAll => open()?
+            .fetch_all()
+            .map_err(|e| git::GitClient::explain_error(&e))
+            .and_then(|fetched| summarize_fetch_all(&fetched)),
+        GitOperation::Push(remote) => open()?
             .push(remote, None)
             .map(|_| format!("Pushed to {}", remote))
             .map_err(|e| git::GitClient::explain_error(&e)),
-        GitOperation::Pull(remote) => client
+        GitOperation::Pull(remote) => open()?
             .pull(remote, None)
             .map(|_| format!("Pulled from {}", remote))
             .map_err(|e| git::GitClient::explain_error(&e)),
+        GitOperation::Clone(url, dest) => clone_reporting_progress(url, dest, op, progress)
+            .map(|_| format!("Cloned {} into {}", url, dest.display()))
+            .map_err(|e| git::GitClient::explain_error(&e)),
     }
 }
 
+/// One line covering every remote of a fetch-all: an error when any remote
+/// failed, naming each failure with its reason and then the remotes that
+/// were fetched
+fn summarize_fetch_all(fetched: &[(String, Result<usize, String>)]) -> OpResult {
+    if fetched.is_empty() {
+        return Err("No remotes configured".to_string());
+    }
+    let succeeded: Vec<String> = fetched
+        .iter()
+        .filter_map(|(remote, result)| {
+            let count = result.as_ref().ok()?;
+            Some(format!("{} ({} objects)", remote, count))
+        })
+        .collect();
+    let failed: Vec<String> = fetched
+        .iter()
+        .filter_map(|(remote, result)| {
+            let reason = result.as_ref().err()?;
+            Some(format!("{}: {}", remote, reason))
+        })
+        .collect();
+
+    if failed.is_empty() {
+        return Ok(format!("Fetched {}", succeeded.join(", ")));
+    }
+    let mut message = format!("Failed to fetch {}", failed.join("; "));
+    if !succeeded.is_empty() {
+        message.push_str(&format!("; fetched {}", succeeded.join(", ")));
+    }
+    Err(message)
+}
+
+/// Clone on a scoped thread, sending `op`'s transfer progress to `updates`
+/// whenever it has moved on since the last check
+fn clone_reporting_progress(
+    url: &str,
+    dest: &Path,
+    op: &GitOperation,
+    updates: &Sender<ProgressUpdate>,
+) -> color_eyre::Result<GitClient> {
+    let tracker = Arc::new(Mutex::new(TransferProgress::default()));
+    thread::scope(|scope| {
+        let clone = scope
+            .spawn(|| GitClient::clone_repo_with_progress(url, dest, Some(tracker.clone()), None));
+        let mut reported = (0, 0);
+        while !clone.is_finished() {
+            thread::sleep(PROGRESS_INTERVAL);
+            let Ok(progress) = tracker.lock().map(|p| p.clone()) else {
+                continue;
+            };
+            let current = (progress.received_objects, progress.indexed_deltas);
+            if current != reported {
+                reported = current;
+                let _ = updates.send(ProgressUpdate {
+                    op: op.clone(),
+                    progress,
+                });
+            }
+        }
+        clone
+            .join()
+            .unwrap_or_else(|_| Err(color_eyre::eyre::eyre!("Clone stopped unexpectedly")))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,4 +317,65 @@ mod tests {
         assert!(result.is_some());
         assert_eq!(tm.pending_count(), 0);
     }
+
+    #[test]
+    fn test_try_recv_progress_returns_the_latest_update() {
+        let mut tm = TaskManager::new();
+        assert!(tm.try_recv_progress().is_none());
+
+        let op = GitOperation::Clone("url".to_string(), PathBuf::from("dest"));
+        for received_objects in [1, 5] {
+            tm.progress_sender
+                .send(ProgressUpdate {
+                    op: op.clone(),
+                    progress: TransferProgress {
+                        received_objects,
+                        total_objects: 10,
+                        ..Default::default()
+                    },
+                })
+                .unwrap();
+        }
+        let update = tm.try_recv_progress().expect("progress was sent");
+        assert_eq!(update.op, op);
+        assert_eq!(update.progress.received_objects, 5);
+        assert!(tm.try_recv_progress().is_none());
+    }
+
+    #[test]
+    fn test_clone_runs_without_an_existing_repository() {
+        let source = init_temp_repo();
+        let target = TempDir::new().expect("Failed to create temp dir");
+        let dest = target.path().join("clone");
+        let op = GitOperation::Clone(source.path().to_string_lossy().into_owned(), dest.clone());
+
+        let (progress, _) = unbounded();
+        let result = run_git_operation(&dest, &op, GitClientOptions::default(), &progress);
+        assert!(result.is_ok(), "{:?}", result);
+        assert!(dest.join(".git").exists());
+    }
+
+    #[test]
+    fn test_summarize_fetch_all_names_each_failed_remote() {
+        let fetched = vec![
+            ("origin".to_string(), Ok(3)),
+            ("broken".to_string(), Err("Remote not found".to_string())),
+            ("backup".to_string(), Ok(0)),
+            ("mirror".to_string(), Err("Network error".to_string())),
+        ];
+        assert_eq!(
+            summarize_fetch_all(&fetched),
+            Err(
+                "Failed to fetch broken: Remote not found; mirror: Network error; \
+                 fetched origin (3 objects), backup (0 objects)"
+                    .to_string()
+            )
+        );
+
+        assert_eq!(
+            summarize_fetch_all(&fetched[..1]),
+            Ok("Fetched origin (3 objects)".to_string())
+        );
+        assert!(summarize_fetch_all(&[]).is_err());
+    }
 }
@@ -0,0 +1,23 @@
+//! System clipboard access.
+//!
+//! Copying uses the OSC 52 terminal escape sequence and is only available when
+//! built with the `clipboard` feature. Without it, [`copy`] returns an error the
+//! caller can surface in the status bar.
+
+use color_eyre::Result;
+
+/// Copies `text` to the system clipboard.
+#[cfg(feature = "clipboard")]
+pub fn copy(text: &str) -> Result<()> {
+    use crossterm::{clipboard::CopyToClipboard, execute};
+    execute!(std::io::stdout(), CopyToClipboard::to_clipboard_from(text))?;
+    Ok(())
+}
+
+/// Copies `text` to the system clipboard.
+#[cfg(not(feature = "clipboard"))]
+pub fn copy(_text: &str) -> Result<()> {
+    Err(color_eyre::eyre::eyre!(
+        "Clipboard support not enabled (rebuild with --features clipboard)"
+    ))
+}
@@ -1,11 +1,16 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum FileStatus {
     Modified,
     Added,
     Deleted,
+    /// Moved from `from` to `to`, possibly with edits
+    Renamed {
+        from: String,
+        to: String,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -17,6 +22,12 @@ pub struct Change {
     pub local_preview: Option<String>,
     pub incoming_preview: Option<String>,
     pub staged: bool,
+    /// Binary content; previews hold a size summary instead of patch text
+    pub is_binary: bool,
+    /// Diff lines left out of `diff_preview` by the preview line cap
+    pub hidden_lines: usize,
+    /// A submodule checked out at another commit or with local changes
+    pub is_submodule: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -39,8 +50,17 @@ pub struct Module {
     pub owner: Option<Uuid>,
     pub status: ModuleStatus,
     pub progress_score: u8,
+    /// Modules that must be Completed before this one can become Current
+    #[serde(default)]
+    pub depends_on: Vec<Uuid>,
+    /// Free-form description shown under the module in the Module Manager
+    #[serde(default)]
+    pub notes: String,
 }
 
+/// A module entering a status, and when
+pub type StatusChange = (Uuid, ModuleStatus, std::time::SystemTime);
+
 #[derive(Debug, Clone)]
 pub struct Project {
     pub id: Uuid,
@@ -50,18 +70,154 @@ pub struct Project {
     pub changes: Vec<Change>,
     pub modules: Vec<Module>,
     pub developers: Vec<Developer>,
+    /// Every module status change, oldest first
+    pub status_history: Vec<StatusChange>,
+}
+
+impl Project {
+    /// First dependency of `module_id` that is not yet Completed
+    pub fn blocking_dependency(&self, module_id: Uuid) -> Option<&Module> {
+        let module = self.modules.iter().find(|m| m.id == module_id)?;
+        module.depends_on.iter().find_map(|dep| {
+            self.modules
+                .iter()
+                .find(|m| m.id == *dep && m.status != ModuleStatus::Completed)
+        })
+    }
+
+    /// Whether `module_id` depends on `target_id`, directly or transitively
+    pub fn depends_on(&self, module_id: Uuid, target_id: Uuid) -> bool {
+        let mut stack = vec![module_id];
+        let mut seen = Vec::new();
+        while let Some(id) = stack.pop() {
+            if seen.contains(&id) {
+                continue;
+            }
+            seen.push(id);
+            if let Some(module) = self.modules.iter().find(|m| m.id == id) {
+                if module.depends_on.contains(&target_id) {
+                    return true;
+                }
+                stack.extend(module.depends_on.iter().copied());
+            }
+        }
+        false
+    }
+
+    /// Status changes of `module_id`, oldest first
+    pub fn module_timeline(
+        &self,
+        module_id: Uuid,
+    ) -> impl Iterator<Item = (ModuleStatus, std::time::SystemTime)> + '_ {
+        self.status_history
+            .iter()
+            .filter(move |(id, ..)| *id == module_id)
+            .map(|&(_, status, at)| (status, at))
+    }
+
+    /// Average module progress score, or `None` when the project has no modules
+    pub fn progress(&self) -> Option<u8> {
+        if self.modules.is_empty() {
+            return None;
+        }
+        let total: usize = self.modules.iter().map(|m| m.progress_score as usize).sum();
+        Some((total / self.modules.len()) as u8)
+    }
+}
+
+/// Orderings available for the Dashboard project list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectSort {
+    NameAsc,
+    NameDesc,
+    MostModules,
+}
+
+impl ProjectSort {
+    /// Next ordering in the toggle cycle
+    pub fn next(self) -> Self {
+        match self {
+            ProjectSort::NameAsc => ProjectSort::NameDesc,
+            ProjectSort::NameDesc => ProjectSort::MostModules,
+            ProjectSort::MostModules => ProjectSort::NameAsc,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ProjectSort::NameAsc => "Name A–Z",
+            ProjectSort::NameDesc => "Name Z–A",
+            ProjectSort::MostModules => "Most modules",
+        }
+    }
+}
+
+/// Sorts projects in place; ties keep their existing relative order
+pub fn sort_projects(projects: &mut [Project], mode: ProjectSort) {
+    match mode {
+        ProjectSort::NameAsc => projects.sort_by_key(|p| p.name.to_lowercase()),
+        ProjectSort::NameDesc => projects.sort_by_key(|p| std::cmp::Reverse(p.name.to_lowercase())),
+        ProjectSort::MostModules => projects.sort_by_key(|p| std::cmp::Reverse(p.modules.len())),
+    }
+}
+
+/// Outcome of importing modules from a task list
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ModuleImport {
+    pub imported: usize,
+    /// One message per row skipped as malformed or whose status was not
+    /// recognised, prefixed with its line number
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Default)]
 pub struct Store {
     pub projects: Vec<Project>,
+    // Set by mutations, cleared once an autosave has written them out
+    dirty: bool,
 }
 
 impl Store {
     pub fn new() -> Self {
         Self {
             projects: Vec::new(),
+            dirty: false,
+        }
+    }
+
+    /// Whether a mutation has happened since the last save
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Flags unsaved changes for callers that edit `projects` directly
+    /// rather than through a method
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Saves progress to `state_dir` (and JSON to `workdir` when
+    /// `include_json`) only if something changed; returns whether anything
+    /// was written
+    pub fn save_if_dirty(
+        &mut self,
+        workdir: &std::path::Path,
+        state_dir: &std::path::Path,
+        include_json: bool,
+    ) -> std::io::Result<bool> {
+        if !self.dirty {
+            return Ok(false);
+        }
+        self.save_progress(state_dir)?;
+        if include_json {
+            self.save_to_json(workdir)?;
         }
+        self.dirty = false;
+        Ok(true)
+    }
+
+    pub fn project_progress(&self, project_idx: usize) -> Option<u8> {
+        self.projects.get(project_idx).and_then(Project::progress)
     }
 
     pub fn bump_progress_on_commit(&mut self, project_idx: usize) {
@@ -73,17 +229,18 @@ impl Store {
                 .find(|m| m.status == ModuleStatus::Current)
             {
                 m.progress_score = (m.progress_score.saturating_add(8)).min(100);
+                self.dirty = true;
             }
         }
     }
 
-    // Minimal persistence of module progress to .git/forge/progress.txt
-    pub fn save_progress(&self, workdir: &std::path::Path) -> std::io::Result<()> {
+    // Minimal persistence of module progress to progress.txt in the state
+    // directory (see `GitClient::state_dir`)
+    pub fn save_progress(&self, state_dir: &std::path::Path) -> std::io::Result<()> {
         use std::fs::{create_dir_all, File};
         use std::io::Write;
-        let dir = workdir.join(".git/forge");
-        create_dir_all(&dir)?;
-        let mut f = File::create(dir.join("progress.txt"))?;
+        create_dir_all(state_dir)?;
+        let mut f = File::create(state_dir.join("progress.txt"))?;
         for p in &self.projects {
             for m in &p.modules {
                 let owner = m
@@ -118,6 +275,11 @@ impl Store {
             let devs_json = serde_json::to_string_pretty(&project.developers)?;
             let mut f = File::create(dir.join("developers.json"))?;
             f.write_all(devs_json.as_bytes())?;
+
+            // Save module status history
+            let history_json = serde_json::to_string_pretty(&project.status_history)?;
+            let mut f = File::create(dir.join("timeline.json"))?;
+            f.write_all(history_json.as_bytes())?;
         }
 
         Ok(())
@@ -152,15 +314,26 @@ impl Store {
                     project.developers = developers;
                 }
             }
+
+            // Load module status history
+            let history_path = dir.join("timeline.json");
+            if history_path.exists() {
+                let mut f = File::open(&history_path)?;
+                let mut contents = String::new();
+                f.read_to_string(&mut contents)?;
+                if let Ok(history) = serde_json::from_str(&contents) {
+                    project.status_history = history;
+                }
+            }
         }
 
         Ok(())
     }
 
-    pub fn load_progress(&mut self, workdir: &std::path::Path) -> std::io::Result<()> {
+    pub fn load_progress(&mut self, state_dir: &std::path::Path) -> std::io::Result<()> {
         use std::fs::File;
         use std::io::{BufRead, BufReader};
-        let path = workdir.join(".git/forge/progress.txt");
+        let path = state_dir.join("progress.txt");
         if !path.exists() {
             return Ok(());
         }
@@ -191,6 +364,57 @@ impl Store {
         Ok(())
     }
 
+    /// Adds a module per `name,status,progress` row of `csv`. Markdown task
+    /// items (`- [ ] name` / `- [x] name`) are accepted too. Blank lines,
+    /// `#` lines and a header row are ignored; malformed rows are skipped and
+    /// unknown statuses import as Pending, each adding a warning.
+    /// Fails for an unknown project.
+    pub fn import_modules_csv(
+        &mut self,
+        project_idx: usize,
+        csv: &str,
+    ) -> Result<ModuleImport, String> {
+        let project = self
+            .projects
+            .get_mut(project_idx)
+            .ok_or_else(|| format!("no project at index {}", project_idx))?;
+        let mut result = ModuleImport::default();
+        for (number, line) in csv.lines().map(str::trim).enumerate() {
+            let number = number + 1;
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let row = match parse_task_item(line) {
+                Some(row) => Some(row),
+                None => parse_csv_row(line),
+            };
+            let Some((name, status, progress)) = row else {
+                if !line.to_lowercase().starts_with("name,") {
+                    result
+                        .warnings
+                        .push(format!("line {}: malformed row", number));
+                }
+                continue;
+            };
+            let status = status.unwrap_or_else(|e| {
+                result.warnings.push(format!("line {}: {}", number, e));
+                ModuleStatus::Pending
+            });
+            project.modules.push(Module {
+                id: Uuid::new_v4(),
+                name,
+                owner: None,
+                status,
+                progress_score: progress,
+                depends_on: Vec::new(),
+                notes: String::new(),
+            });
+            result.imported += 1;
+        }
+        self.dirty |= result.imported > 0;
+        Ok(result)
+    }
+
     // CRUD operations for modules
     pub fn add_module(&mut self, project_idx: usize, name: String) -> Option<Uuid> {
         if let Some(project) = self.projects.get_mut(project_idx) {
@@ -200,9 +424,12 @@ impl Store {
                 owner: None,
                 status: ModuleStatus::Pending,
                 progress_score: 0,
+                depends_on: Vec::new(),
+                notes: String::new(),
             };
             let id = module.id;
             project.modules.push(module);
+            self.dirty = true;
             Some(id)
         } else {
             None
@@ -213,17 +440,67 @@ impl Store {
         if let Some(project) = self.projects.get_mut(project_idx) {
             if let Some(module) = project.modules.iter_mut().find(|m| m.id == module_id) {
                 module.name = name;
+                self.dirty = true;
                 return true;
             }
         }
         false
     }
 
+    /// Replaces a module's notes; an empty string clears them
+    pub fn set_module_notes(&mut self, project_idx: usize, module_id: Uuid, notes: String) -> bool {
+        if let Some(project) = self.projects.get_mut(project_idx) {
+            if let Some(module) = project.modules.iter_mut().find(|m| m.id == module_id) {
+                module.notes = notes;
+                self.dirty = true;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Sets a module's progress, clamped to 100
+    pub fn set_module_progress(&mut self, project_idx: usize, module_id: Uuid, score: u8) -> bool {
+        if let Some(project) = self.projects.get_mut(project_idx) {
+            if let Some(module) = project.modules.iter_mut().find(|m| m.id == module_id) {
+                module.progress_score = score.min(100);
+                self.dirty = true;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Moves a module's progress by `delta`, staying within 0–100; returns the new value
+    pub fn adjust_module_progress(
+        &mut self,
+        project_idx: usize,
+        module_id: Uuid,
+        delta: i16,
+    ) -> Option<u8> {
+        let current = self
+            .projects
+            .get(project_idx)?
+            .modules
+            .iter()
+            .find(|m| m.id == module_id)?
+            .progress_score;
+        let score = (current as i16 + delta).clamp(0, 100) as u8;
+        self.set_module_progress(project_idx, module_id, score);
+        Some(score)
+    }
+
     pub fn delete_module(&mut self, project_idx: usize, module_id: Uuid) -> bool {
         if let Some(project) = self.projects.get_mut(project_idx) {
             let len_before = project.modules.len();
             project.modules.retain(|m| m.id != module_id);
-            project.modules.len() < len_before
+            let removed = project.modules.len() < len_before;
+            for module in &mut project.modules {
+                module.depends_on.retain(|&id| id != module_id);
+            }
+            project.status_history.retain(|(id, ..)| *id != module_id);
+            self.dirty |= removed;
+            removed
         } else {
             false
         }
@@ -238,12 +515,80 @@ impl Store {
         if let Some(project) = self.projects.get_mut(project_idx) {
             if let Some(module) = project.modules.iter_mut().find(|m| m.id == module_id) {
                 module.owner = developer_id;
+                self.dirty = true;
                 return true;
             }
         }
         false
     }
 
+    // Give every unowned module to one developer; returns how many were assigned
+    pub fn assign_all_unassigned(&mut self, project_idx: usize, developer_id: Uuid) -> usize {
+        let Some(project) = self.projects.get_mut(project_idx) else {
+            return 0;
+        };
+        if !project.developers.iter().any(|d| d.id == developer_id) {
+            return 0;
+        }
+        let mut assigned = 0;
+        for module in project.modules.iter_mut().filter(|m| m.owner.is_none()) {
+            module.owner = Some(developer_id);
+            assigned += 1;
+        }
+        self.dirty |= assigned > 0;
+        assigned
+    }
+
+    /// Makes `module_id` depend on `dependency_id`. Refuses self-dependencies,
+    /// duplicates, unknown modules, and anything that would form a cycle.
+    pub fn add_dependency(
+        &mut self,
+        project_idx: usize,
+        module_id: Uuid,
+        dependency_id: Uuid,
+    ) -> bool {
+        let Some(project) = self.projects.get_mut(project_idx) else {
+            return false;
+        };
+        if module_id == dependency_id
+            || !project.modules.iter().any(|m| m.id == dependency_id)
+            || project.depends_on(dependency_id, module_id)
+        {
+            return false;
+        }
+        let Some(module) = project.modules.iter_mut().find(|m| m.id == module_id) else {
+            return false;
+        };
+        if module.depends_on.contains(&dependency_id) {
+            return false;
+        }
+        module.depends_on.push(dependency_id);
+        self.dirty = true;
+        true
+    }
+
+    pub fn remove_dependency(
+        &mut self,
+        project_idx: usize,
+        module_id: Uuid,
+        dependency_id: Uuid,
+    ) -> bool {
+        let Some(module) = self
+            .projects
+            .get_mut(project_idx)
+            .and_then(|p| p.modules.iter_mut().find(|m| m.id == module_id))
+        else {
+            return false;
+        };
+        let len_before = module.depends_on.len();
+        module.depends_on.retain(|&id| id != dependency_id);
+        let removed = module.depends_on.len() < len_before;
+        self.dirty |= removed;
+        removed
+    }
+
+    /// Sets a module's status, adding an entry to the project's status
+    /// history when it changes
     pub fn set_module_status(
         &mut self,
         project_idx: usize,
@@ -252,7 +597,13 @@ impl Store {
     ) -> bool {
         if let Some(project) = self.projects.get_mut(project_idx) {
             if let Some(module) = project.modules.iter_mut().find(|m| m.id == module_id) {
-                module.status = status;
+                if module.status != status {
+                    module.status = status;
+                    project
+                        .status_history
+                        .push((module_id, status, std::time::SystemTime::now()));
+                }
+                self.dirty = true;
                 return true;
             }
         }
@@ -260,6 +611,13 @@ impl Store {
     }
 
     // CRUD operations for developers
+    /// Developers of the given project; empty if there is no such project
+    pub fn developers(&self, project_idx: usize) -> &[Developer] {
+        self.projects
+            .get(project_idx)
+            .map_or(&[], |p| p.developers.as_slice())
+    }
+
     pub fn add_developer(&mut self, project_idx: usize, name: String) -> Option<Uuid> {
         if let Some(project) = self.projects.get_mut(project_idx) {
             let developer = Developer {
@@ -268,12 +626,29 @@ impl Store {
             };
             let id = developer.id;
             project.developers.push(developer);
+            self.dirty = true;
             Some(id)
         } else {
             None
         }
     }
 
+    pub fn update_developer(
+        &mut self,
+        project_idx: usize,
+        developer_id: Uuid,
+        name: String,
+    ) -> bool {
+        if let Some(project) = self.projects.get_mut(project_idx) {
+            if let Some(developer) = project.developers.iter_mut().find(|d| d.id == developer_id) {
+                developer.name = name;
+                self.dirty = true;
+                return true;
+            }
+        }
+        false
+    }
+
     pub fn delete_developer(&mut self, project_idx: usize, developer_id: Uuid) -> bool {
         if let Some(project) = self.projects.get_mut(project_idx) {
             let len_before = project.developers.len();
@@ -284,7 +659,9 @@ impl Store {
                     module.owner = None;
                 }
             }
-            project.developers.len() < len_before
+            let removed = project.developers.len() < len_before;
+            self.dirty |= removed;
+            removed
         } else {
             false
         }
@@ -304,11 +681,61 @@ impl Store {
                         id: Uuid::new_v4(),
                         name,
                     });
+                    self.dirty = true;
                 }
             }
         }
     }
 }
+
+/// Case-insensitive status name; the error names the unrecognised text
+fn parse_module_status(text: &str) -> Result<ModuleStatus, String> {
+    match text.to_lowercase().as_str() {
+        "pending" => Ok(ModuleStatus::Pending),
+        "current" => Ok(ModuleStatus::Current),
+        "completed" => Ok(ModuleStatus::Completed),
+        _ => Err(format!("unknown status {:?}", text)),
+    }
+}
+
+type ImportRow = (String, Result<ModuleStatus, String>, u8);
+
+/// `name,status,progress`, with progress capped at 100
+fn parse_csv_row(line: &str) -> Option<ImportRow> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    let [name, status, progress] = fields[..] else {
+        return None;
+    };
+    let progress: u32 = progress.parse().ok()?;
+    if name.is_empty() {
+        return None;
+    }
+    Some((
+        name.to_string(),
+        parse_module_status(status),
+        progress.min(100) as u8,
+    ))
+}
+
+/// `- [ ] name` is Pending at 0, `- [x] name` Completed at 100
+fn parse_task_item(line: &str) -> Option<ImportRow> {
+    let item = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))?;
+    let (status, progress, name) = if let Some(name) = item.strip_prefix("[ ]") {
+        (ModuleStatus::Pending, 0, name)
+    } else if let Some(name) = item
+        .strip_prefix("[x]")
+        .or_else(|| item.strip_prefix("[X]"))
+    {
+        (ModuleStatus::Completed, 100, name)
+    } else {
+        return None;
+    };
+    let name = name.trim();
+    (!name.is_empty()).then(|| (name.to_string(), Ok(status), progress))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -319,8 +746,13 @@ mod tests {
         let _status_added = FileStatus::Added;
         let _status_deleted = FileStatus::Deleted;
 
-        // Verify Copy trait works
-        let status_copy = _status_modified;
+        let _status_renamed = FileStatus::Renamed {
+            from: "old.rs".into(),
+            to: "new.rs".into(),
+        };
+
+        // Verify Clone works
+        let status_copy = _status_modified.clone();
         assert!(matches!(status_copy, FileStatus::Modified));
     }
 
@@ -333,6 +765,9 @@ mod tests {
             local_preview: Some("local changes".to_string()),
             incoming_preview: Some("incoming changes".to_string()),
             staged: true,
+            is_binary: false,
+            hidden_lines: 0,
+            is_submodule: false,
         };
 
         assert_eq!(change.path, "src/main.rs");
@@ -374,6 +809,8 @@ mod tests {
             owner: Some(dev_id),
             status: ModuleStatus::Current,
             progress_score: 50,
+            depends_on: Vec::new(),
+            notes: String::new(),
         };
 
         assert_eq!(module.name, "Authentication");
@@ -392,6 +829,7 @@ mod tests {
             changes: Vec::new(),
             modules: Vec::new(),
             developers: Vec::new(),
+            status_history: Vec::new(),
         };
 
         assert_eq!(project.name, "MyProject");
@@ -416,6 +854,7 @@ mod tests {
             changes: Vec::new(),
             modules: Vec::new(),
             developers: Vec::new(),
+            status_history: Vec::new(),
         };
 
         store.projects.push(project);
@@ -431,6 +870,8 @@ mod tests {
             owner: None,
             status: ModuleStatus::Current,
             progress_score: 50,
+            depends_on: Vec::new(),
+            notes: String::new(),
         };
 
         let project = Project {
@@ -441,6 +882,7 @@ mod tests {
             changes: Vec::new(),
             modules: vec![module],
             developers: Vec::new(),
+            status_history: Vec::new(),
         };
 
         store.projects.push(project);
@@ -460,6 +902,8 @@ mod tests {
             owner: None,
             status: ModuleStatus::Current,
             progress_score: 95,
+            depends_on: Vec::new(),
+            notes: String::new(),
         };
 
         let project = Project {
@@ -470,6 +914,7 @@ mod tests {
             changes: Vec::new(),
             modules: vec![module],
             developers: Vec::new(),
+            status_history: Vec::new(),
         };
 
         store.projects.push(project);
@@ -488,6 +933,8 @@ mod tests {
             owner: None,
             status: ModuleStatus::Pending,
             progress_score: 0,
+            depends_on: Vec::new(),
+            notes: String::new(),
         };
 
         let project = Project {
@@ -498,6 +945,7 @@ mod tests {
             changes: Vec::new(),
             modules: vec![module_pending],
             developers: Vec::new(),
+            status_history: Vec::new(),
         };
 
         store.projects.push(project);
@@ -518,6 +966,7 @@ mod tests {
             changes: Vec::new(),
             modules: Vec::new(),
             developers: Vec::new(),
+            status_history: Vec::new(),
         };
 
         store.projects.push(project);
@@ -542,6 +991,7 @@ mod tests {
             changes: Vec::new(),
             modules: Vec::new(),
             developers: vec![developer],
+            status_history: Vec::new(),
         };
 
         store.projects.push(project);
@@ -568,6 +1018,7 @@ mod tests {
             changes: Vec::new(),
             modules: Vec::new(),
             developers: Vec::new(),
+            status_history: Vec::new(),
         };
 
         store.projects.push(project);
@@ -597,6 +1048,7 @@ mod tests {
             changes: Vec::new(),
             modules: Vec::new(),
             developers: vec![developer],
+            status_history: Vec::new(),
         };
 
         store.projects.push(project);
@@ -613,4 +1065,428 @@ mod tests {
             "Only new developer should be added"
         );
     }
+
+    #[test]
+    fn test_assign_all_unassigned() {
+        let mut store = Store::new();
+        let lead = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let module = |name: &str, owner: Option<Uuid>| Module {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            owner,
+            status: ModuleStatus::Pending,
+            progress_score: 0,
+            depends_on: Vec::new(),
+            notes: String::new(),
+        };
+
+        store.projects.push(Project {
+            id: Uuid::new_v4(),
+            name: "Test".to_string(),
+            description: "".to_string(),
+            branch: "main".to_string(),
+            changes: Vec::new(),
+            modules: vec![
+                module("Owned", Some(other)),
+                module("Free A", None),
+                module("Free B", None),
+            ],
+            developers: vec![
+                Developer {
+                    id: lead,
+                    name: "Lead".to_string(),
+                },
+                Developer {
+                    id: other,
+                    name: "Other".to_string(),
+                },
+            ],
+            status_history: Vec::new(),
+        });
+
+        assert_eq!(store.assign_all_unassigned(0, lead), 2);
+        let owners: Vec<Option<Uuid>> = store.projects[0].modules.iter().map(|m| m.owner).collect();
+        assert_eq!(owners, vec![Some(other), Some(lead), Some(lead)]);
+
+        // Nothing left to assign, and unknown developers/projects are ignored
+        assert_eq!(store.assign_all_unassigned(0, lead), 0);
+        assert_eq!(store.assign_all_unassigned(0, Uuid::new_v4()), 0);
+        assert_eq!(store.assign_all_unassigned(5, lead), 0);
+    }
+
+    #[test]
+    fn test_project_progress() {
+        let mut store = Store::new();
+        store.projects.push(Project {
+            id: Uuid::new_v4(),
+            name: "Empty".to_string(),
+            description: "".to_string(),
+            branch: "main".to_string(),
+            changes: Vec::new(),
+            modules: Vec::new(),
+            developers: Vec::new(),
+            status_history: Vec::new(),
+        });
+        assert_eq!(store.project_progress(0), None);
+        assert_eq!(store.project_progress(3), None);
+
+        store.projects[0].modules = [
+            (ModuleStatus::Completed, 100),
+            (ModuleStatus::Current, 45),
+            (ModuleStatus::Pending, 0),
+        ]
+        .into_iter()
+        .map(|(status, progress_score)| Module {
+            id: Uuid::new_v4(),
+            name: "Module".to_string(),
+            owner: None,
+            status,
+            progress_score,
+            depends_on: Vec::new(),
+            notes: String::new(),
+        })
+        .collect();
+        assert_eq!(store.project_progress(0), Some(48));
+    }
+
+    fn named_project(name: &str, module_count: usize) -> Project {
+        Project {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            description: "".to_string(),
+            branch: "main".to_string(),
+            changes: Vec::new(),
+            modules: (0..module_count)
+                .map(|i| Module {
+                    id: Uuid::new_v4(),
+                    name: format!("Module {}", i),
+                    owner: None,
+                    status: ModuleStatus::Pending,
+                    progress_score: 0,
+                    depends_on: Vec::new(),
+                    notes: String::new(),
+                })
+                .collect(),
+            developers: Vec::new(),
+            status_history: Vec::new(),
+        }
+    }
+
+    fn sorted_names(mode: ProjectSort) -> Vec<String> {
+        let mut projects = vec![
+            named_project("beta", 1),
+            named_project("Alpha", 3),
+            named_project("gamma", 2),
+        ];
+        sort_projects(&mut projects, mode);
+        projects.into_iter().map(|p| p.name).collect()
+    }
+
+    #[test]
+    fn test_sort_projects_name_asc() {
+        assert_eq!(
+            sorted_names(ProjectSort::NameAsc),
+            ["Alpha", "beta", "gamma"]
+        );
+    }
+
+    #[test]
+    fn test_sort_projects_name_desc() {
+        assert_eq!(
+            sorted_names(ProjectSort::NameDesc),
+            ["gamma", "beta", "Alpha"]
+        );
+    }
+
+    #[test]
+    fn test_sort_projects_most_modules() {
+        assert_eq!(
+            sorted_names(ProjectSort::MostModules),
+            ["Alpha", "gamma", "beta"]
+        );
+    }
+
+    #[test]
+    fn test_project_sort_cycles() {
+        let start = ProjectSort::NameAsc;
+        assert_eq!(start.next().next().next(), start);
+    }
+
+    #[test]
+    fn test_dirty_flag_set_on_mutation_and_cleared_by_save() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 0));
+        assert!(!store.is_dirty());
+
+        // Nothing to write while clean
+        let state_dir = dir.path().join(".git/forge");
+        assert!(!store.save_if_dirty(dir.path(), &state_dir, true).unwrap());
+
+        store.add_module(0, "Auth".to_string());
+        assert!(store.is_dirty());
+
+        assert!(store.save_if_dirty(dir.path(), &state_dir, true).unwrap());
+        assert!(!store.is_dirty());
+        assert!(state_dir.join("progress.txt").exists());
+        assert!(dir.path().join(".forge/modules.json").exists());
+
+        // Failed mutations leave the store clean
+        assert!(!store.update_module(0, Uuid::new_v4(), "Missing".to_string()));
+        assert!(!store.update_developer(0, Uuid::new_v4(), "Missing".to_string()));
+        assert!(!store.is_dirty());
+    }
+
+    #[test]
+    fn test_incomplete_dependency_blocks_module() {
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 2));
+        let (base, feature) = (
+            store.projects[0].modules[0].id,
+            store.projects[0].modules[1].id,
+        );
+
+        assert!(store.add_dependency(0, feature, base));
+        let blocker = store.projects[0].blocking_dependency(feature);
+        assert_eq!(blocker.map(|m| m.id), Some(base));
+
+        // The dependency itself is free to start
+        assert!(store.projects[0].blocking_dependency(base).is_none());
+    }
+
+    #[test]
+    fn test_completed_dependency_allows_module() {
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 2));
+        let (base, feature) = (
+            store.projects[0].modules[0].id,
+            store.projects[0].modules[1].id,
+        );
+        store.add_dependency(0, feature, base);
+
+        store.set_module_status(0, base, ModuleStatus::Completed);
+        assert!(store.projects[0].blocking_dependency(feature).is_none());
+
+        // Removing the dependency also unblocks
+        store.set_module_status(0, base, ModuleStatus::Current);
+        assert!(store.remove_dependency(0, feature, base));
+        assert!(store.projects[0].blocking_dependency(feature).is_none());
+    }
+
+    #[test]
+    fn test_add_dependency_rejects_self_duplicates_and_cycles() {
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 3));
+        let ids: Vec<Uuid> = store.projects[0].modules.iter().map(|m| m.id).collect();
+
+        assert!(!store.add_dependency(0, ids[0], ids[0]));
+        assert!(store.add_dependency(0, ids[0], ids[1]));
+        assert!(!store.add_dependency(0, ids[0], ids[1]));
+        assert!(store.add_dependency(0, ids[1], ids[2]));
+        assert!(!store.add_dependency(0, ids[2], ids[0]), "cycle via ids[1]");
+
+        // Deleting a module drops it from other modules' dependencies
+        store.delete_module(0, ids[1]);
+        assert!(store.projects[0].modules[0].depends_on.is_empty());
+    }
+
+    #[test]
+    fn test_import_modules_csv() {
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 1));
+        let csv =
+            "name,status,progress\nParser,Current,40\nLexer, completed ,100\n\nDocs,pending,0\n";
+
+        let result = store.import_modules_csv(0, csv).unwrap();
+        assert_eq!(
+            result,
+            ModuleImport {
+                imported: 3,
+                warnings: Vec::new()
+            }
+        );
+        assert!(store.is_dirty());
+
+        let modules = &store.projects[0].modules;
+        assert_eq!(modules.len(), 4);
+        assert_eq!(modules[1].name, "Parser");
+        assert_eq!(modules[1].status, ModuleStatus::Current);
+        assert_eq!(modules[1].progress_score, 40);
+        assert_eq!(modules[2].status, ModuleStatus::Completed);
+        assert_ne!(modules[1].id, modules[2].id);
+
+        assert_eq!(
+            store.import_modules_csv(5, csv),
+            Err("no project at index 5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_import_unknown_status_defaults_to_pending() {
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 0));
+
+        let result = store.import_modules_csv(0, "Search,blocked,250").unwrap();
+        assert_eq!(
+            result,
+            ModuleImport {
+                imported: 1,
+                warnings: vec!["line 1: unknown status \"blocked\"".to_string()]
+            }
+        );
+        let module = &store.projects[0].modules[0];
+        assert_eq!(module.status, ModuleStatus::Pending);
+        assert_eq!(module.progress_score, 100, "progress is capped");
+    }
+
+    #[test]
+    fn test_import_skips_malformed_rows() {
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 0));
+        let text = "# Sprint 4\n- [x] Login\n- [ ] Logout\nonly,two\n,Pending,10\nCache,Current,lots\nApi,Current,5\n";
+
+        let result = store.import_modules_csv(0, text).unwrap();
+        assert_eq!(
+            result,
+            ModuleImport {
+                imported: 3,
+                warnings: vec![
+                    "line 4: malformed row".to_string(),
+                    "line 5: malformed row".to_string(),
+                    "line 6: malformed row".to_string(),
+                ]
+            }
+        );
+        let names: Vec<&str> = store.projects[0]
+            .modules
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(names, ["Login", "Logout", "Api"]);
+        assert_eq!(store.projects[0].modules[0].status, ModuleStatus::Completed);
+
+        // Nothing imported leaves the store clean
+        let mut empty = Store::new();
+        empty.projects.push(named_project("Empty", 0));
+        empty.import_modules_csv(0, "bad row").unwrap();
+        assert!(!empty.is_dirty());
+    }
+
+    #[test]
+    fn test_module_notes_persist() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 1));
+        let id = store.projects[0].modules[0].id;
+
+        assert!(store.set_module_notes(0, id, "Waiting on API keys".to_string()));
+        assert!(store.is_dirty());
+        assert!(!store.set_module_notes(0, Uuid::new_v4(), "Missing".to_string()));
+        store.save_to_json(dir.path()).unwrap();
+
+        let mut loaded = Store::new();
+        loaded.projects.push(named_project("Test", 0));
+        loaded.load_from_json(dir.path()).unwrap();
+        assert_eq!(loaded.projects[0].modules[0].notes, "Waiting on API keys");
+    }
+
+    #[test]
+    fn test_status_change_appends_timeline_entry() {
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 2));
+        let first = store.projects[0].modules[0].id;
+        let second = store.projects[0].modules[1].id;
+        let before = std::time::SystemTime::now();
+
+        assert!(store.set_module_status(0, first, ModuleStatus::Current));
+        assert!(store.set_module_status(0, second, ModuleStatus::Completed));
+        assert!(store.set_module_status(0, first, ModuleStatus::Completed));
+        // Setting the status a module already has is not a transition
+        assert!(store.set_module_status(0, first, ModuleStatus::Completed));
+
+        let history = &store.projects[0].status_history;
+        assert_eq!(history.len(), 3);
+        assert!(history.iter().all(|&(_, _, at)| at >= before));
+        let statuses: Vec<ModuleStatus> = store.projects[0]
+            .module_timeline(first)
+            .map(|(status, _)| status)
+            .collect();
+        assert_eq!(statuses, [ModuleStatus::Current, ModuleStatus::Completed]);
+
+        // Deleting a module forgets its history
+        assert!(store.delete_module(0, first));
+        assert_eq!(store.projects[0].status_history.len(), 1);
+        assert_eq!(store.projects[0].status_history[0].0, second);
+    }
+
+    #[test]
+    fn test_status_history_round_trips_through_json() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 1));
+        let id = store.projects[0].modules[0].id;
+        store.set_module_status(0, id, ModuleStatus::Current);
+        store.set_module_status(0, id, ModuleStatus::Completed);
+        store.save_to_json(dir.path()).unwrap();
+        assert!(dir.path().join(".forge/timeline.json").exists());
+
+        let mut loaded = Store::new();
+        loaded.projects.push(named_project("Test", 0));
+        loaded.load_from_json(dir.path()).unwrap();
+        assert_eq!(
+            loaded.projects[0].status_history,
+            store.projects[0].status_history
+        );
+    }
+
+    #[test]
+    fn test_set_module_progress_clamps() {
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 1));
+        let id = store.projects[0].modules[0].id;
+
+        assert!(store.set_module_progress(0, id, 42));
+        assert_eq!(store.projects[0].modules[0].progress_score, 42);
+        assert!(store.is_dirty());
+        assert!(store.set_module_progress(0, id, 250));
+        assert_eq!(store.projects[0].modules[0].progress_score, 100);
+        assert!(!store.set_module_progress(0, Uuid::new_v4(), 10));
+        assert!(!store.set_module_progress(5, id, 10));
+    }
+
+    #[test]
+    fn test_adjust_module_progress_stays_in_range() {
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 1));
+        let id = store.projects[0].modules[0].id;
+        store.set_module_progress(0, id, 97);
+
+        assert_eq!(store.adjust_module_progress(0, id, 5), Some(100));
+        assert_eq!(store.adjust_module_progress(0, id, -5), Some(95));
+        store.set_module_progress(0, id, 3);
+        assert_eq!(store.adjust_module_progress(0, id, -5), Some(0));
+        assert_eq!(store.adjust_module_progress(0, Uuid::new_v4(), 5), None);
+    }
+
+    #[test]
+    fn test_load_modules_without_notes_field() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join(".forge")).unwrap();
+        let id = Uuid::new_v4();
+        std::fs::write(
+            dir.path().join(".forge/modules.json"),
+            format!(
+                r#"[{{"id":"{id}","name":"Auth","owner":null,"status":"Pending","progress_score":0}}]"#
+            ),
+        )
+        .unwrap();
+
+        let mut store = Store::new();
+        store.projects.push(named_project("Test", 0));
+        store.load_from_json(dir.path()).unwrap();
+        let module = &store.projects[0].modules[0];
+        assert_eq!(module.id, id);
+        assert_eq!(module.notes, "");
+    }
 }
@@ -14,7 +14,7 @@
 //! - UI layer should handle errors gracefully and display user-friendly messages
 //!
 //! **Known Edge Cases:**
-//! 1. **Missing or corrupted HEAD**: `head_branch()` returns `None`, operations may fail
+//! 1. **Missing or corrupted HEAD**: `head_branch()` returns `HeadRef::Unborn`, operations may fail
 //! 2. **Corrupted index**: `list_changes()`, `stage_file()`, and `commit_all()` will error
 //! 3. **Missing objects**: Diff operations may fail silently, returning empty strings
 //! 4. **Invalid references**: Branch operations may fail with obscure error messages
@@ -33,12 +33,18 @@
 //! - UI should never panic on Git errors - display errors in status bar instead
 //! - Benchmark code tracks errors via `is_err()` checks (see benches/git_operations.rs)
 
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 use color_eyre::eyre::Result;
-use git2::{DiffFormat, DiffOptions, IndexAddOption, Repository, Signature, StatusOptions, Tree};
+use git2::{
+    ApplyLocation, Diff, DiffDelta, DiffFindOptions, DiffFormat, DiffLine, DiffOptions,
+    IndexAddOption, Repository, Signature, StatusOptions, Tree,
+};
 
 use crate::data::{Change, FileStatus};
 
@@ -74,12 +80,430 @@ impl TransferProgress {
     }
 }
 
-/// Commit info: (hash, author, date, message, files_changed)
-pub type CommitData = (String, String, String, String, Vec<String>);
+/// Error returned by working-tree operations on a bare repository
+pub const BARE_REPO_MESSAGE: &str = "bare repository \u{2014} no working tree";
+
+/// Error returned when a commit would record the same tree as HEAD
+pub const NOTHING_TO_COMMIT_MESSAGE: &str = "nothing to commit";
+
+/// Error returned when another process holds `.git/index.lock`
+pub const INDEX_LOCKED_MESSAGE: &str =
+    "Repository index is locked \u{2014} is another git process running?";
+
+/// Author shown by blame for staged lines that are in no commit yet
+pub const NOT_COMMITTED_AUTHOR: &str = "Not committed yet";
+
+/// Note placed above patch text that had bytes which are not valid UTF-8
+const NON_UTF8_NOTE: &str = "(contains non-UTF-8 content; invalid bytes shown as \u{FFFD})";
+
+/// Lines kept in each diff preview unless [`GitClientOptions`] says otherwise
+pub const DEFAULT_PREVIEW_LINE_CAP: usize = 500;
+
+/// Largest file diffed unless [`GitClientOptions`] says otherwise
+pub const DEFAULT_MAX_DIFF_BYTES: u64 = 1024 * 1024;
+
+/// Patch text for a single path, as shown in the diff previews
+struct PathDiff {
+    text: String,
+    is_binary: bool,
+    /// Lines left out after the preview line cap
+    hidden_lines: usize,
+}
+
+/// Collects the printed lines of a diff into a [`PathDiff`]
+#[derive(Default)]
+struct PatchBuilder {
+    out: String,
+    binary_size: Option<u64>,
+    non_utf8: bool,
+    shown_lines: usize,
+    hidden_lines: usize,
+    truncated: bool,
+}
+
+impl PatchBuilder {
+    fn push(&mut self, delta: &DiffDelta, line: &DiffLine, line_cap: usize) {
+        if delta.flags().is_binary() || line.origin() == 'B' {
+            let file = if delta.new_file().exists() {
+                delta.new_file()
+            } else {
+                delta.old_file()
+            };
+            self.binary_size = Some(file.size());
+            return;
+        }
+        // Count newlines: file headers arrive as one multi-line chunk, and
+        // a "no newline at end of file" note ends the line before it
+        let line_count = line.content().iter().filter(|&&b| b == b'\n').count();
+        self.truncated |= line_cap > 0 && self.shown_lines + line_count > line_cap;
+        if self.truncated {
+            self.hidden_lines += line_count;
+            return;
+        }
+        self.shown_lines += line_count;
+        // Content lines carry their +/-/space marker in `origin`, not `content`
+        if matches!(line.origin(), '+' | '-' | ' ') {
+            self.out.push(line.origin());
+        }
+        let content = String::from_utf8_lossy(line.content());
+        self.non_utf8 |= matches!(content, std::borrow::Cow::Owned(_));
+        self.out.push_str(&content);
+    }
+
+    /// `max_bytes` is the diff size cap the diff was made with; larger files
+    /// come through as binary and are reported as too large instead
+    fn finish(mut self, max_bytes: u64) -> Option<PathDiff> {
+        if self.non_utf8 {
+            self.out.insert_str(0, &format!("{}\n", NON_UTF8_NOTE));
+        }
+        if self.truncated {
+            self.hidden_lines = self.hidden_lines.max(1);
+            if !self.out.ends_with('\n') {
+                self.out.push('\n');
+            }
+            self.out.push_str(&format!(
+                "\u{2026} ({} more lines, press + to expand)\n",
+                self.hidden_lines
+            ));
+        }
+        if let Some(size) = self
+            .binary_size
+            .filter(|&size| max_bytes > 0 && size > max_bytes)
+        {
+            Some(PathDiff {
+                text: format!("File too large to diff ({} bytes)", size),
+                is_binary: false,
+                hidden_lines: 0,
+            })
+        } else if let Some(size) = self.binary_size {
+            Some(PathDiff {
+                text: format!("Binary file ({} bytes)", size),
+                is_binary: true,
+                hidden_lines: 0,
+            })
+        } else if self.out.is_empty() {
+            None
+        } else {
+            Some(PathDiff {
+                text: self.out,
+                is_binary: false,
+                hidden_lines: self.hidden_lines,
+            })
+        }
+    }
+}
+
+/// What HEAD currently points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeadRef {
+    /// On a local branch, by short name
+    Branch(String),
+    /// At a commit rather than a branch, by abbreviated hash
+    Detached(String),
+    /// No commit yet, or HEAD cannot be read
+    Unborn,
+}
+
+impl HeadRef {
+    /// Branch name, if HEAD is on a branch
+    pub fn branch(&self) -> Option<&str> {
+        match self {
+            HeadRef::Branch(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Text shown wherever the current branch is displayed
+    pub fn label(&self) -> String {
+        match self {
+            HeadRef::Branch(name) => name.clone(),
+            HeadRef::Detached(short) => format!("(detached at {})", short),
+            HeadRef::Unborn => "(no commits yet)".to_string(),
+        }
+    }
+}
+
+/// Last commit to touch one line of a file, as reported by blame
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlameLine {
+    /// Abbreviated hash of the commit
+    pub short_hash: String,
+    /// Author name of the commit
+    pub author: String,
+}
+
+/// Pairs each line of `diff` with the blame of the old-side line it shows.
+///
+/// Context and removed lines are looked up in `blame` (one entry per line of
+/// the committed file); added lines, headers and hunk markers get `None`.
+pub fn align_blame<'a>(diff: &str, blame: &'a [BlameLine]) -> Vec<Option<&'a BlameLine>> {
+    let mut old_line = 0usize;
+    let mut in_hunk = false;
+    diff.lines()
+        .map(|line| {
+            if let Some(header) = line.strip_prefix("@@ -") {
+                in_hunk = true;
+                old_line = header
+                    .split([',', ' '])
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or(0);
+                return None;
+            }
+            if line.starts_with("diff ") {
+                in_hunk = false;
+            }
+            if !in_hunk || !(line.starts_with(' ') || line.starts_with('-')) {
+                return None;
+            }
+            let entry = old_line.checked_sub(1).and_then(|i| blame.get(i));
+            old_line += 1;
+            entry
+        })
+        .collect()
+}
+
+/// Callbacks shared by clone, fetch and push.
+///
+/// Credentials come from the SSH agent, then `~/.ssh/id_rsa`, then
+/// `config`'s credential helper. Transfer statistics are copied into
+/// `progress`, and setting `cancel_flag` stops the transfer.
+fn remote_callbacks(
+    config: git2::Config,
+    progress: Option<Arc<Mutex<TransferProgress>>>,
+    cancel_flag: Option<Arc<AtomicBool>>,
+) -> git2::RemoteCallbacks<'static> {
+    let mut callbacks = git2::RemoteCallbacks::new();
+
+    // Credential callback for authentication
+    callbacks.credentials(move |url, username_from_url, allowed_types| {
+        // Check if SSH is allowed
+        if allowed_types.is_ssh_key() {
+            // Try SSH agent first
+            if let Some(username) = username_from_url {
+                if let Ok(cred) = git2::Cred::ssh_key_from_agent(username) {
+                    return Ok(cred);
+                }
+            }
+
+            // Try default SSH key locations
+            if let Some(username) = username_from_url.or_else(|| url.split('@').next()) {
+                if let Ok(home) = std::env::var("HOME") {
+                    let ssh_key = std::path::PathBuf::from(&home).join(".ssh/id_rsa");
+                    if ssh_key.exists() {
+                        if let Ok(cred) = git2::Cred::ssh_key(username, None, &ssh_key, None) {
+                            return Ok(cred);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Try username/password for HTTPS
+        if allowed_types.is_user_pass_plaintext() {
+            if let Ok(cred) = git2::Cred::credential_helper(&config, url, username_from_url) {
+                return Ok(cred);
+            }
+        }
+
+        // Fallback to default credentials
+        git2::Cred::default()
+    });
+
+    // Transfer progress callback
+    if let Some(progress_tracker) = progress {
+        callbacks.transfer_progress(move |stats| {
+            if let Ok(mut p) = progress_tracker.lock() {
+                p.total_objects = stats.total_objects();
+                p.indexed_objects = stats.indexed_objects();
+                p.received_objects = stats.received_objects();
+                p.received_bytes = stats.received_bytes();
+                p.total_deltas = stats.total_deltas();
+                p.indexed_deltas = stats.indexed_deltas();
+            }
+
+            // Check cancellation flag
+            if let Some(ref cancel) = cancel_flag {
+                if cancel.load(Ordering::Relaxed) {
+                    return false; // Cancel the operation
+                }
+            }
+
+            true // Continue
+        });
+    }
+
+    callbacks
+}
+
+/// Directory name `git clone` would pick for `url`: its last path segment
+/// without a trailing `.git` or `/.git`, e.g. `forge` for `git@github.com:me/forge.git`.
+pub fn clone_dir_name(url: &str) -> String {
+    let url = url.trim_end_matches(['/', '\\']);
+    let name = url
+        .strip_suffix("/.git")
+        .unwrap_or(url)
+        .rsplit(['/', '\\', ':'])
+        .next()
+        .unwrap_or_default();
+    let name = name.strip_suffix(".git").unwrap_or(name);
+    if name.is_empty() {
+        "repository".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Commit info: (hash, author, date, message, files_changed, timestamp, author_email)
+///
+/// `timestamp` is the commit time in seconds since the Unix epoch.
+pub type CommitData = (String, String, String, String, Vec<String>, i64, String);
+
+/// Where a walk of the history from HEAD stopped, so the next page of
+/// [`GitClient::commit_history_page`] resumes it instead of walking from
+/// HEAD again. The default cursor starts at HEAD.
+#[derive(Debug, Clone, Default)]
+pub struct HistoryCursor {
+    started: bool,
+    /// Commits waiting to be visited, newest commit time first; ties go to
+    /// the one queued earliest so children come before their parents
+    pending: BinaryHeap<(i64, Reverse<u64>, git2::Oid)>,
+    /// Every commit ever queued, so a merge base is visited once
+    seen: HashSet<git2::Oid>,
+    queued: u64,
+}
+
+impl HistoryCursor {
+    fn queue(&mut self, commit: &git2::Commit) {
+        if self.seen.insert(commit.id()) {
+            self.queued += 1;
+            self.pending
+                .push((commit.time().seconds(), Reverse(self.queued), commit.id()));
+        }
+    }
+}
+
+/// Conflicted file contents: (local, incoming), `None` for a deleted side
+pub type ConflictSides = (Option<Vec<u8>>, Option<Vec<u8>>);
+
+/// Whether `path` is a file this user may execute
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Produces a detached, ASCII-armored signature over raw commit content.
+pub trait CommitSigner {
+    fn sign(&self, content: &str) -> Result<String>;
+}
+
+/// How [`GitClient::commit`] records a commit
+#[derive(Default, Clone, Copy)]
+pub struct CommitOptions<'a> {
+    /// Run the repository's `pre-commit` and `commit-msg` hooks first
+    pub run_hooks: bool,
+    /// Sign the commit; signing failures never fall back to unsigned
+    pub signer: Option<&'a dyn CommitSigner>,
+    /// Commit even when the index matches HEAD, like `git commit --allow-empty`
+    pub allow_empty: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigningFormat {
+    OpenPgp,
+    Ssh,
+}
+
+/// Signs commits by shelling out to `gpg` or `ssh-keygen`, as git does.
+#[derive(Debug, Clone)]
+pub struct ConfiguredSigner {
+    pub key: String,
+    pub format: SigningFormat,
+}
+
+impl CommitSigner for ConfiguredSigner {
+    fn sign(&self, content: &str) -> Result<String> {
+        use std::io::Write;
+        use std::process::{Command, Stdio};
+
+        let mut command = match self.format {
+            SigningFormat::OpenPgp => {
+                let mut cmd = Command::new("gpg");
+                cmd.args(["--status-fd=2", "-bsau", &self.key]);
+                cmd
+            }
+            SigningFormat::Ssh => {
+                let mut cmd = Command::new("ssh-keygen");
+                cmd.args(["-Y", "sign", "-n", "git", "-f", &self.key]);
+                cmd
+            }
+        };
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to start signing program: {}", e))?;
+        child
+            .stdin
+            .take()
+            .ok_or_else(|| color_eyre::eyre::eyre!("Signing program has no stdin"))?
+            .write_all(content.as_bytes())?;
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(color_eyre::eyre::eyre!(
+                "Signing failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8(output.stdout)?)
+    }
+}
+
+/// Which files `list_changes` reports, and how remotes are fetched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct GitClientOptions {
+    /// Report files matched by `.gitignore`
+    pub include_ignored: bool,
+    /// Report files git does not track yet
+    pub include_untracked: bool,
+    /// List the files inside untracked directories rather than the directory
+    pub recurse_untracked: bool,
+    /// Lines kept in each diff preview; the rest are only read by
+    /// [`GitClient::expand_change`]. 0 keeps every line.
+    pub preview_line_cap: usize,
+    /// Files larger than this many bytes are not diffed. 0 diffs every file.
+    pub max_diff_bytes: u64,
+    /// Delete remote-tracking branches that no longer exist on the remote
+    pub prune_on_fetch: bool,
+}
+
+impl Default for GitClientOptions {
+    fn default() -> Self {
+        Self {
+            include_ignored: false,
+            include_untracked: true,
+            recurse_untracked: true,
+            preview_line_cap: DEFAULT_PREVIEW_LINE_CAP,
+            max_diff_bytes: DEFAULT_MAX_DIFF_BYTES,
+            prune_on_fetch: false,
+        }
+    }
+}
 
 pub struct GitClient {
     repo: Repository,
     pub workdir: PathBuf,
+    options: GitClientOptions,
 }
 
 impl GitClient {
@@ -90,7 +514,8 @@ impl GitClient {
     /// # Edge Cases
     ///
     /// - **Corrupted repo**: Returns `Err` if `.git` directory is malformed
-    /// - **Bare repo**: Handles bare repositories by using repo path as workdir
+    /// - **Bare repo**: Uses the repo directory's parent as workdir; history and
+    ///   branch listing work, working-tree operations return [`BARE_REPO_MESSAGE`]
     /// - **Submodules**: Discovers parent repo, not submodule (libgit2 behavior)
     /// - **Missing workdir**: Returns error if workdir cannot be determined
     ///
@@ -101,30 +526,314 @@ impl GitClient {
     /// - Repository structure is corrupted
     /// - Unable to determine working directory
     pub fn discover(start: impl AsRef<Path>) -> Result<Self> {
+        Self::open_with_options(start, GitClientOptions::default())
+    }
+
+    /// Discover a repository like [`GitClient::discover`], reporting changes
+    /// according to `options`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`GitClient::discover`].
+    pub fn open_with_options(start: impl AsRef<Path>, options: GitClientOptions) -> Result<Self> {
         let repo = Repository::discover(start)?;
         let workdir = repo
             .workdir()
             .map(Path::to_path_buf)
             .or_else(|| repo.path().parent().map(Path::to_path_buf))
             .ok_or_else(|| color_eyre::eyre::eyre!("Unable to determine workdir"))?;
-        Ok(Self { repo, workdir })
+        Ok(Self {
+            repo,
+            workdir,
+            options,
+        })
+    }
+
+    /// Clone `url` into `dest` and open the new repository.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`GitClient::clone_repo_with_progress`].
+    pub fn clone_repo(url: &str, dest: &Path) -> Result<Self> {
+        Self::clone_repo_with_progress(url, dest, None, None)
+    }
+
+    /// Clone `url` into `dest` with progress tracking and open the new
+    /// repository.
+    ///
+    /// Credentials are tried like [`GitClient::fetch_with_progress`], using
+    /// the global git configuration since there is no repository yet.
+    ///
+    /// # Errors
+    ///
+    /// - `dest` exists and is not an empty directory
+    /// - Authentication failed (SSH/HTTPS credentials)
+    /// - Network errors or `url` is not a repository
+    /// - Operation was cancelled
+    pub fn clone_repo_with_progress(
+        url: &str,
+        dest: &Path,
+        progress: Option<Arc<Mutex<TransferProgress>>>,
+        cancel_flag: Option<Arc<AtomicBool>>,
+    ) -> Result<Self> {
+        let callbacks = remote_callbacks(git2::Config::open_default()?, progress, cancel_flag);
+
+        let mut fetch_options = git2::FetchOptions::new();
+        fetch_options.remote_callbacks(callbacks);
+        git2::build::RepoBuilder::new()
+            .fetch_options(fetch_options)
+            .clone(url, dest)?;
+
+        Self::discover(dest)
+    }
+
+    /// Options currently used by `list_changes`.
+    pub fn options(&self) -> GitClientOptions {
+        self.options
+    }
+
+    /// Changes which files later `list_changes` calls report.
+    pub fn set_options(&mut self, options: GitClientOptions) {
+        self.options = options;
+    }
+
+    /// Directory for Forge's state shared by every worktree: `forge/` in
+    /// the repository's common git directory. Resolved through git rather
+    /// than `.git/forge`, since `.git` is a file in linked worktrees and
+    /// submodules.
+    pub fn state_dir(&self) -> PathBuf {
+        self.repo.commondir().join("forge")
+    }
+
+    /// Directory for Forge's state belonging to this worktree alone, such as
+    /// an in-progress merge; the same as [`Self::state_dir`] in the main one.
+    pub fn worktree_state_dir(&self) -> PathBuf {
+        self.repo.path().join("forge")
+    }
+
+    /// Returns `true` if the repository has no working tree.
+    pub fn is_bare(&self) -> bool {
+        self.repo.is_bare()
+    }
+
+    /// Returns `true` while a merge is in progress (`MERGE_HEAD` exists).
+    pub fn is_merging(&self) -> bool {
+        self.repo.state() == git2::RepositoryState::Merge
+    }
+
+    /// Fails with [`BARE_REPO_MESSAGE`] when there is no working tree to act on
+    fn require_worktree(&self) -> Result<()> {
+        if self.repo.is_bare() {
+            return Err(color_eyre::eyre::eyre!(BARE_REPO_MESSAGE));
+        }
+        Ok(())
+    }
+
+    /// Writes `index` to disk, naming the usual cause when its lock is taken
+    fn write_index(index: &mut git2::Index) -> Result<()> {
+        index.write().map_err(|e| {
+            if e.code() == git2::ErrorCode::Locked {
+                color_eyre::eyre::eyre!(INDEX_LOCKED_MESSAGE)
+            } else {
+                e.into()
+            }
+        })
     }
 
-    /// Get the current branch name.
+    /// Get what HEAD points at: a branch, a detached commit, or nothing yet.
+    ///
+    /// # Edge Cases
     ///
-    /// Returns `None` in edge cases rather than erroring.
+    /// - **Detached HEAD**: `Detached` with the commit's abbreviated hash
+    /// - **Empty repo**: `Unborn` (no commits or HEAD yet)
+    /// - **Corrupted HEAD**: `Unborn` (cannot read `.git/HEAD`)
+    pub fn head_branch(&self) -> HeadRef {
+        let Ok(head) = self.repo.head() else {
+            return HeadRef::Unborn;
+        };
+        if self.is_head_detached() {
+            return match head.target() {
+                Some(oid) => HeadRef::Detached(self.short_hash(oid)),
+                None => HeadRef::Unborn,
+            };
+        }
+        match head.shorthand() {
+            Some(name) => HeadRef::Branch(name.to_string()),
+            None => HeadRef::Unborn,
+        }
+    }
+
+    /// Whether HEAD points directly at a commit rather than a branch.
+    pub fn is_head_detached(&self) -> bool {
+        self.repo.head_detached().unwrap_or(false)
+    }
+
+    /// Whether the index and working tree match HEAD. Untracked files count
+    /// as changes, ignored files do not; a bare repository is always clean.
+    pub fn is_clean(&self) -> Result<bool> {
+        if self.repo.is_bare() {
+            return Ok(true);
+        }
+        let mut opts = StatusOptions::new();
+        opts.include_untracked(true).include_ignored(false);
+        Ok(self.repo.statuses(Some(&mut opts))?.is_empty())
+    }
+
+    /// Lines inserted and deleted by staged and unstaged changes together,
+    /// measured against HEAD.
     ///
     /// # Edge Cases
     ///
-    /// - **Detached HEAD**: Returns `None` (HEAD points to commit, not branch)
-    /// - **Empty repo**: Returns `None` (no commits or HEAD yet)
-    /// - **Corrupted HEAD**: Returns `None` (cannot read `.git/HEAD`)
-    /// - **Initial state**: Returns `None` before first commit
-    pub fn head_branch(&self) -> Option<String> {
-        self.repo
-            .head()
-            .ok()
-            .and_then(|h| h.shorthand().map(|s| s.to_string()))
+    /// - **Untracked files**: Not counted until staged
+    /// - **Unborn HEAD**: Everything in the index counts as inserted
+    /// - **Binary files**: Contribute no lines
+    /// - **Bare repository**: Returns `(0, 0)`
+    pub fn uncommitted_stats(&self) -> Result<(usize, usize)> {
+        if self.repo.is_bare() {
+            return Ok((0, 0));
+        }
+        let head = self.head_tree();
+        let diff = self
+            .repo
+            .diff_tree_to_workdir_with_index(head.as_ref(), None)?;
+        let stats = diff.stats()?;
+        Ok((stats.insertions(), stats.deletions()))
+    }
+
+    /// Paths with unresolved merge conflicts in the index, in index order.
+    /// Empty when no merge is in progress; a bare repository has none.
+    pub fn list_conflicts(&self) -> Result<Vec<String>> {
+        if self.repo.is_bare() {
+            return Ok(Vec::new());
+        }
+        let index = self.repo.index()?;
+        if !index.has_conflicts() {
+            return Ok(Vec::new());
+        }
+        let mut paths = Vec::new();
+        for conflict in index.conflicts()? {
+            let conflict = conflict?;
+            // A side is missing when the file was added or deleted on one branch
+            let entry = conflict.our.or(conflict.their).or(conflict.ancestor);
+            if let Some(path) = entry.and_then(|e| String::from_utf8(e.path).ok()) {
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+
+    /// Contents of the local ("ours") and incoming ("theirs") sides of a
+    /// conflicted file; a side is `None` when that branch deleted the file.
+    ///
+    /// # Errors
+    ///
+    /// - `path` has no conflict in the index
+    pub fn conflict_sides(&self, path: &str) -> Result<ConflictSides> {
+        let index = self.repo.index()?;
+        let conflict = index
+            .conflict_get(Path::new(path))
+            .map_err(|_| color_eyre::eyre::eyre!("{} is not in conflict", path))?;
+        let blob = |entry: Option<git2::IndexEntry>| -> Result<Option<Vec<u8>>> {
+            entry
+                .map(|e| Ok(self.repo.find_blob(e.id)?.content().to_vec()))
+                .transpose()
+        };
+        Ok((blob(conflict.our)?, blob(conflict.their)?))
+    }
+
+    /// Writes `contents` as the resolution of a conflicted file and stages
+    /// it, which clears the conflict from the index.
+    pub fn resolve_conflict(&self, path: &str, contents: &[u8]) -> Result<()> {
+        self.require_worktree()?;
+        let workdir = self
+            .repo
+            .workdir()
+            .ok_or_else(|| color_eyre::eyre::eyre!(BARE_REPO_MESSAGE))?;
+        std::fs::write(workdir.join(path), contents)?;
+        self.stage_file(path)
+    }
+
+    /// Working tree status with this client's [`GitClientOptions`] applied,
+    /// shared by [`Self::list_changes`] and [`Self::status_porcelain`]
+    fn scan_status(&self) -> Result<git2::Statuses<'_>> {
+        self.require_worktree()?;
+        let mut opts = StatusOptions::new();
+        opts.include_untracked(self.options.include_untracked)
+            .recurse_untracked_dirs(self.options.recurse_untracked)
+            .include_ignored(self.options.include_ignored)
+            .recurse_ignored_dirs(self.options.include_ignored && self.options.recurse_untracked)
+            .renames_head_to_index(true)
+            .renames_index_to_workdir(true);
+        Ok(self.repo.statuses(Some(&mut opts))?)
+    }
+
+    /// The status scan behind [`Self::list_changes`], formatted like
+    /// `git status --porcelain=v1`
+    ///
+    /// One `XY path` line per entry, where X is the index state and Y the
+    /// working tree state; renames read `R  old -> new`, untracked files
+    /// `?? path` and ignored files `!! path`. Useful for checking forge's
+    /// view against git's own.
+    ///
+    /// # Edge Cases
+    ///
+    /// - **Conflicts**: Reported as `UU` whatever the kind of conflict
+    /// - **Special characters**: Paths are written as-is, never quoted
+    pub fn status_porcelain(&self) -> Result<String> {
+        let statuses = self.scan_status()?;
+        let mut out = String::new();
+        for entry in statuses.iter() {
+            let status = entry.status();
+            let code = if status.is_conflicted() {
+                "UU".to_string()
+            } else if status.is_ignored() {
+                "!!".to_string()
+            } else if status.is_wt_new() && !status.is_index_new() {
+                "??".to_string()
+            } else {
+                let index = if status.is_index_new() {
+                    'A'
+                } else if status.is_index_modified() {
+                    'M'
+                } else if status.is_index_deleted() {
+                    'D'
+                } else if status.is_index_renamed() {
+                    'R'
+                } else if status.is_index_typechange() {
+                    'T'
+                } else {
+                    ' '
+                };
+                let worktree = if status.is_wt_modified() {
+                    'M'
+                } else if status.is_wt_deleted() {
+                    'D'
+                } else if status.is_wt_renamed() {
+                    'R'
+                } else if status.is_wt_typechange() {
+                    'T'
+                } else {
+                    ' '
+                };
+                format!("{}{}", index, worktree)
+            };
+
+            let rename = if status.is_index_renamed() {
+                entry.head_to_index()
+            } else if status.is_wt_renamed() {
+                entry.index_to_workdir()
+            } else {
+                None
+            }
+            .and_then(|d| Some((d.old_file().path()?, d.new_file().path()?)));
+            let path = match (rename, entry.path()) {
+                (Some((from, to)), _) => format!("{} -> {}", from.display(), to.display()),
+                (None, Some(p)) => p.to_string(),
+                (None, None) => continue,
+            };
+            out.push_str(&format!("{} {}\n", code, path));
+        }
+        Ok(out)
     }
 
     /// List all changes in the working directory and staging area.
@@ -133,9 +842,11 @@ impl GitClient {
     ///
     /// - **Corrupted index**: Returns `Err` - caller should display error to user
     /// - **Large repos**: May be slow (1000s of files) - consider showing spinner
-    /// - **Untracked files**: Included by default (can be filtered by caller)
-    /// - **Ignored files**: Excluded (per `.gitignore` rules)
-    /// - **Submodules**: Shown as modified files, not expanded
+    /// - **Untracked files**: Included by default, previewed as all additions;
+    ///   see [`GitClientOptions`]
+    /// - **Ignored files**: Excluded by default (per `.gitignore` rules); when
+    ///   included they are reported as added
+    /// - **Submodules**: Dirty ones are shown as modified, with `is_submodule` set, not expanded
     /// - **Invalid UTF-8**: Paths with invalid UTF-8 are skipped (logged to stderr)
     /// - **Missing objects**: Diffs may be empty if referenced objects are missing
     ///
@@ -145,23 +856,41 @@ impl GitClient {
     /// - Filesystem permissions prevent reading files
     /// - Repository structure is corrupted
     pub fn list_changes(&self) -> Result<Vec<Change>> {
-        let mut opts = StatusOptions::new();
-        opts.include_untracked(true)
-            .recurse_untracked_dirs(true)
-            .include_ignored(false);
-
-        let statuses = self.repo.statuses(Some(&mut opts))?;
+        let statuses = self.scan_status()?;
         let mut changes = Vec::new();
+        // One diff per side for the whole repo, rather than one per path
+        let line_cap = self.options.preview_line_cap;
+        let mut workdir_diffs = self.workdir_diffs(line_cap);
+        let mut staged_diffs = self.staged_diffs(line_cap);
 
         for entry in statuses.iter() {
-            let path = match entry.path() {
-                Some(p) => p.to_string(),
-                None => continue,
-            };
-
             let status = entry.status();
+            // For renames `entry.path()` is the old path; the delta has both sides
+            let rename = if status.is_index_renamed() {
+                entry.head_to_index()
+            } else if status.is_wt_renamed() {
+                entry.index_to_workdir()
+            } else {
+                None
+            }
+            .and_then(|d| {
+                let from = d.old_file().path()?.to_str()?.to_string();
+                let to = d.new_file().path()?.to_str()?.to_string();
+                Some((from, to))
+            });
+
+            let (path, renamed_from) = match (rename, entry.path()) {
+                (Some((from, to)), _) => (to, Some(from)),
+                (None, Some(p)) => (p.to_string(), None),
+                (None, None) => continue,
+            };
             // Map git status to our simplified FileStatus
-            let file_status = if status.is_wt_new() || status.is_index_new() {
+            let file_status = if let Some(from) = &renamed_from {
+                FileStatus::Renamed {
+                    from: from.clone(),
+                    to: path.clone(),
+                }
+            } else if status.is_wt_new() || status.is_index_new() || status.is_ignored() {
                 FileStatus::Added
             } else if status.is_wt_deleted() || status.is_index_deleted() {
                 FileStatus::Deleted
@@ -169,15 +898,11 @@ impl GitClient {
                 FileStatus::Modified
             };
 
-            let local_preview = self
-                .diff_index_to_workdir_for_path(&path)
-                .or_else(|| self.diff_for_path(&path));
-            let incoming_preview = self.diff_head_to_index_for_path(&path);
-            let diff_preview = local_preview
-                .as_ref()
-                .or(incoming_preview.as_ref())
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| "(no diff)".into());
+            // Renames need both sides paired up, so they keep the per-path diffs
+            let (local, incoming) = match &renamed_from {
+                Some(from) => self.path_previews(&[from.as_str(), path.as_str()], line_cap),
+                None => (workdir_diffs.remove(&path), staged_diffs.remove(&path)),
+            };
 
             let staged = status.is_index_new()
                 || status.is_index_modified()
@@ -185,99 +910,419 @@ impl GitClient {
                 || status.is_index_renamed()
                 || status.is_index_typechange();
 
-            changes.push(Change {
+            let mut change = Change {
                 path,
                 status: file_status,
-                diff_preview,
-                local_preview,
-                incoming_preview,
+                diff_preview: String::new(),
+                local_preview: None,
+                incoming_preview: None,
                 staged,
-            });
+                is_binary: false,
+                hidden_lines: 0,
+                is_submodule: false,
+            };
+            Self::fill_previews(&mut change, local, incoming);
+            changes.push(change);
+        }
+
+        // The scan lists most dirty submodules as modified files; tag those, and add
+        // any that a `submodule.<name>.ignore` setting kept out of it
+        for (path, dirty) in self.submodule_status().unwrap_or_default() {
+            if !dirty {
+                continue;
+            }
+            match changes.iter_mut().find(|c| c.path == path) {
+                Some(change) => change.is_submodule = true,
+                None => changes.push(Change {
+                    diff_preview: format!(
+                        "Submodule {} is at another commit or has local changes",
+                        path
+                    ),
+                    path,
+                    status: FileStatus::Modified,
+                    local_preview: None,
+                    incoming_preview: None,
+                    staged: false,
+                    is_binary: false,
+                    hidden_lines: 0,
+                    is_submodule: true,
+                }),
+            }
         }
 
         Ok(changes)
     }
 
-    fn diff_for_path(&self, path: &str) -> Option<String> {
-        let mut opts = DiffOptions::new();
-        opts.pathspec(path);
-        // Compare index to workdir to show staged+unstaged deltas
-        let diff = self
-            .repo
-            .diff_index_to_workdir(None, Some(&mut opts))
-            .ok()?;
-        let mut out = String::new();
-        let _ = diff.print(DiffFormat::Patch, |_delta, _hunk, line| {
-            out.push_str(std::str::from_utf8(line.content()).unwrap_or(""));
-            true
-        });
-        if out.is_empty() {
-            None
-        } else {
-            Some(out)
+    /// Each submodule's path and whether it is dirty: checked out at another
+    /// commit than the one recorded, or holding uncommitted or untracked files
+    ///
+    /// # Edge Cases
+    ///
+    /// - **Uninitialized submodules**: Reported clean; there is no checkout to compare
+    pub fn submodule_status(&self) -> Result<Vec<(String, bool)>> {
+        let mut out = Vec::new();
+        for submodule in self.repo.submodules()? {
+            let Some(name) = submodule.name() else {
+                continue;
+            };
+            let path = submodule.path().to_string_lossy().into_owned();
+            let status = self
+                .repo
+                .submodule_status(name, git2::SubmoduleIgnore::None)?;
+            let dirty = status.is_wd_modified()
+                || status.intersects(git2::SubmoduleStatus::WD_INDEX_MODIFIED)
+                || status.is_wd_wd_modified()
+                || status.is_wd_untracked();
+            out.push((path, dirty));
         }
+        Ok(out)
     }
 
-    fn diff_index_to_workdir_for_path(&self, path: &str) -> Option<String> {
-        let mut opts = DiffOptions::new();
-        opts.pathspec(path);
-        let diff = self
-            .repo
-            .diff_index_to_workdir(None, Some(&mut opts))
-            .ok()?;
-        let mut out = String::new();
-        let _ = diff.print(DiffFormat::Patch, |_delta, _hunk, line| {
-            out.push_str(std::str::from_utf8(line.content()).unwrap_or(""));
-            true
-        });
-        if out.is_empty() {
-            None
-        } else {
-            Some(out)
-        }
+    /// Re-reads `change`'s previews without the line cap, for a diff that
+    /// [`GitClientOptions::preview_line_cap`] cut short.
+    pub fn expand_change(&self, change: &mut Change) {
+        let paths: Vec<String> = match &change.status {
+            FileStatus::Renamed { from, to } => vec![from.clone(), to.clone()],
+            _ => vec![change.path.clone()],
+        };
+        let paths: Vec<&str> = paths.iter().map(String::as_str).collect();
+        let (local, incoming) = self.path_previews(&paths, 0);
+        Self::fill_previews(change, local, incoming);
     }
 
-    fn head_tree(&self) -> Option<Tree<'_>> {
-        self.repo.head().ok()?.peel_to_tree().ok()
+    /// Working-tree and staged diffs of `paths`, each cut at `line_cap` lines
+    fn path_previews(
+        &self,
+        paths: &[&str],
+        line_cap: usize,
+    ) -> (Option<PathDiff>, Option<PathDiff>) {
+        let local = self
+            .diff_index_to_workdir_for_path(paths, line_cap)
+            .or_else(|| self.diff_for_path(paths, line_cap))
+            .or_else(|| {
+                let path = paths.last()?;
+                self.untracked_preview(path, line_cap)
+            });
+        let incoming = self.diff_head_to_index_for_path(paths, line_cap);
+        (local, incoming)
     }
 
-    fn diff_head_to_index_for_path(&self, path: &str) -> Option<String> {
-        let head = self.head_tree()?;
-        let mut opts = DiffOptions::new();
-        opts.pathspec(path);
-        let mut index = self.repo.index().ok()?;
-        let index_tree = self.repo.find_tree(index.write_tree().ok()?).ok()?;
+    /// Working-tree diffs of every file, keyed by path; untracked files show
+    /// their content as additions
+    fn workdir_diffs(&self, line_cap: usize) -> HashMap<String, PathDiff> {
+        let mut opts = self.diff_options();
+        opts.include_untracked(true).show_untracked_content(true);
+        self.repo
+            .diff_index_to_workdir(None, Some(&mut opts))
+            .map(|diff| Self::patch_texts(&diff, line_cap, self.options.max_diff_bytes))
+            .unwrap_or_default()
+    }
+
+    /// Staged diffs of every file, keyed by path
+    fn staged_diffs(&self, line_cap: usize) -> HashMap<String, PathDiff> {
+        let diff = || -> Option<Diff<'_>> {
+            // On an unborn HEAD everything in the index is an addition
+            let head = self.head_tree();
+            let mut index = self.repo.index().ok()?;
+            let index_tree = self.repo.find_tree(index.write_tree().ok()?).ok()?;
+            self.repo
+                .diff_tree_to_tree(
+                    head.as_ref(),
+                    Some(&index_tree),
+                    Some(&mut self.diff_options()),
+                )
+                .ok()
+        };
+        diff()
+            .map(|diff| Self::patch_texts(&diff, line_cap, self.options.max_diff_bytes))
+            .unwrap_or_default()
+    }
+
+    /// Sets the preview fields of `change`, preferring the working-tree diff
+    fn fill_previews(change: &mut Change, local: Option<PathDiff>, incoming: Option<PathDiff>) {
+        let shown = local.as_ref().or(incoming.as_ref());
+        change.diff_preview = shown
+            .map(|d| d.text.clone())
+            .unwrap_or_else(|| "(no diff)".into());
+        change.hidden_lines = shown.map_or(0, |d| d.hidden_lines);
+        change.is_binary = local.iter().chain(incoming.iter()).any(|d| d.is_binary);
+        change.local_preview = local.map(|d| d.text);
+        change.incoming_preview = incoming.map(|d| d.text);
+    }
+
+    /// An untracked file's content as an all-additions diff, subject to the
+    /// same line cap, size limit and binary detection as other previews.
+    /// `None` for untracked directories, which are listed without recursing.
+    fn untracked_preview(&self, path: &str, line_cap: usize) -> Option<PathDiff> {
+        if !self.workdir.join(path).is_file() {
+            return None;
+        }
+        let mut opts = self.diff_options();
+        opts.pathspec(path)
+            .disable_pathspec_match(true)
+            .include_untracked(true)
+            .show_untracked_content(true);
         let diff = self
             .repo
-            .diff_tree_to_tree(Some(&head), Some(&index_tree), Some(&mut opts))
+            .diff_index_to_workdir(None, Some(&mut opts))
             .ok()?;
-        let mut out = String::new();
-        let _ = diff.print(DiffFormat::Patch, |_delta, _hunk, line| {
-            out.push_str(std::str::from_utf8(line.content()).unwrap_or(""));
+        Self::patch_text(&diff, line_cap, self.options.max_diff_bytes)
+    }
+
+    fn diff_for_path(&self, paths: &[&str], line_cap: usize) -> Option<PathDiff> {
+        let mut opts = self.path_diff_options(paths);
+        // Compare index to workdir to show staged+unstaged deltas
+        let mut diff = self
+            .repo
+            .diff_index_to_workdir(None, Some(&mut opts))
+            .ok()?;
+        Self::find_renames(&mut diff);
+        Self::patch_text(&diff, line_cap, self.options.max_diff_bytes)
+    }
+
+    fn diff_index_to_workdir_for_path(&self, paths: &[&str], line_cap: usize) -> Option<PathDiff> {
+        let mut opts = self.path_diff_options(paths);
+        let mut diff = self
+            .repo
+            .diff_index_to_workdir(None, Some(&mut opts))
+            .ok()?;
+        Self::find_renames(&mut diff);
+        Self::patch_text(&diff, line_cap, self.options.max_diff_bytes)
+    }
+
+    /// Diff options limited to `paths`. A rename passes both of its paths, and
+    /// its untracked new side is included so it can be paired with the old one.
+    fn path_diff_options(&self, paths: &[&str]) -> DiffOptions {
+        let mut opts = self.diff_options();
+        for path in paths {
+            opts.pathspec(path);
+        }
+        if paths.len() > 1 {
+            opts.include_untracked(true).show_untracked_content(true);
+        }
+        opts
+    }
+
+    /// Diff options that treat files over [`GitClientOptions::max_diff_bytes`]
+    /// as binary, so their content is never read into a patch
+    fn diff_options(&self) -> DiffOptions {
+        let mut opts = DiffOptions::new();
+        if self.options.max_diff_bytes > 0 {
+            opts.max_size(i64::try_from(self.options.max_diff_bytes).unwrap_or(i64::MAX));
+        }
+        opts
+    }
+
+    /// Collapse delete + add pairs of similar files into single rename deltas
+    fn find_renames(diff: &mut git2::Diff) {
+        let mut find = DiffFindOptions::new();
+        find.renames(true).for_untracked(true);
+        let _ = diff.find_similar(Some(&mut find));
+    }
+
+    /// Render a diff as patch text, replacing binary content with a short summary.
+    /// Bytes that are not valid UTF-8 are replaced rather than dropped, and the
+    /// text is prefixed with [`NON_UTF8_NOTE`]. Past `line_cap` lines (0 for no
+    /// limit) the rest is only counted and a "more lines" marker ends the text.
+    /// Files over `max_bytes` (0 for no limit) get a "too large" note instead.
+    fn patch_text(diff: &git2::Diff, line_cap: usize, max_bytes: u64) -> Option<PathDiff> {
+        let mut patch = PatchBuilder::default();
+        let _ = diff.print(DiffFormat::Patch, |delta, _hunk, line| {
+            patch.push(&delta, &line, line_cap);
             true
         });
-        if out.is_empty() {
-            None
+        patch.finish(max_bytes)
+    }
+
+    /// Like [`Self::patch_text`], but split by file, with `line_cap` applied to
+    /// each file on its own
+    fn patch_texts(
+        diff: &git2::Diff,
+        line_cap: usize,
+        max_bytes: u64,
+    ) -> HashMap<String, PathDiff> {
+        let mut patches: HashMap<String, PatchBuilder> = HashMap::new();
+        let _ = diff.print(DiffFormat::Patch, |delta, _hunk, line| {
+            let path = delta.new_file().path().or(delta.old_file().path());
+            if let Some(path) = path.and_then(Path::to_str) {
+                patches
+                    .entry(path.to_string())
+                    .or_default()
+                    .push(&delta, &line, line_cap);
+            }
+            true
+        });
+        patches
+            .into_iter()
+            .filter_map(|(path, patch)| Some((path, patch.finish(max_bytes)?)))
+            .collect()
+    }
+
+    /// Patch text for everything a commit changed relative to its first parent.
+    ///
+    /// # Edge Cases
+    ///
+    /// - **Root commit**: Diffs against an empty tree
+    /// - **Binary files**: Summarised as "Binary file (N bytes)"
+    /// - **Empty commit**: Returns an empty string
+    pub fn commit_diff(&self, hash: &str) -> Result<String> {
+        let commit = self.repo.revparse_single(hash)?.peel_to_commit()?;
+        let tree = commit.tree()?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+        let diff = self.repo.diff_tree_to_tree(
+            parent_tree.as_ref(),
+            Some(&tree),
+            Some(&mut self.diff_options()),
+        )?;
+        Ok(Self::patch_text(&diff, 0, self.options.max_diff_bytes)
+            .map(|d| d.text)
+            .unwrap_or_default())
+    }
+
+    /// Applies a unified diff to the working tree, and to the index as well
+    /// when `to_index` is set.
+    ///
+    /// # Errors
+    ///
+    /// - The text cannot be parsed as a patch
+    /// - A hunk does not match the current file contents; nothing is
+    ///   written in that case
+    pub fn apply_patch(&self, patch_text: &str, to_index: bool) -> Result<()> {
+        self.require_worktree()?;
+        let diff = Diff::from_buffer(patch_text.as_bytes())
+            .map_err(|e| color_eyre::eyre::eyre!("Invalid patch: {}", e.message()))?;
+        let location = if to_index {
+            ApplyLocation::Both
         } else {
-            Some(out)
+            ApplyLocation::WorkDir
+        };
+        self.repo
+            .apply(&diff, location, None)
+            .map_err(|e| color_eyre::eyre::eyre!("Patch does not apply cleanly: {}", e.message()))
+    }
+
+    /// Blames the committed version of `path`, one entry per line.
+    ///
+    /// # Errors
+    ///
+    /// - The file has never been committed
+    pub fn blame_file(&self, path: &str) -> Result<Vec<BlameLine>> {
+        let blame = self.repo.blame_file(Path::new(path), None)?;
+        Ok(self.blame_lines(&blame))
+    }
+
+    /// Blames the staged version of `path`, one entry per line, for diffs
+    /// whose old side is the index. Staged lines not yet in any commit are
+    /// attributed to [`NOT_COMMITTED_AUTHOR`].
+    ///
+    /// # Errors
+    ///
+    /// - The file has never been committed
+    /// - The file is not in the index
+    pub fn blame_staged(&self, path: &str) -> Result<Vec<BlameLine>> {
+        let committed = self.repo.blame_file(Path::new(path), None)?;
+        let index = self.repo.index()?;
+        let entry = index
+            .get_path(Path::new(path), 0)
+            .ok_or_else(|| color_eyre::eyre::eyre!("{} is not in the index", path))?;
+        let blob = self.repo.find_blob(entry.id)?;
+        let blame = committed.blame_buffer(blob.content())?;
+        Ok(self.blame_lines(&blame))
+    }
+
+    fn blame_lines(&self, blame: &git2::Blame) -> Vec<BlameLine> {
+        let mut lines = Vec::new();
+        for hunk in blame.iter() {
+            let entry = if hunk.final_commit_id().is_zero() {
+                BlameLine {
+                    short_hash: "0000000".into(),
+                    author: NOT_COMMITTED_AUTHOR.into(),
+                }
+            } else {
+                BlameLine {
+                    short_hash: self.short_hash(hunk.final_commit_id()),
+                    author: hunk
+                        .final_signature()
+                        .name()
+                        .unwrap_or("unknown")
+                        .to_string(),
+                }
+            };
+            lines.extend(std::iter::repeat_n(entry, hunk.lines_in_hunk()));
+        }
+        lines
+    }
+
+    fn head_tree(&self) -> Option<Tree<'_>> {
+        self.repo.head().ok()?.peel_to_tree().ok()
+    }
+
+    fn diff_head_to_index_for_path(&self, paths: &[&str], line_cap: usize) -> Option<PathDiff> {
+        let head = self.head_tree()?;
+        let mut opts = self.diff_options();
+        for path in paths {
+            opts.pathspec(path);
         }
+        let mut index = self.repo.index().ok()?;
+        let index_tree = self.repo.find_tree(index.write_tree().ok()?).ok()?;
+        let mut diff = self
+            .repo
+            .diff_tree_to_tree(Some(&head), Some(&index_tree), Some(&mut opts))
+            .ok()?;
+        Self::find_renames(&mut diff);
+        Self::patch_text(&diff, line_cap, self.options.max_diff_bytes)
     }
 
+    /// Stages every changed file; see [`GitClient::stage_paths`].
     pub fn stage_all(&self) -> Result<()> {
+        self.stage_paths(&["*"]).map(|_| ())
+    }
+
+    /// Stages the changed files matching any of `specs` (git pathspecs such
+    /// as `src/*`), returning how many were staged.
+    ///
+    /// # Edge Cases
+    ///
+    /// - **Ignored files**: Never staged, even when a pattern names them
+    /// - **No matches**: Returns `Ok(0)` and leaves the index untouched
+    /// - **Locked index**: Fails with [`INDEX_LOCKED_MESSAGE`]
+    pub fn stage_paths(&self, specs: &[&str]) -> Result<usize> {
+        self.require_worktree()?;
         let mut index = self.repo.index()?;
-        index.add_all(["*"], IndexAddOption::DEFAULT, None)?;
-        index.write()?;
-        Ok(())
+        let mut staged = 0;
+        let mut count = |_: &Path, _: &[u8]| {
+            staged += 1;
+            0
+        };
+        index.add_all(specs, IndexAddOption::DEFAULT, Some(&mut count))?;
+        if staged > 0 {
+            Self::write_index(&mut index)?;
+        }
+        Ok(staged)
     }
 
     pub fn stage_file(&self, path: &str) -> Result<()> {
+        self.require_worktree()?;
         let mut index = self.repo.index()?;
-        index.add_path(std::path::Path::new(path))?;
-        index.write()?;
-        Ok(())
+        let path_obj = std::path::Path::new(path);
+        // A deleted file (or the old side of a rename) is staged by dropping it
+        let missing = self
+            .repo
+            .workdir()
+            .is_some_and(|dir| !dir.join(path_obj).exists());
+        if missing {
+            index.remove_path(path_obj)?;
+        } else {
+            index.add_path(path_obj)?;
+        }
+        Self::write_index(&mut index)
     }
 
     pub fn unstage_file(&self, path: &str) -> Result<()> {
+        self.require_worktree()?;
         let mut index = self.repo.index()?;
         // Get HEAD tree
         if let Some(head_tree) = self.head_tree() {
@@ -335,7 +1380,8 @@ impl GitClient {
     ///
     /// # Edge Cases
     ///
-    /// - **Empty commit**: Returns `Err` if no changes are staged
+    /// - **Empty commit**: Fails with [`NOTHING_TO_COMMIT_MESSAGE`] when the
+    ///   index matches HEAD's tree; see [`CommitOptions::allow_empty`]
     /// - **First commit**: Handles initial commit (no parent) correctly
     /// - **Merge state**: May fail if repository is in merge/rebase state
     /// - **Invalid signature**: Uses fallback signature if git config incomplete
@@ -349,6 +1395,93 @@ impl GitClient {
     /// - Index is locked or corrupted
     /// - Cannot write tree or commit object
     pub fn commit_all(&self, message: &str) -> Result<git2::Oid> {
+        self.commit(message, &CommitOptions::default())
+    }
+
+    /// Commit all staged changes with a signature produced by `signer`.
+    ///
+    /// # Edge Cases
+    ///
+    /// - **Signer fails**: Returns `Err` and leaves HEAD untouched; never falls
+    ///   back to an unsigned commit
+    /// - **Detached HEAD**: Moves the detached HEAD to the new commit
+    ///
+    /// # Errors
+    ///
+    /// Same as [`GitClient::commit_all`], plus any error from the signer.
+    pub fn commit_all_signed(&self, message: &str, signer: &dyn CommitSigner) -> Result<git2::Oid> {
+        let options = CommitOptions {
+            signer: Some(signer),
+            ..CommitOptions::default()
+        };
+        self.commit(message, &options)
+    }
+
+    /// Commit all staged changes, optionally running hooks and signing.
+    ///
+    /// git2 never runs hooks itself, so with `run_hooks` set the executable
+    /// `pre-commit` and `commit-msg` hooks are run here, the way `git commit`
+    /// would. `commit-msg` may rewrite the message; the rewritten message is
+    /// the one recorded.
+    ///
+    /// # Edge Cases
+    ///
+    /// - **Missing or non-executable hook**: Skipped, as git does
+    /// - **`core.hooksPath`**: Honoured; relative paths are taken from the
+    ///   working tree
+    /// - **Hook changes the index**: The index is read after `pre-commit`, so
+    ///   files it stages are included
+    ///
+    /// # Errors
+    ///
+    /// Same as [`GitClient::commit_all`], plus a hook exiting non-zero; the
+    /// error carries the hook's output and nothing is committed.
+    pub fn commit(&self, message: &str, options: &CommitOptions) -> Result<git2::Oid> {
+        if !options.run_hooks {
+            return self.create_commit(message, options);
+        }
+        self.require_worktree()?;
+        self.run_hook("pre-commit", &[])?;
+
+        let message_path = self.repo.path().join("COMMIT_EDITMSG");
+        std::fs::write(&message_path, message)?;
+        self.run_hook("commit-msg", &[message_path.as_os_str()])?;
+        let message = std::fs::read_to_string(&message_path)?;
+
+        self.create_commit(&message, options)
+    }
+
+    /// Run the named hook from the working tree if it exists and is executable
+    fn run_hook(&self, name: &str, args: &[&std::ffi::OsStr]) -> Result<()> {
+        let hooks_dir = match self.repo.config()?.get_path("core.hooksPath") {
+            Ok(path) => self.workdir.join(path),
+            Err(_) => self.repo.path().join("hooks"),
+        };
+        let hook = hooks_dir.join(name);
+        if !is_executable(&hook) {
+            return Ok(());
+        }
+
+        let output = std::process::Command::new(&hook)
+            .args(args)
+            .current_dir(&self.workdir)
+            .output()
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to run {} hook: {}", name, e))?;
+        if output.status.success() {
+            return Ok(());
+        }
+        let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+        text.push_str(&String::from_utf8_lossy(&output.stderr));
+        let text = text.trim();
+        Err(if text.is_empty() {
+            color_eyre::eyre::eyre!("{} hook failed", name)
+        } else {
+            color_eyre::eyre::eyre!("{} hook failed: {}", name, text)
+        })
+    }
+
+    fn create_commit(&self, message: &str, options: &CommitOptions) -> Result<git2::Oid> {
+        self.require_worktree()?;
         let mut index = self.repo.index()?;
         let tree_id = index.write_tree()?;
         let tree = self.repo.find_tree(tree_id)?;
@@ -366,20 +1499,207 @@ impl GitClient {
             }
             Err(_) => Vec::new(),
         };
+        let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
 
-        let oid = if parents.is_empty() {
-            // Initial commit
-            self.repo
-                .commit(Some("HEAD"), &sig, &sig, message, &tree, &[])?
-        } else {
-            let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
-            self.repo
-                .commit(Some("HEAD"), &sig, &sig, message, &tree, &parent_refs)?
+        let unchanged = match parents.first() {
+            Some(parent) => parent.tree_id() == tree_id,
+            None => tree.is_empty(),
+        };
+        if unchanged && !options.allow_empty {
+            return Err(color_eyre::eyre::eyre!(NOTHING_TO_COMMIT_MESSAGE));
+        }
+
+        let Some(signer) = options.signer else {
+            return Ok(self
+                .repo
+                .commit(Some("HEAD"), &sig, &sig, message, &tree, &parent_refs)?);
         };
 
+        // Signed commits are written without touching refs, so HEAD (or the
+        // branch it points to) is advanced by hand afterwards
+        let buffer = self
+            .repo
+            .commit_create_buffer(&sig, &sig, message, &tree, &parent_refs)?;
+        let content = buffer
+            .as_str()
+            .ok_or_else(|| color_eyre::eyre::eyre!("Commit content is not valid UTF-8"))?;
+        let signature = signer.sign(content)?;
+        let oid = self.repo.commit_signed(content, &signature, None)?;
+
+        let head = self.repo.find_reference("HEAD")?;
+        match head.symbolic_target() {
+            Some(branch_ref) => {
+                self.repo
+                    .reference(branch_ref, oid, true, &format!("commit: {}", message))?;
+            }
+            None => self.repo.set_head_detached(oid)?,
+        }
+
+        Ok(oid)
+    }
+
+    /// Replace the last `n` commits on HEAD with a single commit.
+    ///
+    /// The new commit has HEAD's tree, the given message, and the author of
+    /// the oldest squashed commit; its parent is the commit before that one.
+    /// HEAD then moves to it like a soft reset, so staged and unstaged
+    /// changes are left as they were.
+    ///
+    /// # Edge Cases
+    ///
+    /// - **Whole history** (`n` equals the commit count): the result is a new
+    ///   root commit
+    /// - **Merge commits**: only first parents are followed
+    ///
+    /// # Errors
+    ///
+    /// - `n` is zero, or larger than the commits reachable from HEAD
+    /// - HEAD has no commits
+    pub fn squash_last(&self, n: usize, message: &str) -> Result<git2::Oid> {
+        if n == 0 {
+            return Err(color_eyre::eyre::eyre!("Nothing to squash"));
+        }
+        let head = self.repo.head()?.peel_to_commit()?;
+        let mut oldest = head.clone();
+        for _ in 1..n {
+            oldest = oldest.parent(0).map_err(|_| {
+                color_eyre::eyre::eyre!("Cannot squash {} commits; history is shorter", n)
+            })?;
+        }
+        let parent = oldest.parent(0).ok();
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+
+        let committer = self.default_signature()?;
+        let oid = self.repo.commit(
+            None,
+            &oldest.author(),
+            &committer,
+            message,
+            &head.tree()?,
+            &parents,
+        )?;
+        self.repo.reset(
+            self.repo.find_commit(oid)?.as_object(),
+            git2::ResetType::Soft,
+            None,
+        )?;
         Ok(oid)
     }
 
+    /// Change the message of `hash`, an earlier commit on the current branch.
+    ///
+    /// Works like an interactive-rebase "reword": the commit is recreated
+    /// with `new_message`, and every commit after it is replayed on top with
+    /// its original tree, author, committer and message. The branch (or
+    /// detached HEAD) then moves to the replayed tip. Returns the reworded
+    /// commit's new id.
+    ///
+    /// # Edge Cases
+    ///
+    /// - **Target is HEAD**: Nothing to replay; HEAD moves to the new commit
+    /// - **Working tree changes**: Untouched, since every tree is unchanged
+    ///
+    /// # Errors
+    ///
+    /// - `hash` does not resolve, or is not on HEAD's first-parent history
+    /// - A merge commit lies between the target and HEAD (replaying it is
+    ///   not supported and would need conflict resolution)
+    pub fn reword_commit(&self, hash: &str, new_message: &str) -> Result<git2::Oid> {
+        let target = self.repo.revparse_single(hash)?.peel_to_commit()?;
+
+        // Commits after the target, newest first
+        let mut descendants = Vec::new();
+        let mut commit = self.repo.head()?.peel_to_commit()?;
+        while commit.id() != target.id() {
+            if commit.parent_count() > 1 {
+                return Err(color_eyre::eyre::eyre!(
+                    "Cannot reword across merge commit {}; replaying it would need conflict resolution",
+                    self.short_hash(commit.id())
+                ));
+            }
+            let parent = commit.parent(0).map_err(|_| {
+                color_eyre::eyre::eyre!("Commit {} is not in the current branch's history", hash)
+            })?;
+            descendants.push(commit);
+            commit = parent;
+        }
+
+        let parents: Vec<git2::Commit> = target.parents().collect();
+        let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
+        let reworded = self.repo.commit(
+            None,
+            &target.author(),
+            &target.committer(),
+            new_message,
+            &target.tree()?,
+            &parent_refs,
+        )?;
+
+        let mut tip = reworded;
+        for old in descendants.iter().rev() {
+            let parent = self.repo.find_commit(tip)?;
+            tip = self.repo.commit(
+                None,
+                &old.author(),
+                &old.committer(),
+                old.message_raw().unwrap_or(""),
+                &old.tree()?,
+                &[&parent],
+            )?;
+        }
+
+        let head = self.repo.find_reference("HEAD")?;
+        match head.symbolic_target() {
+            Some(branch_ref) => {
+                self.repo.reference(
+                    branch_ref,
+                    tip,
+                    true,
+                    &format!("reword: {}", new_message.lines().next().unwrap_or("")),
+                )?;
+            }
+            None => self.repo.set_head_detached(tip)?,
+        }
+        Ok(reworded)
+    }
+
+    /// The configured `user.name`, if any.
+    pub fn user_name(&self) -> Option<String> {
+        self.repo.config().ok()?.get_string("user.name").ok()
+    }
+
+    /// The configured `user.email`, if any and not blank.
+    pub fn user_email(&self) -> Option<String> {
+        let email = self.repo.config().ok()?.get_string("user.email").ok()?;
+        (!email.trim().is_empty()).then_some(email)
+    }
+
+    /// Build a signer from the repository's git config.
+    ///
+    /// Reads `user.signingkey` and `gpg.format` (`openpgp` or `ssh`).
+    ///
+    /// # Errors
+    ///
+    /// - No `user.signingkey` is configured
+    /// - `gpg.format` names an unsupported format (e.g. `x509`)
+    pub fn configured_signer(&self) -> Result<ConfiguredSigner> {
+        let config = self.repo.config()?;
+        let key = config.get_string("user.signingkey").map_err(|_| {
+            color_eyre::eyre::eyre!("No signing key configured (set user.signingkey)")
+        })?;
+        let format = match config.get_string("gpg.format").ok().as_deref() {
+            None | Some("openpgp") => SigningFormat::OpenPgp,
+            Some("ssh") => SigningFormat::Ssh,
+            Some(other) => {
+                return Err(color_eyre::eyre::eyre!(
+                    "Unsupported gpg.format '{}' (expected openpgp or ssh)",
+                    other
+                ))
+            }
+        };
+        Ok(ConfiguredSigner { key, format })
+    }
+
     /// Get list of unique committer names from repository history
     pub fn get_committers(&self) -> Result<Vec<String>> {
         let mut names = std::collections::HashSet::new();
@@ -403,7 +1723,8 @@ impl GitClient {
     /// List all branches (local and remote)
     pub fn list_branches(&self, local: bool, remote: bool) -> Result<Vec<(String, bool)>> {
         let mut branches = Vec::new();
-        let current_branch = self.head_branch().unwrap_or_default();
+        let head = self.head_branch();
+        let current_branch = head.branch().unwrap_or_default();
 
         // List local branches
         if local {
@@ -429,167 +1750,548 @@ impl GitClient {
         Ok(branches)
     }
 
-    /// Switch to a different branch
-    pub fn checkout_branch(&self, branch_name: &str) -> Result<()> {
-        let obj = self
+    /// Local branches that have not been published to any remote.
+    ///
+    /// A branch counts as unpublished when it has no upstream configured, or
+    /// when its tip is not contained in any remote-tracking branch.
+    ///
+    /// # Edge Cases
+    ///
+    /// - **No remotes**: Every local branch is reported
+    /// - **Stale remote refs**: Uses the last fetched state; no network access
+    pub fn unpublished_branches(&self) -> Result<Vec<String>> {
+        let remote_tips: Vec<git2::Oid> = self
             .repo
-            .revparse_single(&format!("refs/heads/{}", branch_name))?;
-        self.repo.checkout_tree(&obj, None)?;
-        self.repo.set_head(&format!("refs/heads/{}", branch_name))?;
-        Ok(())
+            .branches(Some(git2::BranchType::Remote))?
+            .flatten()
+            .filter_map(|(branch, _)| branch.get().target())
+            .collect();
+
+        let mut unpublished = Vec::new();
+        for (branch, _) in self.repo.branches(Some(git2::BranchType::Local))?.flatten() {
+            let Some(name) = branch.name()? else {
+                continue;
+            };
+            let Some(tip) = branch.get().target() else {
+                continue;
+            };
+            let has_upstream = branch.upstream().is_ok();
+            let reachable = remote_tips.iter().any(|&remote| {
+                remote == tip || self.repo.graph_descendant_of(remote, tip).unwrap_or(false)
+            });
+            if !has_upstream || !reachable {
+                unpublished.push(name.to_string());
+            }
+        }
+        Ok(unpublished)
     }
 
-    /// Create a new branch from current HEAD
-    pub fn create_branch(&self, branch_name: &str) -> Result<()> {
-        let head = self.repo.head()?;
-        let commit = head.peel_to_commit()?;
-        self.repo.branch(branch_name, &commit, false)?;
-        Ok(())
+    /// Count commits reachable from `to` but not from `from`.
+    ///
+    /// With `from` as the current branch and `to` as another branch this is
+    /// how far `to` is ahead; swap the arguments for how far it is behind.
+    /// Both arguments accept any revision (`main`, `HEAD`, a hash).
+    ///
+    /// # Errors
+    ///
+    /// - Either revision does not resolve to a commit
+    pub fn count_commits_between(&self, from: &str, to: &str) -> Result<usize> {
+        let from = self.repo.revparse_single(from)?.peel_to_commit()?.id();
+        let to = self.repo.revparse_single(to)?.peel_to_commit()?.id();
+
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push(to)?;
+        revwalk.hide(from)?;
+
+        let mut count = 0;
+        for oid in revwalk {
+            oid?;
+            count += 1;
+        }
+        Ok(count)
     }
 
-    /// Delete a branch
-    pub fn delete_branch(&self, branch_name: &str) -> Result<()> {
-        let mut branch = self
-            .repo
-            .find_branch(branch_name, git2::BranchType::Local)?;
-        branch.delete()?;
-        Ok(())
+    /// Steps from HEAD back to `rev` following only first parents, the way
+    /// [`GitClient::squash_last`] walks; 0 when `rev` is HEAD.
+    ///
+    /// # Errors
+    ///
+    /// - `rev` does not resolve to a commit
+    /// - `rev` is not on HEAD's first-parent chain, e.g. it was merged in
+    pub fn first_parent_distance(&self, rev: &str) -> Result<usize> {
+        let target = self.repo.revparse_single(rev)?.peel_to_commit()?.id();
+        let mut commit = self.repo.head()?.peel_to_commit()?;
+        let mut steps = 0;
+        while commit.id() != target {
+            commit = commit.parent(0).map_err(|_| {
+                color_eyre::eyre::eyre!("{} is not on the first-parent history of HEAD", rev)
+            })?;
+            steps += 1;
+        }
+        Ok(steps)
     }
 
-    /// Commit info: (hash, author, date, message, files_changed)
-    pub fn get_commit_history(&self, limit: usize) -> Result<Vec<CommitData>> {
-        let mut commits = Vec::new();
+    /// Number of commits reachable from HEAD, or 0 before the first commit.
+    ///
+    /// # Errors
+    ///
+    /// - HEAD cannot be read for a reason other than being unborn
+    pub fn total_commits(&self) -> Result<usize> {
+        match self.repo.head() {
+            Ok(_) => {}
+            Err(e) if e.code() == git2::ErrorCode::UnbornBranch => return Ok(0),
+            Err(e) => return Err(e.into()),
+        }
         let mut revwalk = self.repo.revwalk()?;
         revwalk.push_head()?;
 
-        for oid in revwalk.take(limit).flatten() {
-            if let Ok(commit) = self.repo.find_commit(oid) {
-                let hash = oid.to_string();
-                let author = commit.author().name().unwrap_or("Unknown").to_string();
-                let time = commit.time();
-                let date = chrono::DateTime::from_timestamp(time.seconds(), 0)
-                    .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-                    .unwrap_or_else(|| "Unknown date".to_string());
-                let message = commit.message().unwrap_or("").to_string();
-
-                // Get files changed
-                let mut files = Vec::new();
-                if let Ok(tree) = commit.tree() {
-                    let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
-                    if let Ok(diff) =
-                        self.repo
-                            .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
-                    {
-                        diff.foreach(
-                            &mut |delta, _| {
-                                if let Some(path) = delta.new_file().path() {
-                                    files.push(path.to_string_lossy().to_string());
-                                }
-                                true
-                            },
-                            None,
-                            None,
-                            None,
-                        )
-                        .ok();
-                    }
-                }
-
-                commits.push((hash, author, date, message, files));
-            }
+        let mut count = 0;
+        for oid in revwalk {
+            oid?;
+            count += 1;
         }
-
-        Ok(commits)
+        Ok(count)
     }
 
-    /// Fetch from a remote repository with progress tracking
-    ///
-    /// Returns the number of objects received
+    /// Number of files in HEAD's tree, counting every subdirectory, or 0
+    /// before the first commit.
     ///
-    /// # Arguments
+    /// # Edge Cases
     ///
-    /// * `remote_name` - Name of the remote (e.g., "origin")
-    /// * `progress` - Optional progress tracker (wrapped in Arc<Mutex> for thread safety)
-    /// * `cancel_flag` - Optional cancellation flag (wrapped in Arc for thread safety)
+    /// - **Submodules**: Not counted; their files live in another repository
+    /// - **Untracked and staged files**: Not counted until committed
     ///
     /// # Errors
     ///
-    /// - Remote does not exist
-    /// - Authentication failed (SSH/HTTPS credentials)
-    /// - Network errors
-    /// - Operation was cancelled
-    pub fn fetch_with_progress(
+    /// - HEAD cannot be read for a reason other than being unborn
+    /// - A tree object is missing or corrupt
+    pub fn tracked_file_count(&self) -> Result<usize> {
+        let head = match self.repo.head() {
+            Ok(head) => head,
+            Err(e) if e.code() == git2::ErrorCode::UnbornBranch => return Ok(0),
+            Err(e) => return Err(e.into()),
+        };
+        let tree = head.peel_to_tree()?;
+        let mut count = 0;
+        tree.walk(git2::TreeWalkMode::PreOrder, |_, entry| {
+            if entry.kind() == Some(git2::ObjectType::Blob) {
+                count += 1;
+            }
+            git2::TreeWalkResult::Ok
+        })?;
+        Ok(count)
+    }
+
+    /// Switch to a different branch
+    pub fn checkout_branch(&self, branch_name: &str) -> Result<()> {
+        self.require_worktree()?;
+        let obj = self
+            .repo
+            .revparse_single(&format!("refs/heads/{}", branch_name))?;
+        self.repo.checkout_tree(&obj, None)?;
+        self.repo.set_head(&format!("refs/heads/{}", branch_name))?;
+        Ok(())
+    }
+
+    /// Detach HEAD at a commit so its tree can be inspected.
+    ///
+    /// # Edge Cases
+    ///
+    /// - **Dirty working tree**: Returns `Err` rather than risk overwriting edits;
+    ///   untracked files are ignored
+    /// - **Abbreviated hash**: Accepted, resolved like `git rev-parse`
+    ///
+    /// # Errors
+    ///
+    /// - Hash does not resolve to a commit
+    /// - Tracked files have uncommitted changes
+    pub fn checkout_commit(&self, hash: &str) -> Result<()> {
+        self.require_worktree()?;
+        let commit = self.repo.revparse_single(hash)?.peel_to_commit()?;
+
+        let mut opts = StatusOptions::new();
+        opts.include_untracked(false).include_ignored(false);
+        if !self.repo.statuses(Some(&mut opts))?.is_empty() {
+            return Err(color_eyre::eyre::eyre!(
+                "Working tree has uncommitted changes; commit or stash them first"
+            ));
+        }
+
+        let mut checkout = git2::build::CheckoutBuilder::new();
+        checkout.safe();
+        self.repo
+            .checkout_tree(commit.as_object(), Some(&mut checkout))?;
+        self.repo.set_head_detached(commit.id())?;
+        Ok(())
+    }
+
+    /// Working trees of this repository as (name, path), main one first.
+    ///
+    /// The main working tree is named `main`; linked ones use the name given
+    /// to `git worktree add`. Linked worktrees whose directory has gone
+    /// missing are skipped.
+    ///
+    /// # Edge Cases
+    ///
+    /// - **No linked worktrees**: Only the main working tree is listed
+    /// - **Bare repo**: No main working tree, so only linked ones are listed
+    /// - **Opened from a linked worktree**: Lists the same set, main first
+    pub fn worktrees(&self) -> Result<Vec<(String, PathBuf)>> {
+        let mut list = Vec::new();
+        // commondir is the main repository's .git, even from a linked worktree
+        let common = self.repo.commondir();
+        if let Ok(main) = Repository::open(common) {
+            if let Some(workdir) = main.workdir() {
+                list.push(("main".to_string(), workdir.to_path_buf()));
+            }
+        }
+        for name in self.repo.worktrees()?.iter().flatten() {
+            let worktree = self.repo.find_worktree(name)?;
+            if worktree.validate().is_ok() {
+                list.push((name.to_string(), worktree.path().to_path_buf()));
+            }
+        }
+        Ok(list)
+    }
+
+    /// Recent moves of HEAD, newest first, as (short hash, reflog message)
+    ///
+    /// Lists at most `limit` entries. Commits dropped by a reset or rebase
+    /// stay reachable here until the reflog expires.
+    pub fn reflog(&self, limit: usize) -> Result<Vec<(String, String)>> {
+        let reflog = self.repo.reflog("HEAD")?;
+        Ok(reflog
+            .iter()
+            .take(limit)
+            .map(|entry| {
+                (
+                    self.short_hash(entry.id_new()),
+                    entry.message().unwrap_or("").to_string(),
+                )
+            })
+            .collect())
+    }
+
+    /// Shortest abbreviation of `oid` that is unique in this repository
+    ///
+    /// Never shorter than `core.abbrev` (7 by default), and longer where a
+    /// 7-character prefix would be ambiguous. Falls back to the first seven
+    /// characters if the object is missing.
+    pub fn short_hash(&self, oid: git2::Oid) -> String {
+        self.repo
+            .find_object(oid, None)
+            .and_then(|object| object.short_id())
+            .ok()
+            .and_then(|buf| buf.as_str().map(str::to_string))
+            .unwrap_or_else(|| oid.to_string()[..7].to_string())
+    }
+
+    /// The commit `rev` names: a full or abbreviated hash, a branch or any
+    /// other revision expression
+    ///
+    /// # Errors
+    ///
+    /// - `rev` does not resolve, is ambiguous, or does not name a commit
+    pub fn resolve_commit(&self, rev: &str) -> Result<git2::Oid> {
+        Ok(self.repo.revparse_single(rev)?.peel_to_commit()?.id())
+    }
+
+    /// Move the current branch (or detached HEAD) to `hash`, like `git reset`
+    ///
+    /// `Soft` keeps the index and working tree, `Mixed` resets the index
+    /// only, and `Hard` also overwrites the working tree.
+    pub fn reset(&self, hash: &str, kind: git2::ResetType) -> Result<()> {
+        if kind != git2::ResetType::Soft {
+            self.require_worktree()?;
+        }
+        let commit = self.repo.revparse_single(hash)?.peel_to_commit()?;
+        self.repo.reset(commit.as_object(), kind, None)?;
+        Ok(())
+    }
+
+    /// Create a new branch from current HEAD
+    pub fn create_branch(&self, branch_name: &str) -> Result<()> {
+        let head = self.repo.head()?;
+        let commit = head.peel_to_commit()?;
+        self.repo.branch(branch_name, &commit, false)?;
+        Ok(())
+    }
+
+    /// Delete a branch
+    pub fn delete_branch(&self, branch_name: &str) -> Result<()> {
+        let mut branch = self
+            .repo
+            .find_branch(branch_name, git2::BranchType::Local)?;
+        branch.delete()?;
+        Ok(())
+    }
+
+    /// Tag names, sorted
+    pub fn list_tags(&self) -> Result<Vec<String>> {
+        let mut tags: Vec<String> = self
+            .repo
+            .tag_names(None)?
+            .iter()
+            .flatten()
+            .map(str::to_string)
+            .collect();
+        tags.sort();
+        Ok(tags)
+    }
+
+    /// Tag names, sorted, keyed by the full hash of the commit each points to
+    pub fn tags_by_commit(&self) -> Result<HashMap<String, Vec<String>>> {
+        let mut tags: HashMap<String, Vec<String>> = HashMap::new();
+        for name in self.list_tags()? {
+            let commit = self
+                .repo
+                .revparse_single(&format!("refs/tags/{}", name))
+                .and_then(|object| object.peel_to_commit());
+            if let Ok(commit) = commit {
+                tags.entry(commit.id().to_string()).or_default().push(name);
+            }
+        }
+        Ok(tags)
+    }
+
+    /// Delete a tag, lightweight or annotated
+    pub fn delete_tag(&self, name: &str) -> Result<()> {
+        self.repo.tag_delete(name)?;
+        Ok(())
+    }
+
+    /// Commit info: (hash, author, date, message, files_changed, timestamp, author_email)
+    pub fn get_commit_history(&self, limit: usize) -> Result<Vec<CommitData>> {
+        self.commit_history_page(&mut HistoryCursor::default(), limit)
+    }
+
+    /// Load the next page of commit history, newest first
+    ///
+    /// A fresh `cursor` starts the walk at HEAD; passing the same cursor again
+    /// continues where the previous page stopped, so consecutive pages are
+    /// contiguous and never repeat a commit, merges included. Each page costs
+    /// only the commits it returns, however deep the walk already is. An
+    /// exhausted cursor yields an empty page.
+    ///
+    /// # Errors
+    ///
+    /// - HEAD cannot be resolved (e.g. unborn branch)
+    pub fn commit_history_page(
         &self,
-        remote_name: &str,
-        progress: Option<Arc<Mutex<TransferProgress>>>,
-        cancel_flag: Option<Arc<AtomicBool>>,
-    ) -> Result<usize> {
-        let mut remote = self.repo.find_remote(remote_name)?;
+        cursor: &mut HistoryCursor,
+        limit: usize,
+    ) -> Result<Vec<CommitData>> {
+        if !cursor.started {
+            let head = self.repo.head()?.peel_to_commit()?;
+            cursor.queue(&head);
+            cursor.started = true;
+        }
 
-        let mut fetch_options = git2::FetchOptions::new();
-        let mut callbacks = git2::RemoteCallbacks::new();
-
-        // Credential callback for authentication
-        callbacks.credentials(|url, username_from_url, allowed_types| {
-            // Check if SSH is allowed
-            if allowed_types.is_ssh_key() {
-                // Try SSH agent first
-                if let Some(username) = username_from_url {
-                    if let Ok(cred) = git2::Cred::ssh_key_from_agent(username) {
-                        return Ok(cred);
-                    }
-                }
+        let mut page = Vec::new();
+        while page.len() < limit {
+            let Some((_, _, oid)) = cursor.pending.pop() else {
+                break;
+            };
+            let commit = self.repo.find_commit(oid)?;
+            for parent in commit.parents() {
+                cursor.queue(&parent);
+            }
+            page.push(self.commit_data(&commit));
+        }
+        Ok(page)
+    }
 
-                // Try default SSH key locations
-                if let Some(username) = username_from_url.or_else(|| url.split('@').next()) {
-                    if let Ok(home) = std::env::var("HOME") {
-                        let ssh_key = std::path::PathBuf::from(&home).join(".ssh/id_rsa");
-                        if ssh_key.exists() {
-                            if let Ok(cred) = git2::Cred::ssh_key(username, None, &ssh_key, None) {
-                                return Ok(cred);
-                            }
-                        }
-                    }
-                }
+    /// Commits on HEAD that changed `path`, most recent first
+    ///
+    /// Summarises every commit and keeps those whose changed files include
+    /// `path`. Simple, but it diffs each commit in full; prefer
+    /// [`GitClient::path_history`] on large repositories.
+    pub fn file_history(&self, path: &str, limit: usize) -> Result<Vec<CommitData>> {
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push_head()?;
+
+        Ok(revwalk
+            .flatten()
+            .filter_map(|oid| self.repo.find_commit(oid).ok())
+            .map(|commit| self.commit_data(&commit))
+            .filter(|(_, _, _, _, files, _, _)| files.iter().any(|f| f == path))
+            .take(limit)
+            .collect())
+    }
+
+    /// Commits on HEAD that changed `path`, optionally within a date range
+    ///
+    /// Walks newest to oldest, diffing each commit against its first parent
+    /// restricted to `path`, so only that path's tree entries are compared.
+    /// Commits newer than `until` are skipped and the walk stops at the first
+    /// commit older than `since`. Directory paths match anything beneath them.
+    ///
+    /// # Errors
+    ///
+    /// - HEAD cannot be resolved (e.g. unborn branch)
+    pub fn path_history(
+        &self,
+        path: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        limit: usize,
+    ) -> Result<Vec<CommitData>> {
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.set_sorting(git2::Sort::TIME)?;
+        revwalk.push_head()?;
+
+        let mut opts = DiffOptions::new();
+        opts.pathspec(path)
+            .disable_pathspec_match(true)
+            .skip_binary_check(true);
+
+        let mut history = Vec::new();
+        for oid in revwalk.flatten() {
+            if history.len() >= limit {
+                break;
+            }
+            let Ok(commit) = self.repo.find_commit(oid) else {
+                continue;
+            };
+            let seconds = commit.time().seconds();
+            if since.is_some_and(|since| seconds < since.timestamp()) {
+                break;
+            }
+            if until.is_some_and(|until| seconds > until.timestamp()) {
+                continue;
             }
 
-            // Try username/password for HTTPS
-            if allowed_types.is_user_pass_plaintext() {
-                if let Ok(cred) =
-                    git2::Cred::credential_helper(&self.repo.config()?, url, username_from_url)
-                {
-                    return Ok(cred);
-                }
+            let tree = commit.tree()?;
+            let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+            let diff =
+                self.repo
+                    .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut opts))?;
+            if diff.deltas().len() > 0 {
+                history.push(self.commit_data(&commit));
             }
+        }
+        Ok(history)
+    }
 
-            // Fallback to default credentials
-            git2::Cred::default()
-        });
+    /// Commits on HEAD per local calendar day for the last `days` days
+    ///
+    /// Returns one `("YYYY-MM-DD", count)` entry per day, oldest first and
+    /// ending today, including days without commits.
+    ///
+    /// # Errors
+    ///
+    /// - HEAD cannot be resolved (e.g. unborn branch)
+    pub fn commit_counts_by_day(&self, days: usize) -> Result<Vec<(String, usize)>> {
+        let today = chrono::Local::now().date_naive();
+        let first = today - chrono::Days::new(days.saturating_sub(1) as u64);
+        let mut counts = vec![0usize; days];
 
-        // Transfer progress callback
-        if let Some(progress_tracker) = progress.clone() {
-            callbacks.transfer_progress(move |stats| {
-                if let Ok(mut p) = progress_tracker.lock() {
-                    p.total_objects = stats.total_objects();
-                    p.indexed_objects = stats.indexed_objects();
-                    p.received_objects = stats.received_objects();
-                    p.received_bytes = stats.received_bytes();
-                    p.total_deltas = stats.total_deltas();
-                    p.indexed_deltas = stats.indexed_deltas();
-                }
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.set_sorting(git2::Sort::TIME)?;
+        revwalk.push_head()?;
+        for oid in revwalk.flatten() {
+            let Ok(commit) = self.repo.find_commit(oid) else {
+                continue;
+            };
+            let Some(date) = chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
+                .map(|dt| dt.with_timezone(&chrono::Local).date_naive())
+            else {
+                continue;
+            };
+            if date < first {
+                break;
+            }
+            if let Some(count) = counts.get_mut((date - first).num_days() as usize) {
+                *count += 1;
+            }
+        }
 
-                // Check cancellation flag
-                if let Some(ref cancel) = cancel_flag {
-                    if cancel.load(Ordering::Relaxed) {
-                        return false; // Cancel the operation
-                    }
-                }
+        Ok(counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let day = first + chrono::Days::new(i as u64);
+                (day.format("%Y-%m-%d").to_string(), count)
+            })
+            .collect())
+    }
 
-                true // Continue
-            });
+    /// Summarise a commit as (hash, author, date, message, files_changed, timestamp, author_email)
+    fn commit_data(&self, commit: &git2::Commit) -> CommitData {
+        let hash = commit.id().to_string();
+        let author = commit.author().name().unwrap_or("Unknown").to_string();
+        let author_email = commit.author().email().unwrap_or("").to_string();
+        let time = commit.time();
+        let date = chrono::DateTime::from_timestamp(time.seconds(), 0)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| "Unknown date".to_string());
+        let message = commit.message().unwrap_or("").to_string();
+
+        // Get files changed
+        let mut files = Vec::new();
+        if let Ok(tree) = commit.tree() {
+            let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+            if let Ok(diff) = self
+                .repo
+                .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+            {
+                diff.foreach(
+                    &mut |delta, _| {
+                        if let Some(path) = delta.new_file().path() {
+                            files.push(path.to_string_lossy().to_string());
+                        }
+                        true
+                    },
+                    None,
+                    None,
+                    None,
+                )
+                .ok();
+            }
         }
 
+        (
+            hash,
+            author,
+            date,
+            message,
+            files,
+            time.seconds(),
+            author_email,
+        )
+    }
+
+    /// Fetch from a remote repository with progress tracking
+    ///
+    /// Returns the number of objects received
+    ///
+    /// # Arguments
+    ///
+    /// * `remote_name` - Name of the remote (e.g., "origin")
+    /// * `progress` - Optional progress tracker (wrapped in Arc<Mutex> for thread safety)
+    /// * `cancel_flag` - Optional cancellation flag (wrapped in Arc for thread safety)
+    ///
+    /// # Errors
+    ///
+    /// - Remote does not exist
+    /// - Authentication failed (SSH/HTTPS credentials)
+    /// - Network errors
+    /// - Operation was cancelled
+    pub fn fetch_with_progress(
+        &self,
+        remote_name: &str,
+        progress: Option<Arc<Mutex<TransferProgress>>>,
+        cancel_flag: Option<Arc<AtomicBool>>,
+    ) -> Result<usize> {
+        let mut remote = self.repo.find_remote(remote_name)?;
+
+        let mut fetch_options = git2::FetchOptions::new();
+        let callbacks = remote_callbacks(self.repo.config()?, progress.clone(), cancel_flag);
+
         fetch_options.remote_callbacks(callbacks);
+        if self.options.prune_on_fetch {
+            fetch_options.prune(git2::FetchPrune::On);
+        }
 
         // Fetch all refs (equivalent to `git fetch origin`)
         let empty_refspecs: Vec<&str> = vec![];
@@ -616,6 +2318,30 @@ impl GitClient {
         self.fetch("origin")
     }
 
+    /// Fetch from every configured remote
+    ///
+    /// Returns each remote in configuration order with the objects received
+    /// from it, or why its fetch failed as given by
+    /// [`GitClient::explain_error`]. A failing remote does not stop the
+    /// others from being fetched.
+    ///
+    /// # Errors
+    ///
+    /// - The remotes could not be listed
+    pub fn fetch_all(&self) -> Result<Vec<(String, std::result::Result<usize, String>)>> {
+        Ok(self
+            .list_remotes()?
+            .into_iter()
+            .map(|remote| {
+                let progress = Arc::new(Mutex::new(TransferProgress::default()));
+                let result = self
+                    .fetch_with_progress(&remote, Some(progress), None)
+                    .map_err(|e| Self::explain_error(&e));
+                (remote, result)
+            })
+            .collect())
+    }
+
     /// List all remotes in the repository
     pub fn list_remotes(&self) -> Result<Vec<String>> {
         let remotes = self.repo.remotes()?;
@@ -631,6 +2357,25 @@ impl GitClient {
             .ok_or_else(|| color_eyre::eyre::eyre!("Remote has no URL"))
     }
 
+    /// Time of the last fetch from a remote, taken from the modification time
+    /// of `FETCH_HEAD` in the common git directory, which every worktree shares
+    ///
+    /// # Edge Cases
+    ///
+    /// - **Never fetched**: Returns `None` (no `FETCH_HEAD` yet)
+    /// - **Different remote**: Returns `None` if the last fetch was from another remote
+    /// - **Unknown remote**: Returns `None`
+    pub fn last_fetch_time(&self, remote: &str) -> Option<SystemTime> {
+        let url = self.remote_url(remote).ok()?;
+        let fetch_head = self.repo.commondir().join("FETCH_HEAD");
+        let contents = std::fs::read_to_string(&fetch_head).ok()?;
+        // An up-to-date fetch can leave FETCH_HEAD empty; otherwise every line names its source
+        if !contents.trim().is_empty() && !contents.contains(&url) {
+            return None;
+        }
+        std::fs::metadata(&fetch_head).ok()?.modified().ok()
+    }
+
     /// Push to a remote branch with progress tracking
     ///
     /// # Arguments
@@ -657,67 +2402,7 @@ impl GitClient {
         let mut remote = self.repo.find_remote(remote_name)?;
 
         let mut push_options = git2::PushOptions::new();
-        let mut callbacks = git2::RemoteCallbacks::new();
-
-        // Credential callback for authentication
-        callbacks.credentials(|url, username_from_url, allowed_types| {
-            // Check if SSH is allowed
-            if allowed_types.is_ssh_key() {
-                // Try SSH agent first
-                if let Some(username) = username_from_url {
-                    if let Ok(cred) = git2::Cred::ssh_key_from_agent(username) {
-                        return Ok(cred);
-                    }
-                }
-
-                // Try default SSH key locations
-                if let Some(username) = username_from_url.or_else(|| url.split('@').next()) {
-                    if let Ok(home) = std::env::var("HOME") {
-                        let ssh_key = std::path::PathBuf::from(&home).join(".ssh/id_rsa");
-                        if ssh_key.exists() {
-                            if let Ok(cred) = git2::Cred::ssh_key(username, None, &ssh_key, None) {
-                                return Ok(cred);
-                            }
-                        }
-                    }
-                }
-            }
-
-            // Try username/password for HTTPS
-            if allowed_types.is_user_pass_plaintext() {
-                if let Ok(cred) =
-                    git2::Cred::credential_helper(&self.repo.config()?, url, username_from_url)
-                {
-                    return Ok(cred);
-                }
-            }
-
-            // Fallback to default credentials
-            git2::Cred::default()
-        });
-
-        // Transfer progress callback
-        if let Some(progress_tracker) = progress.clone() {
-            callbacks.transfer_progress(move |stats| {
-                if let Ok(mut p) = progress_tracker.lock() {
-                    p.total_objects = stats.total_objects();
-                    p.indexed_objects = stats.indexed_objects();
-                    p.received_objects = stats.received_objects();
-                    p.received_bytes = stats.received_bytes();
-                    p.total_deltas = stats.total_deltas();
-                    p.indexed_deltas = stats.indexed_deltas();
-                }
-
-                // Check cancellation flag
-                if let Some(ref cancel) = cancel_flag {
-                    if cancel.load(Ordering::Relaxed) {
-                        return false; // Cancel the operation
-                    }
-                }
-
-                true // Continue
-            });
-        }
+        let mut callbacks = remote_callbacks(self.repo.config()?, progress, cancel_flag);
 
         // Push update callback for errors
         callbacks.push_update_reference(|refname, status| {
@@ -801,6 +2486,8 @@ impl GitClient {
         progress: Option<Arc<Mutex<TransferProgress>>>,
         cancel_flag: Option<Arc<AtomicBool>>,
     ) -> Result<()> {
+        self.require_worktree()?;
+
         // Step 1: Fetch from remote
         self.fetch_with_progress(remote_name, progress, cancel_flag.clone())?;
 
@@ -998,13 +2685,18 @@ impl GitClient {
         }
 
         // Index lock errors (common with concurrent operations)
-        if error_lower.contains("index") && error_lower.contains("lock") {
-            return "Git index is locked. Another Git operation is running.\n\
-                    • Wait for other operations to complete\n\
-                    • If stuck, check: ps aux | grep git\n\
-                    • Force unlock: rm -f .git/index.lock\n\
-                    • Warning: Only force unlock if no git process is active"
-                .to_string();
+        let locked = e
+            .downcast_ref::<git2::Error>()
+            .is_some_and(|e| e.code() == git2::ErrorCode::Locked);
+        if locked || (error_lower.contains("index") && error_lower.contains("lock")) {
+            return format!(
+                "{}\n\
+                 • Wait for other operations to complete\n\
+                 • If stuck, check: ps aux | grep git\n\
+                 • Force unlock: rm -f .git/index.lock\n\
+                 • Warning: Only force unlock if no git process is active",
+                INDEX_LOCKED_MESSAGE
+            );
         }
 
         // Corrupted index
@@ -1134,6 +2826,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_clone_dir_name() {
+        assert_eq!(clone_dir_name("https://github.com/me/forge.git"), "forge");
+        assert_eq!(clone_dir_name("git@github.com:me/forge.git"), "forge");
+        assert_eq!(clone_dir_name("/srv/git/upstream/"), "upstream");
+        assert_eq!(clone_dir_name("/srv/git/upstream/.git"), "upstream");
+        assert_eq!(clone_dir_name(".git"), "repository");
+    }
+
     #[test]
     fn test_gitclient_discover_invalid_path() {
         let result = GitClient::discover("/nonexistent/path/that/does/not/exist");
@@ -1151,11 +2852,9 @@ mod tests {
         git2::Repository::init(repo_path).expect("Failed to initialize repo");
         let client = GitClient::discover(repo_path).expect("Failed to create GitClient");
 
-        // Empty repo has no HEAD
-        let branch = client.head_branch();
-        // Could be None or Some("master") depending on git config
-        // Just verify it doesn't panic
-        let _ = branch;
+        // Empty repo has no commit for HEAD to point at
+        assert_eq!(client.head_branch(), HeadRef::Unborn);
+        assert_eq!(client.head_branch().label(), "(no commits yet)");
     }
 
     #[test]
@@ -1243,6 +2942,8 @@ mod tests {
             "2024-01-01".to_string(),
             "Test commit".to_string(),
             vec!["file1.rs".to_string(), "file2.rs".to_string()],
+            1_704_067_200,
+            "john@example.com".to_string(),
         );
         // If compilation succeeds, the type alias is correct
     }
@@ -1396,6 +3097,1564 @@ mod integration_tests {
         assert!(stage_result.is_ok(), "Should stage file successfully");
     }
 
+    /// Create a repository with `count` sequential commits on HEAD
+    fn repo_with_commits(count: usize) -> (TempDir, GitClient) {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let repo_path = temp_dir.path();
+        let repo = git2::Repository::init(repo_path).expect("Failed to initialize repo");
+        let sig = git2::Signature::now("Test", "test@example.com").expect("Failed to create sig");
+
+        for i in 0..count {
+            let name = format!("file{}.txt", i);
+            fs::write(repo_path.join(&name), format!("content {}", i)).expect("Failed to write");
+            let mut index = repo.index().expect("Failed to get index");
+            index.add_path(std::path::Path::new(&name)).ok();
+            index.write().expect("Failed to write index");
+            let tree_id = index.write_tree().expect("Failed to write tree");
+            let tree = repo.find_tree(tree_id).expect("Failed to find tree");
+            let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+            let parents: Vec<&git2::Commit> = parent.iter().collect();
+            repo.commit(
+                Some("HEAD"),
+                &sig,
+                &sig,
+                &format!("Commit {}", i),
+                &tree,
+                &parents,
+            )
+            .expect("Failed to commit");
+        }
+
+        let client = GitClient::discover(repo_path).expect("Failed to create client");
+        (temp_dir, client)
+    }
+
+    #[test]
+    fn test_clone_repo_from_a_local_bare_repository() {
+        let (_source_dir, source) = repo_with_commits(3);
+        let bare_dir = TempDir::new().expect("Failed to create temp directory");
+        let bare_path = bare_dir.path().join("upstream.git");
+        git2::build::RepoBuilder::new()
+            .bare(true)
+            .clone(source.workdir.to_str().unwrap(), &bare_path)
+            .expect("Failed to create bare repo");
+
+        let dest_dir = TempDir::new().expect("Failed to create temp directory");
+        let dest = dest_dir.path().join("clone");
+        let client = GitClient::clone_repo(bare_path.to_str().unwrap(), &dest)
+            .expect("clone should succeed");
+
+        assert_eq!(client.workdir, dest);
+        assert!(dest.join("file2.txt").exists());
+        assert_eq!(client.list_remotes().unwrap(), vec!["origin".to_string()]);
+        let subjects: Vec<String> = client
+            .get_commit_history(10)
+            .unwrap()
+            .into_iter()
+            .map(|(_, _, _, message, ..)| message)
+            .collect();
+        assert_eq!(subjects, vec!["Commit 2", "Commit 1", "Commit 0"]);
+    }
+
+    #[test]
+    fn test_clone_repo_refuses_a_non_empty_destination() {
+        let (source_dir, _source) = repo_with_commits(1);
+        let (dest_dir, _dest) = repo_with_commits(1);
+
+        let result = GitClient::clone_repo(source_dir.path().to_str().unwrap(), dest_dir.path());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_commit_history_captures_author_email() {
+        let (_dir, client) = repo_with_commits(2);
+        let history = client.get_commit_history(2).unwrap();
+        assert!(history
+            .iter()
+            .all(|(_, author, .., email)| author == "Test" && email == "test@example.com"));
+    }
+
+    #[test]
+    fn test_checkout_commit_detaches_head() {
+        let (dir, client) = repo_with_commits(2);
+        let first_hash = client
+            .get_commit_history(2)
+            .expect("Failed to load history")[1]
+            .0
+            .clone();
+
+        fs::write(dir.path().join("file0.txt"), "changed").expect("Failed to write");
+        assert!(
+            client.checkout_commit(&first_hash).is_err(),
+            "Dirty tree should refuse checkout"
+        );
+
+        client.stage_all().expect("Failed to stage");
+        client.commit_all("Change file0").expect("Failed to commit");
+        client
+            .checkout_commit(&first_hash)
+            .expect("Failed to checkout commit");
+
+        assert!(client.is_head_detached());
+        assert_eq!(
+            client.head_branch(),
+            HeadRef::Detached(first_hash[..7].to_string())
+        );
+        assert_eq!(
+            client.head_branch().label(),
+            format!("(detached at {})", &first_hash[..7])
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("file0.txt")).unwrap(),
+            "content 0"
+        );
+        assert!(!dir.path().join("file1.txt").exists());
+    }
+
+    /// Commits "ours\n" and "theirs\n" to file0.txt on HEAD and on a branch
+    /// `other`, then merges `other`, leaving file0.txt conflicted
+    fn merge_conflicting_edits(dir: &TempDir, client: &GitClient) {
+        let repo = &client.repo;
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let base = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("other", &base, false).unwrap();
+        let commit_on = |refname: &str, text: &str, parent: &git2::Commit| {
+            fs::write(dir.path().join("file0.txt"), text).unwrap();
+            let mut index = repo.index().unwrap();
+            index.add_path(std::path::Path::new("file0.txt")).unwrap();
+            index.write().unwrap();
+            let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+            repo.commit(Some(refname), &sig, &sig, text, &tree, &[parent])
+                .unwrap()
+        };
+        commit_on("HEAD", "ours\n", &base);
+        let theirs = commit_on("refs/heads/other", "theirs\n", &base);
+        // Put the working tree back on HEAD before merging
+        repo.checkout_head(Some(git2::build::CheckoutBuilder::new().force()))
+            .unwrap();
+
+        let annotated = repo.find_annotated_commit(theirs).unwrap();
+        repo.merge(&[&annotated], None, None).unwrap();
+    }
+
+    #[test]
+    fn test_list_conflicts_after_merge() {
+        let (dir, client) = repo_with_commits(1);
+        assert!(client.list_conflicts().unwrap().is_empty());
+        assert!(!client.is_merging());
+
+        merge_conflicting_edits(&dir, &client);
+        assert_eq!(client.list_conflicts().unwrap(), ["file0.txt"]);
+        assert!(client.is_merging());
+    }
+
+    #[test]
+    fn test_resolve_conflict_writes_and_stages() {
+        let (dir, client) = repo_with_commits(1);
+        merge_conflicting_edits(&dir, &client);
+
+        let (ours, theirs) = client.conflict_sides("file0.txt").unwrap();
+        assert_eq!(ours.as_deref(), Some(&b"ours\n"[..]));
+        assert_eq!(theirs.as_deref(), Some(&b"theirs\n"[..]));
+
+        client.resolve_conflict("file0.txt", b"merged\n").unwrap();
+        assert!(client.list_conflicts().unwrap().is_empty());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("file0.txt")).unwrap(),
+            "merged\n"
+        );
+        assert!(client.conflict_sides("file0.txt").is_err());
+    }
+
+    #[test]
+    fn test_squash_last_combines_commits() {
+        let (dir, client) = repo_with_commits(4);
+        let history = client.get_commit_history(4).unwrap();
+        let base = history[3].0.clone();
+
+        let oid = client
+            .squash_last(3, "Combined work")
+            .expect("Failed to squash");
+        let history = client.get_commit_history(10).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].0, oid.to_string());
+        assert_eq!(history[0].3, "Combined work");
+        assert_eq!(history[1].0, base);
+        for i in 0..4 {
+            assert!(dir.path().join(format!("file{}.txt", i)).exists());
+        }
+        assert!(client.is_clean().unwrap(), "tree and index are unchanged");
+
+        let squashed = client.repo.find_commit(oid).unwrap();
+        assert_eq!(squashed.author().name(), Some("Test"));
+        assert_eq!(squashed.tree().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_squash_last_rejects_too_many() {
+        let (_dir, client) = repo_with_commits(2);
+        let tip = client.get_commit_history(1).unwrap()[0].0.clone();
+
+        assert!(client.squash_last(3, "Too far").is_err());
+        assert!(client.squash_last(0, "Nothing").is_err());
+        assert_eq!(client.get_commit_history(1).unwrap()[0].0, tip);
+
+        // Squashing everything leaves a single root commit
+        client.squash_last(2, "Everything").unwrap();
+        assert_eq!(client.get_commit_history(10).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_worktrees_lists_linked_worktree() {
+        let (dir, client) = repo_with_commits(1);
+        let main = client.worktrees().expect("Failed to list worktrees");
+        assert_eq!(main.len(), 1);
+        assert_eq!(main[0].0, "main");
+        assert_eq!(
+            main[0].1.canonicalize().unwrap(),
+            dir.path().canonicalize().unwrap()
+        );
+
+        let linked_dir = TempDir::new().expect("Failed to create temp directory");
+        let linked_path = linked_dir.path().join("feature");
+        client
+            .repo
+            .worktree("feature", &linked_path, None)
+            .expect("Failed to add worktree");
+
+        let list = client.worktrees().expect("Failed to list worktrees");
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[1].0, "feature");
+        assert_eq!(
+            list[1].1.canonicalize().unwrap(),
+            linked_path.canonicalize().unwrap()
+        );
+
+        // The linked worktree sees the same list, and opens on its own branch
+        let linked = GitClient::discover(&linked_path).expect("Failed to open worktree");
+        assert_eq!(linked.worktrees().unwrap().len(), 2);
+        assert_eq!(linked.head_branch(), HeadRef::Branch("feature".into()));
+    }
+
+    #[test]
+    fn test_reword_commit_replays_descendants() {
+        let (dir, client) = repo_with_commits(4);
+        let before = client.get_commit_history(4).unwrap();
+        let target = before[2].0.clone();
+        let branch = client.head_branch();
+
+        let reworded = client
+            .reword_commit(&target, "Better message")
+            .expect("Failed to reword");
+
+        let after = client.get_commit_history(10).unwrap();
+        let messages: Vec<&str> = after.iter().map(|c| c.3.as_str()).collect();
+        assert_eq!(
+            messages,
+            ["Commit 3", "Commit 2", "Better message", "Commit 0"]
+        );
+        assert_eq!(after[2].0, reworded.to_string());
+        // The root is untouched; everything from the target on is rewritten
+        assert_eq!(after[3].0, before[3].0);
+        assert_ne!(after[1].0, before[1].0);
+
+        let repo = &client.repo;
+        let tip = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(tip.parent(0).unwrap().parent_id(0).unwrap(), reworded);
+        let old_tip = repo
+            .find_commit(git2::Oid::from_str(&before[0].0).unwrap())
+            .unwrap();
+        assert_eq!(tip.tree_id(), old_tip.tree_id());
+        assert_eq!(tip.author().name(), old_tip.author().name());
+        assert!(dir.path().join("file3.txt").exists());
+        assert_eq!(client.head_branch(), branch);
+    }
+
+    #[test]
+    fn test_reword_commit_rejects_unrelated_commit() {
+        let (_dir, client) = repo_with_commits(2);
+        let repo = &client.repo;
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree = repo.head().unwrap().peel_to_tree().unwrap();
+        let orphan = repo.commit(None, &sig, &sig, "Orphan", &tree, &[]).unwrap();
+
+        let tip = client.get_commit_history(1).unwrap()[0].0.clone();
+        assert!(client.reword_commit(&orphan.to_string(), "Nope").is_err());
+        assert_eq!(client.get_commit_history(1).unwrap()[0].0, tip);
+    }
+
+    #[test]
+    fn test_short_hash_resolves_uniquely() {
+        let (_dir, client) = repo_with_commits(5);
+        for (hash, ..) in client.get_commit_history(5).unwrap() {
+            let oid = git2::Oid::from_str(&hash).unwrap();
+            let short = client.short_hash(oid);
+            assert!(short.len() >= 7 && hash.starts_with(&short));
+            assert_eq!(client.resolve_commit(&short).unwrap(), oid);
+        }
+
+        // A longer configured minimum is honoured
+        client
+            .repo
+            .config()
+            .unwrap()
+            .set_i32("core.abbrev", 12)
+            .unwrap();
+        let head = client.resolve_commit("HEAD").unwrap();
+        assert_eq!(client.short_hash(head).len(), 12);
+    }
+
+    #[test]
+    fn test_resolve_commit_expression_forms() {
+        let (_dir, client) = repo_with_commits(4);
+        let history: Vec<git2::Oid> = client
+            .get_commit_history(4)
+            .unwrap()
+            .into_iter()
+            .map(|(hash, ..)| git2::Oid::from_str(&hash).unwrap())
+            .collect();
+
+        let repo = &client.repo;
+        let first = repo.find_commit(history[3]).unwrap();
+        repo.tag_lightweight("v0.1", first.as_object(), false)
+            .unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        repo.tag(
+            "v0.2",
+            repo.find_commit(history[2]).unwrap().as_object(),
+            &sig,
+            "release",
+            false,
+        )
+        .unwrap();
+        repo.branch("older", &repo.find_commit(history[1]).unwrap(), false)
+            .unwrap();
+
+        assert_eq!(client.resolve_commit("HEAD").unwrap(), history[0]);
+        assert_eq!(client.resolve_commit("HEAD~2").unwrap(), history[2]);
+        assert_eq!(client.resolve_commit("HEAD^").unwrap(), history[1]);
+        assert_eq!(client.resolve_commit("v0.1").unwrap(), history[3]);
+        // Annotated tags are peeled to the commit they point at
+        assert_eq!(client.resolve_commit("v0.2").unwrap(), history[2]);
+        assert_eq!(client.resolve_commit("older").unwrap(), history[1]);
+        let short = &history[3].to_string()[..8];
+        assert_eq!(client.resolve_commit(short).unwrap(), history[3]);
+
+        assert!(client.resolve_commit("HEAD~9").is_err());
+        assert!(client.resolve_commit("no-such-ref").is_err());
+    }
+
+    #[test]
+    fn test_tag_list_checkout_and_delete() {
+        let (_dir, client) = repo_with_commits(3);
+        let history: Vec<git2::Oid> = client
+            .get_commit_history(3)
+            .unwrap()
+            .into_iter()
+            .map(|(hash, ..)| git2::Oid::from_str(&hash).unwrap())
+            .collect();
+        let repo = &client.repo;
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        repo.tag(
+            "v1.0",
+            repo.find_commit(history[1]).unwrap().as_object(),
+            &sig,
+            "release",
+            false,
+        )
+        .unwrap();
+        repo.tag_lightweight(
+            "v0.9",
+            repo.find_commit(history[2]).unwrap().as_object(),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(client.list_tags().unwrap(), ["v0.9", "v1.0"]);
+        // Annotated tags are keyed by the commit they point at
+        let by_commit = client.tags_by_commit().unwrap();
+        assert_eq!(by_commit[&history[1].to_string()], ["v1.0"]);
+        assert_eq!(by_commit[&history[2].to_string()], ["v0.9"]);
+        assert!(!by_commit.contains_key(&history[0].to_string()));
+
+        // An annotated tag detaches HEAD at the commit, not the tag object
+        client.checkout_commit("refs/tags/v1.0").unwrap();
+        assert!(repo.head_detached().unwrap());
+        assert_eq!(client.resolve_commit("HEAD").unwrap(), history[1]);
+        client.checkout_commit("refs/tags/v0.9").unwrap();
+        assert_eq!(client.resolve_commit("HEAD").unwrap(), history[2]);
+        assert!(client.checkout_commit("refs/tags/v2.0").is_err());
+
+        client.delete_tag("v1.0").unwrap();
+        assert_eq!(client.list_tags().unwrap(), ["v0.9"]);
+        client.delete_tag("v0.9").unwrap();
+        assert!(client.list_tags().unwrap().is_empty());
+        assert!(client.delete_tag("v0.9").is_err());
+    }
+
+    #[test]
+    fn test_submodule_status_reports_moved_head() {
+        let (sub_dir, _) = repo_with_commits(1);
+        let (dir, client) = repo_with_commits(1);
+        let url = format!("file://{}", sub_dir.path().display());
+        let mut submodule = client.repo.submodule(&url, Path::new("lib"), true).unwrap();
+        submodule.clone(None).unwrap();
+        submodule.add_finalize().unwrap();
+        client.stage_all().unwrap();
+        client.commit_all("Add submodule").unwrap();
+
+        assert_eq!(
+            client.submodule_status().unwrap(),
+            [("lib".to_string(), false)]
+        );
+        assert!(client.list_changes().unwrap().is_empty());
+
+        // A new commit inside the checkout moves it off the recorded one
+        let inner = GitClient::discover(dir.path().join("lib")).unwrap();
+        fs::write(dir.path().join("lib/extra.txt"), "extra").unwrap();
+        inner.stage_all().unwrap();
+        inner.commit_all("Inner change").unwrap();
+
+        assert_eq!(
+            client.submodule_status().unwrap(),
+            [("lib".to_string(), true)]
+        );
+        let changes = client.list_changes().unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "lib");
+        assert!(changes[0].is_submodule);
+        assert!(changes[0].diff_preview.contains("Subproject commit"));
+
+        // Still listed when an ignore setting hides it from the status scan
+        client
+            .repo
+            .config()
+            .unwrap()
+            .set_str("submodule.lib.ignore", "all")
+            .unwrap();
+        let changes = client.list_changes().unwrap();
+        assert_eq!(changes.len(), 1);
+        assert!(changes[0].is_submodule);
+        assert_eq!(changes[0].status, FileStatus::Modified);
+    }
+
+    #[test]
+    fn test_reflog_keeps_reset_commit() {
+        let (_dir, client) = repo_with_commits(3);
+        let history = client
+            .get_commit_history(3)
+            .expect("Failed to load history");
+        let (tip, first) = (history[0].0.clone(), history[2].0.clone());
+
+        client
+            .reset(&first, git2::ResetType::Soft)
+            .expect("Failed to reset");
+        assert_eq!(client.get_commit_history(3).unwrap().len(), 1);
+
+        let reflog = client.reflog(10).expect("Failed to read reflog");
+        assert!(
+            reflog[0].1.starts_with("reset"),
+            "newest entry is the reset"
+        );
+        assert!(reflog
+            .iter()
+            .any(|(hash, _)| tip.starts_with(hash.as_str())));
+        assert_eq!(client.reflog(1).unwrap().len(), 1);
+
+        // The dropped commit can be restored from its reflog hash
+        let (hash, _) = reflog
+            .iter()
+            .find(|(h, _)| tip.starts_with(h.as_str()))
+            .unwrap();
+        client
+            .reset(hash, git2::ResetType::Soft)
+            .expect("Failed to restore");
+        assert_eq!(client.get_commit_history(3).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_unpublished_branches() {
+        let (_dir, client) = repo_with_commits(2);
+        let repo = &client.repo;
+        let tip = repo.head().unwrap().peel_to_commit().unwrap();
+
+        repo.remote("origin", "https://example.com/repo.git")
+            .expect("Failed to add remote");
+        repo.reference("refs/remotes/origin/published", tip.id(), false, "test")
+            .expect("Failed to create remote ref");
+        let mut published = repo.branch("published", &tip, false).unwrap();
+        published
+            .set_upstream(Some("origin/published"))
+            .expect("Failed to set upstream");
+        repo.branch("unpublished", &tip, false).unwrap();
+
+        let branches = client.unpublished_branches().expect("Failed to list");
+        assert!(branches.contains(&"unpublished".to_string()));
+        assert!(!branches.contains(&"published".to_string()));
+    }
+
+    struct StubSigner(Result<&'static str, &'static str>);
+
+    impl CommitSigner for StubSigner {
+        fn sign(&self, _content: &str) -> Result<String> {
+            self.0
+                .map(str::to_string)
+                .map_err(|e| color_eyre::eyre::eyre!(e))
+        }
+    }
+
+    #[test]
+    fn test_commit_all_signed_uses_signer() {
+        let (dir, client) = repo_with_commits(1);
+        fs::write(dir.path().join("signed.txt"), "signed").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+
+        let signature = "-----BEGIN PGP SIGNATURE-----\nstub\n-----END PGP SIGNATURE-----";
+        let oid = client
+            .commit_all_signed("Signed commit", &StubSigner(Ok(signature)))
+            .expect("Failed to commit");
+
+        let head = client.repo.head().unwrap().target();
+        assert_eq!(
+            head,
+            Some(oid),
+            "Branch should advance to the signed commit"
+        );
+        let (extracted, _) = client
+            .repo
+            .extract_signature(&oid, None)
+            .expect("Commit should carry a signature");
+        assert_eq!(extracted.as_str(), Some(signature));
+    }
+
+    #[test]
+    fn test_commit_all_signed_failure_does_not_commit() {
+        let (dir, client) = repo_with_commits(1);
+        let before = client.repo.head().unwrap().target();
+        fs::write(dir.path().join("signed.txt"), "signed").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+
+        let result = client.commit_all_signed("Signed commit", &StubSigner(Err("no agent")));
+        assert!(result.is_err());
+        assert_eq!(client.repo.head().unwrap().target(), before);
+    }
+
+    #[cfg(unix)]
+    fn install_hook(dir: &Path, name: &str, script: &str) {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join(".git/hooks").join(name);
+        fs::create_dir_all(path.parent().unwrap()).expect("Failed to create hooks dir");
+        fs::write(&path, script).expect("Failed to write hook");
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))
+            .expect("Failed to make hook executable");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_commit_blocked_by_failing_pre_commit_hook() {
+        let (dir, client) = repo_with_commits(1);
+        install_hook(
+            dir.path(),
+            "pre-commit",
+            "#!/bin/sh\necho 'lint: trailing whitespace' >&2\nexit 1\n",
+        );
+        let before = client.repo.head().unwrap().target();
+        fs::write(dir.path().join("hooked.txt"), "hooked").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+
+        let options = CommitOptions {
+            run_hooks: true,
+            ..Default::default()
+        };
+        let err = client
+            .commit("Hooked", &options)
+            .expect_err("Hook should block the commit");
+        assert!(err.to_string().contains("pre-commit hook failed"));
+        assert!(err.to_string().contains("lint: trailing whitespace"));
+        assert_eq!(client.repo.head().unwrap().target(), before);
+
+        // With hooks off the same commit goes through
+        client
+            .commit("Hooked", &CommitOptions::default())
+            .expect("Commit without hooks should succeed");
+        assert_ne!(client.repo.head().unwrap().target(), before);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_commit_msg_hook_can_rewrite_message() {
+        let (dir, client) = repo_with_commits(1);
+        install_hook(
+            dir.path(),
+            "commit-msg",
+            "#!/bin/sh\nprintf 'Rewritten by hook' > \"$1\"\n",
+        );
+        fs::write(dir.path().join("hooked.txt"), "hooked").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+
+        let options = CommitOptions {
+            run_hooks: true,
+            ..Default::default()
+        };
+        let oid = client
+            .commit("Original", &options)
+            .expect("Failed to commit");
+        let commit = client.repo.find_commit(oid).unwrap();
+        assert_eq!(commit.message(), Some("Rewritten by hook"));
+    }
+
+    #[test]
+    fn test_configured_signer_reads_git_config() {
+        let (_dir, client) = repo_with_commits(1);
+        let mut config = client.repo.config().unwrap();
+        config
+            .set_str("user.signingkey", "~/.ssh/id_ed25519.pub")
+            .unwrap();
+        config.set_str("gpg.format", "ssh").unwrap();
+
+        let signer = client.configured_signer().expect("Signer should be built");
+        assert_eq!(signer.format, SigningFormat::Ssh);
+        assert_eq!(signer.key, "~/.ssh/id_ed25519.pub");
+
+        config.set_str("gpg.format", "x509").unwrap();
+        assert!(client.configured_signer().is_err());
+    }
+
+    #[test]
+    fn test_count_commits_between_divergent_branches() {
+        let (_dir, client) = repo_with_commits(2);
+        let repo = &client.repo;
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let base = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("feature", &base, false).unwrap();
+
+        let commit_on = |refname: &str, count: usize| {
+            for i in 0..count {
+                let parent = repo
+                    .find_reference(refname)
+                    .unwrap()
+                    .peel_to_commit()
+                    .unwrap();
+                let tree = parent.tree().unwrap();
+                repo.commit(
+                    Some(refname),
+                    &sig,
+                    &sig,
+                    &format!("{} {}", refname, i),
+                    &tree,
+                    &[&parent],
+                )
+                .expect("Failed to commit");
+            }
+        };
+        commit_on("refs/heads/feature", 3);
+        commit_on("HEAD", 1);
+
+        assert_eq!(client.count_commits_between("HEAD", "feature").unwrap(), 3);
+        assert_eq!(client.count_commits_between("feature", "HEAD").unwrap(), 1);
+        assert_eq!(client.count_commits_between("HEAD", "HEAD").unwrap(), 0);
+        assert!(client.count_commits_between("HEAD", "missing").is_err());
+    }
+
+    #[test]
+    fn test_list_changes_detects_rename() {
+        let (dir, client) = repo_with_commits(1);
+        fs::rename(dir.path().join("file0.txt"), dir.path().join("moved.txt"))
+            .expect("Failed to rename");
+
+        let expected = FileStatus::Renamed {
+            from: "file0.txt".into(),
+            to: "moved.txt".into(),
+        };
+        let changes = client.list_changes().expect("Failed to list changes");
+        assert_eq!(changes.len(), 1, "rename should not be delete + add");
+        assert_eq!(changes[0].status, expected);
+        assert_eq!(changes[0].path, "moved.txt");
+        assert!(!changes[0].staged);
+
+        client.stage_all().expect("Failed to stage");
+        let changes = client.list_changes().expect("Failed to list changes");
+        assert_eq!(changes.len(), 1, "staged rename should not be delete + add");
+        assert_eq!(changes[0].status, expected);
+        assert!(changes[0].staged);
+        assert!(changes[0].diff_preview.contains("rename from file0.txt"));
+    }
+
+    #[test]
+    fn test_stage_file_stages_deletion() {
+        let (dir, client) = repo_with_commits(1);
+        fs::remove_file(dir.path().join("file0.txt")).expect("Failed to delete");
+
+        client.stage_file("file0.txt").expect("Failed to stage");
+        let changes = client.list_changes().expect("Failed to list changes");
+        assert_eq!(changes[0].status, FileStatus::Deleted);
+        assert!(changes[0].staged);
+    }
+
+    #[test]
+    fn test_diff_keeps_non_utf8_lines() {
+        let (dir, client) = repo_with_commits(1);
+        // "café" in latin-1: the é is a lone 0xE9 byte
+        fs::write(dir.path().join("file0.txt"), b"caf\xe9\n").expect("Failed to write");
+
+        let changes = client.list_changes().expect("Failed to list changes");
+        let diff = &changes[0].diff_preview;
+        assert!(!changes[0].is_binary);
+        assert!(diff.starts_with(NON_UTF8_NOTE));
+        assert!(diff.lines().any(|l| l == "+caf\u{FFFD}"));
+        assert!(diff.lines().any(|l| l == "-content 0"));
+    }
+
+    #[test]
+    fn test_uncommitted_stats_counts_staged_and_unstaged_lines() {
+        let (dir, client) = repo_with_commits(1);
+        assert_eq!(client.uncommitted_stats().unwrap(), (0, 0));
+
+        fs::write(dir.path().join("untracked.txt"), "x\n").expect("Failed to write");
+        assert_eq!(client.uncommitted_stats().unwrap(), (0, 0));
+
+        fs::write(dir.path().join("notes.txt"), "one\ntwo\nthree\n").expect("Failed to write");
+        client.stage_file("notes.txt").expect("Failed to stage");
+        assert_eq!(client.uncommitted_stats().unwrap(), (3, 0));
+
+        // Unstaged edits count alongside the staged ones
+        fs::remove_file(dir.path().join("file0.txt")).expect("Failed to delete");
+        assert_eq!(client.uncommitted_stats().unwrap(), (3, 1));
+    }
+
+    #[test]
+    fn test_uncommitted_stats_counts_restaged_edits_once() {
+        let (dir, client) = repo_with_commits(1);
+        fs::write(dir.path().join("file0.txt"), "a\n").expect("Failed to write");
+        client.stage_file("file0.txt").expect("Failed to stage");
+        client.commit_all("Set a").expect("Failed to commit");
+
+        // HEAD "a", index "b", workdir "c" is one line swapped, not two
+        fs::write(dir.path().join("file0.txt"), "b\n").expect("Failed to write");
+        client.stage_file("file0.txt").expect("Failed to stage");
+        fs::write(dir.path().join("file0.txt"), "c\n").expect("Failed to write");
+        assert_eq!(client.uncommitted_stats().unwrap(), (1, 1));
+
+        // A staged line removed again in the workdir nets out
+        fs::write(dir.path().join("file0.txt"), "a\nextra\n").expect("Failed to write");
+        client.stage_file("file0.txt").expect("Failed to stage");
+        fs::write(dir.path().join("file0.txt"), "a\n").expect("Failed to write");
+        assert_eq!(client.uncommitted_stats().unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn test_is_clean() {
+        let (dir, client) = repo_with_commits(1);
+        assert!(client.is_clean().unwrap());
+
+        fs::write(dir.path().join("notes.txt"), "draft").expect("Failed to write");
+        assert!(!client.is_clean().unwrap());
+
+        fs::write(dir.path().join(".gitignore"), "notes.txt\n").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+        client.commit_all("Ignore notes").expect("Failed to commit");
+        assert!(client.is_clean().unwrap(), "ignored files are not changes");
+    }
+
+    #[test]
+    fn test_stage_paths_limits_to_pathspec() {
+        let (dir, client) = repo_with_commits(1);
+        fs::create_dir(dir.path().join("src")).expect("Failed to create dir");
+        fs::write(dir.path().join("src/lib.rs"), "pub fn a() {}").expect("Failed to write");
+        fs::write(dir.path().join("src/main.rs"), "fn main() {}").expect("Failed to write");
+        fs::write(dir.path().join("README.md"), "# Demo").expect("Failed to write");
+
+        assert_eq!(client.stage_paths(&["src/*"]).unwrap(), 2);
+        assert_eq!(client.stage_paths(&["docs/*"]).unwrap(), 0);
+
+        let staged: Vec<(String, bool)> = client
+            .list_changes()
+            .unwrap()
+            .into_iter()
+            .map(|c| (c.path, c.staged))
+            .collect();
+        assert!(staged.contains(&("src/lib.rs".to_string(), true)));
+        assert!(staged.contains(&("src/main.rs".to_string(), true)));
+        assert!(staged.contains(&("README.md".to_string(), false)));
+    }
+
+    #[test]
+    fn test_large_diff_truncated_until_expanded() {
+        let (dir, client) = repo_with_commits(1);
+        let body: String = (0..2000).map(|i| format!("line {}\n", i)).collect();
+        fs::write(dir.path().join("file0.txt"), body).expect("Failed to write");
+
+        let mut change = client.list_changes().expect("Failed to list changes")[0].clone();
+        let lines: Vec<&str> = change.diff_preview.lines().collect();
+        assert_eq!(lines.len(), DEFAULT_PREVIEW_LINE_CAP + 1);
+        assert!(change.hidden_lines > 0);
+        assert_eq!(
+            *lines.last().unwrap(),
+            format!(
+                "\u{2026} ({} more lines, press + to expand)",
+                change.hidden_lines
+            )
+        );
+        assert!(!change.diff_preview.contains("+line 1999"));
+
+        let hidden = change.hidden_lines;
+        client.expand_change(&mut change);
+        assert_eq!(change.hidden_lines, 0);
+        assert!(change.diff_preview.contains("+line 1999"));
+        assert!(!change.diff_preview.contains("more lines"));
+        assert_eq!(
+            change.diff_preview.lines().count(),
+            DEFAULT_PREVIEW_LINE_CAP + hidden
+        );
+    }
+
+    #[test]
+    fn test_batched_previews_match_per_path_diffs() {
+        let (dir, client) = repo_with_commits(5);
+        let write = |name: &str, body: &[u8]| fs::write(dir.path().join(name), body).unwrap();
+        write("file0.txt", b"unstaged edit\n");
+        write("file1.txt", b"staged edit\n");
+        client.stage_file("file1.txt").unwrap();
+        write("file1.txt", b"staged edit\nthen more\n");
+        fs::remove_file(dir.path().join("file2.txt")).unwrap();
+        write("file3.txt", b"\x00\x01binary\x00");
+        let body: String = (0..2000).map(|i| format!("line {}\n", i)).collect();
+        write("file4.txt", body.as_bytes());
+        write("added.txt", b"new and staged\n");
+        client.stage_file("added.txt").unwrap();
+        write("untracked.txt", b"not in the index\n");
+
+        let changes = client.list_changes().expect("Failed to list changes");
+        assert_eq!(changes.len(), 7);
+        for change in &changes {
+            let (local, incoming) =
+                client.path_previews(&[change.path.as_str()], DEFAULT_PREVIEW_LINE_CAP);
+            let mut expected = change.clone();
+            GitClient::fill_previews(&mut expected, local, incoming);
+            let previews = |c: &Change| {
+                (
+                    c.diff_preview.clone(),
+                    c.local_preview.clone(),
+                    c.incoming_preview.clone(),
+                    c.is_binary,
+                    c.hidden_lines,
+                )
+            };
+            assert_eq!(
+                previews(change),
+                previews(&expected),
+                "previews differ for {}",
+                change.path
+            );
+        }
+    }
+
+    #[test]
+    fn test_blame_aligns_with_diff_context() {
+        let (dir, client) = repo_with_commits(1);
+        let file = dir.path().join("file0.txt");
+        fs::write(&file, "one\ntwo\nthree\n").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+        let oid = client.commit_all("Three lines").expect("Failed to commit");
+        fs::write(&file, "one\nTWO\nthree\nfour\n").expect("Failed to write");
+
+        let blame = client.blame_file("file0.txt").expect("Failed to blame");
+        assert_eq!(blame.len(), 3);
+        let diff = client.list_changes().expect("Failed to list changes")[0]
+            .diff_preview
+            .clone();
+        let aligned = align_blame(&diff, &blame);
+        assert_eq!(aligned.len(), diff.lines().count());
+
+        let short = &oid.to_string()[..7];
+        let commit = client.repo.find_commit(oid).expect("Failed to find commit");
+        let author = commit.author().name().unwrap().to_string();
+        let attribution = |text: &str| {
+            let idx = diff.lines().position(|l| l == text).expect("line in diff");
+            aligned[idx].map(|b| (b.short_hash.as_str(), b.author.as_str()))
+        };
+        assert_eq!(attribution(" one"), Some((short, author.as_str())));
+        assert_eq!(attribution("-two"), Some((short, author.as_str())));
+        assert_eq!(attribution(" three"), Some((short, author.as_str())));
+        // Added lines have no history yet
+        assert_eq!(attribution("+TWO"), None);
+        assert_eq!(attribution("+four"), None);
+        assert!(client.blame_file("missing.txt").is_err());
+    }
+
+    #[test]
+    fn test_blame_staged_follows_the_index() {
+        let (dir, client) = repo_with_commits(1);
+        let file = dir.path().join("file0.txt");
+        fs::write(&file, "one\ntwo\n").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+        let oid = client.commit_all("Two lines").expect("Failed to commit");
+        fs::write(&file, "zero\none\ntwo\n").expect("Failed to write");
+        client.stage_file("file0.txt").expect("Failed to stage");
+        fs::write(&file, "zero\none\nTWO\n").expect("Failed to write");
+
+        let blame = client.blame_staged("file0.txt").expect("Failed to blame");
+        let authors: Vec<&str> = blame.iter().map(|b| b.author.as_str()).collect();
+        assert_eq!(authors.len(), 3);
+        assert_eq!(authors[0], NOT_COMMITTED_AUTHOR);
+        let short = &oid.to_string()[..7];
+        assert_eq!(blame[1].short_hash, short);
+        assert_eq!(blame[2].short_hash, short);
+        assert!(client.blame_staged("missing.txt").is_err());
+    }
+
+    #[test]
+    fn test_bare_repo_reports_missing_worktree() {
+        let (dir, _client) = repo_with_commits(2);
+        let bare_dir = TempDir::new().expect("Failed to create temp directory");
+        let bare_path = bare_dir.path().join("repo.git");
+        git2::build::RepoBuilder::new()
+            .bare(true)
+            .clone(dir.path().to_str().unwrap(), &bare_path)
+            .expect("Failed to clone bare repo");
+
+        let client = GitClient::discover(&bare_path).expect("Failed to open bare repo");
+        assert!(client.is_bare());
+
+        let err = client.list_changes().unwrap_err();
+        assert_eq!(err.to_string(), BARE_REPO_MESSAGE);
+        assert!(client.stage_all().is_err());
+        assert!(client.commit_all("nope").is_err());
+        assert!(client.checkout_branch("master").is_err());
+
+        // Browsing history and branches still works
+        assert_eq!(client.get_commit_history(10).unwrap().len(), 2);
+        assert!(!client.list_branches(true, false).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_commit_rejects_an_unchanged_tree() {
+        let (dir, client) = repo_with_commits(1);
+        let head = client.resolve_commit("HEAD").unwrap();
+        let err = client.commit_all("Nothing here").unwrap_err();
+        assert_eq!(err.to_string(), NOTHING_TO_COMMIT_MESSAGE);
+        // Unstaged edits are not enough either
+        fs::write(dir.path().join("file0.txt"), "edited\n").expect("Failed to write");
+        assert!(client.commit_all("Still nothing").is_err());
+        assert_eq!(client.resolve_commit("HEAD").unwrap(), head);
+
+        let options = CommitOptions {
+            allow_empty: true,
+            ..CommitOptions::default()
+        };
+        let empty = client.commit("Marker", &options).unwrap();
+        assert_ne!(empty, head);
+
+        client.stage_file("file0.txt").unwrap();
+        let staged = client.commit_all("Edit file0").unwrap();
+        assert_eq!(client.resolve_commit("HEAD").unwrap(), staged);
+    }
+
+    #[test]
+    fn test_commit_rejects_an_empty_first_commit() {
+        let dir = TempDir::new().expect("Failed to create temp dir");
+        git2::Repository::init(dir.path()).expect("Failed to init repo");
+        let client = GitClient::discover(dir.path()).expect("Failed to open repo");
+        let err = client.commit_all("Empty root").unwrap_err();
+        assert_eq!(err.to_string(), NOTHING_TO_COMMIT_MESSAGE);
+    }
+
+    #[test]
+    fn test_stage_all_reports_a_locked_index() {
+        let (dir, client) = repo_with_commits(1);
+        fs::write(dir.path().join("file0.txt"), "edited\n").expect("Failed to write");
+        let lock = dir.path().join(".git").join("index.lock");
+        fs::write(&lock, "").expect("Failed to write lock");
+
+        let err = client.stage_all().unwrap_err();
+        assert_eq!(err.to_string(), INDEX_LOCKED_MESSAGE);
+        assert!(GitClient::explain_error(&err).starts_with(INDEX_LOCKED_MESSAGE));
+        // A raw git2 lock error is recognised by its code, whatever its text
+        let raw = git2::Error::new(git2::ErrorCode::Locked, git2::ErrorClass::Os, "busy");
+        let explanation = GitClient::explain_error(&raw.into());
+        assert!(explanation.starts_with(INDEX_LOCKED_MESSAGE));
+
+        fs::remove_file(&lock).expect("Failed to remove lock");
+        client.stage_all().expect("Failed to stage once unlocked");
+    }
+
+    #[test]
+    fn test_untracked_file_previews_its_content_as_additions() {
+        let (dir, mut client) = repo_with_commits(1);
+        fs::write(dir.path().join("notes.md"), "first line\nsecond line\n")
+            .expect("Failed to write");
+
+        let changes = client.list_changes().unwrap();
+        let notes = changes.iter().find(|c| c.path == "notes.md").unwrap();
+        assert_eq!(notes.status, FileStatus::Added);
+        assert!(notes.diff_preview.contains("+first line\n+second line"));
+        assert_eq!(notes.local_preview.as_ref(), Some(&notes.diff_preview));
+
+        // The size cap applies to untracked files too
+        client.set_options(GitClientOptions {
+            max_diff_bytes: 8,
+            ..GitClientOptions::default()
+        });
+        let changes = client.list_changes().unwrap();
+        let notes = changes.iter().find(|c| c.path == "notes.md").unwrap();
+        assert_eq!(notes.diff_preview, "File too large to diff (23 bytes)");
+    }
+
+    #[test]
+    fn test_files_over_max_diff_bytes_are_not_diffed() {
+        let (dir, mut client) = repo_with_commits(1);
+        let big = "line of text\n".repeat(40000);
+        fs::write(dir.path().join("file0.txt"), &big).expect("Failed to write");
+        client.set_options(GitClientOptions {
+            max_diff_bytes: 256 * 1024,
+            ..GitClientOptions::default()
+        });
+
+        let change = &client.list_changes().unwrap()[0];
+        assert_eq!(
+            change.diff_preview,
+            format!("File too large to diff ({} bytes)", big.len())
+        );
+        assert!(!change.is_binary);
+
+        // Staged, the same file is skipped on the index side too
+        client.stage_file("file0.txt").unwrap();
+        let change = &client.list_changes().unwrap()[0];
+        assert!(change.diff_preview.starts_with("File too large to diff"));
+
+        client.set_options(GitClientOptions {
+            max_diff_bytes: 0,
+            ..GitClientOptions::default()
+        });
+        let change = &client.list_changes().unwrap()[0];
+        assert!(change.diff_preview.contains("+line of text"));
+    }
+
+    #[test]
+    fn test_include_ignored_option_lists_ignored_files() {
+        let (dir, mut client) = repo_with_commits(1);
+        fs::write(dir.path().join(".gitignore"), "*.log\n").expect("Failed to write");
+        fs::write(dir.path().join("debug.log"), "noise").expect("Failed to write");
+        let paths = |client: &GitClient| -> Vec<String> {
+            client
+                .list_changes()
+                .expect("Failed to list changes")
+                .into_iter()
+                .map(|c| c.path)
+                .collect()
+        };
+
+        assert_eq!(paths(&client), [".gitignore"]);
+
+        client.set_options(GitClientOptions {
+            include_ignored: true,
+            ..GitClientOptions::default()
+        });
+        let listed = paths(&client);
+        assert!(listed.contains(&"debug.log".to_string()));
+        let ignored = client
+            .list_changes()
+            .unwrap()
+            .into_iter()
+            .find(|c| c.path == "debug.log")
+            .unwrap();
+        assert_eq!(ignored.status, FileStatus::Added);
+
+        let no_untracked = GitClient::open_with_options(
+            dir.path(),
+            GitClientOptions {
+                include_untracked: false,
+                ..GitClientOptions::default()
+            },
+        )
+        .expect("Failed to open repo");
+        assert!(paths(&no_untracked).is_empty());
+    }
+
+    #[test]
+    fn test_total_commits() {
+        let (_dir, client) = repo_with_commits(3);
+        assert_eq!(client.total_commits().unwrap(), 3);
+
+        let fresh = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(fresh.path()).expect("Failed to initialize repo");
+        let client = GitClient::discover(fresh.path()).expect("Failed to create client");
+        assert_eq!(client.total_commits().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_tracked_file_count_walks_subdirectories() {
+        let (dir, client) = repo_with_commits(3);
+        assert_eq!(client.tracked_file_count().unwrap(), 3);
+
+        fs::create_dir_all(dir.path().join("src/nested")).expect("Failed to create dir");
+        fs::write(dir.path().join("src/lib.rs"), "pub mod nested;").expect("Failed to write");
+        fs::write(dir.path().join("src/nested/mod.rs"), "").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+        assert_eq!(
+            client.tracked_file_count().unwrap(),
+            3,
+            "staged files are not yet tracked"
+        );
+
+        client.commit_all("Add sources").expect("Failed to commit");
+        assert_eq!(client.tracked_file_count().unwrap(), 5);
+
+        let fresh = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(fresh.path()).expect("Failed to initialize repo");
+        let client = GitClient::discover(fresh.path()).expect("Failed to create client");
+        assert_eq!(client.tracked_file_count().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_commit_diff_has_line_markers() {
+        let (dir, client) = repo_with_commits(1);
+        fs::write(dir.path().join("file0.txt"), "replaced\n").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+        let oid = client
+            .commit_all("Replace file0")
+            .expect("Failed to commit");
+
+        let diff = client
+            .commit_diff(&oid.to_string())
+            .expect("Failed to diff commit");
+        assert!(diff.contains("file0.txt"));
+        assert!(diff.lines().any(|l| l == "-content 0"));
+        assert!(diff.lines().any(|l| l == "+replaced"));
+
+        // Root commit diffs against nothing
+        let root = client.get_commit_history(2).unwrap()[1].0.clone();
+        assert!(client.commit_diff(&root).unwrap().contains("+content 0"));
+    }
+
+    /// Unified diff of the current unstaged changes
+    fn workdir_patch(client: &GitClient) -> String {
+        let diff = client
+            .repo
+            .diff_index_to_workdir(None, None)
+            .expect("Failed to diff workdir");
+        let mut patch = String::new();
+        diff.print(DiffFormat::Patch, |_, _, line| {
+            if matches!(line.origin(), '+' | '-' | ' ') {
+                patch.push(line.origin());
+            }
+            patch.push_str(std::str::from_utf8(line.content()).unwrap_or_default());
+            true
+        })
+        .expect("Failed to format patch");
+        patch
+    }
+
+    #[test]
+    fn test_apply_patch_updates_workdir() {
+        let (dir, client) = repo_with_commits(1);
+        let file = dir.path().join("file0.txt");
+        fs::write(&file, "patched\n").expect("Failed to write");
+        let patch = workdir_patch(&client);
+        fs::write(&file, "content 0").expect("Failed to restore");
+
+        client
+            .apply_patch(&patch, false)
+            .expect("Failed to apply patch");
+        assert_eq!(fs::read_to_string(&file).unwrap(), "patched\n");
+        let changes = client.list_changes().expect("Failed to list changes");
+        assert!(!changes[0].staged);
+
+        // Applying again no longer matches the file
+        let err = client.apply_patch(&patch, false).unwrap_err();
+        assert!(err.to_string().contains("does not apply cleanly"));
+        assert!(client.apply_patch("not a patch", false).is_err());
+    }
+
+    #[test]
+    fn test_apply_patch_to_index_stages_change() {
+        let (dir, client) = repo_with_commits(1);
+        let file = dir.path().join("file0.txt");
+        fs::write(&file, "patched\n").expect("Failed to write");
+        let patch = workdir_patch(&client);
+        fs::write(&file, "content 0").expect("Failed to restore");
+
+        client
+            .apply_patch(&patch, true)
+            .expect("Failed to apply patch");
+        assert_eq!(fs::read_to_string(&file).unwrap(), "patched\n");
+        let changes = client.list_changes().expect("Failed to list changes");
+        assert_eq!(changes.len(), 1);
+        assert!(changes[0].staged);
+    }
+
+    #[test]
+    fn test_path_history_matches_naive_walk() {
+        let (dir, client) = repo_with_commits(4);
+        fs::write(dir.path().join("file1.txt"), "changed").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+        client.commit_all("Change file1").expect("Failed to commit");
+
+        for path in ["file0.txt", "file1.txt", "file3.txt", "missing.txt"] {
+            let naive = client.file_history(path, 10).expect("Failed naive history");
+            let walked = client
+                .path_history(path, None, None, 10)
+                .expect("Failed path history");
+            assert_eq!(walked, naive, "history of {}", path);
+        }
+
+        let file1 = client.path_history("file1.txt", None, None, 10).unwrap();
+        let messages: Vec<&str> = file1.iter().map(|c| c.3.as_str()).collect();
+        assert_eq!(messages, ["Change file1", "Commit 1"]);
+        assert_eq!(
+            client
+                .path_history("file1.txt", None, None, 1)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_path_history_date_range() {
+        let (_dir, client) = repo_with_commits(2);
+        let now = chrono::Utc::now();
+        let day = chrono::Duration::days(1);
+
+        let in_range = client
+            .path_history("file0.txt", Some(now - day), Some(now + day), 10)
+            .unwrap();
+        assert_eq!(in_range.len(), 1);
+        assert!(client
+            .path_history("file0.txt", Some(now + day), None, 10)
+            .unwrap()
+            .is_empty());
+        assert!(client
+            .path_history("file0.txt", None, Some(now - day), 10)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_commit_counts_by_day() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        let repo = git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let now = chrono::Local::now().timestamp();
+        // Two commits yesterday, one today
+        for (i, seconds) in [now - 86_400, now - 86_400, now].into_iter().enumerate() {
+            let sig =
+                git2::Signature::new("Test", "test@example.com", &git2::Time::new(seconds, 0))
+                    .unwrap();
+            let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+            let parents: Vec<&git2::Commit> = parent.iter().collect();
+            repo.commit(
+                Some("HEAD"),
+                &sig,
+                &sig,
+                &format!("Commit {}", i),
+                &tree,
+                &parents,
+            )
+            .unwrap();
+        }
+
+        let client = GitClient::discover(dir.path()).expect("Failed to create client");
+        let counts = client
+            .commit_counts_by_day(3)
+            .expect("Failed to count commits");
+        let today = chrono::Local::now().date_naive();
+        let day = |ago: u64| {
+            (today - chrono::Days::new(ago))
+                .format("%Y-%m-%d")
+                .to_string()
+        };
+        assert_eq!(counts, [(day(2), 0), (day(1), 2), (day(0), 1)]);
+
+        let today_only = client.commit_counts_by_day(1).unwrap();
+        assert_eq!(today_only, [(day(0), 1)]);
+    }
+
+    #[test]
+    fn test_commit_history_pages_are_contiguous() {
+        let (_dir, client) = repo_with_commits(7);
+
+        let mut cursor = HistoryCursor::default();
+        let first = client
+            .commit_history_page(&mut cursor, 4)
+            .expect("Failed to load first page");
+        let second = client
+            .commit_history_page(&mut cursor, 4)
+            .expect("Failed to load second page");
+
+        assert_eq!(first.len(), 4);
+        assert_eq!(second.len(), 3, "Only the remaining commits are returned");
+
+        // Together the pages match a single full walk, in order, without overlap
+        let combined: Vec<String> = first.iter().chain(&second).map(|c| c.0.clone()).collect();
+        let full: Vec<String> = client
+            .get_commit_history(100)
+            .expect("Failed to load history")
+            .into_iter()
+            .map(|c| c.0)
+            .collect();
+        assert_eq!(combined, full);
+        assert_eq!(second[0].3, "Commit 2");
+
+        // Paging past the root commit yields nothing
+        assert!(client
+            .commit_history_page(&mut cursor, 4)
+            .expect("Failed to page past root")
+            .is_empty());
+    }
+
+    /// Repository whose HEAD merges a side branch: "Merge" has parents
+    /// "Main" and "Side", both on top of "Commit 0"
+    fn repo_with_merge() -> (TempDir, GitClient) {
+        let (dir, client) = repo_with_commits(1);
+        let repo = git2::Repository::open(dir.path()).expect("Failed to open repo");
+        let sig = git2::Signature::now("Test", "test@example.com").expect("Failed to create sig");
+        let base = repo.head().unwrap().peel_to_commit().unwrap();
+        let tree = base.tree().unwrap();
+        let side = repo
+            .commit(None, &sig, &sig, "Side", &tree, &[&base])
+            .expect("Failed to commit");
+        let side = repo.find_commit(side).unwrap();
+        let main = repo
+            .commit(Some("HEAD"), &sig, &sig, "Main", &tree, &[&base])
+            .expect("Failed to commit");
+        let main = repo.find_commit(main).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "Merge", &tree, &[&main, &side])
+            .expect("Failed to commit");
+        (dir, client)
+    }
+
+    #[test]
+    fn test_commit_history_pages_cover_both_sides_of_a_merge() {
+        let (_dir, client) = repo_with_merge();
+        let full: Vec<String> = client
+            .get_commit_history(100)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.3)
+            .collect();
+        assert_eq!(full.len(), 4);
+
+        // Every page size splits the history without losing or repeating commits
+        for size in 1..=3 {
+            let mut paged = Vec::new();
+            let mut cursor = HistoryCursor::default();
+            loop {
+                let page = client
+                    .commit_history_page(&mut cursor, size)
+                    .expect("Failed to load page");
+                if page.is_empty() {
+                    break;
+                }
+                paged.extend(page.into_iter().map(|c| c.3));
+            }
+            assert_eq!(paged, full, "page size {}", size);
+        }
+        assert_eq!(full[0], "Merge");
+        assert_eq!(full[3], "Commit 0", "the base comes after both sides");
+    }
+
+    #[test]
+    fn test_squash_counts_first_parents_across_a_merge() {
+        let (_dir, client) = repo_with_merge();
+        let hash_of = |message: &str| {
+            client
+                .get_commit_history(10)
+                .unwrap()
+                .into_iter()
+                .find(|c| c.3 == message)
+                .map(|c| c.0)
+                .unwrap()
+        };
+
+        let side = hash_of("Side");
+        assert!(client.first_parent_distance(&side).is_err());
+        assert_eq!(client.first_parent_distance("HEAD").unwrap(), 0);
+
+        // The full walk counts "Side" too, which would squash one commit too many
+        let base = hash_of("Commit 0");
+        assert_eq!(client.count_commits_between(&base, "HEAD").unwrap(), 3);
+        let main = hash_of("Main");
+        let count = client.first_parent_distance(&main).unwrap() + 1;
+        assert_eq!(count, 2);
+        client
+            .squash_last(count, "Squashed")
+            .expect("Failed to squash");
+
+        let messages: Vec<String> = client
+            .get_commit_history(10)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.3)
+            .collect();
+        assert_eq!(messages, ["Squashed", "Commit 0"]);
+    }
+
+    #[test]
+    fn test_binary_file_preview() {
+        let (dir, client) = repo_with_commits(1);
+        let repo_path = dir.path();
+
+        // Stage a new binary blob (NUL bytes force binary detection)
+        let blob: Vec<u8> = vec![0u8, 159, 146, 150, 0, 255, 1, 2, 3, 0];
+        fs::write(repo_path.join("image.bin"), &blob).expect("Failed to write blob");
+        client
+            .stage_file("image.bin")
+            .expect("Failed to stage blob");
+
+        let changes = client.list_changes().expect("Failed to list changes");
+        let change = changes
+            .iter()
+            .find(|c| c.path == "image.bin")
+            .expect("Binary file should be listed");
+
+        assert!(change.is_binary);
+        assert_eq!(change.diff_preview, "Binary file (10 bytes)");
+        assert_eq!(
+            change.incoming_preview.as_deref(),
+            Some("Binary file (10 bytes)")
+        );
+
+        // Text files are unaffected
+        fs::write(repo_path.join("file0.txt"), "changed").expect("Failed to write");
+        let changes = client.list_changes().expect("Failed to list changes");
+        let text = changes
+            .iter()
+            .find(|c| c.path == "file0.txt")
+            .expect("Text file should be listed");
+        assert!(!text.is_binary);
+        assert!(text.diff_preview.contains("changed"));
+    }
+
+    #[test]
+    fn test_last_fetch_time() {
+        let (upstream_dir, _upstream) = repo_with_commits(2);
+        let (_dir, client) = repo_with_commits(1);
+        let url = upstream_dir.path().to_string_lossy().to_string();
+        client
+            .repo
+            .remote("origin", &url)
+            .expect("Failed to add remote");
+
+        assert!(
+            client.last_fetch_time("origin").is_none(),
+            "Never fetched yet"
+        );
+        assert!(client.last_fetch_time("missing").is_none());
+
+        client
+            .fetch("origin")
+            .expect("Failed to fetch local remote");
+
+        let fetched = client
+            .last_fetch_time("origin")
+            .expect("Fetch time should be recorded");
+        let age = SystemTime::now()
+            .duration_since(fetched)
+            .unwrap_or_default();
+        assert!(age.as_secs() < 60, "Fetch should be recent, was {:?}", age);
+
+        // A linked worktree reads the fetch recorded in the common directory
+        let linked_dir = TempDir::new().expect("Failed to create temp directory");
+        let linked_path = linked_dir.path().join("feature");
+        client
+            .repo
+            .worktree("feature", &linked_path, None)
+            .expect("Failed to add worktree");
+        let linked = GitClient::discover(&linked_path).expect("Failed to open worktree");
+        assert_eq!(linked.last_fetch_time("origin"), Some(fetched));
+    }
+
+    #[test]
+    fn test_status_porcelain_codes() {
+        let (dir, client) = repo_with_commits(3);
+        let root = dir.path();
+        // Staged, then changed again in the working tree
+        fs::write(root.join("file0.txt"), "staged edit").unwrap();
+        fs::write(root.join("added.txt"), "a brand new tracked file").unwrap();
+        let mut index = client.repo.index().unwrap();
+        index.add_path(Path::new("file0.txt")).unwrap();
+        index.add_path(Path::new("added.txt")).unwrap();
+        index.write().unwrap();
+        fs::write(root.join("file0.txt"), "unstaged edit").unwrap();
+        fs::write(root.join("file1.txt"), "only in the working tree").unwrap();
+        fs::remove_file(root.join("file2.txt")).unwrap();
+        fs::write(root.join("notes.md"), "# scratch\nnothing to see here\n").unwrap();
+
+        let porcelain = client.status_porcelain().expect("Failed to get status");
+        let lines: Vec<&str> = porcelain.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "A  added.txt",
+                "MM file0.txt",
+                " M file1.txt",
+                " D file2.txt",
+                "?? notes.md",
+            ]
+        );
+        assert_eq!(client.list_changes().unwrap().len(), lines.len());
+    }
+
+    #[test]
+    fn test_fetch_all_remotes() {
+        let (first_dir, _first) = repo_with_commits(2);
+        let (second_dir, _second) = repo_with_commits(3);
+        let (_dir, client) = repo_with_commits(1);
+        for (name, dir) in [("origin", &first_dir), ("backup", &second_dir)] {
+            client
+                .repo
+                .remote(name, &dir.path().to_string_lossy())
+                .expect("Failed to add remote");
+        }
+
+        let fetched = client.fetch_all().expect("Failed to fetch all");
+        let names: Vec<&str> = fetched.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names.len(), 2);
+        assert!(fetched.iter().all(|(_, result)| result.is_ok()));
+        assert!(names.contains(&"origin") && names.contains(&"backup"));
+        for name in ["origin", "backup"] {
+            let tracking = client
+                .repo
+                .references_glob(&format!("refs/remotes/{}/*", name))
+                .unwrap()
+                .count();
+            assert!(tracking > 0, "{} should have remote-tracking refs", name);
+        }
+    }
+
+    #[test]
+    fn test_fetch_all_reports_failed_remote() {
+        let (good_dir, _good) = repo_with_commits(2);
+        let (_dir, client) = repo_with_commits(1);
+        client
+            .repo
+            .remote("good", &good_dir.path().to_string_lossy())
+            .expect("Failed to add remote");
+        client
+            .repo
+            .remote("broken", "/nonexistent/forge-remote")
+            .expect("Failed to add remote");
+
+        let fetched = client.fetch_all().expect("Failed to fetch all");
+        assert_eq!(fetched.len(), 2);
+        for (name, result) in &fetched {
+            match name.as_str() {
+                "good" => assert!(result.is_ok(), "{:?}", result),
+                "broken" => assert!(result.is_err()),
+                other => panic!("unexpected remote {}", other),
+            }
+        }
+        // The good remote was still fetched
+        let tracking = client
+            .repo
+            .references_glob("refs/remotes/good/*")
+            .unwrap()
+            .count();
+        assert!(tracking > 0);
+    }
+
     #[test]
     fn test_list_remotes() {
         // Create a temporary directory
@@ -1462,15 +4721,18 @@ mod integration_tests {
         );
 
         // Verify we're on the correct branch
-        let current_branch = client.head_branch().expect("Failed to get current branch");
         assert_eq!(
-            current_branch, "feature/test",
+            client.head_branch(),
+            HeadRef::Branch("feature/test".into()),
             "Should be on feature/test branch after switch"
         );
 
         // Create and commit a new file on the branch
         let feature_file = repo_path.join("feature.txt");
         fs::write(&feature_file, "feature content").expect("Failed to write feature file");
+        client
+            .stage_file("feature.txt")
+            .expect("Failed to stage feature file");
 
         let commit_result = client.commit_all("Add feature file");
         assert!(
@@ -1,5 +1,7 @@
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 
+use crate::pages::settings::{COMMIT_DATES_SETTING, NOTIFICATIONS_SETTING, THEME_SETTING};
+use crate::state::PromptKind;
 use crate::ui_utils::adjust_pane_ratio;
 use crate::{AppMode, Focus};
 
@@ -24,9 +26,34 @@ pub enum KeyAction {
     Fetch,
     Push,
     Pull,
+    CopyChangeset,
+    ToggleActionLog,
+    /// Stage everything and commit from any view
+    QuickCommit,
+    /// Apply a patch file to the working tree (Changes view)
+    ApplyPatch,
+    /// Open the fuzzy branch-switch picker from any view
+    BranchPicker,
+    /// Browse HEAD's reflog from any view
+    Reflog,
+    /// Switch to another worktree of the repository from any view
+    Worktrees,
+    /// Toggle the setting at this Settings row from any view
+    ToggleSetting(usize),
+    /// Write `git status --porcelain` output to a file, for debugging
+    DumpStatus,
+    /// Rename the selected row in place (Module Manager)
+    Rename,
+    /// Swap to the view shown before the current one
+    AlternateView,
+    /// Hide the banner warning that no repository is open
+    DismissBanner,
     PaneNarrow,
     PaneWiden,
-    TerminalResized,
+    /// The terminal is now this many columns and rows
+    Resize(u16, u16),
+    /// No input arrived within the poll timeout
+    Tick,
     None,
 }
 
@@ -44,12 +71,25 @@ impl KeyHandler {
         Self
     }
 
-    pub fn handle_crossterm_events(&mut self) -> color_eyre::Result<KeyAction> {
+    /// Waits up to `timeout` for input, returning `KeyAction::Tick` if none arrives.
+    /// While `text_input` is set, keys are mapped by [`Self::on_text_key_event`].
+    pub fn handle_crossterm_events(
+        &mut self,
+        timeout: std::time::Duration,
+        text_input: bool,
+    ) -> color_eyre::Result<KeyAction> {
+        if !event::poll(timeout)? {
+            return Ok(KeyAction::Tick);
+        }
         match event::read()? {
             // it's important to check KeyEventKind::Press to avoid handling key release events
-            Event::Key(key) if key.kind == KeyEventKind::Press => Ok(self.on_key_event(key)),
+            Event::Key(key) if key.kind == KeyEventKind::Press => Ok(if text_input {
+                self.on_text_key_event(key)
+            } else {
+                self.on_key_event(key)
+            }),
             Event::Mouse(_) => Ok(KeyAction::None),
-            Event::Resize(_, _) => Ok(KeyAction::TerminalResized),
+            Event::Resize(width, height) => Ok(KeyAction::Resize(width, height)),
             _ => Ok(KeyAction::None),
         }
     }
@@ -62,22 +102,75 @@ impl KeyHandler {
             (_, KeyCode::Char('?')) => KeyAction::Help,
             (KeyModifiers::CONTROL, KeyCode::Char('f') | KeyCode::Char('F')) => KeyAction::Search,
             (KeyModifiers::CONTROL, KeyCode::Char('l') | KeyCode::Char('L')) => KeyAction::Pull,
+            (KeyModifiers::CONTROL, KeyCode::Char('g') | KeyCode::Char('G')) => {
+                KeyAction::ToggleActionLog
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('y') | KeyCode::Char('Y')) => {
+                KeyAction::CopyChangeset
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('p') | KeyCode::Char('P')) => {
+                KeyAction::ApplyPatch
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('b') | KeyCode::Char('B')) => {
+                KeyAction::BranchPicker
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('r') | KeyCode::Char('R')) => KeyAction::Reflog,
+            (KeyModifiers::CONTROL, KeyCode::Char('w') | KeyCode::Char('W')) => {
+                KeyAction::Worktrees
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('t') | KeyCode::Char('T')) => {
+                KeyAction::ToggleSetting(THEME_SETTING)
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('n') | KeyCode::Char('N')) => {
+                KeyAction::ToggleSetting(NOTIFICATIONS_SETTING)
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('d') | KeyCode::Char('D')) => {
+                KeyAction::ToggleSetting(COMMIT_DATES_SETTING)
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('x') | KeyCode::Char('X')) => {
+                KeyAction::DismissBanner
+            }
+            // Without keyboard enhancement most terminals report Ctrl+Enter as Ctrl+J
+            (KeyModifiers::CONTROL, KeyCode::Enter | KeyCode::Char('j') | KeyCode::Char('J')) => {
+                KeyAction::QuickCommit
+            }
+            // Terminals send Ctrl+^ as Ctrl+6, as in vim's alternate-file key
+            (KeyModifiers::CONTROL, KeyCode::Char('6') | KeyCode::Char('^')) => {
+                KeyAction::AlternateView
+            }
+            // Debug aid, deliberately left out of the help overlay
+            (KeyModifiers::NONE, KeyCode::F(12)) => KeyAction::DumpStatus,
+            (KeyModifiers::NONE, KeyCode::F(2)) => KeyAction::Rename,
             (KeyModifiers::NONE, KeyCode::Tab) => KeyAction::NextView,
             (KeyModifiers::NONE, KeyCode::Up | KeyCode::Char('k')) => KeyAction::NavigateUp,
             (KeyModifiers::NONE, KeyCode::Down | KeyCode::Char('j')) => KeyAction::NavigateDown,
             (KeyModifiers::NONE, KeyCode::Left | KeyCode::Char('h')) => KeyAction::NavigateLeft,
             (KeyModifiers::NONE, KeyCode::Right | KeyCode::Char('l')) => KeyAction::NavigateRight,
-            (KeyModifiers::ALT, KeyCode::Left) => KeyAction::PaneNarrow,
-            (KeyModifiers::ALT, KeyCode::Right) => KeyAction::PaneWiden,
+            (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT, KeyCode::Left) => {
+                KeyAction::PaneNarrow
+            }
+            (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT, KeyCode::Right) => {
+                KeyAction::PaneWiden
+            }
             (KeyModifiers::NONE, KeyCode::PageUp) => KeyAction::ScrollPageUp,
             (KeyModifiers::NONE, KeyCode::PageDown) => KeyAction::ScrollPageDown,
             (KeyModifiers::NONE, KeyCode::Enter) => KeyAction::Select,
             (KeyModifiers::NONE, KeyCode::Backspace) => KeyAction::Backspace,
             (KeyModifiers::NONE, KeyCode::Char(' ')) => KeyAction::ToggleStaging,
-            (KeyModifiers::NONE, KeyCode::Char(c)) => KeyAction::InputChar(c),
+            // Uppercase letters arrive with SHIFT set
+            (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => KeyAction::InputChar(c),
             _ => KeyAction::None,
         }
     }
+
+    /// Like [`Self::on_key_event`], but every printable character is text, so
+    /// `q`, `?`, space and the vim keys can be typed into prompts and filters
+    pub fn on_text_key_event(&mut self, key: KeyEvent) -> KeyAction {
+        match (key.modifiers, key.code) {
+            (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => KeyAction::InputChar(c),
+            _ => self.on_key_event(key),
+        }
+    }
 }
 
 /// Action handler result: (should_quit, side_effects_callback)
@@ -152,6 +245,7 @@ pub struct ActionContext {
     pub focus: Focus,
     pub current_view: AppMode,
     pub show_help: bool,
+    pub show_action_log: bool,
     pub search_active: bool,
     pub menu_selected_index: usize,
     pub selected_project_index: usize,
@@ -180,22 +274,91 @@ pub struct ActionContext {
     pub module_edit_mode: bool,
     pub developer_create_mode: bool,
     pub module_assign_mode: bool,
+    /// Picking dependencies for a module in the Module Manager
+    pub module_dependency_mode: bool,
+    /// A module or developer row is being renamed in place
+    pub module_rename_mode: bool,
     pub module_input_empty: bool,
+    /// A "discard commit message and quit?" confirmation is on screen
+    pub confirm_quit_pending: bool,
+    /// The diff quick view popup is on screen
+    pub quick_view_open: bool,
+    /// A search is being typed in the quick view popup
+    pub quick_view_searching: bool,
+    /// A text prompt is on screen, and for what
+    pub text_prompt: Option<PromptKind>,
+    pub text_prompt_input_empty: bool,
+    /// The branch-switch picker is on screen
+    pub branch_picker_open: bool,
+    /// The reflog popup is on screen
+    pub reflog_open: bool,
+    /// The worktree switcher popup is on screen
+    pub worktrees_open: bool,
+    /// Commit History is narrowed to an author
+    pub commit_filter_active: bool,
+    /// Plain Enter must not commit from the Changes view; Ctrl+Enter does
+    pub commit_requires_modifier: bool,
+    /// Digits typed so far form a count for the next navigation key
+    pub count_pending: bool,
+    /// The view shown before the current one, for the alternate-view key
+    pub previous_view: Option<AppMode>,
+    /// Space ticks Changes rows for staging together instead of staging one
+    pub changes_multi_select: bool,
 }
 
+/// Percentage points `+` / `-` move a module's progress in Module Manager
+pub const MODULE_PROGRESS_STEP: i16 = 5;
+
+/// Lines scrolled by PageUp/PageDown in the quick view popup
+const QUICK_VIEW_PAGE: i16 = 10;
+
 /// Stateless action processor: takes action + context, returns result + modified state
 pub struct ActionProcessor;
 
 impl ActionProcessor {
     pub fn process(action: KeyAction, ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
-        match action {
-            KeyAction::Quit => (
+        // Scroll windows follow the terminal size whatever popup is open
+        if let KeyAction::Resize(width, height) = action {
+            return (
                 ActionResult {
-                    should_quit: true,
+                    should_quit: false,
                     status_message: None,
                 },
-                ActionStateUpdate::none(),
-            ),
+                ActionStateUpdate {
+                    terminal_resized: Some((width, height)),
+                    ..Default::default()
+                },
+            );
+        }
+        // A pending confirmation swallows every key until it is answered
+        if ctx.confirm_quit_pending {
+            return Self::handle_quit_confirmation(action);
+        }
+        // A text prompt captures typing until submitted or cancelled
+        if let Some(kind) = ctx.text_prompt {
+            return Self::handle_text_prompt(action, kind, ctx);
+        }
+        // The branch picker takes typing as its filter; arrows, Enter and Esc
+        // drive it and Ctrl+C still quits
+        if ctx.branch_picker_open && !matches!(action, KeyAction::Quit | KeyAction::Tick) {
+            return Self::handle_branch_picker(action);
+        }
+        // The reflog popup keeps navigation keys for its list; quitting still works
+        if ctx.reflog_open && !matches!(action, KeyAction::Quit | KeyAction::Tick) {
+            return Self::handle_reflog(action);
+        }
+        if ctx.worktrees_open && !matches!(action, KeyAction::Quit | KeyAction::Tick) {
+            return Self::handle_worktrees(action);
+        }
+        // The quick view keeps navigation keys for scrolling; quitting still works,
+        // except while a search query is being typed
+        let quits = matches!(action, KeyAction::Quit) && !ctx.quick_view_searching;
+        if ctx.quick_view_open && !quits && !matches!(action, KeyAction::Tick) {
+            return Self::handle_quick_view(action, ctx.quick_view_searching);
+        }
+
+        match action {
+            KeyAction::Quit => Self::handle_quit(ctx),
             KeyAction::Help => (
                 ActionResult {
                     should_quit: false,
@@ -206,88 +369,153 @@ impl ActionProcessor {
                     ..Default::default()
                 },
             ),
-            KeyAction::Back => {
-                if ctx.show_help {
-                    return (
+            // With the safety setting on, Ctrl+Enter is how the typed message is committed
+            KeyAction::QuickCommit
+                if ctx.commit_requires_modifier
+                    && ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::Changes)
+                    && !ctx.commit_message_empty =>
+            {
+                Self::handle_commit(ctx)
+            }
+            KeyAction::QuickCommit => {
+                if ctx.has_git_client {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some(
+                                "Stage all and commit: type a message, Enter to commit".into(),
+                            ),
+                        },
+                        ActionStateUpdate {
+                            text_prompt_open: Some(PromptKind::QuickCommit),
+                            ..Default::default()
+                        },
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("No Git repository detected".into()),
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::BranchPicker => {
+                if ctx.has_git_client {
+                    (
                         ActionResult {
                             should_quit: false,
                             status_message: None,
                         },
                         ActionStateUpdate {
-                            show_help: Some(false),
+                            branch_picker_requested: Some(()),
                             ..Default::default()
                         },
-                    );
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("No Git repository detected".into()),
+                        },
+                        ActionStateUpdate::none(),
+                    )
                 }
-                if ctx.search_active {
-                    return (
+            }
+            KeyAction::ToggleSetting(index) => (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate {
+                    toggle_setting: Some(index),
+                    ..Default::default()
+                },
+            ),
+            KeyAction::Worktrees => {
+                if ctx.has_git_client {
+                    (
                         ActionResult {
                             should_quit: false,
-                            status_message: Some("Exited search".into()),
+                            status_message: None,
                         },
                         ActionStateUpdate {
-                            search_active: Some(false),
-                            search_buffer: Some(String::new()),
-                            selected_project_index: Some(0),
+                            worktrees_requested: Some(()),
                             ..Default::default()
                         },
-                    );
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("No Git repository detected".into()),
+                        },
+                        ActionStateUpdate::none(),
+                    )
                 }
-                if ctx.branch_create_mode {
-                    return (
+            }
+            KeyAction::Reflog => {
+                if ctx.has_git_client {
+                    (
                         ActionResult {
                             should_quit: false,
-                            status_message: Some("Cancelled branch creation".into()),
+                            status_message: None,
                         },
                         ActionStateUpdate {
-                            branch_create_mode: Some(false),
-                            branch_input_clear: Some(()),
+                            reflog_requested: Some(()),
                             ..Default::default()
                         },
-                    );
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("No Git repository detected".into()),
+                        },
+                        ActionStateUpdate::none(),
+                    )
                 }
-                if ctx.module_create_mode
-                    || ctx.module_edit_mode
-                    || ctx.developer_create_mode
-                    || ctx.module_assign_mode
+            }
+            KeyAction::ApplyPatch => {
+                if ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::Changes)
+                    && ctx.has_git_client
                 {
-                    return (
+                    (
                         ActionResult {
                             should_quit: false,
-                            status_message: Some("Cancelled".into()),
+                            status_message: Some(
+                                "Apply patch: type the file path, Enter to apply".into(),
+                            ),
                         },
                         ActionStateUpdate {
-                            module_create_mode: Some(false),
-                            module_edit_mode: Some(false),
-                            developer_create_mode: Some(false),
-                            module_assign_mode: Some(false),
-                            module_input_clear: Some(()),
+                            text_prompt_open: Some(PromptKind::ApplyPatch),
                             ..Default::default()
                         },
-                    );
-                }
-                if ctx.focus == Focus::Menu {
-                    return (
+                    )
+                } else {
+                    (
                         ActionResult {
-                            should_quit: true,
+                            should_quit: false,
                             status_message: None,
                         },
                         ActionStateUpdate::none(),
-                    );
+                    )
                 }
-                (
-                    ActionResult {
-                        should_quit: false,
-                        status_message: Some(
-                            "Menu: Tab to navigate, ↵ to select, q to quit".into(),
-                        ),
-                    },
-                    ActionStateUpdate {
-                        focus: Some(Focus::Menu),
-                        ..Default::default()
-                    },
-                )
             }
+            KeyAction::ToggleActionLog => (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate {
+                    show_action_log: Some(!ctx.show_action_log),
+                    ..Default::default()
+                },
+            ),
+            KeyAction::Back => Self::handle_back(ctx),
             KeyAction::NextView => {
                 if ctx.focus == Focus::Menu {
                     let menu_len = 5; // Fixed: 5 menu items
@@ -303,37 +531,81 @@ impl ActionProcessor {
                         },
                     )
                 } else {
-                    let next_view = ctx.current_view.next();
-                    let next_idx = next_view.menu_index();
-                    let update = ActionStateUpdate {
-                        current_view: Some(next_view),
-                        menu_selected_index: Some(next_idx),
-                        search_active: if matches!(next_view, AppMode::Dashboard) {
-                            None
-                        } else {
-                            Some(false)
-                        },
-                        search_buffer: if matches!(next_view, AppMode::Dashboard) {
-                            None
-                        } else {
-                            Some(String::new())
-                        },
-                        ..Default::default()
-                    };
                     (
                         ActionResult {
                             should_quit: false,
                             status_message: None,
                         },
-                        update,
+                        Self::switch_view(ctx.current_view.next()),
                     )
                 }
             }
+            KeyAction::AlternateView => match ctx.previous_view {
+                Some(view) => (
+                    ActionResult {
+                        should_quit: false,
+                        status_message: None,
+                    },
+                    ActionStateUpdate {
+                        focus: Some(Focus::View),
+                        ..Self::switch_view(view)
+                    },
+                ),
+                None => (
+                    ActionResult {
+                        should_quit: false,
+                        status_message: Some("No previous view yet".into()),
+                    },
+                    ActionStateUpdate::none(),
+                ),
+            },
             KeyAction::Select => Self::handle_select(ctx),
             KeyAction::NavigateUp => Self::handle_navigate_up(ctx),
             KeyAction::NavigateDown => Self::handle_navigate_down(ctx),
             KeyAction::NavigateLeft => Self::handle_navigate_left(ctx),
             KeyAction::NavigateRight => Self::handle_navigate_right(ctx),
+            KeyAction::InputChar(c)
+                if c.is_ascii_digit()
+                    && (c != '0' || ctx.count_pending)
+                    && Self::accepts_count(ctx) =>
+            {
+                (
+                    ActionResult {
+                        should_quit: false,
+                        status_message: None,
+                    },
+                    ActionStateUpdate {
+                        count_digit: c.to_digit(10),
+                        ..Default::default()
+                    },
+                )
+            }
+            // Like vim's command line, ':' jumps to a revision unless text is being typed
+            KeyAction::InputChar(':') if !Self::is_typing(ctx) => {
+                if ctx.has_git_client {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some(
+                                "Go to revision: type HEAD~3, a tag, branch or hash, Enter to jump"
+                                    .into(),
+                            ),
+                        },
+                        ActionStateUpdate {
+                            text_prompt_open: Some(PromptKind::GoToRef),
+                            ..Default::default()
+                        },
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("No Git repository detected".into()),
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
             KeyAction::InputChar(c) => {
                 if ctx.search_active {
                     (
@@ -371,72 +643,84 @@ impl ActionProcessor {
                                 ..Default::default()
                             },
                         ),
-                        _ => (
+                        'v' if ctx.commit_message_empty => (
                             ActionResult {
                                 should_quit: false,
                                 status_message: None,
                             },
                             ActionStateUpdate {
-                                commit_message_append: Some(c),
+                                quick_view_requested: Some(()),
                                 ..Default::default()
                             },
                         ),
-                    }
-                } else if ctx.focus == Focus::View && matches!(ctx.current_view, AppMode::Dashboard)
-                {
-                    match c {
-                        'f' => (
+                        'Y' if ctx.commit_message_empty => (
                             ActionResult {
                                 should_quit: false,
-                                status_message: Some("Fetching from origin...".into()),
+                                status_message: None,
                             },
                             ActionStateUpdate {
-                                fetch_requested: Some(()),
+                                copy_diff_requested: Some(()),
                                 ..Default::default()
                             },
                         ),
-                        _ => (
+                        'B' if ctx.commit_message_empty => (
                             ActionResult {
                                 should_quit: false,
                                 status_message: None,
                             },
-                            ActionStateUpdate::none(),
+                            ActionStateUpdate {
+                                blame_toggle: Some(()),
+                                ..Default::default()
+                            },
                         ),
-                    }
-                } else if ctx.focus == Focus::View
-                    && matches!(ctx.current_view, AppMode::BranchManager)
-                {
-                    // Handle branch-specific actions
-                    match c {
-                        'n' if !ctx.branch_create_mode => (
+                        '+' if ctx.commit_message_empty => (
                             ActionResult {
                                 should_quit: false,
-                                status_message: Some(
-                                    "Enter branch name (Enter to create, Esc to cancel)".into(),
-                                ),
+                                status_message: None,
                             },
                             ActionStateUpdate {
-                                branch_create_mode: Some(true),
+                                diff_expand_requested: Some(()),
                                 ..Default::default()
                             },
                         ),
-                        'd' if !ctx.branch_create_mode => (
+                        'o' if ctx.commit_message_empty => (
                             ActionResult {
                                 should_quit: false,
-                                status_message: Some("Deleting branch...".into()),
+                                status_message: None,
                             },
                             ActionStateUpdate {
-                                branch_delete_requested: Some(()),
+                                open_in_editor_requested: Some(()),
                                 ..Default::default()
                             },
                         ),
-                        _ if ctx.branch_create_mode => (
+                        'S' if ctx.commit_message_empty => (
                             ActionResult {
                                 should_quit: false,
                                 status_message: None,
                             },
                             ActionStateUpdate {
-                                branch_input_append: Some(c),
+                                stage_and_advance_requested: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        'V' if ctx.commit_message_empty => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                multi_select_toggle: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        // 'p' pushes here, so the path copy takes Shift
+                        'P' if ctx.commit_message_empty => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                copy_path_requested: Some(()),
                                 ..Default::default()
                             },
                         ),
@@ -445,121 +729,47 @@ impl ActionProcessor {
                                 should_quit: false,
                                 status_message: None,
                             },
-                            ActionStateUpdate::none(),
+                            ActionStateUpdate {
+                                commit_message_append: Some(c),
+                                ..Default::default()
+                            },
                         ),
                     }
-                } else if ctx.focus == Focus::View
-                    && matches!(ctx.current_view, AppMode::ModuleManager)
+                } else if ctx.focus == Focus::View && matches!(ctx.current_view, AppMode::Dashboard)
                 {
-                    // Handle module manager specific actions
                     match c {
-                        'a' if !ctx.module_create_mode
-                            && !ctx.module_edit_mode
-                            && !ctx.developer_create_mode
-                            && !ctx.module_manager_in_developer_list =>
-                        {
-                            (
-                                ActionResult {
-                                    should_quit: false,
-                                    status_message: Some(
-                                        "Press ↑↓ to select developer, Enter to assign".into(),
-                                    ),
-                                },
-                                ActionStateUpdate {
-                                    module_assign_mode: Some(true),
-                                    ..Default::default()
-                                },
-                            )
-                        }
-                        'n' if !ctx.module_create_mode
-                            && !ctx.module_edit_mode
-                            && !ctx.developer_create_mode =>
-                        {
-                            let in_developer_list = ctx.module_manager_in_developer_list;
-                            (
-                                ActionResult {
-                                    should_quit: false,
-                                    status_message: Some(if in_developer_list {
-                                        "Enter developer name (Enter to create, Esc to cancel)"
-                                            .into()
-                                    } else {
-                                        "Enter module name (Enter to create, Esc to cancel)".into()
-                                    }),
-                                },
-                                ActionStateUpdate {
-                                    module_create_mode: if !in_developer_list {
-                                        Some(true)
-                                    } else {
-                                        None
-                                    },
-                                    developer_create_mode: if in_developer_list {
-                                        Some(true)
-                                    } else {
-                                        None
-                                    },
-                                    ..Default::default()
-                                },
-                            )
-                        }
-                        'e' if !ctx.module_create_mode
-                            && !ctx.module_edit_mode
-                            && !ctx.developer_create_mode
-                            && !ctx.module_manager_in_developer_list =>
-                        {
-                            (
-                                ActionResult {
-                                    should_quit: false,
-                                    status_message: Some(
-                                        "Edit module name (Enter to save, Esc to cancel)".into(),
-                                    ),
-                                },
-                                ActionStateUpdate {
-                                    module_edit_mode: Some(true),
-                                    module_load_selected: Some(()),
-                                    ..Default::default()
-                                },
-                            )
-                        }
-                        'd' if !ctx.module_create_mode
-                            && !ctx.module_edit_mode
-                            && !ctx.developer_create_mode =>
-                        {
-                            let in_developer_list = ctx.module_manager_in_developer_list;
-                            (
-                                ActionResult {
-                                    should_quit: false,
-                                    status_message: Some("Deleting...".into()),
-                                },
-                                ActionStateUpdate {
-                                    module_delete_requested: if !in_developer_list {
-                                        Some(())
-                                    } else {
-                                        None
-                                    },
-                                    developer_delete_requested: if in_developer_list {
-                                        Some(())
-                                    } else {
-                                        None
-                                    },
-                                    ..Default::default()
-                                },
-                            )
-                        }
-                        _ if ctx.module_create_mode
-                            || ctx.module_edit_mode
-                            || ctx.developer_create_mode =>
-                        {
-                            (
-                                ActionResult {
-                                    should_quit: false,
-                                    status_message: None,
-                                },
-                                ActionStateUpdate {
-                                    module_input_append: Some(c),
-                                    ..Default::default()
-                                },
-                            )
-                        }
+                        'c' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: Some(
+                                    "Clone: type a repository URL, Enter to clone it here".into(),
+                                ),
+                            },
+                            ActionStateUpdate {
+                                text_prompt_open: Some(PromptKind::CloneRepo),
+                                ..Default::default()
+                            },
+                        ),
+                        'f' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: Some("Fetching from origin...".into()),
+                            },
+                            ActionStateUpdate {
+                                fetch_requested: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        'o' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                cycle_project_sort: Some(()),
+                                ..Default::default()
+                            },
+                        ),
                         _ => (
                             ActionResult {
                                 should_quit: false,
@@ -568,1161 +778,3688 @@ impl ActionProcessor {
                             ActionStateUpdate::none(),
                         ),
                     }
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                }
-            }
-            KeyAction::Backspace => {
-                if ctx.search_active {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate {
-                            search_buffer_pop: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                } else if ctx.focus == Focus::View && matches!(ctx.current_view, AppMode::Changes) {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate {
-                            commit_message_pop: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                } else if ctx.focus == Focus::View
-                    && matches!(ctx.current_view, AppMode::BranchManager)
-                    && ctx.branch_create_mode
-                {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate {
-                            branch_input_pop: Some(()),
-                            ..Default::default()
-                        },
-                    )
                 } else if ctx.focus == Focus::View
-                    && matches!(ctx.current_view, AppMode::ModuleManager)
-                    && (ctx.module_create_mode || ctx.module_edit_mode || ctx.developer_create_mode)
+                    && matches!(ctx.current_view, AppMode::MergeVisualizer)
                 {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate {
-                            module_input_pop: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                }
-            }
-            KeyAction::ScrollPageUp => {
-                let update = match ctx.current_view {
-                    AppMode::Dashboard => ActionStateUpdate {
-                        project_scroll_up: Some(5),
-                        ..Default::default()
-                    },
-                    AppMode::Changes => ActionStateUpdate {
-                        changes_scroll_up: Some(5),
-                        ..Default::default()
-                    },
-                    AppMode::MergeVisualizer => ActionStateUpdate {
-                        merge_scroll_up: Some(5),
-                        ..Default::default()
-                    },
-                    _ => ActionStateUpdate::none(),
-                };
-                (
-                    ActionResult {
-                        should_quit: false,
-                        status_message: None,
-                    },
-                    update,
-                )
-            }
-            KeyAction::ScrollPageDown => {
-                let update = match ctx.current_view {
-                    AppMode::Dashboard => ActionStateUpdate {
-                        project_scroll_down: Some(5),
-                        ..Default::default()
-                    },
-                    AppMode::Changes => ActionStateUpdate {
-                        changes_scroll_down: Some(5),
-                        ..Default::default()
-                    },
-                    AppMode::MergeVisualizer => ActionStateUpdate {
-                        merge_scroll_down: Some(5),
-                        ..Default::default()
-                    },
-                    _ => ActionStateUpdate::none(),
-                };
-                (
-                    ActionResult {
-                        should_quit: false,
-                        status_message: None,
-                    },
-                    update,
-                )
-            }
-            KeyAction::Search => {
-                if ctx.focus == Focus::View {
-                    if !matches!(ctx.current_view, AppMode::Dashboard) {
-                        (
+                    match c {
+                        'o' => (
                             ActionResult {
                                 should_quit: false,
-                                status_message: Some(
-                                    "Search is available only in Dashboard".into(),
-                                ),
+                                status_message: None,
                             },
-                            ActionStateUpdate::none(),
-                        )
-                    } else {
-                        let next_active = !ctx.search_active;
-                        let status = if next_active {
-                            "Search projects (type to filter, Esc to exit)".to_string()
-                        } else {
-                            String::new() // Will be set by update_status_message
-                        };
-                        (
+                            ActionStateUpdate {
+                                open_in_editor_requested: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        'p' => (
                             ActionResult {
                                 should_quit: false,
-                                status_message: if next_active { Some(status) } else { None },
+                                status_message: None,
                             },
                             ActionStateUpdate {
-                                search_active: Some(next_active),
-                                search_buffer: Some(String::new()),
-                                selected_project_index: if next_active { Some(0) } else { None },
+                                copy_path_requested: Some(()),
                                 ..Default::default()
                             },
-                        )
+                        ),
+                        'b' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                accept_both_requested: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        _ => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate::none(),
+                        ),
                     }
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                }
-            }
-            KeyAction::SwitchModuleList => {
-                if matches!(ctx.current_view, AppMode::ModuleManager)
-                    && !ctx.module_create_mode
-                    && !ctx.module_edit_mode
-                    && !ctx.developer_create_mode
+                } else if ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::ProjectBoard)
                 {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate {
-                            toggle_module_list: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                } else {
-                    // Fall back to NextView if not in module manager
-                    let next_view = ctx.current_view.next();
-                    let next_idx = next_view.menu_index();
-                    let update = ActionStateUpdate {
-                        current_view: Some(next_view),
-                        menu_selected_index: Some(next_idx),
-                        search_active: if matches!(next_view, AppMode::Dashboard) {
-                            None
-                        } else {
-                            Some(false)
-                        },
-                        search_buffer: if matches!(next_view, AppMode::Dashboard) {
-                            None
-                        } else {
-                            Some(String::new())
-                        },
-                        ..Default::default()
-                    };
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        update,
-                    )
-                }
-            }
-            KeyAction::ToggleStaging => {
-                if ctx.focus == Focus::View && matches!(ctx.current_view, AppMode::Changes) {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Toggling staging...".into()),
-                        },
-                        ActionStateUpdate {
-                            toggle_staging_requested: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                }
-            }
-            KeyAction::PaneNarrow => {
-                if ctx.focus == Focus::View {
-                    let update = match ctx.current_view {
-                        AppMode::Changes => ActionStateUpdate {
-                            changes_pane_ratio: Some(adjust_pane_ratio(ctx.changes_pane_ratio, -5)),
-                            ..Default::default()
-                        },
-                        AppMode::CommitHistory => ActionStateUpdate {
-                            commit_pane_ratio: Some(adjust_pane_ratio(ctx.commit_pane_ratio, -5)),
-                            ..Default::default()
-                        },
-                        AppMode::ModuleManager => ActionStateUpdate {
-                            module_pane_ratio: Some(adjust_pane_ratio(ctx.module_pane_ratio, -5)),
-                            ..Default::default()
-                        },
-                        AppMode::Dashboard => ActionStateUpdate {
-                            dashboard_pane_ratio: Some(adjust_pane_ratio(
-                                ctx.dashboard_pane_ratio,
-                                -5,
-                            )),
-                            ..Default::default()
-                        },
-                        _ => ActionStateUpdate::none(),
-                    };
-
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        update,
-                    )
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                }
-            }
-            KeyAction::PaneWiden => {
-                if ctx.focus == Focus::View {
-                    let update = match ctx.current_view {
-                        AppMode::Changes => ActionStateUpdate {
-                            changes_pane_ratio: Some(adjust_pane_ratio(ctx.changes_pane_ratio, 5)),
-                            ..Default::default()
-                        },
-                        AppMode::CommitHistory => ActionStateUpdate {
-                            commit_pane_ratio: Some(adjust_pane_ratio(ctx.commit_pane_ratio, 5)),
-                            ..Default::default()
-                        },
-                        AppMode::ModuleManager => ActionStateUpdate {
-                            module_pane_ratio: Some(adjust_pane_ratio(ctx.module_pane_ratio, 5)),
-                            ..Default::default()
-                        },
-                        AppMode::Dashboard => ActionStateUpdate {
-                            dashboard_pane_ratio: Some(adjust_pane_ratio(
-                                ctx.dashboard_pane_ratio,
-                                5,
-                            )),
-                            ..Default::default()
-                        },
-                        _ => ActionStateUpdate::none(),
-                    };
-
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        update,
-                    )
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                }
-            }
-            KeyAction::Fetch => {
-                if ctx.focus == Focus::View
-                    && (matches!(ctx.current_view, AppMode::Dashboard | AppMode::Changes))
+                    match c {
+                        'g' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                board_group_toggle: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        _ => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate::none(),
+                        ),
+                    }
+                } else if ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::CommitHistory)
                 {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Fetching from origin...".into()),
-                        },
-                        ActionStateUpdate {
-                            fetch_requested: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                }
-            }
-            KeyAction::Push => {
-                if ctx.focus == Focus::View && matches!(ctx.current_view, AppMode::Changes) {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Pushing to origin...".into()),
-                        },
-                        ActionStateUpdate {
-                            push_requested: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: None,
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                }
-            }
-            KeyAction::Pull => {
-                if ctx.focus == Focus::View && matches!(ctx.current_view, AppMode::Changes) {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Pulling from origin...".into()),
-                        },
-                        ActionStateUpdate {
-                            pull_requested: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                } else {
-                    (
+                    match c {
+                        'c' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: Some("Checking out commit...".into()),
+                            },
+                            ActionStateUpdate {
+                                commit_checkout_requested: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        's' if ctx.cached_commits_len > 0 => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: Some(
+                                    "Squash HEAD into this commit: edit the message, Enter to squash"
+                                        .into(),
+                                ),
+                            },
+                            ActionStateUpdate {
+                                text_prompt_open: Some(PromptKind::SquashCommits),
+                                ..Default::default()
+                            },
+                        ),
+                        'r' if ctx.cached_commits_len > 0 => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: Some(
+                                    "Reword this commit: edit the message, Enter to rewrite history"
+                                        .into(),
+                                ),
+                            },
+                            ActionStateUpdate {
+                                text_prompt_open: Some(PromptKind::RewordCommit),
+                                ..Default::default()
+                            },
+                        ),
+                        'E' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                commit_export_requested: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        'g' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                changelog_requested: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        'p' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                copy_path_requested: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        'm' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                commit_filter_mine_toggle: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        'T' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                commit_dates_toggle: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        'z' => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                commit_density_toggle: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        _ => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate::none(),
+                        ),
+                    }
+                } else if ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::BranchManager)
+                {
+                    // Handle branch-specific actions
+                    match c {
+                        'n' if !ctx.branch_create_mode => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: Some(
+                                    "Enter branch name (Enter to create, Esc to cancel)".into(),
+                                ),
+                            },
+                            ActionStateUpdate {
+                                branch_create_mode: Some(true),
+                                ..Default::default()
+                            },
+                        ),
+                        'd' if !ctx.branch_create_mode => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: Some("Deleting branch...".into()),
+                            },
+                            ActionStateUpdate {
+                                branch_delete_requested: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        'R' if !ctx.branch_create_mode => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                branch_filter_cycle: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        'F' if !ctx.branch_create_mode => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: Some("Fetching from all remotes...".into()),
+                            },
+                            ActionStateUpdate {
+                                fetch_all_requested: Some(()),
+                                ..Default::default()
+                            },
+                        ),
+                        _ if ctx.branch_create_mode => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                branch_input_append: Some(c),
+                                ..Default::default()
+                            },
+                        ),
+                        _ => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate::none(),
+                        ),
+                    }
+                } else if ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::ModuleManager)
+                {
+                    // Handle module manager specific actions
+                    match c {
+                        // Only Enter and Esc act while picking dependencies
+                        _ if ctx.module_dependency_mode => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate::none(),
+                        ),
+                        // Typing while assigning filters the developer list
+                        _ if ctx.module_assign_mode => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                module_assign_filter_append: Some(c),
+                                ..Default::default()
+                            },
+                        ),
+                        // Typing while renaming edits the row's name
+                        _ if ctx.module_rename_mode => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate {
+                                module_input_append: Some(c),
+                                ..Default::default()
+                            },
+                        ),
+                        'D' if !ctx.module_create_mode
+                            && !ctx.module_edit_mode
+                            && !ctx.developer_create_mode
+                            && !ctx.module_assign_mode
+                            && !ctx.module_manager_in_developer_list =>
+                        {
+                            (
+                                ActionResult {
+                                    should_quit: false,
+                                    status_message: Some(
+                                        "Select a dependency with ↑↓, Enter to add/remove, Esc to finish"
+                                            .into(),
+                                    ),
+                                },
+                                ActionStateUpdate {
+                                    module_dependency_mode: Some(true),
+                                    ..Default::default()
+                                },
+                            )
+                        }
+                        'a' if !ctx.module_create_mode
+                            && !ctx.module_edit_mode
+                            && !ctx.developer_create_mode
+                            && !ctx.module_manager_in_developer_list =>
+                        {
+                            (
+                                ActionResult {
+                                    should_quit: false,
+                                    status_message: Some(
+                                        "Type to filter, ↑↓ to select developer, Enter to assign"
+                                            .into(),
+                                    ),
+                                },
+                                ActionStateUpdate {
+                                    module_assign_mode: Some(true),
+                                    ..Default::default()
+                                },
+                            )
+                        }
+                        'A' if !ctx.module_create_mode
+                            && !ctx.module_edit_mode
+                            && !ctx.developer_create_mode
+                            && !ctx.module_assign_mode =>
+                        {
+                            (
+                                ActionResult {
+                                    should_quit: false,
+                                    status_message: Some(
+                                        "Assigning unowned modules to selected developer...".into(),
+                                    ),
+                                },
+                                ActionStateUpdate {
+                                    module_assign_all_requested: Some(()),
+                                    ..Default::default()
+                                },
+                            )
+                        }
+                        'I' if !ctx.module_create_mode
+                            && !ctx.module_edit_mode
+                            && !ctx.developer_create_mode
+                            && !ctx.module_assign_mode =>
+                        {
+                            (
+                                ActionResult {
+                                    should_quit: false,
+                                    status_message: Some(
+                                        "Import modules: type the task list path, Enter to import"
+                                            .into(),
+                                    ),
+                                },
+                                ActionStateUpdate {
+                                    text_prompt_open: Some(PromptKind::ImportModules),
+                                    ..Default::default()
+                                },
+                            )
+                        }
+                        'P' if !ctx.module_create_mode
+                            && !ctx.module_edit_mode
+                            && !ctx.developer_create_mode
+                            && !ctx.module_assign_mode
+                            && !ctx.module_manager_in_developer_list =>
+                        {
+                            (
+                                ActionResult {
+                                    should_quit: false,
+                                    status_message: Some(
+                                        "Set module progress (Enter to save, Esc to cancel)"
+                                            .into(),
+                                    ),
+                                },
+                                ActionStateUpdate {
+                                    text_prompt_open: Some(PromptKind::ModuleProgress),
+                                    ..Default::default()
+                                },
+                            )
+                        }
+                        '+' | '-'
+                            if !ctx.module_create_mode
+                                && !ctx.module_edit_mode
+                                && !ctx.developer_create_mode
+                                && !ctx.module_assign_mode
+                                && !ctx.module_manager_in_developer_list =>
+                        {
+                            (
+                                ActionResult {
+                                    should_quit: false,
+                                    status_message: None,
+                                },
+                                ActionStateUpdate {
+                                    module_progress_adjust: Some(if c == '+' {
+                                        MODULE_PROGRESS_STEP
+                                    } else {
+                                        -MODULE_PROGRESS_STEP
+                                    }),
+                                    ..Default::default()
+                                },
+                            )
+                        }
+                        'N' if !ctx.module_create_mode
+                            && !ctx.module_edit_mode
+                            && !ctx.developer_create_mode
+                            && !ctx.module_assign_mode
+                            && !ctx.module_manager_in_developer_list =>
+                        {
+                            (
+                                ActionResult {
+                                    should_quit: false,
+                                    status_message: Some(
+                                        "Edit module notes (Enter to save, Esc to cancel)".into(),
+                                    ),
+                                },
+                                ActionStateUpdate {
+                                    text_prompt_open: Some(PromptKind::ModuleNotes),
+                                    ..Default::default()
+                                },
+                            )
+                        }
+                        'n' if !ctx.module_create_mode
+                            && !ctx.module_edit_mode
+                            && !ctx.developer_create_mode =>
+                        {
+                            let in_developer_list = ctx.module_manager_in_developer_list;
+                            (
+                                ActionResult {
+                                    should_quit: false,
+                                    status_message: Some(if in_developer_list {
+                                        "Enter developer name (Enter to create, Esc to cancel)"
+                                            .into()
+                                    } else {
+                                        "Enter module name (Enter to create, Esc to cancel)".into()
+                                    }),
+                                },
+                                ActionStateUpdate {
+                                    module_create_mode: if !in_developer_list {
+                                        Some(true)
+                                    } else {
+                                        None
+                                    },
+                                    developer_create_mode: if in_developer_list {
+                                        Some(true)
+                                    } else {
+                                        None
+                                    },
+                                    ..Default::default()
+                                },
+                            )
+                        }
+                        'e' if !ctx.module_create_mode
+                            && !ctx.module_edit_mode
+                            && !ctx.developer_create_mode
+                            && !ctx.module_manager_in_developer_list =>
+                        {
+                            (
+                                ActionResult {
+                                    should_quit: false,
+                                    status_message: Some(
+                                        "Edit module name (Enter to save, Esc to cancel)".into(),
+                                    ),
+                                },
+                                ActionStateUpdate {
+                                    module_edit_mode: Some(true),
+                                    module_load_selected: Some(()),
+                                    ..Default::default()
+                                },
+                            )
+                        }
+                        'd' if !ctx.module_create_mode
+                            && !ctx.module_edit_mode
+                            && !ctx.developer_create_mode =>
+                        {
+                            let in_developer_list = ctx.module_manager_in_developer_list;
+                            (
+                                ActionResult {
+                                    should_quit: false,
+                                    status_message: Some("Deleting...".into()),
+                                },
+                                ActionStateUpdate {
+                                    module_delete_requested: if !in_developer_list {
+                                        Some(())
+                                    } else {
+                                        None
+                                    },
+                                    developer_delete_requested: if in_developer_list {
+                                        Some(())
+                                    } else {
+                                        None
+                                    },
+                                    ..Default::default()
+                                },
+                            )
+                        }
+                        _ if ctx.module_create_mode
+                            || ctx.module_edit_mode
+                            || ctx.developer_create_mode =>
+                        {
+                            (
+                                ActionResult {
+                                    should_quit: false,
+                                    status_message: None,
+                                },
+                                ActionStateUpdate {
+                                    module_input_append: Some(c),
+                                    ..Default::default()
+                                },
+                            )
+                        }
+                        _ => (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: None,
+                            },
+                            ActionStateUpdate::none(),
+                        ),
+                    }
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::Backspace => {
+                if ctx.search_active {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate {
+                            search_buffer_pop: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else if ctx.focus == Focus::View && matches!(ctx.current_view, AppMode::Changes) {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate {
+                            commit_message_pop: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else if ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::BranchManager)
+                    && ctx.branch_create_mode
+                {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate {
+                            branch_input_pop: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else if ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::ModuleManager)
+                    && ctx.module_assign_mode
+                {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate {
+                            module_assign_filter_pop: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else if ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::ModuleManager)
+                    && (ctx.module_create_mode
+                        || ctx.module_edit_mode
+                        || ctx.developer_create_mode
+                        || ctx.module_rename_mode)
+                {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate {
+                            module_input_pop: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::ScrollPageUp => {
+                let update = match ctx.current_view {
+                    AppMode::Dashboard => ActionStateUpdate {
+                        project_scroll_up: Some(5),
+                        ..Default::default()
+                    },
+                    AppMode::Changes => ActionStateUpdate {
+                        changes_scroll_up: Some(5),
+                        ..Default::default()
+                    },
+                    AppMode::MergeVisualizer => ActionStateUpdate {
+                        merge_scroll_up: Some(5),
+                        ..Default::default()
+                    },
+                    _ => ActionStateUpdate::none(),
+                };
+                (
+                    ActionResult {
+                        should_quit: false,
+                        status_message: None,
+                    },
+                    update,
+                )
+            }
+            KeyAction::ScrollPageDown => {
+                let update = match ctx.current_view {
+                    AppMode::Dashboard => ActionStateUpdate {
+                        project_scroll_down: Some(5),
+                        ..Default::default()
+                    },
+                    AppMode::Changes => ActionStateUpdate {
+                        changes_scroll_down: Some(5),
+                        ..Default::default()
+                    },
+                    AppMode::MergeVisualizer => ActionStateUpdate {
+                        merge_scroll_down: Some(5),
+                        ..Default::default()
+                    },
+                    _ => ActionStateUpdate::none(),
+                };
+                (
+                    ActionResult {
+                        should_quit: false,
+                        status_message: None,
+                    },
+                    update,
+                )
+            }
+            KeyAction::Search => {
+                if ctx.focus == Focus::View {
+                    if !matches!(
+                        ctx.current_view,
+                        AppMode::Dashboard | AppMode::CommitHistory
+                    ) {
+                        (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: Some(
+                                    "Search is available only in Dashboard and Commit History"
+                                        .into(),
+                                ),
+                            },
+                            ActionStateUpdate::none(),
+                        )
+                    } else {
+                        let next_active = !ctx.search_active;
+                        let status = if !next_active {
+                            String::new() // Will be set by update_status_message
+                        } else if matches!(ctx.current_view, AppMode::CommitHistory) {
+                            "Filter commits by author (type to filter, Esc to exit)".to_string()
+                        } else {
+                            "Search projects (type to filter, Esc to exit)".to_string()
+                        };
+                        (
+                            ActionResult {
+                                should_quit: false,
+                                status_message: if next_active { Some(status) } else { None },
+                            },
+                            ActionStateUpdate {
+                                search_active: Some(next_active),
+                                search_buffer: Some(String::new()),
+                                selected_project_index: if next_active { Some(0) } else { None },
+                                ..Default::default()
+                            },
+                        )
+                    }
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::SwitchModuleList => {
+                if matches!(ctx.current_view, AppMode::ModuleManager)
+                    && !ctx.module_create_mode
+                    && !ctx.module_edit_mode
+                    && !ctx.developer_create_mode
+                    && !ctx.module_dependency_mode
+                    && !ctx.module_rename_mode
+                {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate {
+                            toggle_module_list: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else {
+                    // Fall back to NextView if not in module manager
+                    let next_view = ctx.current_view.next();
+                    let next_idx = next_view.menu_index();
+                    let update = ActionStateUpdate {
+                        current_view: Some(next_view),
+                        menu_selected_index: Some(next_idx),
+                        search_active: if matches!(next_view, AppMode::Dashboard) {
+                            None
+                        } else {
+                            Some(false)
+                        },
+                        search_buffer: if matches!(next_view, AppMode::Dashboard) {
+                            None
+                        } else {
+                            Some(String::new())
+                        },
+                        ..Default::default()
+                    };
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        update,
+                    )
+                }
+            }
+            KeyAction::ToggleStaging => {
+                if ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::Changes)
+                    && ctx.changes_multi_select
+                {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate {
+                            row_selection_toggle: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else if ctx.focus == Focus::View && matches!(ctx.current_view, AppMode::Changes) {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Toggling staging...".into()),
+                        },
+                        ActionStateUpdate {
+                            toggle_staging_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else if ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::ModuleManager)
+                    && ctx.module_rename_mode
+                {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate {
+                            module_input_append: Some(' '),
+                            ..Default::default()
+                        },
+                    )
+                } else if ctx.focus == Focus::View
+                    && matches!(
+                        ctx.current_view,
+                        AppMode::MergeVisualizer | AppMode::CommitHistory
+                    )
+                {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate {
+                            quick_view_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::PaneNarrow => {
+                if ctx.focus == Focus::View {
+                    let update = match ctx.current_view {
+                        AppMode::Changes => ActionStateUpdate {
+                            changes_pane_ratio: Some(adjust_pane_ratio(ctx.changes_pane_ratio, -5)),
+                            ..Default::default()
+                        },
+                        AppMode::CommitHistory => ActionStateUpdate {
+                            commit_pane_ratio: Some(adjust_pane_ratio(ctx.commit_pane_ratio, -5)),
+                            ..Default::default()
+                        },
+                        AppMode::ModuleManager => ActionStateUpdate {
+                            module_pane_ratio: Some(adjust_pane_ratio(ctx.module_pane_ratio, -5)),
+                            ..Default::default()
+                        },
+                        AppMode::Dashboard => ActionStateUpdate {
+                            dashboard_pane_ratio: Some(adjust_pane_ratio(
+                                ctx.dashboard_pane_ratio,
+                                -5,
+                            )),
+                            ..Default::default()
+                        },
+                        _ => ActionStateUpdate::none(),
+                    };
+
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        update,
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::PaneWiden => {
+                if ctx.focus == Focus::View {
+                    let update = match ctx.current_view {
+                        AppMode::Changes => ActionStateUpdate {
+                            changes_pane_ratio: Some(adjust_pane_ratio(ctx.changes_pane_ratio, 5)),
+                            ..Default::default()
+                        },
+                        AppMode::CommitHistory => ActionStateUpdate {
+                            commit_pane_ratio: Some(adjust_pane_ratio(ctx.commit_pane_ratio, 5)),
+                            ..Default::default()
+                        },
+                        AppMode::ModuleManager => ActionStateUpdate {
+                            module_pane_ratio: Some(adjust_pane_ratio(ctx.module_pane_ratio, 5)),
+                            ..Default::default()
+                        },
+                        AppMode::Dashboard => ActionStateUpdate {
+                            dashboard_pane_ratio: Some(adjust_pane_ratio(
+                                ctx.dashboard_pane_ratio,
+                                5,
+                            )),
+                            ..Default::default()
+                        },
+                        _ => ActionStateUpdate::none(),
+                    };
+
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        update,
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::Fetch => {
+                if ctx.focus == Focus::View
+                    && (matches!(ctx.current_view, AppMode::Dashboard | AppMode::Changes))
+                {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Fetching from origin...".into()),
+                        },
+                        ActionStateUpdate {
+                            fetch_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::Push => {
+                if ctx.focus == Focus::View && matches!(ctx.current_view, AppMode::Changes) {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Pushing to origin...".into()),
+                        },
+                        ActionStateUpdate {
+                            push_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::Pull => {
+                if ctx.focus == Focus::View && matches!(ctx.current_view, AppMode::Changes) {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Pulling from origin...".into()),
+                        },
+                        ActionStateUpdate {
+                            pull_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::Rename => {
+                let listing = ctx.focus == Focus::View
+                    && matches!(ctx.current_view, AppMode::ModuleManager)
+                    && !ctx.module_create_mode
+                    && !ctx.module_edit_mode
+                    && !ctx.developer_create_mode
+                    && !ctx.module_assign_mode
+                    && !ctx.module_dependency_mode
+                    && !ctx.module_rename_mode;
+                if listing {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Rename (Enter to save, Esc to cancel)".into()),
+                        },
+                        ActionStateUpdate {
+                            module_rename_mode: Some(true),
+                            ..Default::default()
+                        },
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::DismissBanner => (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate {
+                    banner_dismissed: (!ctx.has_git_client).then_some(()),
+                    ..Default::default()
+                },
+            ),
+            KeyAction::DumpStatus => {
+                if ctx.has_git_client {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate {
+                            status_dump_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("No Git repository detected".into()),
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::CopyChangeset => {
+                if ctx.focus == Focus::View && matches!(ctx.current_view, AppMode::Changes) {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate {
+                            copy_changeset_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: None,
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                }
+            }
+            KeyAction::Resize(..) | KeyAction::Tick => (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate::none(),
+            ),
+            KeyAction::None => (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate::none(),
+            ),
+        }
+    }
+
+    /// Quits immediately unless an unfinished commit message would be lost,
+    /// in which case a confirmation dialog is raised instead.
+    fn handle_quit(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
+        if ctx.commit_message_empty {
+            (
+                ActionResult {
+                    should_quit: true,
+                    status_message: None,
+                },
+                ActionStateUpdate::none(),
+            )
+        } else {
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: Some("Discard commit message and quit? (y/n)".into()),
+                },
+                ActionStateUpdate {
+                    confirm_quit: Some(true),
+                    ..Default::default()
+                },
+            )
+        }
+    }
+
+    fn handle_quit_confirmation(action: KeyAction) -> (ActionResult, ActionStateUpdate) {
+        match action {
+            KeyAction::InputChar('y' | 'Y') | KeyAction::Select => (
+                ActionResult {
+                    should_quit: true,
+                    status_message: None,
+                },
+                ActionStateUpdate {
+                    confirm_quit: Some(false),
+                    ..Default::default()
+                },
+            ),
+            KeyAction::InputChar('n' | 'N') | KeyAction::Back => (
+                ActionResult {
+                    should_quit: false,
+                    status_message: Some("Quit cancelled".into()),
+                },
+                ActionStateUpdate {
+                    confirm_quit: Some(false),
+                    ..Default::default()
+                },
+            ),
+            _ => (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate::none(),
+            ),
+        }
+    }
+
+    fn handle_text_prompt(
+        action: KeyAction,
+        kind: PromptKind,
+        ctx: &ActionContext,
+    ) -> (ActionResult, ActionStateUpdate) {
+        let mut status_message = None;
+        let update = match action {
+            KeyAction::InputChar(c) => ActionStateUpdate {
+                text_prompt_append: Some(c),
+                ..Default::default()
+            },
+            // Space is bound to staging elsewhere; here it is just text
+            KeyAction::ToggleStaging => ActionStateUpdate {
+                text_prompt_append: Some(' '),
+                ..Default::default()
+            },
+            KeyAction::Backspace => ActionStateUpdate {
+                text_prompt_pop: Some(()),
+                ..Default::default()
+            },
+            KeyAction::Select | KeyAction::QuickCommit
+                if ctx.text_prompt_input_empty && !kind.allows_empty() =>
+            {
+                status_message = Some(kind.empty_message().into());
+                ActionStateUpdate::none()
+            }
+            KeyAction::Select | KeyAction::QuickCommit => {
+                status_message = Some(
+                    match kind {
+                        PromptKind::QuickCommit => "Staging all changes and committing...",
+                        PromptKind::ApplyPatch => "Applying patch...",
+                        PromptKind::ImportModules => "Importing modules...",
+                        PromptKind::ModuleNotes => "Saving notes...",
+                        PromptKind::SquashCommits => "Squashing commits...",
+                        PromptKind::RewordCommit => "Rewording commit...",
+                        PromptKind::ModuleProgress => "Saving progress...",
+                        PromptKind::GoToRef => "Jumping to revision...",
+                        PromptKind::CloneRepo => "Cloning repository...",
+                    }
+                    .into(),
+                );
+                ActionStateUpdate {
+                    text_prompt_submit: Some(()),
+                    ..Default::default()
+                }
+            }
+            KeyAction::Back => {
+                status_message = Some("Cancelled".into());
+                ActionStateUpdate {
+                    text_prompt_close: Some(()),
+                    ..Default::default()
+                }
+            }
+            _ => ActionStateUpdate::none(),
+        };
+        (
+            ActionResult {
+                should_quit: false,
+                status_message,
+            },
+            update,
+        )
+    }
+
+    fn handle_quick_view(action: KeyAction, searching: bool) -> (ActionResult, ActionStateUpdate) {
+        let update = match action {
+            KeyAction::InputChar(c) if searching => ActionStateUpdate {
+                quick_view_search_append: Some(c),
+                ..Default::default()
+            },
+            KeyAction::ToggleStaging if searching => ActionStateUpdate {
+                quick_view_search_append: Some(' '),
+                ..Default::default()
+            },
+            KeyAction::Backspace if searching => ActionStateUpdate {
+                quick_view_search_pop: Some(()),
+                ..Default::default()
+            },
+            KeyAction::Select if searching => ActionStateUpdate {
+                quick_view_search_submit: Some(()),
+                ..Default::default()
+            },
+            KeyAction::Back if searching => ActionStateUpdate {
+                quick_view_search_cancel: Some(()),
+                ..Default::default()
+            },
+            KeyAction::InputChar('/') => ActionStateUpdate {
+                quick_view_search_start: Some(()),
+                ..Default::default()
+            },
+            KeyAction::InputChar('n') => ActionStateUpdate {
+                quick_view_match: Some(1),
+                ..Default::default()
+            },
+            KeyAction::InputChar('N') => ActionStateUpdate {
+                quick_view_match: Some(-1),
+                ..Default::default()
+            },
+            KeyAction::Back | KeyAction::ToggleStaging => ActionStateUpdate {
+                quick_view_close: Some(()),
+                ..Default::default()
+            },
+            KeyAction::NavigateUp => ActionStateUpdate {
+                quick_view_scroll: Some(-1),
+                ..Default::default()
+            },
+            KeyAction::NavigateDown => ActionStateUpdate {
+                quick_view_scroll: Some(1),
+                ..Default::default()
+            },
+            KeyAction::ScrollPageUp => ActionStateUpdate {
+                quick_view_scroll: Some(-QUICK_VIEW_PAGE),
+                ..Default::default()
+            },
+            KeyAction::ScrollPageDown => ActionStateUpdate {
+                quick_view_scroll: Some(QUICK_VIEW_PAGE),
+                ..Default::default()
+            },
+            _ => ActionStateUpdate::none(),
+        };
+        (
+            ActionResult {
+                should_quit: false,
+                status_message: None,
+            },
+            update,
+        )
+    }
+
+    /// Esc closes the topmost transient state: overlays, then search, then
+    /// input forms, then selection modes and filters. Only when nothing
+    /// transient is open does it move focus to the menu (or quit from there).
+    fn handle_back(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
+        let pop = |status_message: Option<&str>, update: ActionStateUpdate| {
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: status_message.map(Into::into),
+                },
+                update,
+            )
+        };
+
+        if ctx.show_action_log {
+            return pop(
+                None,
+                ActionStateUpdate {
+                    show_action_log: Some(false),
+                    ..Default::default()
+                },
+            );
+        }
+        if ctx.show_help {
+            return pop(
+                None,
+                ActionStateUpdate {
+                    show_help: Some(false),
+                    ..Default::default()
+                },
+            );
+        }
+        if ctx.search_active {
+            return pop(
+                Some("Exited search"),
+                ActionStateUpdate {
+                    search_active: Some(false),
+                    search_buffer: Some(String::new()),
+                    selected_project_index: Some(0),
+                    ..Default::default()
+                },
+            );
+        }
+        if ctx.branch_create_mode {
+            return pop(
+                Some("Cancelled branch creation"),
+                ActionStateUpdate {
+                    branch_create_mode: Some(false),
+                    branch_input_clear: Some(()),
+                    ..Default::default()
+                },
+            );
+        }
+        if ctx.module_rename_mode {
+            return pop(
+                Some("Cancelled rename"),
+                ActionStateUpdate {
+                    module_rename_mode: Some(false),
+                    ..Default::default()
+                },
+            );
+        }
+        // Input forms sit above the list-level selection modes
+        if ctx.module_create_mode || ctx.module_edit_mode || ctx.developer_create_mode {
+            return pop(
+                Some("Cancelled"),
+                ActionStateUpdate {
+                    module_create_mode: ctx.module_create_mode.then_some(false),
+                    module_edit_mode: ctx.module_edit_mode.then_some(false),
+                    developer_create_mode: ctx.developer_create_mode.then_some(false),
+                    module_input_clear: Some(()),
+                    ..Default::default()
+                },
+            );
+        }
+        if ctx.module_assign_mode {
+            return pop(
+                Some("Cancelled assignment"),
+                ActionStateUpdate {
+                    module_assign_mode: Some(false),
+                    ..Default::default()
+                },
+            );
+        }
+        if ctx.module_dependency_mode {
+            return pop(
+                Some("Finished editing dependencies"),
+                ActionStateUpdate {
+                    module_dependency_mode: Some(false),
+                    ..Default::default()
+                },
+            );
+        }
+        if ctx.commit_filter_active && matches!(ctx.current_view, AppMode::CommitHistory) {
+            return pop(
+                Some("Showing all authors"),
+                ActionStateUpdate {
+                    commit_filter_clear: Some(()),
+                    ..Default::default()
+                },
+            );
+        }
+        if ctx.changes_multi_select && matches!(ctx.current_view, AppMode::Changes) {
+            return pop(
+                Some("Left multi-select"),
+                ActionStateUpdate {
+                    multi_select_toggle: Some(()),
+                    ..Default::default()
+                },
+            );
+        }
+        if ctx.focus == Focus::Menu {
+            return Self::handle_quit(ctx);
+        }
+        pop(
+            Some("Menu: Tab to navigate, ↵ to select, q to quit"),
+            ActionStateUpdate {
+                focus: Some(Focus::Menu),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn handle_worktrees(action: KeyAction) -> (ActionResult, ActionStateUpdate) {
+        let update = match action {
+            KeyAction::NavigateUp => ActionStateUpdate {
+                worktrees_move: Some(-1),
+                ..Default::default()
+            },
+            KeyAction::NavigateDown => ActionStateUpdate {
+                worktrees_move: Some(1),
+                ..Default::default()
+            },
+            KeyAction::Select => ActionStateUpdate {
+                worktree_switch: Some(()),
+                ..Default::default()
+            },
+            KeyAction::Back | KeyAction::Worktrees => ActionStateUpdate {
+                worktrees_close: Some(()),
+                ..Default::default()
+            },
+            _ => ActionStateUpdate::none(),
+        };
+        (
+            ActionResult {
+                should_quit: false,
+                status_message: None,
+            },
+            update,
+        )
+    }
+
+    fn handle_reflog(action: KeyAction) -> (ActionResult, ActionStateUpdate) {
+        let update = match action {
+            KeyAction::NavigateUp => ActionStateUpdate {
+                reflog_move: Some(-1),
+                ..Default::default()
+            },
+            KeyAction::NavigateDown => ActionStateUpdate {
+                reflog_move: Some(1),
+                ..Default::default()
+            },
+            KeyAction::Select => ActionStateUpdate {
+                reflog_checkout: Some(()),
+                ..Default::default()
+            },
+            KeyAction::InputChar('r') => ActionStateUpdate {
+                reflog_reset: Some(()),
+                ..Default::default()
+            },
+            KeyAction::Back | KeyAction::Reflog => ActionStateUpdate {
+                reflog_close: Some(()),
+                ..Default::default()
+            },
+            _ => ActionStateUpdate::none(),
+        };
+        (
+            ActionResult {
+                should_quit: false,
+                status_message: None,
+            },
+            update,
+        )
+    }
+
+    fn handle_branch_picker(action: KeyAction) -> (ActionResult, ActionStateUpdate) {
+        let update = match action {
+            KeyAction::InputChar(c) => ActionStateUpdate {
+                branch_picker_append: Some(c),
+                ..Default::default()
+            },
+            KeyAction::Backspace => ActionStateUpdate {
+                branch_picker_pop: Some(()),
+                ..Default::default()
+            },
+            KeyAction::NavigateUp => ActionStateUpdate {
+                branch_picker_move: Some(-1),
+                ..Default::default()
+            },
+            KeyAction::NavigateDown => ActionStateUpdate {
+                branch_picker_move: Some(1),
+                ..Default::default()
+            },
+            KeyAction::Select => ActionStateUpdate {
+                branch_picker_checkout: Some(()),
+                ..Default::default()
+            },
+            KeyAction::Back => ActionStateUpdate {
+                branch_picker_close: Some(()),
+                ..Default::default()
+            },
+            _ => ActionStateUpdate::none(),
+        };
+        (
+            ActionResult {
+                should_quit: false,
+                status_message: None,
+            },
+            update,
+        )
+    }
+
+    fn handle_select(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
+        if ctx.focus == Focus::Menu {
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate {
+                    focus: Some(Focus::View),
+                    current_view: AppMode::from_menu_index(ctx.menu_selected_index),
+                    ..Default::default()
+                },
+            )
+        } else if matches!(ctx.current_view, AppMode::Dashboard) {
+            // Switch to Changes view when pressing Enter on a project
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: Some("Switching to Changes...".into()),
+                },
+                ActionStateUpdate {
+                    current_view: Some(AppMode::Changes),
+                    menu_selected_index: Some(AppMode::Changes.menu_index()),
+                    ..Default::default()
+                },
+            )
+        } else if matches!(ctx.current_view, AppMode::Changes) {
+            if ctx.changes_multi_select {
+                (
+                    ActionResult {
+                        should_quit: false,
+                        status_message: Some("Staging selected files...".into()),
+                    },
+                    ActionStateUpdate {
+                        stage_selected_requested: Some(()),
+                        ..Default::default()
+                    },
+                )
+            } else if ctx.commit_requires_modifier && !ctx.commit_message_empty {
+                (
+                    ActionResult {
+                        should_quit: false,
+                        status_message: Some("Press Ctrl+Enter to commit".into()),
+                    },
+                    ActionStateUpdate::none(),
+                )
+            } else {
+                Self::handle_commit(ctx)
+            }
+        } else if matches!(ctx.current_view, AppMode::ProjectBoard) {
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate {
+                    move_board_item: Some(()),
+                    ..Default::default()
+                },
+            )
+        } else if matches!(ctx.current_view, AppMode::MergeVisualizer) {
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate {
+                    accept_merge_pane: Some(()),
+                    ..Default::default()
+                },
+            )
+        } else if matches!(ctx.current_view, AppMode::Settings) {
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate {
+                    toggle_setting: Some(ctx.selected_setting_index),
+                    ..Default::default()
+                },
+            )
+        } else if matches!(ctx.current_view, AppMode::BranchManager) {
+            if ctx.branch_create_mode {
+                // Create branch
+                if ctx.branch_input_empty {
+                    (
                         ActionResult {
                             should_quit: false,
-                            status_message: None,
+                            status_message: Some("Branch name cannot be empty".into()),
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Creating branch...".into()),
+                        },
+                        ActionStateUpdate {
+                            branch_create_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                }
+            } else {
+                // Switch branch
+                (
+                    ActionResult {
+                        should_quit: false,
+                        status_message: Some("Switching branch...".into()),
+                    },
+                    ActionStateUpdate {
+                        branch_switch_requested: Some(()),
+                        ..Default::default()
+                    },
+                )
+            }
+        } else if matches!(ctx.current_view, AppMode::ModuleManager) {
+            if ctx.module_rename_mode {
+                if ctx.module_input_empty {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Name cannot be empty".into()),
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Renaming...".into()),
+                        },
+                        ActionStateUpdate {
+                            module_rename_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                }
+            } else if ctx.module_assign_mode {
+                (
+                    ActionResult {
+                        should_quit: false,
+                        status_message: Some("Assigning developer to module...".into()),
+                    },
+                    ActionStateUpdate {
+                        module_assign_requested: Some(()),
+                        ..Default::default()
+                    },
+                )
+            } else if ctx.module_dependency_mode {
+                (
+                    ActionResult {
+                        should_quit: false,
+                        status_message: None,
+                    },
+                    ActionStateUpdate {
+                        module_dependency_toggle_requested: Some(()),
+                        ..Default::default()
+                    },
+                )
+            } else if ctx.module_create_mode {
+                if ctx.module_input_empty {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Module name cannot be empty".into()),
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Creating module...".into()),
+                        },
+                        ActionStateUpdate {
+                            module_create_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                }
+            } else if ctx.module_edit_mode {
+                if ctx.module_input_empty {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Module name cannot be empty".into()),
+                        },
+                        ActionStateUpdate::none(),
+                    )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Updating module...".into()),
+                        },
+                        ActionStateUpdate {
+                            module_update_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
+                }
+            } else if ctx.developer_create_mode {
+                if ctx.module_input_empty {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Developer name cannot be empty".into()),
                         },
                         ActionStateUpdate::none(),
                     )
+                } else {
+                    (
+                        ActionResult {
+                            should_quit: false,
+                            status_message: Some("Creating developer...".into()),
+                        },
+                        ActionStateUpdate {
+                            developer_create_requested: Some(()),
+                            ..Default::default()
+                        },
+                    )
                 }
+            } else {
+                (
+                    ActionResult {
+                        should_quit: false,
+                        status_message: None,
+                    },
+                    ActionStateUpdate::none(),
+                )
             }
-            KeyAction::TerminalResized => (
+        } else {
+            (
                 ActionResult {
                     should_quit: false,
                     status_message: None,
                 },
                 ActionStateUpdate::none(),
-            ),
-            KeyAction::None => (
+            )
+        }
+    }
+
+    /// Show `view`, keeping the menu in step; the dashboard keeps its search
+    fn switch_view(view: AppMode) -> ActionStateUpdate {
+        let dashboard = matches!(view, AppMode::Dashboard);
+        ActionStateUpdate {
+            current_view: Some(view),
+            menu_selected_index: Some(view.menu_index()),
+            search_active: if dashboard { None } else { Some(false) },
+            search_buffer: if dashboard { None } else { Some(String::new()) },
+            ..Default::default()
+        }
+    }
+
+    /// Whether digits start a vim-style count rather than being typed as text
+    fn accepts_count(ctx: &ActionContext) -> bool {
+        ctx.focus == Focus::View && !Self::is_typing(ctx)
+    }
+
+    /// Whether typed characters go into a search or input field
+    fn is_typing(ctx: &ActionContext) -> bool {
+        ctx.search_active
+            || (ctx.focus == Focus::View
+                && match ctx.current_view {
+                    // Characters go into the commit message
+                    AppMode::Changes => true,
+                    AppMode::BranchManager => ctx.branch_create_mode,
+                    AppMode::ModuleManager => {
+                        ctx.module_create_mode
+                            || ctx.module_edit_mode
+                            || ctx.developer_create_mode
+                            || ctx.module_assign_mode
+                            || ctx.module_rename_mode
+                    }
+                    _ => false,
+                })
+    }
+
+    /// Commit the typed Changes message, or refuse when there is none
+    fn handle_commit(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
+        if ctx.commit_message_empty {
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: Some("Commit message cannot be empty".into()),
+                },
+                ActionStateUpdate::none(),
+            )
+        } else if ctx.has_git_client {
+            // Real commit handler - will be called in main.rs
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: Some("Attempting commit...".into()),
+                },
+                ActionStateUpdate {
+                    commit_requested: Some(()),
+                    ..Default::default()
+                },
+            )
+        } else {
+            // Mock commit
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: Some(
+                        "Committed (mock only; no Git repository detected)".into(),
+                    ),
+                },
+                ActionStateUpdate {
+                    commit_message_clear: Some(()),
+                    ..Default::default()
+                },
+            )
+        }
+    }
+
+    fn handle_navigate_up(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
+        if ctx.focus == Focus::Menu {
+            let next_idx = ctx.menu_selected_index.saturating_sub(1);
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate {
+                    menu_selected_index: Some(next_idx),
+                    ..Default::default()
+                },
+            )
+        } else {
+            let update = match ctx.current_view {
+                AppMode::Dashboard => ActionStateUpdate {
+                    selected_project_index: Some(ctx.selected_project_index.saturating_sub(1)),
+                    clamp_selections: Some(()),
+                    ..Default::default()
+                },
+                AppMode::Changes => ActionStateUpdate {
+                    selected_change_index: Some(ctx.selected_change_index.saturating_sub(1)),
+                    ..Default::default()
+                },
+                AppMode::CommitHistory => ActionStateUpdate {
+                    selected_commit_index: Some(ctx.selected_commit_index.saturating_sub(1)),
+                    ..Default::default()
+                },
+                AppMode::BranchManager => ActionStateUpdate {
+                    selected_branch_index: Some(ctx.selected_branch_index.saturating_sub(1)),
+                    ..Default::default()
+                },
+                AppMode::ProjectBoard => ActionStateUpdate {
+                    navigate_board_up: Some(()),
+                    ..Default::default()
+                },
+                AppMode::MergeVisualizer => ActionStateUpdate {
+                    selected_merge_file_index: Some(
+                        ctx.selected_merge_file_index.saturating_sub(1),
+                    ),
+                    ..Default::default()
+                },
+                // The row being renamed keeps the selection
+                AppMode::ModuleManager if ctx.module_rename_mode => ActionStateUpdate::none(),
+                AppMode::ModuleManager => {
+                    if ctx.module_assign_mode || ctx.module_manager_in_developer_list {
+                        ActionStateUpdate {
+                            selected_developer_index: Some(
+                                ctx.selected_developer_index.saturating_sub(1),
+                            ),
+                            ..Default::default()
+                        }
+                    } else {
+                        ActionStateUpdate {
+                            selected_module_index: Some(
+                                ctx.selected_module_index.saturating_sub(1),
+                            ),
+                            ..Default::default()
+                        }
+                    }
+                }
+                AppMode::Settings => ActionStateUpdate {
+                    selected_setting_index: Some(ctx.selected_setting_index.saturating_sub(1)),
+                    ..Default::default()
+                },
+            };
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                update,
+            )
+        }
+    }
+
+    fn handle_navigate_down(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
+        if ctx.focus == Focus::Menu {
+            let next_idx = (ctx.menu_selected_index + 1).min(7);
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate {
+                    menu_selected_index: Some(next_idx),
+                    ..Default::default()
+                },
+            )
+        } else {
+            let update = match ctx.current_view {
+                AppMode::Dashboard => ActionStateUpdate {
+                    navigate_project_down: Some(()),
+                    ..Default::default()
+                },
+                AppMode::Changes => ActionStateUpdate {
+                    navigate_change_down: Some(()),
+                    ..Default::default()
+                },
+                AppMode::CommitHistory => {
+                    if ctx.selected_commit_index < ctx.cached_commits_len.saturating_sub(1) {
+                        ActionStateUpdate {
+                            selected_commit_index: Some(ctx.selected_commit_index + 1),
+                            ..Default::default()
+                        }
+                    } else {
+                        ActionStateUpdate::none()
+                    }
+                }
+                AppMode::BranchManager => {
+                    if ctx.selected_branch_index < ctx.cached_branches_len.saturating_sub(1) {
+                        ActionStateUpdate {
+                            selected_branch_index: Some(ctx.selected_branch_index + 1),
+                            ..Default::default()
+                        }
+                    } else {
+                        ActionStateUpdate::none()
+                    }
+                }
+                AppMode::ProjectBoard => ActionStateUpdate {
+                    navigate_board_down: Some(()),
+                    ..Default::default()
+                },
+                AppMode::MergeVisualizer => ActionStateUpdate {
+                    navigate_merge_down: Some(()),
+                    ..Default::default()
+                },
+                // The row being renamed keeps the selection
+                AppMode::ModuleManager if ctx.module_rename_mode => ActionStateUpdate::none(),
+                AppMode::ModuleManager => {
+                    if ctx.module_assign_mode || ctx.module_manager_in_developer_list {
+                        // Navigate developer list in assign mode or when it has focus
+                        ActionStateUpdate {
+                            selected_developer_index: Some(ctx.selected_developer_index + 1),
+                            ..Default::default()
+                        }
+                    } else {
+                        // Get module count from context would require passing more data
+                        // For now, increment and let main.rs clamp it
+                        ActionStateUpdate {
+                            selected_module_index: Some(ctx.selected_module_index + 1),
+                            ..Default::default()
+                        }
+                    }
+                }
+                AppMode::Settings => ActionStateUpdate {
+                    navigate_settings_down: Some(()),
+                    ..Default::default()
+                },
+            };
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                update,
+            )
+        }
+    }
+
+    fn handle_navigate_left(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
+        if ctx.focus == Focus::View {
+            let update = match ctx.current_view {
+                AppMode::ProjectBoard => ActionStateUpdate {
+                    navigate_board_left: Some(()),
+                    ..Default::default()
+                },
+                AppMode::MergeVisualizer => ActionStateUpdate {
+                    merge_focus_prev: Some(()),
+                    ..Default::default()
+                },
+                // Move focus to the module pane
+                AppMode::ModuleManager
+                    if ctx.module_manager_in_developer_list
+                        && !ctx.module_create_mode
+                        && !ctx.module_edit_mode
+                        && !ctx.developer_create_mode
+                        && !ctx.module_assign_mode
+                        && !ctx.module_dependency_mode
+                        && !ctx.module_rename_mode =>
+                {
+                    ActionStateUpdate {
+                        toggle_module_list: Some(()),
+                        ..Default::default()
+                    }
+                }
+                _ => ActionStateUpdate::none(),
+            };
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                update,
+            )
+        } else {
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                ActionStateUpdate::none(),
+            )
+        }
+    }
+
+    fn handle_navigate_right(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
+        if ctx.focus == Focus::View {
+            let update = match ctx.current_view {
+                AppMode::ProjectBoard => ActionStateUpdate {
+                    navigate_board_right: Some(()),
+                    ..Default::default()
+                },
+                AppMode::MergeVisualizer => ActionStateUpdate {
+                    merge_focus_next: Some(()),
+                    ..Default::default()
+                },
+                // Move focus to the developer pane
+                AppMode::ModuleManager
+                    if !ctx.module_manager_in_developer_list
+                        && !ctx.module_create_mode
+                        && !ctx.module_edit_mode
+                        && !ctx.developer_create_mode
+                        && !ctx.module_assign_mode
+                        && !ctx.module_dependency_mode
+                        && !ctx.module_rename_mode =>
+                {
+                    ActionStateUpdate {
+                        toggle_module_list: Some(()),
+                        ..Default::default()
+                    }
+                }
+                _ => ActionStateUpdate::none(),
+            };
+            (
+                ActionResult {
+                    should_quit: false,
+                    status_message: None,
+                },
+                update,
+            )
+        } else {
+            (
                 ActionResult {
                     should_quit: false,
                     status_message: None,
                 },
                 ActionStateUpdate::none(),
-            ),
+            )
         }
     }
+}
+
+/// Structural representation of state changes requested by action handlers
+#[derive(Debug, Default, Clone)]
+pub struct ActionStateUpdate {
+    // Focus and mode
+    pub focus: Option<Focus>,
+    pub terminal_resized: Option<(u16, u16)>,
+    pub current_view: Option<AppMode>,
+    pub show_help: Option<bool>,
+    pub show_action_log: Option<bool>,
+    pub confirm_quit: Option<bool>,
+    pub quick_view_requested: Option<()>,
+    pub quick_view_close: Option<()>,
+    pub quick_view_scroll: Option<i16>,
+    pub quick_view_search_start: Option<()>,
+    pub quick_view_search_append: Option<char>,
+    pub quick_view_search_pop: Option<()>,
+    pub quick_view_search_submit: Option<()>,
+    pub quick_view_search_cancel: Option<()>,
+    /// Jump this many search matches forward (negative for back)
+    pub quick_view_match: Option<i32>,
+    pub text_prompt_open: Option<PromptKind>,
+    pub text_prompt_close: Option<()>,
+    pub text_prompt_append: Option<char>,
+    pub text_prompt_pop: Option<()>,
+    pub text_prompt_submit: Option<()>,
+
+    // Search state
+    pub search_active: Option<bool>,
+    pub search_buffer: Option<String>,
+    pub search_buffer_append: Option<char>,
+    pub search_buffer_pop: Option<()>,
+    pub cycle_project_sort: Option<()>,
+    pub commit_checkout_requested: Option<()>,
+    pub commit_export_requested: Option<()>,
+    /// Write `.forge/CHANGELOG.md` from the loaded commits
+    pub changelog_requested: Option<()>,
+    pub commit_filter_mine_toggle: Option<()>,
+    pub commit_dates_toggle: Option<()>,
+    /// Switch Commit History between one- and two-line rows
+    pub commit_density_toggle: Option<()>,
+    pub commit_filter_clear: Option<()>,
+
+    // Selection state
+    /// Digit appended to the count that repeats the next navigation key
+    pub count_digit: Option<u32>,
+    pub menu_selected_index: Option<usize>,
+    pub selected_project_index: Option<usize>,
+    pub selected_change_index: Option<usize>,
+    pub selected_board_column: Option<usize>,
+    pub selected_board_item: Option<usize>,
+    pub selected_merge_file_index: Option<usize>,
+    pub selected_setting_index: Option<usize>,
+    // New view selections
+    pub selected_commit_index: Option<usize>,
+    pub selected_branch_index: Option<usize>,
+    pub selected_module_index: Option<usize>,
+    pub selected_developer_index: Option<usize>,
+
+    // Commit message
+    pub commit_message_append: Option<char>,
+    pub commit_message_pop: Option<()>,
+    pub commit_message_clear: Option<()>,
+
+    // Scroll state
+    pub project_scroll_up: Option<usize>,
+    pub project_scroll_down: Option<usize>,
+    pub changes_scroll_up: Option<usize>,
+    pub changes_scroll_down: Option<usize>,
+    pub merge_scroll_up: Option<usize>,
+    pub merge_scroll_down: Option<usize>,
+
+    // Layout adjustments
+    pub changes_pane_ratio: Option<u16>,
+    pub commit_pane_ratio: Option<u16>,
+    pub module_pane_ratio: Option<u16>,
+    pub dashboard_pane_ratio: Option<u16>,
+
+    // Complex actions
+    pub clamp_selections: Option<()>,
+    pub navigate_project_down: Option<()>,
+    pub navigate_change_down: Option<()>,
+    pub navigate_board_up: Option<()>,
+    pub navigate_board_down: Option<()>,
+    pub navigate_board_left: Option<()>,
+    pub navigate_board_right: Option<()>,
+    pub navigate_merge_down: Option<()>,
+    pub merge_focus_next: Option<()>,
+    pub merge_focus_prev: Option<()>,
+    pub navigate_settings_down: Option<()>,
+
+    // Commands
+    pub move_board_item: Option<()>,
+    pub board_group_toggle: Option<()>,
+    /// Open the selected Changes or Merge file in `$EDITOR`
+    pub open_in_editor_requested: Option<()>,
+    pub accept_merge_pane: Option<()>,
+    /// Resolve the selected conflict with local then incoming content
+    pub accept_both_requested: Option<()>,
+    /// Settings row to toggle
+    pub toggle_setting: Option<usize>,
+    pub commit_requested: Option<()>,
+
+    // Branch operations
+    pub branch_create_mode: Option<bool>,
+    pub branch_input_append: Option<char>,
+    pub branch_input_pop: Option<()>,
+    pub branch_input_clear: Option<()>,
+    pub branch_switch_requested: Option<()>,
+    pub branch_create_requested: Option<()>,
+    pub branch_delete_requested: Option<()>,
+    /// Show local, then remote, then all branches
+    pub branch_filter_cycle: Option<()>,
+
+    // Module operations
+    pub toggle_module_list: Option<()>,
+    pub module_create_mode: Option<bool>,
+    pub module_edit_mode: Option<bool>,
+    pub developer_create_mode: Option<bool>,
+    pub module_input_append: Option<char>,
+    pub module_input_pop: Option<()>,
+    pub module_input_clear: Option<()>,
+    pub module_load_selected: Option<()>,
+    pub module_create_requested: Option<()>,
+    pub module_update_requested: Option<()>,
+    pub module_delete_requested: Option<()>,
+    pub developer_create_requested: Option<()>,
+    pub developer_delete_requested: Option<()>,
+    pub module_assign_mode: Option<bool>,
+    pub module_assign_filter_append: Option<char>,
+    pub module_assign_filter_pop: Option<()>,
+    pub module_assign_requested: Option<()>,
+    pub module_assign_all_requested: Option<()>,
+    pub module_dependency_mode: Option<bool>,
+    pub module_dependency_toggle_requested: Option<()>,
+    pub module_rename_mode: Option<bool>,
+    pub module_rename_requested: Option<()>,
+    /// Move the selected module's progress by this many points
+    pub module_progress_adjust: Option<i16>,
+
+    // File staging
+    pub toggle_staging_requested: Option<()>,
+    /// Stage the selected file, then select the next unstaged one
+    pub stage_and_advance_requested: Option<()>,
+    /// Enter or leave multi-select mode in the Changes list
+    pub multi_select_toggle: Option<()>,
+    /// Tick or untick the selected Changes row
+    pub row_selection_toggle: Option<()>,
+    /// Stage every ticked Changes row
+    pub stage_selected_requested: Option<()>,
+
+    // Remote operations
+    pub fetch_requested: Option<()>,
+    pub fetch_all_requested: Option<()>,
+    pub status_dump_requested: Option<()>,
+    /// Hide the no-repository banner for the rest of the session
+    pub banner_dismissed: Option<()>,
+    pub push_requested: Option<()>,
+    pub pull_requested: Option<()>,
+
+    // Clipboard
+    pub copy_diff_requested: Option<()>,
+    /// Copy the selected file's path to the clipboard
+    pub copy_path_requested: Option<()>,
+    pub copy_changeset_requested: Option<()>,
+
+    // Diff annotations
+    pub blame_toggle: Option<()>,
+    pub diff_expand_requested: Option<()>,
+
+    // Branch picker
+    pub branch_picker_requested: Option<()>,
+    pub branch_picker_close: Option<()>,
+    pub branch_picker_append: Option<char>,
+    pub branch_picker_pop: Option<()>,
+    pub branch_picker_move: Option<i32>,
+    pub branch_picker_checkout: Option<()>,
+
+    // Reflog
+    pub reflog_requested: Option<()>,
+    pub reflog_close: Option<()>,
+    pub reflog_move: Option<i32>,
+    pub reflog_checkout: Option<()>,
+    pub reflog_reset: Option<()>,
+
+    // Worktrees
+    pub worktrees_requested: Option<()>,
+    pub worktrees_close: Option<()>,
+    pub worktrees_move: Option<i32>,
+    pub worktree_switch: Option<()>,
+}
+
+impl ActionStateUpdate {
+    pub fn none() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_basic_keys() {
+        let mut kh = KeyHandler::new();
+
+        let quit = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char('q'),
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(quit, KeyAction::Quit);
+
+        let help = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char('?'),
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(help, KeyAction::Help);
+
+        let tab = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Tab,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(tab, KeyAction::NextView);
+
+        let up = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Up,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(up, KeyAction::NavigateUp);
+
+        let ch = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char('x'),
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(ch, KeyAction::InputChar('x'));
+    }
+
+    #[test]
+    fn test_navigation_keys() {
+        let mut kh = KeyHandler::new();
+
+        let down = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Down,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(down, KeyAction::NavigateDown);
+
+        let left = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Left,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(left, KeyAction::NavigateLeft);
+
+        let right = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Right,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(right, KeyAction::NavigateRight);
+    }
+
+    #[test]
+    fn test_vim_keybindings() {
+        let mut kh = KeyHandler::new();
+
+        let up_vim = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char('k'),
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(up_vim, KeyAction::NavigateUp);
+
+        let down_vim = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char('j'),
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(down_vim, KeyAction::NavigateDown);
+
+        let left_vim = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char('h'),
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(left_vim, KeyAction::NavigateLeft);
+
+        let right_vim = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char('l'),
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(right_vim, KeyAction::NavigateRight);
+    }
+
+    #[test]
+    fn test_modifier_keys() {
+        let mut kh = KeyHandler::new();
+
+        let ctrl_c = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char('c'),
+            modifiers: crossterm::event::KeyModifiers::CONTROL,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(ctrl_c, KeyAction::Quit);
+
+        let ctrl_f = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char('f'),
+            modifiers: crossterm::event::KeyModifiers::CONTROL,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(ctrl_f, KeyAction::Search);
+    }
+
+    #[test]
+    fn test_staging_and_enter() {
+        let mut kh = KeyHandler::new();
+
+        let space = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char(' '),
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(space, KeyAction::ToggleStaging);
+
+        let enter = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Enter,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(enter, KeyAction::Select);
+    }
 
-    fn handle_select(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
-        if ctx.focus == Focus::Menu {
-            // Menu selection will be handled by main.rs looking at menu_selected_index
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                ActionStateUpdate {
-                    focus: Some(Focus::View),
-                    ..Default::default()
-                },
-            )
-        } else if matches!(ctx.current_view, AppMode::Dashboard) {
-            // Switch to Changes view when pressing Enter on a project
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: Some("Switching to Changes...".into()),
-                },
-                ActionStateUpdate {
-                    current_view: Some(AppMode::Changes),
-                    menu_selected_index: Some(AppMode::Changes.menu_index()),
-                    ..Default::default()
-                },
-            )
-        } else if matches!(ctx.current_view, AppMode::Changes) {
-            if ctx.commit_message_empty {
-                (
-                    ActionResult {
-                        should_quit: false,
-                        status_message: Some("Commit message cannot be empty".into()),
-                    },
-                    ActionStateUpdate::none(),
-                )
-            } else if ctx.has_git_client {
-                // Real commit handler - will be called in main.rs
-                (
-                    ActionResult {
-                        should_quit: false,
-                        status_message: Some("Attempting commit...".into()),
-                    },
-                    ActionStateUpdate {
-                        commit_requested: Some(()),
-                        ..Default::default()
-                    },
-                )
-            } else {
-                // Mock commit
-                (
-                    ActionResult {
-                        should_quit: false,
-                        status_message: Some(
-                            "Committed (mock only; no Git repository detected)".into(),
-                        ),
-                    },
-                    ActionStateUpdate {
-                        commit_message_clear: Some(()),
-                        ..Default::default()
-                    },
-                )
-            }
-        } else if matches!(ctx.current_view, AppMode::ProjectBoard) {
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                ActionStateUpdate {
-                    move_board_item: Some(()),
-                    ..Default::default()
-                },
-            )
-        } else if matches!(ctx.current_view, AppMode::MergeVisualizer) {
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                ActionStateUpdate {
-                    accept_merge_pane: Some(()),
-                    ..Default::default()
-                },
-            )
-        } else if matches!(ctx.current_view, AppMode::Settings) {
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                ActionStateUpdate {
-                    toggle_setting: Some(()),
-                    ..Default::default()
-                },
-            )
-        } else if matches!(ctx.current_view, AppMode::BranchManager) {
-            if ctx.branch_create_mode {
-                // Create branch
-                if ctx.branch_input_empty {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Branch name cannot be empty".into()),
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Creating branch...".into()),
-                        },
-                        ActionStateUpdate {
-                            branch_create_requested: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                }
-            } else {
-                // Switch branch
-                (
-                    ActionResult {
-                        should_quit: false,
-                        status_message: Some("Switching branch...".into()),
-                    },
-                    ActionStateUpdate {
-                        branch_switch_requested: Some(()),
-                        ..Default::default()
-                    },
-                )
-            }
-        } else if matches!(ctx.current_view, AppMode::ModuleManager) {
-            if ctx.module_assign_mode {
-                (
-                    ActionResult {
-                        should_quit: false,
-                        status_message: Some("Assigning developer to module...".into()),
-                    },
-                    ActionStateUpdate {
-                        module_assign_requested: Some(()),
-                        ..Default::default()
-                    },
-                )
-            } else if ctx.module_create_mode {
-                if ctx.module_input_empty {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Module name cannot be empty".into()),
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Creating module...".into()),
-                        },
-                        ActionStateUpdate {
-                            module_create_requested: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                }
-            } else if ctx.module_edit_mode {
-                if ctx.module_input_empty {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Module name cannot be empty".into()),
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Updating module...".into()),
-                        },
-                        ActionStateUpdate {
-                            module_update_requested: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                }
-            } else if ctx.developer_create_mode {
-                if ctx.module_input_empty {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Developer name cannot be empty".into()),
-                        },
-                        ActionStateUpdate::none(),
-                    )
-                } else {
-                    (
-                        ActionResult {
-                            should_quit: false,
-                            status_message: Some("Creating developer...".into()),
-                        },
-                        ActionStateUpdate {
-                            developer_create_requested: Some(()),
-                            ..Default::default()
-                        },
-                    )
-                }
-            } else {
-                (
-                    ActionResult {
-                        should_quit: false,
-                        status_message: None,
-                    },
-                    ActionStateUpdate::none(),
-                )
-            }
-        } else {
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                ActionStateUpdate::none(),
-            )
-        }
+    #[test]
+    fn test_page_keys() {
+        let mut kh = KeyHandler::new();
+
+        let page_up = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::PageUp,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(page_up, KeyAction::ScrollPageUp);
+
+        let page_down = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::PageDown,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(page_down, KeyAction::ScrollPageDown);
+    }
+
+    #[test]
+    fn test_backspace() {
+        let mut kh = KeyHandler::new();
+
+        let backspace = kh.on_key_event(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Backspace,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press,
+            state: crossterm::event::KeyEventState::NONE,
+        });
+        assert_eq!(backspace, KeyAction::Backspace);
     }
 
-    fn handle_navigate_up(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
-        if ctx.focus == Focus::Menu {
-            let next_idx = ctx.menu_selected_index.saturating_sub(1);
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                ActionStateUpdate {
-                    menu_selected_index: Some(next_idx),
-                    ..Default::default()
-                },
-            )
-        } else {
-            let update = match ctx.current_view {
-                AppMode::Dashboard => ActionStateUpdate {
-                    selected_project_index: Some(ctx.selected_project_index.saturating_sub(1)),
-                    clamp_selections: Some(()),
-                    ..Default::default()
-                },
-                AppMode::Changes => ActionStateUpdate {
-                    selected_change_index: Some(ctx.selected_change_index.saturating_sub(1)),
-                    ..Default::default()
-                },
-                AppMode::CommitHistory => ActionStateUpdate {
-                    selected_commit_index: Some(ctx.selected_commit_index.saturating_sub(1)),
-                    ..Default::default()
-                },
-                AppMode::BranchManager => ActionStateUpdate {
-                    selected_branch_index: Some(ctx.selected_branch_index.saturating_sub(1)),
-                    ..Default::default()
-                },
-                AppMode::ProjectBoard => ActionStateUpdate {
-                    navigate_board_up: Some(()),
-                    ..Default::default()
-                },
-                AppMode::MergeVisualizer => ActionStateUpdate {
-                    selected_merge_file_index: Some(
-                        ctx.selected_merge_file_index.saturating_sub(1),
-                    ),
-                    ..Default::default()
-                },
-                AppMode::ModuleManager => {
-                    if ctx.module_assign_mode {
-                        ActionStateUpdate {
-                            selected_developer_index: Some(
-                                ctx.selected_developer_index.saturating_sub(1),
-                            ),
-                            ..Default::default()
-                        }
-                    } else {
-                        ActionStateUpdate {
-                            selected_module_index: Some(
-                                ctx.selected_module_index.saturating_sub(1),
-                            ),
-                            ..Default::default()
-                        }
-                    }
-                }
-                AppMode::Settings => ActionStateUpdate {
-                    selected_setting_index: Some(ctx.selected_setting_index.saturating_sub(1)),
-                    ..Default::default()
-                },
-            };
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                update,
-            )
+    #[test]
+    fn test_action_result_creation() {
+        let result = ActionResult {
+            should_quit: false,
+            status_message: Some("Test message".to_string()),
+        };
+
+        assert!(!result.should_quit);
+        assert_eq!(result.status_message, Some("Test message".to_string()));
+    }
+
+    #[test]
+    fn test_action_state_update_none() {
+        let update = ActionStateUpdate::none();
+        assert!(update.focus.is_none());
+        assert!(update.current_view.is_none());
+        assert!(update.show_help.is_none());
+    }
+
+    fn test_context() -> ActionContext {
+        ActionContext {
+            focus: Focus::View,
+            current_view: AppMode::Changes,
+            show_help: false,
+            show_action_log: false,
+            search_active: false,
+            menu_selected_index: 1,
+            selected_project_index: 0,
+            selected_change_index: 0,
+            selected_board_column: 1,
+            selected_board_item: 0,
+            selected_merge_file_index: 0,
+            selected_setting_index: 0,
+            commit_message_empty: true,
+            has_git_client: false,
+            changes_pane_ratio: 35,
+            commit_pane_ratio: 50,
+            module_pane_ratio: 50,
+            dashboard_pane_ratio: 30,
+            selected_commit_index: 0,
+            selected_branch_index: 0,
+            selected_module_index: 0,
+            selected_developer_index: 0,
+            cached_commits_len: 0,
+            cached_branches_len: 0,
+            branch_create_mode: false,
+            branch_input_empty: true,
+            module_manager_in_developer_list: false,
+            module_create_mode: false,
+            module_edit_mode: false,
+            developer_create_mode: false,
+            module_assign_mode: false,
+            module_dependency_mode: false,
+            module_rename_mode: false,
+            module_input_empty: true,
+            confirm_quit_pending: false,
+            quick_view_open: false,
+            quick_view_searching: false,
+            text_prompt: None,
+            text_prompt_input_empty: true,
+            branch_picker_open: false,
+            reflog_open: false,
+            worktrees_open: false,
+            commit_filter_active: false,
+            commit_requires_modifier: false,
+            count_pending: false,
+            previous_view: None,
+            changes_multi_select: false,
         }
     }
 
-    fn handle_navigate_down(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
-        if ctx.focus == Focus::Menu {
-            let next_idx = (ctx.menu_selected_index + 1).min(7);
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                ActionStateUpdate {
-                    menu_selected_index: Some(next_idx),
-                    ..Default::default()
-                },
-            )
-        } else {
-            let update = match ctx.current_view {
-                AppMode::Dashboard => ActionStateUpdate {
-                    navigate_project_down: Some(()),
-                    ..Default::default()
-                },
-                AppMode::Changes => ActionStateUpdate {
-                    navigate_change_down: Some(()),
-                    ..Default::default()
-                },
-                AppMode::CommitHistory => {
-                    if ctx.selected_commit_index < ctx.cached_commits_len.saturating_sub(1) {
-                        ActionStateUpdate {
-                            selected_commit_index: Some(ctx.selected_commit_index + 1),
-                            ..Default::default()
-                        }
-                    } else {
-                        ActionStateUpdate::none()
-                    }
-                }
-                AppMode::BranchManager => {
-                    if ctx.selected_branch_index < ctx.cached_branches_len.saturating_sub(1) {
-                        ActionStateUpdate {
-                            selected_branch_index: Some(ctx.selected_branch_index + 1),
-                            ..Default::default()
-                        }
-                    } else {
-                        ActionStateUpdate::none()
-                    }
-                }
-                AppMode::ProjectBoard => ActionStateUpdate {
-                    navigate_board_down: Some(()),
-                    ..Default::default()
-                },
-                AppMode::MergeVisualizer => ActionStateUpdate {
-                    navigate_merge_down: Some(()),
-                    ..Default::default()
-                },
-                AppMode::ModuleManager => {
-                    if ctx.module_assign_mode {
-                        // Navigate developer list in assign mode
-                        ActionStateUpdate {
-                            selected_developer_index: Some(ctx.selected_developer_index + 1),
-                            ..Default::default()
-                        }
-                    } else {
-                        // Get module count from context would require passing more data
-                        // For now, increment and let main.rs clamp it
-                        ActionStateUpdate {
-                            selected_module_index: Some(ctx.selected_module_index + 1),
-                            ..Default::default()
-                        }
-                    }
-                }
-                AppMode::Settings => ActionStateUpdate {
-                    navigate_settings_down: Some(()),
-                    ..Default::default()
-                },
+    #[test]
+    fn test_dashboard_sort_key() {
+        let ctx = ActionContext {
+            current_view: AppMode::Dashboard,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('o'), &ctx);
+        assert!(update.cycle_project_sort.is_some());
+
+        // Typed into the query while searching
+        let searching = ActionContext {
+            search_active: true,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('o'), &searching);
+        assert!(update.cycle_project_sort.is_none());
+        assert_eq!(update.search_buffer_append, Some('o'));
+    }
+
+    #[test]
+    fn test_commit_history_checkout_key() {
+        let ctx = ActionContext {
+            current_view: AppMode::CommitHistory,
+            cached_commits_len: 3,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('c'), &ctx);
+        assert!(update.commit_checkout_requested.is_some());
+
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('E'), &ctx);
+        assert!(update.commit_export_requested.is_some());
+
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('g'), &ctx);
+        assert!(update.changelog_requested.is_some());
+        assert!(update.board_group_toggle.is_none());
+
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('m'), &ctx);
+        assert!(update.commit_filter_mine_toggle.is_some());
+
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('T'), &ctx);
+        assert!(update.commit_dates_toggle.is_some());
+
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('z'), &ctx);
+        assert!(update.commit_density_toggle.is_some());
+
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('s'), &ctx);
+        assert_eq!(update.text_prompt_open, Some(PromptKind::SquashCommits));
+
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('r'), &ctx);
+        assert_eq!(update.text_prompt_open, Some(PromptKind::RewordCommit));
+
+        // Ctrl+F filters commits by author using the shared search buffer
+        let (_, update) = ActionProcessor::process(KeyAction::Search, &ctx);
+        assert_eq!(update.search_active, Some(true));
+        let searching = ActionContext {
+            search_active: true,
+            ..ctx.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('m'), &searching);
+        assert_eq!(update.search_buffer_append, Some('m'));
+        assert!(update.commit_filter_mine_toggle.is_none());
+
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('x'), &ctx);
+        assert!(update.commit_checkout_requested.is_none());
+        assert!(update.commit_export_requested.is_none());
+    }
+
+    #[test]
+    fn test_action_log_toggle_and_close() {
+        let mut kh = KeyHandler::new();
+        let ctrl_g = kh.on_key_event(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::CONTROL));
+        assert_eq!(ctrl_g, KeyAction::ToggleActionLog);
+
+        let ctx = test_context();
+        let (_, update) = ActionProcessor::process(KeyAction::ToggleActionLog, &ctx);
+        assert_eq!(update.show_action_log, Some(true));
+
+        let open = ActionContext {
+            show_action_log: true,
+            ..ctx
+        };
+        let (result, update) = ActionProcessor::process(KeyAction::Back, &open);
+        assert_eq!(update.show_action_log, Some(false));
+        assert!(!result.should_quit);
+    }
+
+    #[test]
+    fn test_enter_commits_without_modifier_setting() {
+        let ctx = ActionContext {
+            current_view: AppMode::Changes,
+            commit_message_empty: false,
+            has_git_client: true,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &ctx);
+        assert!(update.commit_requested.is_some());
+
+        // Ctrl+Enter keeps opening the stage-all prompt
+        let (_, update) = ActionProcessor::process(KeyAction::QuickCommit, &ctx);
+        assert!(update.commit_requested.is_none());
+        assert_eq!(update.text_prompt_open, Some(PromptKind::QuickCommit));
+    }
+
+    #[test]
+    fn test_enter_does_not_commit_with_modifier_setting() {
+        let ctx = ActionContext {
+            current_view: AppMode::Changes,
+            commit_message_empty: false,
+            has_git_client: true,
+            commit_requires_modifier: true,
+            ..test_context()
+        };
+        let (result, update) = ActionProcessor::process(KeyAction::Select, &ctx);
+        assert!(update.commit_requested.is_none());
+        assert_eq!(
+            result.status_message.as_deref(),
+            Some("Press Ctrl+Enter to commit")
+        );
+
+        let (_, update) = ActionProcessor::process(KeyAction::QuickCommit, &ctx);
+        assert!(update.commit_requested.is_some());
+        assert!(update.text_prompt_open.is_none());
+
+        // Outside Changes, Ctrl+Enter is still the stage-all prompt
+        let dashboard = ActionContext {
+            current_view: AppMode::Dashboard,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::QuickCommit, &dashboard);
+        assert_eq!(update.text_prompt_open, Some(PromptKind::QuickCommit));
+    }
+
+    #[test]
+    fn test_quick_commit_prompt() {
+        let mut kh = KeyHandler::new();
+        let ctrl_enter = kh.on_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL));
+        let ctrl_j = kh.on_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::CONTROL));
+        assert_eq!(ctrl_enter, KeyAction::QuickCommit);
+        assert_eq!(ctrl_j, KeyAction::QuickCommit);
+
+        let dashboard = ActionContext {
+            current_view: AppMode::Dashboard,
+            has_git_client: true,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::QuickCommit, &dashboard);
+        assert_eq!(update.text_prompt_open, Some(PromptKind::QuickCommit));
+
+        let prompt = ActionContext {
+            text_prompt: Some(PromptKind::QuickCommit),
+            ..dashboard
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('w'), &prompt);
+        assert_eq!(update.text_prompt_append, Some('w'));
+        let (_, update) = ActionProcessor::process(KeyAction::ToggleStaging, &prompt);
+        assert_eq!(update.text_prompt_append, Some(' '));
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &prompt);
+        assert_eq!(update.text_prompt_submit, None);
+
+        let typed = ActionContext {
+            text_prompt_input_empty: false,
+            ..prompt.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &typed);
+        assert_eq!(update.text_prompt_submit, Some(()));
+
+        let (_, update) = ActionProcessor::process(KeyAction::Back, &prompt);
+        assert_eq!(update.text_prompt_close, Some(()));
+    }
+
+    #[test]
+    fn test_apply_patch_prompt_in_changes() {
+        let mut kh = KeyHandler::new();
+        let ctrl_p = kh.on_key_event(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        assert_eq!(ctrl_p, KeyAction::ApplyPatch);
+
+        let changes = ActionContext {
+            has_git_client: true,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::ApplyPatch, &changes);
+        assert_eq!(update.text_prompt_open, Some(PromptKind::ApplyPatch));
+
+        let dashboard = ActionContext {
+            current_view: AppMode::Dashboard,
+            ..changes
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::ApplyPatch, &dashboard);
+        assert_eq!(update.text_prompt_open, None);
+    }
+
+    #[test]
+    fn test_c_on_dashboard_opens_clone_prompt_without_a_repository() {
+        let dashboard = ActionContext {
+            current_view: AppMode::Dashboard,
+            has_git_client: false,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('c'), &dashboard);
+        assert_eq!(update.text_prompt_open, Some(PromptKind::CloneRepo));
+    }
+
+    #[test]
+    fn test_colon_opens_go_to_revision_unless_typing() {
+        let history = ActionContext {
+            current_view: AppMode::CommitHistory,
+            has_git_client: true,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar(':'), &history);
+        assert_eq!(update.text_prompt_open, Some(PromptKind::GoToRef));
+
+        let menu = ActionContext {
+            focus: Focus::Menu,
+            current_view: AppMode::Changes,
+            ..history.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar(':'), &menu);
+        assert_eq!(update.text_prompt_open, Some(PromptKind::GoToRef));
+
+        // A colon in a commit message or search is just text
+        let changes = ActionContext {
+            current_view: AppMode::Changes,
+            ..history.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar(':'), &changes);
+        assert_eq!(update.text_prompt_open, None);
+        let searching = ActionContext {
+            search_active: true,
+            ..history.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar(':'), &searching);
+        assert_eq!(update.search_buffer_append, Some(':'));
+
+        let no_repo = ActionContext {
+            has_git_client: false,
+            ..history
+        };
+        let (result, update) = ActionProcessor::process(KeyAction::InputChar(':'), &no_repo);
+        assert_eq!(update.text_prompt_open, None);
+        assert_eq!(
+            result.status_message.as_deref(),
+            Some("No Git repository detected")
+        );
+    }
+
+    #[test]
+    fn test_import_modules_prompt_in_module_manager() {
+        let modules = ActionContext {
+            current_view: AppMode::ModuleManager,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('I'), &modules);
+        assert_eq!(update.text_prompt_open, Some(PromptKind::ImportModules));
+
+        // Typing a module name keeps the capital as text
+        let creating = ActionContext {
+            module_create_mode: true,
+            ..modules
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('I'), &creating);
+        assert_eq!(update.text_prompt_open, None);
+        assert_eq!(update.module_input_append, Some('I'));
+    }
+
+    #[test]
+    fn test_module_progress_keys() {
+        let ctx = ActionContext {
+            current_view: AppMode::ModuleManager,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('+'), &ctx);
+        assert_eq!(update.module_progress_adjust, Some(MODULE_PROGRESS_STEP));
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('-'), &ctx);
+        assert_eq!(update.module_progress_adjust, Some(-MODULE_PROGRESS_STEP));
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('P'), &ctx);
+        assert_eq!(update.text_prompt_open, Some(PromptKind::ModuleProgress));
+
+        // Typed literally while naming a module
+        let naming = ActionContext {
+            module_create_mode: true,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('+'), &naming);
+        assert_eq!(update.module_progress_adjust, None);
+    }
+
+    #[test]
+    fn test_module_notes_prompt_submits_empty() {
+        let modules = ActionContext {
+            current_view: AppMode::ModuleManager,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('N'), &modules);
+        assert_eq!(update.text_prompt_open, Some(PromptKind::ModuleNotes));
+
+        // An empty notes prompt submits, clearing the notes
+        let prompt = ActionContext {
+            text_prompt: Some(PromptKind::ModuleNotes),
+            ..modules
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &prompt);
+        assert_eq!(update.text_prompt_submit, Some(()));
+
+        // Notes belong to modules, not developers
+        let developers = ActionContext {
+            module_manager_in_developer_list: true,
+            ..modules
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('N'), &developers);
+        assert_eq!(update.text_prompt_open, None);
+    }
+
+    #[test]
+    fn test_quick_view_opens_from_lists() {
+        let ctx = test_context();
+        for view in [AppMode::MergeVisualizer, AppMode::CommitHistory] {
+            let in_view = ActionContext {
+                current_view: view,
+                ..ctx.clone()
             };
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                update,
-            )
+            let (_, update) = ActionProcessor::process(KeyAction::ToggleStaging, &in_view);
+            assert_eq!(update.quick_view_requested, Some(()));
         }
+
+        let changes = ActionContext {
+            current_view: AppMode::Changes,
+            ..ctx.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('v'), &changes);
+        assert_eq!(update.quick_view_requested, Some(()));
+
+        let typing = ActionContext {
+            commit_message_empty: false,
+            ..changes
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('v'), &typing);
+        assert_eq!(update.quick_view_requested, None);
+        assert_eq!(update.commit_message_append, Some('v'));
     }
 
-    fn handle_navigate_left(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
-        if ctx.focus == Focus::View {
-            let update = match ctx.current_view {
-                AppMode::ProjectBoard => ActionStateUpdate {
-                    navigate_board_left: Some(()),
-                    ..Default::default()
-                },
-                AppMode::MergeVisualizer => ActionStateUpdate {
-                    merge_focus_prev: Some(()),
-                    ..Default::default()
-                },
-                _ => ActionStateUpdate::none(),
-            };
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                update,
-            )
-        } else {
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                ActionStateUpdate::none(),
-            )
+    #[test]
+    fn test_quick_view_scrolls_and_closes() {
+        let open = ActionContext {
+            quick_view_open: true,
+            ..test_context()
+        };
+
+        let (_, update) = ActionProcessor::process(KeyAction::NavigateDown, &open);
+        assert_eq!(update.quick_view_scroll, Some(1));
+        assert_eq!(update.navigate_change_down, None);
+
+        let (_, update) = ActionProcessor::process(KeyAction::ScrollPageUp, &open);
+        assert_eq!(update.quick_view_scroll, Some(-QUICK_VIEW_PAGE));
+
+        let (_, update) = ActionProcessor::process(KeyAction::NextView, &open);
+        assert_eq!(update.current_view, None);
+
+        let (result, update) = ActionProcessor::process(KeyAction::Back, &open);
+        assert_eq!(update.quick_view_close, Some(()));
+        assert!(!result.should_quit);
+    }
+
+    #[test]
+    fn test_quick_view_search_keys() {
+        let open = ActionContext {
+            quick_view_open: true,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('/'), &open);
+        assert_eq!(update.quick_view_search_start, Some(()));
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('n'), &open);
+        assert_eq!(update.quick_view_match, Some(1));
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('N'), &open);
+        assert_eq!(update.quick_view_match, Some(-1));
+
+        // While typing, letters go to the query and Esc only ends the search
+        let searching = ActionContext {
+            quick_view_searching: true,
+            ..open
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('n'), &searching);
+        assert_eq!(update.quick_view_search_append, Some('n'));
+        assert_eq!(update.quick_view_match, None);
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &searching);
+        assert_eq!(update.quick_view_search_submit, Some(()));
+        let (_, update) = ActionProcessor::process(KeyAction::Back, &searching);
+        assert_eq!(update.quick_view_search_cancel, Some(()));
+        assert_eq!(update.quick_view_close, None);
+
+        // Typed while searching, q and the vim keys are query text
+        let mut kh = KeyHandler::new();
+        for c in "qhjkl".chars() {
+            let action = kh.on_text_key_event(KeyEvent::from(KeyCode::Char(c)));
+            let (result, update) = ActionProcessor::process(action, &searching);
+            assert!(!result.should_quit);
+            assert_eq!(update.quick_view_search_append, Some(c));
         }
+        let (result, _) = ActionProcessor::process(KeyAction::Quit, &searching);
+        assert!(!result.should_quit);
+        let open = ActionContext {
+            commit_message_empty: true,
+            ..open
+        };
+        let (result, _) = ActionProcessor::process(KeyAction::Quit, &open);
+        assert!(result.should_quit);
     }
 
-    fn handle_navigate_right(ctx: &ActionContext) -> (ActionResult, ActionStateUpdate) {
-        if ctx.focus == Focus::View {
-            let update = match ctx.current_view {
-                AppMode::ProjectBoard => ActionStateUpdate {
-                    navigate_board_right: Some(()),
-                    ..Default::default()
-                },
-                AppMode::MergeVisualizer => ActionStateUpdate {
-                    merge_focus_next: Some(()),
-                    ..Default::default()
-                },
-                _ => ActionStateUpdate::none(),
-            };
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                update,
-            )
-        } else {
-            (
-                ActionResult {
-                    should_quit: false,
-                    status_message: None,
-                },
-                ActionStateUpdate::none(),
-            )
+    #[test]
+    fn test_count_digits_only_outside_text_input() {
+        let ctx = ActionContext {
+            current_view: AppMode::CommitHistory,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('4'), &ctx);
+        assert_eq!(update.count_digit, Some(4));
+
+        // A leading zero is not a count; after a digit it is
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('0'), &ctx);
+        assert_eq!(update.count_digit, None);
+        let pending = ActionContext {
+            count_pending: true,
+            ..ctx.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('0'), &pending);
+        assert_eq!(update.count_digit, Some(0));
+
+        // Digits are text in the commit message, new-branch name and search
+        let changes = ActionContext {
+            current_view: AppMode::Changes,
+            ..ctx.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('4'), &changes);
+        assert_eq!(update.count_digit, None);
+        let naming = ActionContext {
+            current_view: AppMode::BranchManager,
+            branch_create_mode: true,
+            ..ctx.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('4'), &naming);
+        assert_eq!(update.count_digit, None);
+        assert_eq!(update.branch_input_append, Some('4'));
+        let searching = ActionContext {
+            search_active: true,
+            ..ctx.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('4'), &searching);
+        assert_eq!(update.search_buffer_append, Some('4'));
+
+        let menu = ActionContext {
+            focus: Focus::Menu,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('4'), &menu);
+        assert_eq!(update.count_digit, None);
+    }
+
+    #[test]
+    fn test_alternate_view_key() {
+        let mut kh = KeyHandler::new();
+        let ctrl_6 = kh.on_key_event(KeyEvent::new(KeyCode::Char('6'), KeyModifiers::CONTROL));
+        assert_eq!(ctrl_6, KeyAction::AlternateView);
+
+        let (result, update) = ActionProcessor::process(KeyAction::AlternateView, &test_context());
+        assert!(update.current_view.is_none());
+        assert!(result.status_message.is_some());
+
+        let ctx = ActionContext {
+            focus: Focus::Menu,
+            previous_view: Some(AppMode::ProjectBoard),
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::AlternateView, &ctx);
+        assert_eq!(update.current_view, Some(AppMode::ProjectBoard));
+        assert_eq!(
+            update.menu_selected_index,
+            Some(AppMode::ProjectBoard.menu_index())
+        );
+        assert_eq!(update.focus, Some(Focus::View));
+    }
+
+    #[test]
+    fn test_dismiss_banner_key() {
+        let mut kh = KeyHandler::new();
+        let ctrl_x = kh.on_key_event(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL));
+        assert_eq!(ctrl_x, KeyAction::DismissBanner);
+
+        let (_, update) = ActionProcessor::process(KeyAction::DismissBanner, &test_context());
+        assert!(update.banner_dismissed.is_some());
+
+        // With a repository there is no banner to dismiss
+        let ctx = ActionContext {
+            has_git_client: true,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::DismissBanner, &ctx);
+        assert!(update.banner_dismissed.is_none());
+    }
+
+    #[test]
+    fn test_dump_status_key() {
+        let mut kh = KeyHandler::new();
+        let f12 = kh.on_key_event(KeyEvent::new(KeyCode::F(12), KeyModifiers::NONE));
+        assert_eq!(f12, KeyAction::DumpStatus);
+
+        let (_, update) = ActionProcessor::process(KeyAction::DumpStatus, &test_context());
+        assert!(update.status_dump_requested.is_none());
+
+        let ctx = ActionContext {
+            has_git_client: true,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::DumpStatus, &ctx);
+        assert!(update.status_dump_requested.is_some());
+    }
+
+    #[test]
+    fn test_copy_keys() {
+        let mut kh = KeyHandler::new();
+
+        let shift_y = kh.on_key_event(KeyEvent::new(KeyCode::Char('Y'), KeyModifiers::SHIFT));
+        assert_eq!(shift_y, KeyAction::InputChar('Y'));
+
+        let ctrl_y = kh.on_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL));
+        assert_eq!(ctrl_y, KeyAction::CopyChangeset);
+
+        let ctx = test_context();
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('Y'), &ctx);
+        assert!(update.copy_diff_requested.is_some());
+        let (_, update) = ActionProcessor::process(KeyAction::CopyChangeset, &ctx);
+        assert!(update.copy_changeset_requested.is_some());
+
+        // While typing a commit message, 'Y' is just text
+        let typing = ActionContext {
+            commit_message_empty: false,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('Y'), &typing);
+        assert!(update.copy_diff_requested.is_none());
+        assert_eq!(update.commit_message_append, Some('Y'));
+    }
+
+    #[test]
+    fn test_branch_manager_fetch_all_key() {
+        let ctx = ActionContext {
+            current_view: AppMode::BranchManager,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('F'), &ctx);
+        assert!(update.fetch_all_requested.is_some());
+
+        // Typed literally while naming a new branch
+        let creating = ActionContext {
+            branch_create_mode: true,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('F'), &creating);
+        assert!(update.fetch_all_requested.is_none());
+        assert_eq!(update.branch_input_append, Some('F'));
+    }
+
+    #[test]
+    fn test_branch_manager_filter_key() {
+        let ctx = ActionContext {
+            current_view: AppMode::BranchManager,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('R'), &ctx);
+        assert_eq!(update.branch_filter_cycle, Some(()));
+
+        let creating = ActionContext {
+            branch_create_mode: true,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('R'), &creating);
+        assert_eq!(update.branch_filter_cycle, None);
+        assert_eq!(update.branch_input_append, Some('R'));
+    }
+
+    #[test]
+    fn test_branch_picker_keys() {
+        let mut kh = KeyHandler::new();
+        let ctrl_b = kh.on_key_event(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::CONTROL));
+        assert_eq!(ctrl_b, KeyAction::BranchPicker);
+
+        let ctx = ActionContext {
+            current_view: AppMode::Settings,
+            has_git_client: true,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::BranchPicker, &ctx);
+        assert_eq!(update.branch_picker_requested, Some(()));
+
+        let open = ActionContext {
+            branch_picker_open: true,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('f'), &open);
+        assert_eq!(update.branch_picker_append, Some('f'));
+        assert_eq!(update.commit_message_append, None);
+        let (_, update) = ActionProcessor::process(KeyAction::NavigateDown, &open);
+        assert_eq!(update.branch_picker_move, Some(1));
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &open);
+        assert_eq!(update.branch_picker_checkout, Some(()));
+        let (_, update) = ActionProcessor::process(KeyAction::NextView, &open);
+        assert_eq!(update.current_view, None);
+        let (_, update) = ActionProcessor::process(KeyAction::Back, &open);
+        assert_eq!(update.branch_picker_close, Some(()));
+
+        // Every printable key filters, including q, ? and the vim keys
+        for c in "qhjkl?/".chars() {
+            let action = kh.on_text_key_event(KeyEvent::from(KeyCode::Char(c)));
+            let (result, update) = ActionProcessor::process(action, &open);
+            assert!(!result.should_quit);
+            assert_eq!(update.branch_picker_append, Some(c));
+            assert_eq!(update.branch_picker_move, None);
         }
     }
-}
 
-/// Structural representation of state changes requested by action handlers
-#[derive(Debug, Default, Clone)]
-pub struct ActionStateUpdate {
-    // Focus and mode
-    pub focus: Option<Focus>,
-    pub current_view: Option<AppMode>,
-    pub show_help: Option<bool>,
+    #[test]
+    fn test_reflog_keys() {
+        let mut kh = KeyHandler::new();
+        let ctrl_r = kh.on_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        assert_eq!(ctrl_r, KeyAction::Reflog);
 
-    // Search state
-    pub search_active: Option<bool>,
-    pub search_buffer: Option<String>,
-    pub search_buffer_append: Option<char>,
-    pub search_buffer_pop: Option<()>,
+        let ctx = ActionContext {
+            has_git_client: true,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Reflog, &ctx);
+        assert_eq!(update.reflog_requested, Some(()));
 
-    // Selection state
-    pub menu_selected_index: Option<usize>,
-    pub selected_project_index: Option<usize>,
-    pub selected_change_index: Option<usize>,
-    pub selected_board_column: Option<usize>,
-    pub selected_board_item: Option<usize>,
-    pub selected_merge_file_index: Option<usize>,
-    pub selected_setting_index: Option<usize>,
-    // New view selections
-    pub selected_commit_index: Option<usize>,
-    pub selected_branch_index: Option<usize>,
-    pub selected_module_index: Option<usize>,
-    pub selected_developer_index: Option<usize>,
+        let open = ActionContext {
+            reflog_open: true,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::NavigateDown, &open);
+        assert_eq!(update.reflog_move, Some(1));
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &open);
+        assert_eq!(update.reflog_checkout, Some(()));
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('r'), &open);
+        assert_eq!(update.reflog_reset, Some(()));
+        assert_eq!(update.commit_message_append, None);
+        let (_, update) = ActionProcessor::process(KeyAction::Back, &open);
+        assert_eq!(update.reflog_close, Some(()));
+    }
 
-    // Commit message
-    pub commit_message_append: Option<char>,
-    pub commit_message_pop: Option<()>,
-    pub commit_message_clear: Option<()>,
+    #[test]
+    fn test_worktree_keys() {
+        let mut kh = KeyHandler::new();
+        let ctrl_w = kh.on_key_event(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        assert_eq!(ctrl_w, KeyAction::Worktrees);
 
-    // Scroll state
-    pub project_scroll_up: Option<usize>,
-    pub project_scroll_down: Option<usize>,
-    pub changes_scroll_up: Option<usize>,
-    pub changes_scroll_down: Option<usize>,
-    pub merge_scroll_up: Option<usize>,
-    pub merge_scroll_down: Option<usize>,
+        let (_, update) = ActionProcessor::process(KeyAction::Worktrees, &test_context());
+        assert_eq!(update.worktrees_requested, None, "needs a repository");
 
-    // Layout adjustments
-    pub changes_pane_ratio: Option<u16>,
-    pub commit_pane_ratio: Option<u16>,
-    pub module_pane_ratio: Option<u16>,
-    pub dashboard_pane_ratio: Option<u16>,
+        let ctx = ActionContext {
+            has_git_client: true,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Worktrees, &ctx);
+        assert_eq!(update.worktrees_requested, Some(()));
 
-    // Complex actions
-    pub clamp_selections: Option<()>,
-    pub navigate_project_down: Option<()>,
-    pub navigate_change_down: Option<()>,
-    pub navigate_board_up: Option<()>,
-    pub navigate_board_down: Option<()>,
-    pub navigate_board_left: Option<()>,
-    pub navigate_board_right: Option<()>,
-    pub navigate_merge_down: Option<()>,
-    pub merge_focus_next: Option<()>,
-    pub merge_focus_prev: Option<()>,
-    pub navigate_settings_down: Option<()>,
+        let open = ActionContext {
+            worktrees_open: true,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::NavigateDown, &open);
+        assert_eq!(update.worktrees_move, Some(1));
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &open);
+        assert_eq!(update.worktree_switch, Some(()));
+        let (_, update) = ActionProcessor::process(KeyAction::Back, &open);
+        assert_eq!(update.worktrees_close, Some(()));
+    }
 
-    // Commands
-    pub move_board_item: Option<()>,
-    pub accept_merge_pane: Option<()>,
-    pub toggle_setting: Option<()>,
-    pub commit_requested: Option<()>,
+    #[test]
+    fn test_setting_hotkeys_ignore_selected_setting() {
+        let mut kh = KeyHandler::new();
+        let ctx = ActionContext {
+            current_view: AppMode::Settings,
+            selected_setting_index: 4,
+            ..test_context()
+        };
+        for (key, index) in [
+            ('t', THEME_SETTING),
+            ('n', NOTIFICATIONS_SETTING),
+            ('d', COMMIT_DATES_SETTING),
+        ] {
+            let action = kh.on_key_event(KeyEvent::new(KeyCode::Char(key), KeyModifiers::CONTROL));
+            assert_eq!(action, KeyAction::ToggleSetting(index));
+            let (_, update) = ActionProcessor::process(action, &ctx);
+            assert_eq!(update.toggle_setting, Some(index));
+        }
 
-    // Branch operations
-    pub branch_create_mode: Option<bool>,
-    pub branch_input_append: Option<char>,
-    pub branch_input_pop: Option<()>,
-    pub branch_input_clear: Option<()>,
-    pub branch_switch_requested: Option<()>,
-    pub branch_create_requested: Option<()>,
-    pub branch_delete_requested: Option<()>,
+        // Enter still toggles the highlighted row
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &ctx);
+        assert_eq!(update.toggle_setting, Some(4));
+    }
 
-    // Module operations
-    pub toggle_module_list: Option<()>,
-    pub module_create_mode: Option<bool>,
-    pub module_edit_mode: Option<bool>,
-    pub developer_create_mode: Option<bool>,
-    pub module_input_append: Option<char>,
-    pub module_input_pop: Option<()>,
-    pub module_input_clear: Option<()>,
-    pub module_load_selected: Option<()>,
-    pub module_create_requested: Option<()>,
-    pub module_update_requested: Option<()>,
-    pub module_delete_requested: Option<()>,
-    pub developer_create_requested: Option<()>,
-    pub developer_delete_requested: Option<()>,
-    pub module_assign_mode: Option<bool>,
-    pub module_assign_requested: Option<()>,
+    #[test]
+    fn test_blame_toggle_in_changes() {
+        let ctx = test_context();
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('B'), &ctx);
+        assert_eq!(update.blame_toggle, Some(()));
 
-    // File staging
-    pub toggle_staging_requested: Option<()>,
+        let typing = ActionContext {
+            commit_message_empty: false,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('B'), &typing);
+        assert_eq!(update.blame_toggle, None);
+        assert_eq!(update.commit_message_append, Some('B'));
+    }
+
+    #[test]
+    fn test_resize_reaches_app_through_popups() {
+        let prompt = ActionContext {
+            text_prompt: Some(PromptKind::QuickCommit),
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Resize(100, 30), &prompt);
+        assert_eq!(update.terminal_resized, Some((100, 30)));
+        assert_eq!(update.text_prompt_append, None);
+    }
 
-    // Remote operations
-    pub fetch_requested: Option<()>,
-    pub push_requested: Option<()>,
-    pub pull_requested: Option<()>,
-}
+    #[test]
+    fn test_expand_diff_in_changes() {
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('+'), &test_context());
+        assert_eq!(update.diff_expand_requested, Some(()));
 
-impl ActionStateUpdate {
-    pub fn none() -> Self {
-        Self::default()
+        let typing = ActionContext {
+            commit_message_empty: false,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('+'), &typing);
+        assert_eq!(update.diff_expand_requested, None);
+        assert_eq!(update.commit_message_append, Some('+'));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_module_manager_pane_focus_navigation() {
+        let modules = ActionContext {
+            current_view: AppMode::ModuleManager,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::NavigateRight, &modules);
+        assert!(update.toggle_module_list.is_some());
+        let (_, update) = ActionProcessor::process(KeyAction::NavigateLeft, &modules);
+        assert!(update.toggle_module_list.is_none(), "Already on modules");
+
+        let developers = ActionContext {
+            module_manager_in_developer_list: true,
+            ..modules.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::NavigateLeft, &developers);
+        assert!(update.toggle_module_list.is_some());
+        let (_, update) = ActionProcessor::process(KeyAction::NavigateRight, &developers);
+        assert!(update.toggle_module_list.is_none(), "Already on developers");
+
+        // Up/Down follow the focused pane
+        let (_, update) = ActionProcessor::process(KeyAction::NavigateDown, &developers);
+        assert_eq!(update.selected_developer_index, Some(1));
+        assert!(update.selected_module_index.is_none());
+
+        // Focus is locked while a form is open
+        let editing = ActionContext {
+            module_edit_mode: true,
+            ..modules
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::NavigateRight, &editing);
+        assert!(update.toggle_module_list.is_none());
+    }
 
     #[test]
-    fn maps_basic_keys() {
+    fn test_module_manager_pane_ratio_keys() {
         let mut kh = KeyHandler::new();
+        let narrow = kh.on_key_event(KeyEvent::new(KeyCode::Left, KeyModifiers::SHIFT));
+        let widen = kh.on_key_event(KeyEvent::new(KeyCode::Right, KeyModifiers::SHIFT));
+        assert_eq!(narrow, KeyAction::PaneNarrow);
+        assert_eq!(widen, KeyAction::PaneWiden);
+        let ctrl_left = kh.on_key_event(KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL));
+        let ctrl_right = kh.on_key_event(KeyEvent::new(KeyCode::Right, KeyModifiers::CONTROL));
+        assert_eq!(ctrl_left, KeyAction::PaneNarrow);
+        assert_eq!(ctrl_right, KeyAction::PaneWiden);
 
-        let quit = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char('q'),
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(quit, KeyAction::Quit);
+        let ctx = ActionContext {
+            current_view: AppMode::ModuleManager,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::PaneNarrow, &ctx);
+        assert_eq!(update.module_pane_ratio, Some(45));
+        let (_, update) = ActionProcessor::process(KeyAction::PaneWiden, &ctx);
+        assert_eq!(update.module_pane_ratio, Some(55));
 
-        let help = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char('?'),
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(help, KeyAction::Help);
+        let at_min = ActionContext {
+            module_pane_ratio: 20,
+            ..ctx.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::PaneNarrow, &at_min);
+        assert_eq!(update.module_pane_ratio, Some(20));
 
-        let tab = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Tab,
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(tab, KeyAction::NextView);
+        let at_max = ActionContext {
+            module_pane_ratio: 80,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::PaneWiden, &at_max);
+        assert_eq!(update.module_pane_ratio, Some(80));
+    }
 
-        let up = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Up,
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(up, KeyAction::NavigateUp);
+    #[test]
+    fn test_module_manager_dependency_mode() {
+        let modules = ActionContext {
+            current_view: AppMode::ModuleManager,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('D'), &modules);
+        assert_eq!(update.module_dependency_mode, Some(true));
 
-        let ch = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char('x'),
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(ch, KeyAction::InputChar('x'));
+        let picking = ActionContext {
+            module_dependency_mode: true,
+            ..modules
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &picking);
+        assert!(update.module_dependency_toggle_requested.is_some());
+
+        // Other shortcuts are inert until the picker is closed
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('d'), &picking);
+        assert!(update.module_delete_requested.is_none());
+        let (_, update) = ActionProcessor::process(KeyAction::NavigateRight, &picking);
+        assert!(update.toggle_module_list.is_none());
+
+        let (_, update) = ActionProcessor::process(KeyAction::Back, &picking);
+        assert_eq!(update.module_dependency_mode, Some(false));
     }
 
     #[test]
-    fn test_navigation_keys() {
-        let mut kh = KeyHandler::new();
+    fn test_assign_mode_typing_filters_developers() {
+        let ctx = ActionContext {
+            current_view: AppMode::ModuleManager,
+            module_assign_mode: true,
+            ..test_context()
+        };
 
-        let down = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Down,
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(down, KeyAction::NavigateDown);
+        // Shortcut letters are filter text while assigning
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('n'), &ctx);
+        assert_eq!(update.module_assign_filter_append, Some('n'));
+        assert_eq!(update.module_create_mode, None);
 
-        let left = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Left,
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(left, KeyAction::NavigateLeft);
+        let (_, update) = ActionProcessor::process(KeyAction::Backspace, &ctx);
+        assert_eq!(update.module_assign_filter_pop, Some(()));
 
-        let right = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Right,
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(right, KeyAction::NavigateRight);
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &ctx);
+        assert_eq!(update.module_assign_requested, Some(()));
     }
 
     #[test]
-    fn test_vim_keybindings() {
+    fn test_f2_renames_module_manager_row_in_place() {
         let mut kh = KeyHandler::new();
+        let f2 = kh.on_key_event(KeyEvent::new(KeyCode::F(2), KeyModifiers::NONE));
+        assert_eq!(f2, KeyAction::Rename);
 
-        let up_vim = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char('k'),
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(up_vim, KeyAction::NavigateUp);
+        let ctx = ActionContext {
+            current_view: AppMode::ModuleManager,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Rename, &ctx);
+        assert_eq!(update.module_rename_mode, Some(true));
 
-        let down_vim = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char('j'),
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(down_vim, KeyAction::NavigateDown);
+        // Shortcut letters and spaces are name text while renaming
+        let renaming = ActionContext {
+            module_rename_mode: true,
+            module_input_empty: false,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('d'), &renaming);
+        assert_eq!(update.module_input_append, Some('d'));
+        assert_eq!(update.module_delete_requested, None);
+        let (_, update) = ActionProcessor::process(KeyAction::ToggleStaging, &renaming);
+        assert_eq!(update.module_input_append, Some(' '));
+        let (_, update) = ActionProcessor::process(KeyAction::Backspace, &renaming);
+        assert_eq!(update.module_input_pop, Some(()));
 
-        let left_vim = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char('h'),
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(left_vim, KeyAction::NavigateLeft);
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &renaming);
+        assert_eq!(update.module_rename_requested, Some(()));
+        let (_, update) = ActionProcessor::process(KeyAction::Back, &renaming);
+        assert_eq!(update.module_rename_mode, Some(false));
 
-        let right_vim = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char('l'),
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(right_vim, KeyAction::NavigateRight);
+        // An emptied name is refused rather than saved
+        let emptied = ActionContext {
+            module_input_empty: true,
+            ..renaming
+        };
+        let (result, update) = ActionProcessor::process(KeyAction::Select, &emptied);
+        assert_eq!(update.module_rename_requested, None);
+        assert_eq!(
+            result.status_message.as_deref(),
+            Some("Name cannot be empty")
+        );
     }
 
     #[test]
-    fn test_modifier_keys() {
-        let mut kh = KeyHandler::new();
+    fn test_open_in_editor_key() {
+        for view in [AppMode::Changes, AppMode::MergeVisualizer] {
+            let ctx = ActionContext {
+                current_view: view,
+                ..test_context()
+            };
+            let (_, update) = ActionProcessor::process(KeyAction::InputChar('o'), &ctx);
+            assert_eq!(update.open_in_editor_requested, Some(()), "{:?}", view);
+        }
 
-        let ctrl_c = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char('c'),
-            modifiers: crossterm::event::KeyModifiers::CONTROL,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(ctrl_c, KeyAction::Quit);
+        // While typing a commit message 'o' is just a letter
+        let typing = ActionContext {
+            current_view: AppMode::Changes,
+            commit_message_empty: false,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('o'), &typing);
+        assert_eq!(update.open_in_editor_requested, None);
+        assert_eq!(update.commit_message_append, Some('o'));
+    }
 
-        let ctrl_f = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char('f'),
-            modifiers: crossterm::event::KeyModifiers::CONTROL,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(ctrl_f, KeyAction::Search);
+    #[test]
+    fn test_stage_and_advance_key() {
+        let changes = ActionContext {
+            current_view: AppMode::Changes,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('S'), &changes);
+        assert_eq!(update.stage_and_advance_requested, Some(()));
+
+        let typing = ActionContext {
+            commit_message_empty: false,
+            ..changes
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('S'), &typing);
+        assert_eq!(update.stage_and_advance_requested, None);
+        assert_eq!(update.commit_message_append, Some('S'));
     }
 
     #[test]
-    fn test_staging_and_enter() {
-        let mut kh = KeyHandler::new();
+    fn test_multi_select_keys_in_changes() {
+        let changes = ActionContext {
+            current_view: AppMode::Changes,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('V'), &changes);
+        assert_eq!(update.multi_select_toggle, Some(()));
+        // Outside the mode, space still stages the one file
+        let (_, update) = ActionProcessor::process(KeyAction::ToggleStaging, &changes);
+        assert_eq!(update.toggle_staging_requested, Some(()));
+        assert_eq!(update.row_selection_toggle, None);
 
-        let space = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Char(' '),
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(space, KeyAction::ToggleStaging);
+        let selecting = ActionContext {
+            changes_multi_select: true,
+            ..changes
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::ToggleStaging, &selecting);
+        assert_eq!(update.row_selection_toggle, Some(()));
+        assert_eq!(update.toggle_staging_requested, None);
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &selecting);
+        assert_eq!(update.stage_selected_requested, Some(()));
+        assert_eq!(update.commit_requested, None);
+        let (result, update) = ActionProcessor::process(KeyAction::Back, &selecting);
+        assert_eq!(update.multi_select_toggle, Some(()));
+        assert_eq!(update.focus, None);
+        assert_eq!(result.status_message.as_deref(), Some("Left multi-select"));
+    }
 
-        let enter = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Enter,
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(enter, KeyAction::Select);
+    #[test]
+    fn test_accept_both_key_in_merge_view() {
+        let merge = ActionContext {
+            current_view: AppMode::MergeVisualizer,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('b'), &merge);
+        assert_eq!(update.accept_both_requested, Some(()));
+
+        let changes = ActionContext {
+            current_view: AppMode::Changes,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('b'), &changes);
+        assert_eq!(update.accept_both_requested, None);
     }
 
     #[test]
-    fn test_page_keys() {
-        let mut kh = KeyHandler::new();
+    fn test_copy_path_key() {
+        let changes = ActionContext {
+            current_view: AppMode::Changes,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('P'), &changes);
+        assert_eq!(update.copy_path_requested, Some(()));
+        // Lowercase 'p' keeps pushing in Changes
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('p'), &changes);
+        assert_eq!(update.copy_path_requested, None);
 
-        let page_up = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::PageUp,
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(page_up, KeyAction::ScrollPageUp);
+        let merge = ActionContext {
+            current_view: AppMode::MergeVisualizer,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('p'), &merge);
+        assert_eq!(update.copy_path_requested, Some(()));
 
-        let page_down = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::PageDown,
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(page_down, KeyAction::ScrollPageDown);
+        let history = ActionContext {
+            current_view: AppMode::CommitHistory,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('p'), &history);
+        assert_eq!(update.copy_path_requested, Some(()));
+
+        let typing = ActionContext {
+            commit_message_empty: false,
+            ..changes
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('P'), &typing);
+        assert_eq!(update.copy_path_requested, None);
+        assert_eq!(update.commit_message_append, Some('P'));
     }
 
     #[test]
-    fn test_backspace() {
-        let mut kh = KeyHandler::new();
+    fn test_menu_select_enters_highlighted_view() {
+        let menu = ActionContext {
+            focus: Focus::Menu,
+            current_view: AppMode::Dashboard,
+            menu_selected_index: AppMode::BranchManager.menu_index(),
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Select, &menu);
+        assert_eq!(update.focus, Some(Focus::View));
+        assert_eq!(update.current_view, Some(AppMode::BranchManager));
+    }
 
-        let backspace = kh.on_key_event(crossterm::event::KeyEvent {
-            code: crossterm::event::KeyCode::Backspace,
-            modifiers: crossterm::event::KeyModifiers::NONE,
-            kind: crossterm::event::KeyEventKind::Press,
-            state: crossterm::event::KeyEventState::NONE,
-        });
-        assert_eq!(backspace, KeyAction::Backspace);
+    #[test]
+    fn test_board_group_toggle_key() {
+        let ctx = ActionContext {
+            current_view: AppMode::ProjectBoard,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('g'), &ctx);
+        assert_eq!(update.board_group_toggle, Some(()));
+
+        let menu = ActionContext {
+            focus: Focus::Menu,
+            ..ctx
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('g'), &menu);
+        assert_eq!(update.board_group_toggle, None);
     }
 
     #[test]
-    fn test_action_result_creation() {
-        let result = ActionResult {
-            should_quit: false,
-            status_message: Some("Test message".to_string()),
+    fn test_module_manager_assign_all_key() {
+        let ctx = ActionContext {
+            current_view: AppMode::ModuleManager,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('A'), &ctx);
+        assert!(update.module_assign_all_requested.is_some());
+
+        // Typed literally while a name is being entered
+        let creating = ActionContext {
+            module_create_mode: true,
+            ..ctx
         };
+        let (_, update) = ActionProcessor::process(KeyAction::InputChar('A'), &creating);
+        assert!(update.module_assign_all_requested.is_none());
+        assert_eq!(update.module_input_append, Some('A'));
+    }
+
+    #[test]
+    fn test_quit_immediate_without_commit_message() {
+        let ctx = test_context();
+        let (result, update) = ActionProcessor::process(KeyAction::Quit, &ctx);
+        assert!(result.should_quit);
+        assert!(update.confirm_quit.is_none());
+    }
 
+    #[test]
+    fn test_quit_blocked_with_commit_message() {
+        let ctx = ActionContext {
+            commit_message_empty: false,
+            ..test_context()
+        };
+        let (result, update) = ActionProcessor::process(KeyAction::Quit, &ctx);
         assert!(!result.should_quit);
-        assert_eq!(result.status_message, Some("Test message".to_string()));
+        assert_eq!(update.confirm_quit, Some(true));
     }
 
     #[test]
-    fn test_action_state_update_none() {
-        let update = ActionStateUpdate::none();
-        assert!(update.focus.is_none());
-        assert!(update.current_view.is_none());
-        assert!(update.show_help.is_none());
+    fn test_back_pops_one_transient_state_at_a_time() {
+        let list = ActionContext {
+            current_view: AppMode::ModuleManager,
+            ..test_context()
+        };
+        let creating = ActionContext {
+            module_create_mode: true,
+            ..list.clone()
+        };
+
+        // Esc in the create form returns to the list, focus stays on the view
+        let (result, update) = ActionProcessor::process(KeyAction::Back, &creating);
+        assert!(!result.should_quit);
+        assert_eq!(update.module_create_mode, Some(false));
+        assert_eq!(update.module_input_clear, Some(()));
+        assert_eq!(update.focus, None);
+
+        // Help over the form closes first, leaving the form open
+        let help_over_form = ActionContext {
+            show_help: true,
+            ..creating
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Back, &help_over_form);
+        assert_eq!(update.show_help, Some(false));
+        assert_eq!(update.module_create_mode, None);
+
+        // Only the active selection mode is cancelled
+        let assigning = ActionContext {
+            module_assign_mode: true,
+            ..list.clone()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Back, &assigning);
+        assert_eq!(update.module_assign_mode, Some(false));
+        assert_eq!(update.module_dependency_mode, None);
+        assert_eq!(update.focus, None);
+
+        // Nothing transient left: Esc in the list moves focus to the menu
+        let (result, update) = ActionProcessor::process(KeyAction::Back, &list);
+        assert!(!result.should_quit);
+        assert_eq!(update.focus, Some(Focus::Menu));
+    }
+
+    #[test]
+    fn test_back_clears_commit_author_filter() {
+        let filtered = ActionContext {
+            current_view: AppMode::CommitHistory,
+            commit_filter_active: true,
+            ..test_context()
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Back, &filtered);
+        assert_eq!(update.commit_filter_clear, Some(()));
+        assert_eq!(update.focus, None);
+
+        let unfiltered = ActionContext {
+            commit_filter_active: false,
+            ..filtered
+        };
+        let (_, update) = ActionProcessor::process(KeyAction::Back, &unfiltered);
+        assert_eq!(update.commit_filter_clear, None);
+        assert_eq!(update.focus, Some(Focus::Menu));
+    }
+
+    #[test]
+    fn test_back_from_menu_blocked_with_commit_message() {
+        let ctx = ActionContext {
+            focus: Focus::Menu,
+            commit_message_empty: false,
+            ..test_context()
+        };
+        let (result, update) = ActionProcessor::process(KeyAction::Back, &ctx);
+        assert!(!result.should_quit);
+        assert_eq!(update.confirm_quit, Some(true));
+    }
+
+    #[test]
+    fn test_quit_confirmation_answers() {
+        let ctx = ActionContext {
+            commit_message_empty: false,
+            confirm_quit_pending: true,
+            ..test_context()
+        };
+
+        let (result, _) = ActionProcessor::process(KeyAction::InputChar('y'), &ctx);
+        assert!(result.should_quit);
+
+        let (result, update) = ActionProcessor::process(KeyAction::InputChar('n'), &ctx);
+        assert!(!result.should_quit);
+        assert_eq!(update.confirm_quit, Some(false));
+
+        let (result, update) = ActionProcessor::process(KeyAction::Back, &ctx);
+        assert!(!result.should_quit);
+        assert_eq!(update.confirm_quit, Some(false));
+
+        // Unrelated keys are swallowed while the dialog is open
+        let (result, update) = ActionProcessor::process(KeyAction::InputChar('x'), &ctx);
+        assert!(!result.should_quit);
+        assert!(update.commit_message_append.is_none());
+        assert!(update.confirm_quit.is_none());
     }
 }
@@ -5,4 +5,4 @@ pub mod git;
 
 // Re-export main types used in tests
 pub use data::{Change, Developer, FileStatus, Module, ModuleStatus, Project, Store};
-pub use git::{GitClient, TransferProgress};
+pub use git::{GitClient, GitClientOptions, TransferProgress};
@@ -1,8 +1,10 @@
 use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
 
 use ratatui::{DefaultTerminal, Frame};
 
 pub mod async_task;
+pub mod clipboard;
 pub mod data;
 pub mod git;
 pub mod key_handler;
@@ -12,32 +14,124 @@ pub mod state;
 pub mod status_symbols;
 pub mod ui_utils;
 use async_task::{GitOperation, TaskManager};
-use data::ModuleStatus;
+use data::{Module, ModuleStatus};
 use key_handler::{ActionContext, ActionProcessor, ActionStateUpdate, KeyAction, KeyHandler};
 use pages::branch_manager::BranchInfo;
 use pages::commit_history::CommitInfo;
 use pages::merge_visualizer::MergePaneFocus;
 use screen::Screen;
 use state::{
-    BoardState, BranchManagerState, ChangesState, CommitHistoryState, DashboardState, MergeState,
-    ModuleManagerState,
+    board_columns, export_commits_json, generate_changelog, ActionLog, BoardColumn, BoardState,
+    BranchFilter, BranchManagerState, BranchPickerState, ChangesState, CommitHistoryState,
+    DashboardState, LastView, MergeState, ModuleManagerState, PromptKind, QuickViewState,
+    ReflogState, TextPrompt, WorktreeListState,
 };
-use status_symbols::{error, progress, success};
+use status_symbols::{error, info, progress, success, warning};
 
 // UI constants
 const WINDOW_SIZE: usize = 10;
+/// Terminal rows that never hold list items: the outer frame, the status bar
+/// and the list's own border. The no-repository banner takes one more.
+const LIST_CHROME_ROWS: u16 = 5;
+/// How long the event loop waits for input before running periodic work
+const TICK_RATE: Duration = Duration::from_millis(250);
+/// Autosave intervals offered in Settings, in seconds (0 = off)
+const AUTOSAVE_CHOICES: [u64; 4] = [0, 30, 60, 300];
+/// Number of commits loaded per Commit History page
+const COMMIT_PAGE_SIZE: usize = 50;
+/// Initial Commit History sizes offered in Settings
+const HISTORY_LIMIT_CHOICES: [usize; 6] = [10, 50, 100, 250, 500, 1000];
+/// Diff size caps offered in Settings; 0 diffs files of any size
+const MAX_DIFF_BYTES_CHOICES: [u64; 5] = [MIB / 4, MIB, 4 * MIB, 16 * MIB, 0];
+const MIB: u64 = 1024 * 1024;
+/// Range a saved initial Commit History size is clamped to
+const HISTORY_LIMIT_RANGE: (usize, usize) = (10, 1000);
+/// Days covered by the dashboard activity panel
+const ACTIVITY_DAYS: usize = 14;
+/// Reflog entries listed in the reflog popup
+const REFLOG_LIMIT: usize = 100;
+/// Largest vim-style count prefix; more digits are ignored
+const MAX_NAVIGATION_COUNT: usize = 999;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Theme {
     Default,
     HighContrast,
 }
 
-#[derive(Debug, Clone, Copy)]
+/// User preferences, saved per repository as `settings.json` under
+/// [`git::GitClient::state_dir`], i.e. `forge/` in the common git directory
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct AppSettings {
     pub theme: Theme,
     pub notifications: bool,
     pub autosync: bool,
+    /// Sign commits with the key from git config; never falls back to unsigned
+    pub sign_commits: bool,
+    /// Seconds between autosaves of changed progress; 0 disables autosave
+    pub autosave_secs: u64,
+    /// Which files the Changes view lists
+    pub git_options: git::GitClientOptions,
+    /// Commit History shows "2h ago" rather than timestamps
+    pub relative_dates: bool,
+    /// Commit History shows one line per commit instead of two
+    pub compact_commits: bool,
+    /// Commits Commit History loads on entry; more are paged in on demand
+    pub history_limit: usize,
+    /// Only Ctrl+Enter commits from the Changes view; plain Enter does nothing
+    pub commit_requires_modifier: bool,
+    /// Run the repository's pre-commit and commit-msg hooks before committing
+    pub run_hooks: bool,
+    /// Remote that fetch, push and pull talk to
+    pub default_remote: String,
+    /// Fetch the default remote in the background when Forge opens a repository
+    pub auto_fetch: bool,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            theme: Theme::Default,
+            notifications: true,
+            autosync: false,
+            sign_commits: false,
+            autosave_secs: 60,
+            git_options: git::GitClientOptions::default(),
+            relative_dates: false,
+            compact_commits: false,
+            history_limit: 100,
+            commit_requires_modifier: false,
+            run_hooks: false,
+            default_remote: "origin".to_string(),
+            auto_fetch: false,
+        }
+    }
+}
+
+impl AppSettings {
+    /// Location of the saved settings inside the repository's state directory
+    fn path(state_dir: &std::path::Path) -> PathBuf {
+        state_dir.join("settings.json")
+    }
+
+    /// Reads saved settings, with out-of-range values pulled back into range;
+    /// `None` if there are none or they are unreadable
+    fn load(state_dir: &std::path::Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(Self::path(state_dir)).ok()?;
+        let mut settings: Self = serde_json::from_str(&contents).ok()?;
+        let (min, max) = HISTORY_LIMIT_RANGE;
+        settings.history_limit = settings.history_limit.clamp(min, max);
+        Some(settings)
+    }
+
+    fn save(&self, state_dir: &std::path::Path) -> std::io::Result<()> {
+        let path = Self::path(state_dir);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -48,12 +142,24 @@ pub enum Focus {
 
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
+    install_panic_restore(ratatui::restore);
     let terminal = ratatui::init();
+    // Restore before returning so an error report prints to a sane terminal
     let result = App::new().run(terminal);
     ratatui::restore();
     result
 }
 
+/// Runs `restore` ahead of the current panic hook, so a panic in a render or
+/// handler leaves the terminal out of raw mode before the report is printed
+fn install_panic_restore(restore: fn()) {
+    let report = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore();
+        report(info);
+    }));
+}
+
 /// Main application state container
 ///
 /// # Architecture Note
@@ -90,6 +196,30 @@ pub struct App {
     git_workdir: Option<PathBuf>,
     task_manager: TaskManager,
     pending_git_ops: Vec<GitOperation>,
+    /// When `origin` was last fetched (from FETCH_HEAD), if ever
+    last_fetch: Option<SystemTime>,
+    /// Vim-style count typed before a navigation key, e.g. the 5 in "5j"
+    pending_count: Option<usize>,
+    /// View shown before `current_view`, for Ctrl+^
+    previous_view: Option<AppMode>,
+    /// What HEAD points at: a branch, a detached commit, or nothing yet
+    head: git::HeadRef,
+    /// The working tree has uncommitted changes
+    repo_dirty: bool,
+    /// Lines inserted and deleted since HEAD, staged or not
+    uncommitted: Option<(usize, usize)>,
+    /// Unresolved merge conflicts in the index
+    conflict_count: usize,
+    /// History of git operations performed this session
+    action_log: ActionLog,
+    /// When the autosave timer last fired
+    last_autosave: Instant,
+    /// `$EDITOR` as read at startup; empty when unset
+    editor: String,
+    /// Editor to run once the main loop has handed the terminal over
+    editor_command: Option<std::process::Command>,
+    /// The user hid the banner warning that no repository is open
+    no_repo_banner_dismissed: bool,
 
     // ====================================================================
     // Navigation & Focus State
@@ -98,6 +228,19 @@ pub struct App {
     focus: Focus,
     menu_selected_index: usize,
     show_help: bool,
+    show_action_log: bool,
+    /// Waiting for the user to confirm quitting with an unfinished commit message
+    confirm_quit: bool,
+    /// Diff shown in the quick view popup, if open
+    quick_view: Option<QuickViewState>,
+    /// Single-line text prompt (quick commit, patch path), if open
+    prompt: Option<TextPrompt>,
+    /// Fuzzy branch-switch picker, if open
+    branch_picker: Option<BranchPickerState>,
+    /// HEAD reflog popup, if open
+    reflog: Option<ReflogState>,
+    /// Worktree switcher popup, if open
+    worktrees: Option<WorktreeListState>,
     search_active: bool,
     search_buffer: String,
 
@@ -133,7 +276,23 @@ impl Default for App {
 
 impl App {
     pub fn new() -> Self {
-        let mut app = Self {
+        let mut app = Self::without_repo();
+
+        // Attempt to discover a Git repository from the current directory
+        if let Ok(cwd) = std::env::current_dir() {
+            if let Ok(client) = git::GitClient::discover(&cwd) {
+                app.attach_repo(client);
+                app.restore_last_view();
+                app.auto_fetch_on_startup();
+            }
+        }
+
+        app
+    }
+
+    /// App state with no repository attached
+    fn without_repo() -> Self {
+        Self {
             running: false,
             screen: Screen::new(),
             key_handler: KeyHandler::new(),
@@ -145,17 +304,32 @@ impl App {
             last_completion_message: None,
             store: data::Store::new(),
             show_help: false,
+            show_action_log: false,
+            confirm_quit: false,
+            quick_view: None,
+            prompt: None,
+            branch_picker: None,
+            reflog: None,
+            worktrees: None,
             search_active: false,
             search_buffer: String::new(),
-            settings: AppSettings {
-                theme: Theme::Default,
-                notifications: true,
-                autosync: false,
-            },
+            settings: AppSettings::default(),
             git_client: None,
             git_workdir: None,
             task_manager: TaskManager::new(),
             pending_git_ops: Vec::new(),
+            last_fetch: None,
+            pending_count: None,
+            previous_view: None,
+            head: git::HeadRef::Unborn,
+            repo_dirty: false,
+            uncommitted: None,
+            conflict_count: 0,
+            action_log: ActionLog::default(),
+            last_autosave: Instant::now(),
+            editor: std::env::var("EDITOR").unwrap_or_default(),
+            editor_command: None,
+            no_repo_banner_dismissed: false,
             // Page state structs
             dashboard: DashboardState::new(),
             changes: ChangesState::new(),
@@ -166,82 +340,267 @@ impl App {
             commit_history: CommitHistoryState::new(),
             // Settings (kept inline)
             selected_setting_index: 0,
-        };
+        }
+    }
 
-        // Attempt to discover a Git repository from the current directory
-        if let Ok(cwd) = std::env::current_dir() {
-            if let Ok(client) = git::GitClient::discover(&cwd) {
-                let workdir = client.workdir.clone();
-                let branch = client.head_branch().unwrap_or_else(|| "HEAD".into());
-                let repo_name = workdir
-                    .file_name()
-                    .map(|s| s.to_string_lossy().to_string())
-                    .unwrap_or_else(|| "repository".into());
+    /// Load the project, persisted data and committers from a discovered repository
+    fn attach_repo(&mut self, mut client: git::GitClient) {
+        let workdir = client.workdir.clone();
+        let state_dir = client.state_dir();
+        let worktree_state_dir = client.worktree_state_dir();
+        if let Some(settings) = AppSettings::load(&state_dir) {
+            client.set_options(settings.git_options);
+            self.task_manager.set_client_options(settings.git_options);
+            self.settings = settings;
+        }
+        let head = client.head_branch();
+        let repo_name = workdir
+            .file_name()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "repository".into());
 
-                let changes = client.list_changes().unwrap_or_default();
-                let project = data::Project {
-                    id: uuid::Uuid::nil(),
-                    name: repo_name,
-                    description: format!("Git repo at {}", workdir.display()),
-                    branch,
-                    changes,
-                    modules: Vec::new(),
-                    developers: Vec::new(),
-                };
-                app.store.projects = vec![project];
-                app.status_message = format!("Git: loaded status from {}", workdir.display());
-                app.git_client = Some(client);
-                app.git_workdir = Some(workdir);
-                // Load persisted data if available
-                if let Some(wd) = app.git_workdir.as_ref() {
-                    let _ = app.store.load_progress(wd);
-                    let _ = app.store.load_from_json(wd);
-                }
-                // Auto-populate developers from Git history
-                if let Some(client) = &app.git_client {
-                    if let Ok(committers) = client.get_committers() {
-                        app.store.auto_populate_developers_from_git(0, committers);
-                        // Save to persist auto-populated developers
-                        if let Some(wd) = app.git_workdir.as_ref() {
-                            let _ = app.store.save_to_json(wd);
-                        }
-                    }
-                }
+        let changes = client.list_changes().unwrap_or_default();
+        let project = data::Project {
+            id: uuid::Uuid::nil(),
+            name: repo_name,
+            description: format!("Git repo at {}", workdir.display()),
+            branch: head.label(),
+            changes,
+            modules: Vec::new(),
+            developers: Vec::new(),
+            status_history: Vec::new(),
+        };
+        self.store.projects = vec![project];
+        self.changes.clear_row_selection();
+        self.status_message = if client.is_bare() {
+            warning("Bare repository \u{2014} no working tree; history and branches only")
+        } else {
+            format!("Git: loaded status from {}", workdir.display())
+        };
+        self.last_fetch = client.last_fetch_time(&self.settings.default_remote);
+        self.head = head;
+        self.repo_dirty = client.is_clean().is_ok_and(|clean| !clean);
+        self.uncommitted = client.uncommitted_stats().ok();
+        self.conflict_count = client.list_conflicts().map_or(0, |c| c.len());
+        self.dashboard.activity = client
+            .commit_counts_by_day(ACTIVITY_DAYS)
+            .unwrap_or_default();
+        self.dashboard.tracked_files = client.tracked_file_count().ok();
+        // Pick a restarted merge up where it was left; otherwise forget it
+        if client.is_merging() {
+            let conflicts = client.list_conflicts().unwrap_or_default();
+            let paths: Vec<&str> = self.store.projects[0]
+                .changes
+                .iter()
+                .map(|c| c.path.as_str())
+                .collect();
+            let restored = self
+                .merge
+                .load_resolutions(&worktree_state_dir, 0, &paths, &conflicts);
+            if restored > 0 {
+                self.status_message = info(&format!(
+                    "Restored {} merge resolution(s) from the last session",
+                    restored
+                ));
+            }
+        } else {
+            let _ = MergeState::discard_saved_resolutions(&worktree_state_dir);
+        }
+        self.git_client = Some(client);
+        self.git_workdir = Some(workdir);
+        // Load persisted data if available
+        if let Some(wd) = self.git_workdir.as_ref() {
+            let loaded = self
+                .store
+                .load_progress(&state_dir)
+                .and_then(|_| self.store.load_from_json(wd));
+            if let Err(e) = loaded {
+                self.status_message = warning(&format!("Could not load saved progress: {}", e));
+            }
+        }
+        // Auto-populate developers from Git history
+        if let Some(client) = &self.git_client {
+            if let Ok(committers) = client.get_committers() {
+                self.store.auto_populate_developers_from_git(0, committers);
+                // Save to persist auto-populated developers
+                self.save_store_json();
             }
         }
-
-        app
     }
 
     pub fn run(mut self, mut terminal: DefaultTerminal) -> color_eyre::Result<()> {
         self.running = true;
         while self.running {
             terminal.draw(|frame| self.render(frame))?;
-            let action = self.key_handler.handle_crossterm_events()?;
+            let action = self
+                .key_handler
+                .handle_crossterm_events(TICK_RATE, self.text_input_active())?;
             if self.handle_action(action) {
                 self.quit();
             }
+            if let Some(command) = self.editor_command.take() {
+                self.run_editor(&mut terminal, command)?;
+            }
 
             // Poll for completed background operations
             self.poll_background_tasks();
+            self.autosave_if_due();
         }
+        self.save_last_view();
         Ok(())
     }
 
+    /// Where settings and progress are kept; see [`git::GitClient::state_dir`]
+    fn state_dir(&self) -> Option<PathBuf> {
+        self.git_client.as_ref().map(git::GitClient::state_dir)
+    }
+
+    /// Where this worktree's merge resolutions and last view are kept
+    fn worktree_state_dir(&self) -> Option<PathBuf> {
+        self.git_client
+            .as_ref()
+            .map(git::GitClient::worktree_state_dir)
+    }
+
+    /// Remember the open view and its selection for the next launch
+    fn save_last_view(&self) {
+        let Some(dir) = self.worktree_state_dir() else {
+            return;
+        };
+        let item = match self.current_view {
+            AppMode::ProjectBoard => self.board.selected_item,
+            AppMode::Changes => self.changes.selected_index,
+            AppMode::ModuleManager => self.module_manager.selected_module,
+            _ => 0,
+        };
+        let last_view = LastView {
+            view: self.current_view,
+            project: self.dashboard.selected_index,
+            column: self.board.selected_column,
+            item,
+        };
+        // The terminal is already gone, so there is nowhere to report a failure
+        let _ = last_view.save(&dir);
+    }
+
+    /// Reopen the view saved by the previous session; indices that no longer
+    /// fit the loaded data fall back to 0
+    fn restore_last_view(&mut self) {
+        let Some(last) = self
+            .worktree_state_dir()
+            .and_then(|dir| LastView::load(&dir))
+        else {
+            return;
+        };
+        let in_range = |index: usize, len: usize| if index < len { index } else { 0 };
+
+        self.dashboard.selected_index = in_range(last.project, self.store.projects.len());
+        let (change_count, module_count) = self
+            .store
+            .projects
+            .get(self.dashboard.selected_index)
+            .map(|p| (p.changes.len(), p.modules.len()))
+            .unwrap_or((0, 0));
+        match last.view {
+            AppMode::ProjectBoard => {
+                self.board.selected_column = in_range(last.column, self.board_columns().len());
+                self.board.selected_item =
+                    in_range(last.item, self.board_column_len(self.board.selected_column));
+            }
+            AppMode::Changes => self.changes.selected_index = in_range(last.item, change_count),
+            AppMode::ModuleManager => {
+                self.module_manager.selected_module = in_range(last.item, module_count)
+            }
+            _ => {}
+        }
+        self.current_view = last.view;
+        self.menu_selected_index = last.view.menu_index();
+        self.refresh_view_cache();
+    }
+
+    /// Save settings for the next launch, reporting failures in the status bar
+    fn save_settings(&mut self) {
+        let Some(dir) = self.state_dir() else {
+            return;
+        };
+        if let Err(e) = self.settings.save(&dir) {
+            self.status_message = warning(&format!("Could not save settings: {}", e));
+        }
+    }
+
+    /// Save modules and developers, reporting failures in the status bar
+    fn save_store_json(&mut self) {
+        let Some(wd) = self.git_workdir.as_ref() else {
+            return;
+        };
+        if let Err(e) = self.store.save_to_json(wd) {
+            self.status_message = warning(&format!("Could not save project data: {}", e));
+        }
+    }
+
+    /// Save module progress, reporting failures in the status bar
+    fn save_store_progress(&mut self) {
+        let Some(dir) = self.state_dir() else {
+            return;
+        };
+        if let Err(e) = self.store.save_progress(&dir) {
+            self.status_message = warning(&format!("Could not save progress: {}", e));
+        }
+    }
+
+    /// Persist changed progress once the configured autosave interval has passed
+    fn autosave_if_due(&mut self) {
+        let interval = self.settings.autosave_secs;
+        if interval == 0 || self.last_autosave.elapsed() < Duration::from_secs(interval) {
+            return;
+        }
+        self.last_autosave = Instant::now();
+
+        if let (Some(wd), Some(dir)) = (self.git_workdir.as_ref(), self.state_dir()) {
+            if let Err(e) = self.store.save_if_dirty(wd, &dir, self.settings.autosync) {
+                self.status_message = warning(&format!("Autosave failed: {}", e));
+            }
+        }
+    }
+
     /// Poll for completed background Git operations
     fn poll_background_tasks(&mut self) {
+        if let Some(update) = self.task_manager.try_recv_progress() {
+            if self.pending_git_ops.contains(&update.op) {
+                let label = Self::describe_git_operation(&update.op);
+                self.progress_message = Some(progress(&format!(
+                    "{}: {}",
+                    label,
+                    update.progress.status_message()
+                )));
+            }
+        }
         if let Some(result) = self.task_manager.try_recv() {
             self.remove_pending_git_op(&result.op);
-            match result.result {
+            let action = Self::git_operation_name(&result.op);
+            let outcome = match (&result.op, result.result) {
+                // Open the new clone in place of whatever was loaded
+                (GitOperation::Clone(_, dest), Ok(status)) => git::GitClient::discover(dest)
+                    .map_err(|e| git::GitClient::explain_error(&e))
+                    .and_then(|client| self.switch_repo(client))
+                    .map(|()| status),
+                (_, result) => result,
+            };
+            match outcome {
                 Ok(status) => {
+                    self.action_log.record(action, true, status.as_str());
                     let msg = success(&status);
                     self.last_completion_message = Some(msg.clone());
                     self.progress_message = None;
                     self.status_message = msg;
+                    if let Some(client) = &self.git_client {
+                        self.last_fetch = client.last_fetch_time(&self.settings.default_remote);
+                    }
                     // Refresh view cache to show updated data
                     self.refresh_view_cache();
                 }
                 Err(e) => {
+                    self.action_log.record(action, false, e.to_string());
                     let msg = error(&e.to_string());
                     self.last_completion_message = Some(msg.clone());
                     self.progress_message = None;
@@ -268,19 +627,34 @@ impl App {
         }
 
         if let Some(workdir) = self.git_workdir.clone() {
-            let label = Self::describe_git_operation(&op);
-            self.progress_message = Some(progress(&label));
-            self.last_completion_message = None;
-            self.pending_git_ops.push(op.clone());
-            self.task_manager.spawn_operation(workdir, op);
+            self.spawn_git_operation(workdir, op);
+        }
+    }
+
+    fn spawn_git_operation(&mut self, workdir: PathBuf, op: GitOperation) {
+        let label = Self::describe_git_operation(&op);
+        self.progress_message = Some(progress(&label));
+        self.last_completion_message = None;
+        self.pending_git_ops.push(op.clone());
+        self.task_manager.spawn_operation(workdir, op);
+    }
+
+    fn git_operation_name(op: &GitOperation) -> &'static str {
+        match op {
+            GitOperation::Fetch(_) | GitOperation::FetchAll => "Fetch",
+            GitOperation::Push(_) => "Push",
+            GitOperation::Pull(_) => "Pull",
+            GitOperation::Clone(..) => "Clone",
         }
     }
 
     fn describe_git_operation(op: &GitOperation) -> String {
         match op {
             GitOperation::Fetch(remote) => format!("Fetching from {}", remote),
+            GitOperation::FetchAll => "Fetching from all remotes".to_string(),
             GitOperation::Push(remote) => format!("Pushing to {}", remote),
             GitOperation::Pull(remote) => format!("Pulling from {}", remote),
+            GitOperation::Clone(url, _) => format!("Cloning {}", url),
         }
     }
 
@@ -299,6 +673,15 @@ impl App {
     }
 
     fn render(&mut self, frame: &mut Frame) {
+        self.refresh_changes_blame();
+        if let Some(project) = self.store.projects.get(self.dashboard.selected_index) {
+            self.changes.sync_diff_scroll(&project.changes);
+        }
+        if let Some(view) = &mut self.quick_view {
+            // Popup rows minus its border, so search matches can be centred
+            let popup_rows = frame.area().height * pages::quick_view::QUICK_VIEW_PERCENT.1 / 100;
+            view.viewport = popup_rows.saturating_sub(2);
+        }
         let settings_options = self.settings_options();
         let accepted_merge = self.merge.get_resolution(
             self.dashboard.selected_index,
@@ -326,6 +709,7 @@ impl App {
                 .collect()
         };
 
+        let no_repo_banner = self.no_repo_banner_shown();
         let screen = &mut self.screen;
 
         let render_ctx = crate::screen::RenderContext {
@@ -338,24 +722,50 @@ impl App {
             changes_pane_ratio: self.changes.changes_pane_ratio,
             commit_pane_ratio: self.changes.commit_pane_ratio,
             dashboard_pane_ratio: self.dashboard.pane_ratio,
+            dashboard_activity: &self.dashboard.activity,
+            tracked_files: self.dashboard.tracked_files,
             menu_selected_index: self.menu_selected_index,
             focus: self.focus,
             selected_board_column: self.board.selected_column,
             selected_board_item: self.board.selected_item,
+            board_group_by: self.board.group_by,
             merge_file_index: self.merge.selected_file_index,
             merge_focus: self.merge.focus,
             selected_setting: self.selected_setting_index,
             show_help: self.show_help,
+            action_log: self.show_action_log.then_some(&self.action_log),
+            quick_view: self.quick_view.as_ref(),
+            prompt: self.prompt.as_ref(),
+            branch_picker: self.branch_picker.as_ref(),
+            reflog: self.reflog.as_ref(),
+            worktrees: self.worktrees.as_ref(),
+            confirm_message: self
+                .confirm_quit
+                .then_some("Discard commit message and quit?"),
             project_scroll: self.dashboard.scroll,
             changes_scroll: self.changes.scroll,
+            total_commits: self.commit_history.total_commits,
+            relative_dates: self.settings.relative_dates,
+            compact_commits: self.settings.compact_commits,
+            commit_requires_modifier: self.settings.commit_requires_modifier,
+            changes_diff_scroll: self.changes.diff_scroll,
+            changes_multi_select: self.changes.multi_select.as_ref(),
+            changes_blame: self
+                .changes
+                .blame_cache
+                .as_ref()
+                .filter(|_| self.changes.show_blame)
+                .map(|(_, blame)| blame.as_slice()),
             merge_scroll: self.merge.scroll,
             search_active: self.search_active,
             search_buffer: &search_buffer,
+            project_sort: self.dashboard.sort_mode,
             filtered_projects: &filtered_projects,
             settings_options: &settings_options,
             total_projects: self.store.projects.len(),
             settings: &self.settings,
             accepted_merge,
+            merge_resolutions: &self.merge.resolutions,
             workdir,
             module_manager_mode: self.module_manager.mode,
             selected_module: self.module_manager.selected_module,
@@ -363,32 +773,57 @@ impl App {
             module_input_buffer: &module_input_buffer,
             module_scroll: self.module_manager.module_scroll,
             module_pane_ratio: self.module_manager.pane_ratio,
+            module_dependency_target: self.module_manager.dependency_target,
+            module_rename_target: self.module_manager.rename_target,
+            module_assign_query: self
+                .module_manager
+                .assign_mode
+                .then_some(self.module_manager.assign_query.as_str()),
             branch_manager_mode: self.branch_manager.mode,
             selected_branch: self.branch_manager.selected_index,
             branch_input_buffer: &branch_input_buffer,
             branch_scroll: self.branch_manager.scroll,
+            branch_filter: self.branch_manager.filter,
             cached_branches: &self.branch_manager.cached_branches,
             selected_commit: self.commit_history.selected_index,
             commit_scroll: self.commit_history.scroll,
             cached_commits: &self.commit_history.cached_commits,
+            commit_author_filter: self.commit_history.author_filter.as_deref(),
             pending_git_ops_count,
+            detached_at: match &self.head {
+                git::HeadRef::Detached(short) => Some(short.as_str()),
+                _ => None,
+            },
+            repo_dirty: self.git_client.is_some().then_some(self.repo_dirty),
+            uncommitted: self.uncommitted,
+            conflict_count: self.conflict_count,
+            no_repo_banner,
+            last_fetch_age: self
+                .last_fetch
+                .map(|t| SystemTime::now().duration_since(t).unwrap_or_default()),
         };
 
         screen.render(frame, &render_ctx);
     }
 
-    fn board_column_len(&self, column: usize) -> usize {
-        let status = match column {
-            0 => ModuleStatus::Pending,
-            1 => ModuleStatus::Current,
-            _ => ModuleStatus::Completed,
-        };
-
+    /// Board columns for the selected project under the current grouping
+    fn board_columns(&self) -> Vec<BoardColumn> {
         self.store
             .projects
             .get(self.dashboard.selected_index)
-            .map(|p| p.modules.iter().filter(|m| m.status == status).count())
-            .unwrap_or(0)
+            .map(|p| board_columns(p, self.board.group_by))
+            .unwrap_or_default()
+    }
+
+    fn board_column_lens(&self) -> Vec<usize> {
+        self.board_columns()
+            .iter()
+            .map(|c| c.modules.len())
+            .collect()
+    }
+
+    fn board_column_len(&self, column: usize) -> usize {
+        self.board_column_lens().get(column).copied().unwrap_or(0)
     }
 
     fn update_status_message(&mut self) {
@@ -401,6 +836,13 @@ impl App {
                     .map(|p| &p.name)
                     .unwrap_or(&"N/A".to_string())
             ),
+            AppMode::Changes if self.changes.multi_select.is_some() => format!(
+                "Multi-select: {} ticked (Space Tick, ↵ Stage, Esc Done)",
+                self.changes
+                    .multi_select
+                    .as_ref()
+                    .map_or(0, |rows| rows.len())
+            ),
             AppMode::Changes => format!(
                 "Changes: {} (↑↓ Select file, ↵ Commit)",
                 self.store
@@ -414,13 +856,26 @@ impl App {
                 let count = self.commit_history.cached_commits.len();
                 format!("Commit History: {} commits (↑↓ Navigate)", count)
             }
+            AppMode::BranchManager if self.branch_manager.filter == BranchFilter::Tags => {
+                let count = self.branch_manager.cached_branches.len();
+                format!(
+                    "Tags: {} (↑↓ Select, ↵ Checkout, d Delete, R Filter)",
+                    count
+                )
+            }
             AppMode::BranchManager => {
                 let count = self.branch_manager.cached_branches.len();
-                format!("Branches: {} (↑↓ Select, ↵ Switch, n New, d Delete)", count)
+                format!(
+                    "Branches ({}): {} (↑↓ Select, ↵ Switch, n New, d Delete, R Filter)",
+                    self.branch_manager.filter.label(),
+                    count
+                )
             }
             AppMode::ProjectBoard => format!(
-                "Board: {} (←→ Column, ↑↓ Item)",
-                self.board.current_column_name()
+                "Board: {} (←→ Column, ↑↓ Item, g Group)",
+                self.board_columns()
+                    .get(self.board.selected_column)
+                    .map_or("N/A", |c| c.title.as_str())
             ),
             AppMode::MergeVisualizer => format!(
                 "Merge: {} (←→ Pane, ↑↓ File)",
@@ -428,6 +883,7 @@ impl App {
                     MergePaneFocus::Files => "Files",
                     MergePaneFocus::Local => "Local",
                     MergePaneFocus::Incoming => "Incoming",
+                    MergePaneFocus::Both => "Both",
                 }
             ),
             AppMode::ModuleManager => {
@@ -439,7 +895,11 @@ impl App {
                     ModuleManagerMode::CreateDeveloper => "Creating Developer",
                     ModuleManagerMode::EditModule => "Editing Module",
                 };
-                format!("{} (n New, d Delete, Tab Switch)", mode_str)
+                if self.module_manager.rename_target.is_some() {
+                    format!("Renaming in {} (↵ Save, Esc Cancel)", mode_str)
+                } else {
+                    format!("{} (n New, F2 Rename, d Delete, ←→ Pane)", mode_str)
+                }
             }
             AppMode::Settings => {
                 let opts = self.settings_options();
@@ -452,15 +912,41 @@ impl App {
         };
     }
 
+    /// Something is taking typed text, so every printable key is a character
+    fn text_input_active(&self) -> bool {
+        self.prompt.is_some()
+            || self.branch_picker.is_some()
+            || self
+                .quick_view
+                .as_ref()
+                .is_some_and(|view| view.search_input.is_some())
+    }
+
     fn handle_action(&mut self, action: KeyAction) -> bool {
         use pages::branch_manager::BranchManagerMode;
         use pages::module_manager::ModuleManagerMode;
 
+        // A count prefix ("5" then Down) repeats the navigation; this call makes the last move
+        if matches!(
+            action,
+            KeyAction::NavigateUp
+                | KeyAction::NavigateDown
+                | KeyAction::NavigateLeft
+                | KeyAction::NavigateRight
+        ) {
+            if let Some(count) = self.pending_count.take() {
+                for _ in 1..count {
+                    self.handle_action(action.clone());
+                }
+            }
+        }
+
         // Build context for stateless processor
         let ctx = ActionContext {
             focus: self.focus,
             current_view: self.current_view,
             show_help: self.show_help,
+            show_action_log: self.show_action_log,
             search_active: self.search_active,
             menu_selected_index: self.menu_selected_index,
             selected_project_index: self.dashboard.selected_index,
@@ -492,12 +978,37 @@ impl App {
                 ModuleManagerMode::CreateDeveloper
             ),
             module_assign_mode: self.module_manager.assign_mode,
+            module_dependency_mode: self.module_manager.dependency_target.is_some(),
+            module_rename_mode: self.module_manager.rename_target.is_some(),
             module_input_empty: self.module_manager.is_input_empty(),
+            confirm_quit_pending: self.confirm_quit,
+            quick_view_open: self.quick_view.is_some(),
+            quick_view_searching: self
+                .quick_view
+                .as_ref()
+                .is_some_and(|view| view.search_input.is_some()),
+            text_prompt: self.prompt.as_ref().map(|p| p.kind),
+            text_prompt_input_empty: self.prompt.as_ref().is_none_or(TextPrompt::is_blank),
+            branch_picker_open: self.branch_picker.is_some(),
+            reflog_open: self.reflog.is_some(),
+            worktrees_open: self.worktrees.is_some(),
+            commit_filter_active: self.commit_history.author_filter.is_some(),
+            commit_requires_modifier: self.settings.commit_requires_modifier,
+            count_pending: self.pending_count.is_some(),
+            previous_view: self.previous_view,
+            changes_multi_select: self.changes.multi_select.is_some(),
         };
 
+        let idle = matches!(action, KeyAction::Tick | KeyAction::Resize(..));
+
         // Process action (stateless)
         let (result, update) = ActionProcessor::process(action, &ctx);
 
+        // Anything but another digit drops a half-typed count
+        if update.count_digit.is_none() && !idle {
+            self.pending_count = None;
+        }
+
         // Apply state updates
         self.apply_action_updates(update);
 
@@ -517,17 +1028,178 @@ impl App {
         if let Some(focus) = update.focus {
             self.focus = focus;
         }
+        if let Some(digit) = update.count_digit {
+            let count = self.pending_count.unwrap_or(0) * 10 + digit as usize;
+            self.pending_count = Some(count.min(MAX_NAVIGATION_COUNT));
+        }
+        if let Some((_, height)) = update.terminal_resized {
+            self.fit_scroll_to_height(height);
+        }
         if let Some(view) = update.current_view {
-            let old_view = self.current_view;
-            self.current_view = view;
-            // Refresh caches when entering new views
-            if old_view != view {
-                self.refresh_view_cache();
-            }
+            self.on_enter_view(view);
         }
         if let Some(help) = update.show_help {
             self.show_help = help;
         }
+        if let Some(show) = update.show_action_log {
+            self.show_action_log = show;
+        }
+        if let Some(confirm) = update.confirm_quit {
+            self.confirm_quit = confirm;
+        }
+        if let Some(kind) = update.text_prompt_open {
+            let mut prompt = TextPrompt::new(kind);
+            match kind {
+                // Start from the current notes so they can be amended
+                PromptKind::ModuleNotes => {
+                    if let Some(module) = self.selected_module() {
+                        prompt.input = module.notes.clone();
+                    }
+                }
+                PromptKind::ModuleProgress => {
+                    if let Some(module) = self.selected_module() {
+                        prompt.input = module.progress_score.to_string();
+                    }
+                }
+                // Both start from the selected commit's message
+                PromptKind::SquashCommits | PromptKind::RewordCommit => {
+                    if let Some(commit) = self.commit_history.selected_commit() {
+                        prompt.input = commit.message.clone();
+                    }
+                }
+                _ => {}
+            }
+            self.prompt = Some(prompt);
+        }
+        if update.text_prompt_close.is_some() {
+            self.prompt = None;
+        }
+        if let Some(c) = update.text_prompt_append {
+            if let Some(prompt) = &mut self.prompt {
+                prompt.input.push(c);
+            }
+        }
+        if update.text_prompt_pop.is_some() {
+            if let Some(prompt) = &mut self.prompt {
+                prompt.input.pop();
+            }
+        }
+        if update.text_prompt_submit.is_some() {
+            if let Some(prompt) = self.prompt.take() {
+                let input = prompt.input.trim();
+                match prompt.kind {
+                    PromptKind::QuickCommit => {
+                        let message = input.to_string();
+                        // Keep the prompt open with its message so a failed commit can be retried
+                        if !self.perform_quick_commit(&message) {
+                            self.prompt = Some(prompt);
+                        }
+                    }
+                    PromptKind::ApplyPatch => self.perform_apply_patch(input),
+                    PromptKind::ImportModules => self.perform_import_modules(input),
+                    PromptKind::ModuleNotes => self.perform_set_module_notes(input),
+                    PromptKind::SquashCommits => self.perform_squash(input),
+                    PromptKind::RewordCommit => self.perform_reword(input),
+                    PromptKind::ModuleProgress => self.perform_set_module_progress(input),
+                    PromptKind::GoToRef => self.perform_go_to_ref(input),
+                    PromptKind::CloneRepo => self.perform_clone_repo(input),
+                }
+            }
+        }
+        if update.branch_picker_requested.is_some() {
+            self.perform_open_branch_picker();
+        }
+        if update.branch_picker_close.is_some() {
+            self.branch_picker = None;
+        }
+        if let Some(c) = update.branch_picker_append {
+            if let Some(picker) = &mut self.branch_picker {
+                picker.push_char(c);
+            }
+        }
+        if update.branch_picker_pop.is_some() {
+            if let Some(picker) = &mut self.branch_picker {
+                picker.pop_char();
+            }
+        }
+        if let Some(delta) = update.branch_picker_move {
+            if let Some(picker) = &mut self.branch_picker {
+                picker.move_selection(delta);
+            }
+        }
+        if update.branch_picker_checkout.is_some() {
+            self.perform_branch_picker_checkout();
+        }
+        if update.reflog_requested.is_some() {
+            self.perform_open_reflog();
+        }
+        if update.reflog_close.is_some() {
+            self.reflog = None;
+        }
+        if let Some(delta) = update.reflog_move {
+            if let Some(reflog) = &mut self.reflog {
+                reflog.move_selection(delta);
+            }
+        }
+        if update.reflog_checkout.is_some() {
+            self.perform_reflog_checkout();
+        }
+        if update.reflog_reset.is_some() {
+            self.perform_reflog_reset();
+        }
+        if update.worktrees_requested.is_some() {
+            self.perform_open_worktrees();
+        }
+        if update.worktrees_close.is_some() {
+            self.worktrees = None;
+        }
+        if let Some(delta) = update.worktrees_move {
+            if let Some(list) = &mut self.worktrees {
+                list.move_selection(delta);
+            }
+        }
+        if update.worktree_switch.is_some() {
+            self.perform_worktree_switch();
+        }
+        if update.quick_view_requested.is_some() {
+            self.perform_open_quick_view();
+        }
+        if update.quick_view_close.is_some() {
+            self.quick_view = None;
+        }
+        if let Some(delta) = update.quick_view_scroll {
+            if let Some(view) = &mut self.quick_view {
+                view.scroll_by(delta);
+            }
+        }
+        if let Some(view) = &mut self.quick_view {
+            if update.quick_view_search_start.is_some() {
+                view.start_search();
+            }
+            if let Some(input) = &mut view.search_input {
+                if let Some(c) = update.quick_view_search_append {
+                    input.push(c);
+                }
+                if update.quick_view_search_pop.is_some() {
+                    input.pop();
+                }
+            }
+            if update.quick_view_search_cancel.is_some() {
+                view.search_input = None;
+            }
+            if update.quick_view_search_submit.is_some() {
+                self.status_message = match view.submit_search() {
+                    0 => warning(&format!("No matches for \"{}\"", view.query)),
+                    n => info(&format!("{} match(es) for \"{}\"", n, view.query)),
+                };
+            }
+            if let Some(delta) = update.quick_view_match {
+                view.jump_match(delta);
+            }
+        }
+        let search_edited = update.search_buffer.is_some()
+            || update.search_buffer_append.is_some()
+            || update.search_buffer_pop.is_some();
         if let Some(search) = update.search_active {
             self.search_active = search;
         }
@@ -540,6 +1212,45 @@ impl App {
         if update.search_buffer_pop.is_some() {
             self.search_buffer.pop();
         }
+        if search_edited && self.current_view == AppMode::CommitHistory {
+            // Commit History reuses the search buffer as its author filter
+            self.commit_history
+                .set_author_filter(Some(self.search_buffer.clone()));
+        }
+        if update.commit_filter_mine_toggle.is_some() {
+            self.perform_toggle_my_commits();
+        }
+        if update.commit_dates_toggle.is_some() {
+            self.toggle_commit_dates();
+            self.save_settings();
+        }
+        if update.commit_density_toggle.is_some() {
+            self.settings.compact_commits = !self.settings.compact_commits;
+            self.status_message = format!(
+                "⚙ Commit list: {}",
+                if self.settings.compact_commits {
+                    "one line per commit"
+                } else {
+                    "two lines per commit"
+                }
+            );
+            self.save_settings();
+        }
+        if update.commit_filter_clear.is_some() {
+            self.commit_history.set_author_filter(None);
+        }
+        if update.cycle_project_sort.is_some() {
+            self.perform_cycle_project_sort();
+        }
+        if update.commit_checkout_requested.is_some() {
+            self.perform_commit_checkout();
+        }
+        if update.commit_export_requested.is_some() {
+            self.perform_export_commits();
+        }
+        if update.changelog_requested.is_some() {
+            self.perform_write_changelog();
+        }
         if let Some(idx) = update.menu_selected_index {
             self.menu_selected_index = idx;
         }
@@ -575,6 +1286,10 @@ impl App {
                     .selected_index
                     .saturating_sub(WINDOW_SIZE - 1);
             }
+            // Page in older commits before the selection reaches the end
+            if self.commit_history.needs_more(WINDOW_SIZE) {
+                self.load_more_commits();
+            }
         }
         if let Some(idx) = update.selected_branch_index {
             self.branch_manager.selected_index =
@@ -611,12 +1326,13 @@ impl App {
             }
         }
         if let Some(idx) = update.selected_developer_index {
-            let dev_count = self
-                .store
-                .projects
-                .get(self.dashboard.selected_index)
-                .map(|p| p.developers.len())
-                .unwrap_or(0);
+            let developers = self.store.developers(self.dashboard.selected_index);
+            // While assigning the selection moves through the filtered matches
+            let dev_count = if self.module_manager.assign_mode {
+                self.module_manager.assign_matches(developers).len()
+            } else {
+                developers.len()
+            };
             self.module_manager.selected_developer = idx.min(dev_count.saturating_sub(1));
             // Auto-scroll to keep selection visible
             if self.module_manager.selected_developer < self.module_manager.developer_scroll {
@@ -730,19 +1446,12 @@ impl App {
             self.board.navigate_down(len);
         }
         if update.navigate_board_left.is_some() {
-            // Calculate new column first
-            let new_col = if self.board.selected_column == 0 {
-                2
-            } else {
-                self.board.selected_column - 1
-            };
-            let new_len = self.board_column_len(new_col);
-            self.board.navigate_left(new_len);
+            let lens = self.board_column_lens();
+            self.board.navigate_left(&lens);
         }
         if update.navigate_board_right.is_some() {
-            let new_col = (self.board.selected_column + 1) % 3;
-            let new_len = self.board_column_len(new_col);
-            self.board.navigate_right(new_len);
+            let lens = self.board_column_lens();
+            self.board.navigate_right(&lens);
         }
         if update.navigate_merge_down.is_some() {
             let max = self
@@ -770,11 +1479,22 @@ impl App {
         if update.move_board_item.is_some() {
             self.move_board_item_to_next_status();
         }
+        if update.open_in_editor_requested.is_some() {
+            self.perform_open_in_editor();
+        }
+        if update.board_group_toggle.is_some() {
+            self.board.toggle_group_by();
+            self.status_message =
+                info(&format!("Board grouped by {}", self.board.group_by.label()));
+        }
         if update.accept_merge_pane.is_some() {
             self.accept_merge_pane();
         }
-        if update.toggle_setting.is_some() {
-            self.toggle_setting();
+        if update.accept_both_requested.is_some() {
+            self.perform_accept_both();
+        }
+        if let Some(index) = update.toggle_setting {
+            self.toggle_setting(index);
         }
         if update.commit_requested.is_some() {
             self.perform_commit();
@@ -807,6 +1527,10 @@ impl App {
         if update.branch_delete_requested.is_some() {
             self.perform_branch_delete();
         }
+        if update.branch_filter_cycle.is_some() {
+            self.branch_manager.cycle_filter();
+            self.refresh_view_cache();
+        }
 
         // Module operations
         if update.toggle_module_list.is_some() {
@@ -864,64 +1588,259 @@ impl App {
             self.perform_developer_delete();
         }
         if let Some(mode) = update.module_assign_mode {
-            self.module_manager.assign_mode = mode;
+            if mode {
+                self.module_manager.enter_assign_mode();
+            } else {
+                let developers = self.store.developers(self.dashboard.selected_index);
+                self.module_manager.leave_assign_mode(developers);
+            }
+        }
+        if let Some(c) = update.module_assign_filter_append {
+            let developers = self.store.developers(self.dashboard.selected_index);
+            self.module_manager.push_assign_char(c, developers);
+        }
+        if update.module_assign_filter_pop.is_some() {
+            let developers = self.store.developers(self.dashboard.selected_index);
+            self.module_manager.pop_assign_char(developers);
         }
         if update.module_assign_requested.is_some() {
             self.perform_module_assignment();
         }
+        if update.module_assign_all_requested.is_some() {
+            self.perform_assign_all_unassigned();
+        }
+        if let Some(mode) = update.module_dependency_mode {
+            let selected = self
+                .store
+                .projects
+                .get(self.dashboard.selected_index)
+                .and_then(|p| p.modules.get(self.module_manager.selected_module))
+                .map(|m| m.id);
+            match selected {
+                Some(id) if mode => self.module_manager.enter_dependency_mode(id),
+                _ => self.module_manager.dependency_target = None,
+            }
+        }
+        if update.module_dependency_toggle_requested.is_some() {
+            self.perform_toggle_module_dependency();
+        }
+        if let Some(mode) = update.module_rename_mode {
+            if mode {
+                self.start_inline_rename();
+            } else {
+                self.module_manager.leave_rename();
+            }
+        }
+        if update.module_rename_requested.is_some() {
+            self.perform_inline_rename();
+        }
+        if let Some(delta) = update.module_progress_adjust {
+            self.perform_adjust_module_progress(delta);
+        }
         if update.toggle_staging_requested.is_some() {
             self.toggle_file_staging();
         }
+        if update.stage_and_advance_requested.is_some() {
+            self.perform_stage_and_advance();
+        }
+        if update.multi_select_toggle.is_some() {
+            self.changes.toggle_multi_select();
+        }
+        if update.row_selection_toggle.is_some() {
+            self.changes.toggle_row_selection();
+        }
+        if update.stage_selected_requested.is_some() {
+            self.perform_stage_selected();
+        }
         if update.fetch_requested.is_some() {
             self.perform_fetch();
         }
+        if update.fetch_all_requested.is_some() {
+            self.perform_fetch_all();
+        }
+        if update.status_dump_requested.is_some() {
+            self.perform_dump_status();
+        }
+        if update.banner_dismissed.is_some() {
+            self.no_repo_banner_dismissed = true;
+        }
         if update.push_requested.is_some() {
             self.perform_push();
         }
         if update.pull_requested.is_some() {
             self.perform_pull();
         }
+        if update.copy_path_requested.is_some() {
+            self.perform_copy_path();
+        }
+        if update.copy_diff_requested.is_some() {
+            self.perform_copy_diff(false);
+        }
+        if update.copy_changeset_requested.is_some() {
+            self.perform_copy_diff(true);
+        }
+        if update.blame_toggle.is_some() {
+            self.changes.show_blame = !self.changes.show_blame;
+            self.changes.blame_cache = None;
+            self.status_message = if self.changes.show_blame {
+                info("Blame annotations on")
+            } else {
+                info("Blame annotations off")
+            };
+        }
+        if update.diff_expand_requested.is_some() {
+            self.perform_expand_diff();
+        }
     }
 
     fn quit(&mut self) {
         self.running = false;
     }
 
-    fn clamp_selections_for_project(&mut self) {
-        // When switching projects, ensure selections are valid for the new project
-        if let Some(project) = self.store.projects.get(self.dashboard.selected_index) {
-            self.changes.clamp_selection(project.changes.len());
-            self.merge.clamp_selection(project.changes.len());
+    /// Whether the "not a git repository" banner sits above the main view
+    fn no_repo_banner_shown(&self) -> bool {
+        self.git_client.is_none() && !self.no_repo_banner_dismissed
+    }
+
+    /// Pull the active view's scroll offset back so its selection stays on
+    /// screen once the terminal is `height` rows tall
+    fn fit_scroll_to_height(&mut self, height: u16) {
+        use crate::ui_utils::auto_scroll;
+
+        let chrome = LIST_CHROME_ROWS + u16::from(self.no_repo_banner_shown());
+        let rows = height.saturating_sub(chrome) as usize;
+        let window = |rows: usize| rows.clamp(1, WINDOW_SIZE);
+        match self.current_view {
+            AppMode::Dashboard => auto_scroll(
+                self.dashboard.selected_index,
+                &mut self.dashboard.scroll,
+                window(rows),
+            ),
+            // The commit message box sits under the file list
+            AppMode::Changes => auto_scroll(
+                self.changes.selected_index,
+                &mut self.changes.scroll,
+                window(rows.saturating_sub(3)),
+            ),
+            // Each commit takes two lines unless the list is compact
+            AppMode::CommitHistory => auto_scroll(
+                self.commit_history.selected_index,
+                &mut self.commit_history.scroll,
+                window(if self.settings.compact_commits {
+                    rows
+                } else {
+                    rows / 2
+                }),
+            ),
+            AppMode::BranchManager => auto_scroll(
+                self.branch_manager.selected_index,
+                &mut self.branch_manager.scroll,
+                window(rows),
+            ),
+            AppMode::MergeVisualizer => auto_scroll(
+                self.merge.selected_file_index,
+                &mut self.merge.scroll,
+                window(rows),
+            ),
+            AppMode::ModuleManager => {
+                auto_scroll(
+                    self.module_manager.selected_module,
+                    &mut self.module_manager.module_scroll,
+                    window(rows),
+                );
+                auto_scroll(
+                    self.module_manager.selected_developer,
+                    &mut self.module_manager.developer_scroll,
+                    window(rows),
+                );
+            }
+            AppMode::ProjectBoard | AppMode::Settings => {}
+        }
+    }
+
+    /// Show `mode`, refreshing its caches if it wasn't already shown, and pull
+    /// its selections back onto rows that exist
+    fn on_enter_view(&mut self, mode: AppMode) {
+        let old_view = self.current_view;
+        self.current_view = mode;
+        if old_view != mode {
+            self.previous_view = Some(old_view);
+            self.refresh_view_cache();
+        }
+        let project = self.store.projects.get(self.dashboard.selected_index);
+        match mode {
+            AppMode::Dashboard => self.dashboard.clamp_selection(self.store.projects.len()),
+            AppMode::Changes | AppMode::MergeVisualizer => {
+                let count = project.map_or(0, |p| p.changes.len());
+                self.changes.clamp_selection(count);
+                self.merge.clamp_selection(count);
+            }
+            AppMode::CommitHistory => self.commit_history.clamp_selection(),
+            AppMode::BranchManager => self.branch_manager.clamp_selection(),
+            AppMode::ModuleManager => {
+                let modules = project.map_or(0, |p| p.modules.len());
+                let developers = project.map_or(0, |p| p.developers.len());
+                self.module_manager.clamp_selections(modules, developers);
+            }
+            AppMode::ProjectBoard => {
+                let board_len = self.board_column_len(self.board.selected_column);
+                self.board.clamp_selection(board_len);
+            }
+            _ => {}
+        }
+    }
+
+    fn clamp_selections_for_project(&mut self) {
+        // When switching projects, ensure selections are valid for the new project
+        if let Some(project) = self.store.projects.get(self.dashboard.selected_index) {
+            self.changes.clamp_selection(project.changes.len());
+            self.merge.clamp_selection(project.changes.len());
             let board_len = self.board_column_len(self.board.selected_column);
             self.board.clamp_selection(board_len);
         }
     }
 
     fn move_board_item_to_next_status(&mut self) {
-        if let Some(project) = self.store.projects.get_mut(self.dashboard.selected_index) {
-            let status = self.board.current_status();
-
-            let modules_in_col: Vec<usize> = project
-                .modules
-                .iter()
-                .enumerate()
-                .filter(|(_, m)| m.status == status)
-                .map(|(i, _)| i)
-                .collect();
-
-            if let Some(&module_idx) = modules_in_col.get(self.board.selected_item) {
-                let next_status = match status {
-                    ModuleStatus::Pending => ModuleStatus::Current,
-                    ModuleStatus::Current => ModuleStatus::Completed,
-                    ModuleStatus::Completed => ModuleStatus::Completed,
-                };
-                project.modules[module_idx].status = next_status;
-                self.status_message = success(&format!(
-                    "Moved {} to {:?}",
-                    project.modules[module_idx].name, next_status
+        let modules_in_col = self
+            .board_columns()
+            .into_iter()
+            .nth(self.board.selected_column)
+            .map(|c| c.modules)
+            .unwrap_or_default();
+        let project_idx = self.dashboard.selected_index;
+        let Some(project) = self.store.projects.get(project_idx) else {
+            return;
+        };
+        let Some(module) = modules_in_col
+            .get(self.board.selected_item)
+            .map(|&module_idx| &project.modules[module_idx])
+        else {
+            return;
+        };
+        let next_status = match module.status {
+            ModuleStatus::Pending => ModuleStatus::Current,
+            ModuleStatus::Current => ModuleStatus::Completed,
+            ModuleStatus::Completed => ModuleStatus::Completed,
+        };
+        if next_status == ModuleStatus::Current {
+            if let Some(blocker) = project.blocking_dependency(module.id) {
+                self.status_message = warning(&format!(
+                    "{} is blocked: depends on {} ({:?})",
+                    module.name, blocker.name, blocker.status
                 ));
+                return;
             }
         }
+        self.status_message = success(&format!("Moved {} to {:?}", module.name, next_status));
+        let module_id = module.id;
+        if self
+            .store
+            .set_module_status(project_idx, module_id, next_status)
+        {
+            // The timeline lives in the JSON files, which autosave skips
+            // unless autosync is on
+            self.save_store_json();
+        }
     }
 
     fn accept_merge_pane(&mut self) {
@@ -930,13 +1849,166 @@ impl App {
             .accept_current_pane(self.dashboard.selected_index)
         {
             self.status_message = success(msg);
+            self.save_merge_resolutions();
         } else {
             self.status_message = "Selected file for merge".to_string();
         }
     }
 
-    fn toggle_setting(&mut self) {
-        match self.selected_setting_index {
+    /// Resolve the selected conflict by writing the local side followed by
+    /// the incoming side, and stage the result
+    fn perform_accept_both(&mut self) {
+        let Some(path) = self.selected_file_path().map(str::to_string) else {
+            self.status_message = warning("No file selected");
+            return;
+        };
+        let Some(client) = &self.git_client else {
+            return;
+        };
+        let resolved = client.conflict_sides(&path).and_then(|(local, incoming)| {
+            let mut combined = local.unwrap_or_default();
+            if !combined.is_empty() && !combined.ends_with(b"\n") {
+                combined.push(b'\n');
+            }
+            combined.extend(incoming.unwrap_or_default());
+            client.resolve_conflict(&path, &combined)
+        });
+        match resolved {
+            Ok(()) => {
+                self.merge.accept_both(self.dashboard.selected_index);
+                if let Ok(changes) = client.list_changes() {
+                    if let Some(project) =
+                        self.store.projects.get_mut(self.dashboard.selected_index)
+                    {
+                        self.changes.replace_changes(&mut project.changes, changes);
+                    }
+                }
+                self.action_log.record("Resolve", true, path.clone());
+                self.status_message = success(&format!("Kept both sides of {}", path));
+                self.save_merge_resolutions();
+            }
+            Err(e) => {
+                self.action_log
+                    .record("Resolve", false, format!("{}: {}", path, e));
+                self.status_message = error(&format!("Failed to resolve {}: {}", path, e));
+            }
+        }
+    }
+
+    /// Path of the file selected in the Merge view, or else in Changes
+    fn selected_file_path(&self) -> Option<&str> {
+        let index = match self.current_view {
+            AppMode::MergeVisualizer => self.merge.selected_file_index,
+            _ => self.changes.selected_index,
+        };
+        self.store
+            .projects
+            .get(self.dashboard.selected_index)
+            .and_then(|p| p.changes.get(index))
+            .map(|c| c.path.as_str())
+    }
+
+    /// Paths `perform_copy_path` copies, one per line. Commit History has no
+    /// file cursor, so there it is every file the selected commit changed.
+    fn paths_to_copy(&self) -> Option<String> {
+        match self.current_view {
+            AppMode::CommitHistory => self
+                .commit_history
+                .selected_commit()
+                .map(|c| c.files_changed.join("\n"))
+                .filter(|paths| !paths.is_empty()),
+            _ => self.selected_file_path().map(str::to_string),
+        }
+    }
+
+    fn perform_copy_path(&mut self) {
+        let Some(paths) = self.paths_to_copy() else {
+            self.status_message = warning("No file selected");
+            return;
+        };
+        let count = paths.lines().count();
+        self.status_message = match clipboard::copy(&paths) {
+            Ok(()) if count == 1 => success(&format!("Copied path {} to clipboard", paths)),
+            Ok(()) => success(&format!("Copied {} paths to clipboard", count)),
+            Err(e) => error(&format!("Copy failed: {}", e)),
+        };
+    }
+
+    /// Queue the file selected in Changes or Merge for `$EDITOR`; the main
+    /// loop runs it with the terminal suspended
+    fn perform_open_in_editor(&mut self) {
+        let path = self.selected_file_path().map(str::to_string);
+        let (Some(path), Some(workdir)) = (path, self.git_workdir.as_ref()) else {
+            self.status_message = warning("No file selected");
+            return;
+        };
+        match Self::editor_command(&self.editor, workdir, &path) {
+            Some(command) => self.editor_command = Some(command),
+            None => self.status_message = error("Set $EDITOR to open files in an editor"),
+        }
+    }
+
+    /// `editor` (which may carry arguments, e.g. "code --wait") run on `path`
+    /// from `workdir`; `None` if no editor is configured
+    fn editor_command(
+        editor: &str,
+        workdir: &std::path::Path,
+        path: &str,
+    ) -> Option<std::process::Command> {
+        let mut words = editor.split_whitespace();
+        let mut command = std::process::Command::new(words.next()?);
+        command.args(words).arg(path).current_dir(workdir);
+        Some(command)
+    }
+
+    /// Hand the terminal to `command` until it exits, then take it back and
+    /// pick up whatever the editor changed
+    fn run_editor(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        mut command: std::process::Command,
+    ) -> color_eyre::Result<()> {
+        use crossterm::terminal::{
+            disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+        };
+
+        crossterm::execute!(std::io::stdout(), LeaveAlternateScreen)?;
+        disable_raw_mode()?;
+        let status = command.status();
+        enable_raw_mode()?;
+        crossterm::execute!(std::io::stdout(), EnterAlternateScreen)?;
+        terminal.clear()?;
+
+        let editor = command.get_program().to_string_lossy().into_owned();
+        self.status_message = match status {
+            Ok(status) if status.success() => info(&format!("Returned from {}", editor)),
+            Ok(status) => warning(&format!("{} exited with {}", editor, status)),
+            Err(e) => error(&format!("Could not start {}: {}", editor, e)),
+        };
+        self.refresh_view_cache();
+        Ok(())
+    }
+
+    /// Persist the selected project's merge resolutions for the next session
+    fn save_merge_resolutions(&mut self) {
+        let (Some(dir), Some(project)) = (
+            self.worktree_state_dir(),
+            self.store.projects.get(self.dashboard.selected_index),
+        ) else {
+            return;
+        };
+        let paths: Vec<&str> = project.changes.iter().map(|c| c.path.as_str()).collect();
+        if let Err(e) = self
+            .merge
+            .save_resolutions(&dir, self.dashboard.selected_index, &paths)
+        {
+            self.status_message = warning(&format!("Could not save merge resolutions: {}", e));
+        }
+    }
+
+    /// Toggle or cycle the setting shown at `index` in the Settings list
+    fn toggle_setting(&mut self, index: usize) {
+        match index {
             0 => {
                 // Cycle theme
                 self.settings.theme = match self.settings.theme {
@@ -969,12 +2041,279 @@ impl App {
                     if self.settings.autosync { "On" } else { "Off" }
                 );
             }
-            _ => {}
+            3 => {
+                self.settings.sign_commits = !self.settings.sign_commits;
+                self.status_message = format!(
+                    "⚙ Sign commits: {}",
+                    if self.settings.sign_commits {
+                        "On"
+                    } else {
+                        "Off"
+                    }
+                );
+            }
+            4 => {
+                let current = AUTOSAVE_CHOICES
+                    .iter()
+                    .position(|&secs| secs == self.settings.autosave_secs)
+                    .unwrap_or(0);
+                self.settings.autosave_secs =
+                    AUTOSAVE_CHOICES[(current + 1) % AUTOSAVE_CHOICES.len()];
+                self.last_autosave = Instant::now();
+                self.status_message = format!(
+                    "⚙ Autosave: {}",
+                    Self::autosave_label(self.settings.autosave_secs)
+                );
+            }
+            5 => {
+                let options = &mut self.settings.git_options;
+                options.include_ignored = !options.include_ignored;
+                self.status_message = format!(
+                    "⚙ Show ignored files: {}",
+                    Self::on_off(options.include_ignored)
+                );
+                self.apply_git_options();
+            }
+            6 => {
+                let options = &mut self.settings.git_options;
+                options.include_untracked = !options.include_untracked;
+                self.status_message = format!(
+                    "⚙ Show untracked files: {}",
+                    Self::on_off(options.include_untracked)
+                );
+                self.apply_git_options();
+            }
+            7 => {
+                let options = &mut self.settings.git_options;
+                options.recurse_untracked = !options.recurse_untracked;
+                self.status_message = format!(
+                    "⚙ Expand untracked folders: {}",
+                    Self::on_off(options.recurse_untracked)
+                );
+                self.apply_git_options();
+            }
+            8 => self.toggle_commit_dates(),
+            9 => {
+                let current = HISTORY_LIMIT_CHOICES
+                    .iter()
+                    .position(|&limit| limit == self.settings.history_limit)
+                    .unwrap_or(0);
+                self.settings.history_limit =
+                    HISTORY_LIMIT_CHOICES[(current + 1) % HISTORY_LIMIT_CHOICES.len()];
+                self.status_message =
+                    format!("⚙ Initial history: {} commits", self.settings.history_limit);
+            }
+            10 => {
+                self.settings.commit_requires_modifier = !self.settings.commit_requires_modifier;
+                self.status_message = format!(
+                    "⚙ Commit key: {}",
+                    Self::commit_key_label(self.settings.commit_requires_modifier)
+                );
+            }
+            11 => {
+                self.settings.run_hooks = !self.settings.run_hooks;
+                self.status_message = format!(
+                    "⚙ Run commit hooks: {}",
+                    Self::on_off(self.settings.run_hooks)
+                );
+            }
+            12 => {
+                let options = &mut self.settings.git_options;
+                let current = MAX_DIFF_BYTES_CHOICES
+                    .iter()
+                    .position(|&bytes| bytes == options.max_diff_bytes)
+                    .unwrap_or(0);
+                options.max_diff_bytes =
+                    MAX_DIFF_BYTES_CHOICES[(current + 1) % MAX_DIFF_BYTES_CHOICES.len()];
+                self.status_message = format!(
+                    "⚙ Max diff size: {}",
+                    Self::diff_size_label(options.max_diff_bytes)
+                );
+                self.apply_git_options();
+            }
+            13 => self.cycle_default_remote(),
+            14 => {
+                let options = &mut self.settings.git_options;
+                options.prune_on_fetch = !options.prune_on_fetch;
+                self.status_message =
+                    format!("⚙ Prune on fetch: {}", Self::on_off(options.prune_on_fetch));
+                self.apply_git_options();
+            }
+            15 => {
+                self.settings.auto_fetch = !self.settings.auto_fetch;
+                self.status_message = format!(
+                    "⚙ Fetch on startup: {}",
+                    Self::on_off(self.settings.auto_fetch)
+                );
+            }
+            _ => return,
         }
+        self.save_settings();
+    }
+
+    /// Move the default remote on to the next one the repository configures
+    fn cycle_default_remote(&mut self) {
+        let remotes = self
+            .git_client
+            .as_ref()
+            .and_then(|client| client.list_remotes().ok())
+            .unwrap_or_default();
+        if remotes.is_empty() {
+            self.status_message = warning("No remotes configured");
+            return;
+        }
+        let next = remotes
+            .iter()
+            .position(|remote| *remote == self.settings.default_remote)
+            .map_or(0, |current| (current + 1) % remotes.len());
+        self.settings.default_remote = remotes[next].clone();
+        if let Some(client) = &self.git_client {
+            self.last_fetch = client.last_fetch_time(&self.settings.default_remote);
+        }
+        self.status_message = format!("⚙ Default remote: {}", self.settings.default_remote);
+    }
+
+    /// Start fetching the default remote in the background, if the user asked
+    /// for it and the repository has that remote
+    fn auto_fetch_on_startup(&mut self) {
+        if !self.settings.auto_fetch {
+            return;
+        }
+        let configured = self
+            .git_client
+            .as_ref()
+            .and_then(|client| client.list_remotes().ok())
+            .is_some_and(|remotes| remotes.contains(&self.settings.default_remote));
+        if configured {
+            self.perform_fetch();
+        }
+    }
+
+    /// Flip Commit History between relative and absolute dates
+    fn toggle_commit_dates(&mut self) {
+        self.settings.relative_dates = !self.settings.relative_dates;
+        self.status_message = format!(
+            "⚙ Commit dates: {}",
+            Self::dates_label(self.settings.relative_dates)
+        );
+    }
+
+    fn commit_key_label(requires_modifier: bool) -> &'static str {
+        if requires_modifier {
+            "Ctrl+Enter"
+        } else {
+            "Enter"
+        }
+    }
+
+    fn dates_label(relative: bool) -> &'static str {
+        if relative {
+            "Relative"
+        } else {
+            "Absolute"
+        }
+    }
+
+    fn diff_size_label(bytes: u64) -> String {
+        match bytes {
+            0 => "Unlimited".to_string(),
+            b if b % MIB == 0 => format!("{} MB", b / MIB),
+            b => format!("{} KB", b / 1024),
+        }
+    }
+
+    fn on_off(enabled: bool) -> &'static str {
+        if enabled {
+            "On"
+        } else {
+            "Off"
+        }
+    }
+
+    /// Hand the Settings file-listing toggles to the Git client and relist changes
+    fn apply_git_options(&mut self) {
+        let Some(client) = &mut self.git_client else {
+            return;
+        };
+        client.set_options(self.settings.git_options);
+        self.task_manager
+            .set_client_options(self.settings.git_options);
+        if let Ok(changes) = client.list_changes() {
+            if let Some(project) = self.store.projects.get_mut(self.dashboard.selected_index) {
+                self.changes.replace_changes(&mut project.changes, changes);
+            }
+        }
+        self.clamp_selections_for_project();
     }
 
     fn perform_commit(&mut self) {
-        let msg = self.changes.commit_message.trim();
+        let msg = self.changes.commit_message.trim().to_string();
+        if self.commit_staged(&msg) {
+            self.changes.clear_commit_message();
+        }
+    }
+
+    /// Stage everything and commit with the prompt's message, from any view
+    /// Returns whether the commit was made
+    fn perform_quick_commit(&mut self, message: &str) -> bool {
+        let Some(client) = &self.git_client else {
+            return false;
+        };
+
+        if let Err(e) = client.stage_all() {
+            self.action_log.record("Commit", false, e.to_string());
+            self.status_message = error(&format!("Failed to stage changes: {}", e));
+            return false;
+        }
+        if let Ok(changes) = client.list_changes() {
+            if let Some(project) = self.store.projects.get_mut(self.dashboard.selected_index) {
+                self.changes.replace_changes(&mut project.changes, changes);
+            }
+        }
+        let committed = self.commit_staged(message);
+        self.clamp_selections_for_project();
+        committed
+    }
+
+    /// Apply the patch file at `path` (relative to the repository root) to the working tree
+    fn perform_apply_patch(&mut self, path: &str) {
+        let Some(client) = &self.git_client else {
+            return;
+        };
+
+        let full_path = match &self.git_workdir {
+            Some(workdir) => workdir.join(path),
+            None => PathBuf::from(path),
+        };
+        let result = std::fs::read_to_string(&full_path)
+            .map_err(|e| color_eyre::eyre::eyre!("Cannot read {}: {}", path, e))
+            .and_then(|text| client.apply_patch(&text, false));
+
+        match result {
+            Ok(()) => {
+                if let Ok(changes) = client.list_changes() {
+                    if let Some(project) =
+                        self.store.projects.get_mut(self.dashboard.selected_index)
+                    {
+                        self.changes.replace_changes(&mut project.changes, changes);
+                    }
+                }
+                self.repo_dirty = client.is_clean().is_ok_and(|clean| !clean);
+                self.uncommitted = client.uncommitted_stats().ok();
+                self.action_log
+                    .record("Apply patch", true, format!("Applied {}", path));
+                self.status_message = success(&format!("Applied patch {}", path));
+                self.clamp_selections_for_project();
+            }
+            Err(e) => {
+                self.action_log.record("Apply patch", false, e.to_string());
+                self.status_message = error(&format!("Failed to apply patch: {}", e));
+            }
+        }
+    }
+
+    /// Commit the staged changes, returning whether a commit was made
+    fn commit_staged(&mut self, msg: &str) -> bool {
         if let Some(client) = &self.git_client {
             // Check if any files are staged
             let has_staged = self
@@ -986,63 +2325,138 @@ impl App {
 
             if !has_staged {
                 self.status_message = "No files staged for commit".into();
-                return;
+                return false;
             }
 
-            match client.commit_all(msg) {
+            let signer = if self.settings.sign_commits {
+                client.configured_signer().map(Some)
+            } else {
+                Ok(None)
+            };
+            let result = signer.and_then(|signer| {
+                let options = git::CommitOptions {
+                    run_hooks: self.settings.run_hooks,
+                    signer: signer.as_ref().map(|s| s as &dyn git::CommitSigner),
+                    ..Default::default()
+                };
+                client.commit(msg, &options)
+            });
+
+            match result {
                 Ok(_oid) => {
                     // Refresh changes and bump progress
                     if let Ok(changes) = client.list_changes() {
                         if let Some(project) =
                             self.store.projects.get_mut(self.dashboard.selected_index)
                         {
-                            project.changes = changes;
+                            self.changes.replace_changes(&mut project.changes, changes);
                         }
                     }
+                    self.commit_history.total_commits = client.total_commits().ok();
+                    self.dashboard.tracked_files = client.tracked_file_count().ok();
+                    self.repo_dirty = client.is_clean().is_ok_and(|clean| !clean);
+                    self.uncommitted = client.uncommitted_stats().ok();
                     self.store
                         .bump_progress_on_commit(self.dashboard.selected_index);
+                    self.action_log.record("Commit", true, msg);
                     self.status_message = success(&format!("Committed: {}", msg));
-                    self.changes.clear_commit_message();
-                    if let Some(wd) = self.git_workdir.as_ref() {
-                        let _ = self.store.save_progress(wd);
+                    self.save_store_progress();
+                    return true;
+                }
+                // Files marked staged may since have been reverted to HEAD
+                Err(e) if e.to_string() == git::NOTHING_TO_COMMIT_MESSAGE => {
+                    if let Ok(changes) = client.list_changes() {
+                        if let Some(project) =
+                            self.store.projects.get_mut(self.dashboard.selected_index)
+                        {
+                            self.changes.replace_changes(&mut project.changes, changes);
+                        }
                     }
+                    self.status_message = warning("Nothing to commit");
                 }
                 Err(e) => {
+                    self.action_log.record("Commit", false, e.to_string());
                     self.status_message = error(&format!("Commit failed: {}", e));
                 }
             }
         }
+        false
     }
 
     fn refresh_view_cache(&mut self) {
         if let Some(client) = &self.git_client {
+            self.repo_dirty = client.is_clean().is_ok_and(|clean| !clean);
+            self.uncommitted = client.uncommitted_stats().ok();
+            self.conflict_count = client.list_conflicts().map_or(0, |c| c.len());
+            self.head = client.head_branch();
             match self.current_view {
+                AppMode::BranchManager if self.branch_manager.filter == BranchFilter::Tags => {
+                    if let Ok(tags) = client.list_tags() {
+                        let tag_infos = tags
+                            .into_iter()
+                            .map(|name| BranchInfo {
+                                name,
+                                is_current: false,
+                                is_remote: false,
+                                is_unpushed: false,
+                                is_tag: true,
+                                ahead_behind: None,
+                            })
+                            .collect();
+                        self.branch_manager.update_branches(tag_infos);
+                    }
+                }
                 AppMode::BranchManager => {
-                    if let Ok(branches) = client.list_branches(true, false) {
+                    let (local, remote) = self.branch_manager.filter.includes();
+                    // Listed apart so remote-tracking branches can be told from local ones
+                    let branches = client.list_branches(local, false).and_then(|locals| {
+                        let remotes = client.list_branches(false, remote)?;
+                        Ok(locals
+                            .into_iter()
+                            .map(|(name, is_current)| (name, is_current, false))
+                            .chain(remotes.into_iter().map(|(name, _)| (name, false, true)))
+                            .collect::<Vec<_>>())
+                    });
+                    if let Ok(branches) = branches {
+                        let unpublished = client.unpublished_branches().unwrap_or_default();
                         let branch_infos: Vec<BranchInfo> = branches
                             .into_iter()
-                            .map(|(name, is_current)| BranchInfo {
+                            .map(|(name, is_current, is_remote)| BranchInfo {
+                                is_unpushed: unpublished.contains(&name),
+                                is_tag: false,
+                                ahead_behind: if is_current {
+                                    None
+                                } else {
+                                    client
+                                        .count_commits_between("HEAD", &name)
+                                        .and_then(|ahead| {
+                                            client
+                                                .count_commits_between(&name, "HEAD")
+                                                .map(|behind| (ahead, behind))
+                                        })
+                                        .ok()
+                                },
                                 name,
                                 is_current,
-                                is_remote: false,
+                                is_remote,
                             })
                             .collect();
                         self.branch_manager.update_branches(branch_infos);
                     }
                 }
                 AppMode::CommitHistory => {
-                    if let Ok(commits) = client.get_commit_history(50) {
-                        let commit_infos: Vec<CommitInfo> = commits
-                            .into_iter()
-                            .map(|(hash, author, date, message, files)| CommitInfo {
-                                hash,
-                                author,
-                                date,
-                                message,
-                                files_changed: files,
-                            })
-                            .collect();
-                        self.commit_history.update_commits(commit_infos);
+                    self.commit_history.total_commits = client.total_commits().ok();
+                    let limit = self.settings.history_limit;
+                    let mut cursor = git::HistoryCursor::default();
+                    if let Ok(commits) = client.commit_history_page(&mut cursor, limit) {
+                        self.commit_history
+                            .update_commits(Self::to_commit_infos(client, commits));
+                        self.commit_history.cursor = cursor;
+                    }
+                }
+                AppMode::Dashboard => {
+                    if let Ok(activity) = client.commit_counts_by_day(ACTIVITY_DAYS) {
+                        self.dashboard.activity = activity;
                     }
                 }
                 AppMode::Changes => {
@@ -1051,8 +2465,8 @@ impl App {
                         if let Some(project) =
                             self.store.projects.get_mut(self.dashboard.selected_index)
                         {
-                            project.changes = changes;
-                            project.branch = client.head_branch().unwrap_or_default();
+                            self.changes.replace_changes(&mut project.changes, changes);
+                            project.branch = client.head_branch().label();
                         }
                     }
                 }
@@ -1061,52 +2475,513 @@ impl App {
         }
     }
 
+    /// Append the next page of older commits to the Commit History cache
+    fn load_more_commits(&mut self) {
+        let Some(client) = &self.git_client else {
+            return;
+        };
+        match client.commit_history_page(&mut self.commit_history.cursor, COMMIT_PAGE_SIZE) {
+            Ok(commits) => self
+                .commit_history
+                .append_commits(Self::to_commit_infos(client, commits)),
+            Err(e) => {
+                self.commit_history.has_more = false;
+                self.status_message = error(&format!("Failed to load more commits: {}", e));
+            }
+        }
+    }
+
+    fn to_commit_infos(client: &git::GitClient, commits: Vec<git::CommitData>) -> Vec<CommitInfo> {
+        let mut tags = client.tags_by_commit().unwrap_or_default();
+        commits
+            .into_iter()
+            .map(
+                |(hash, author, date, message, files, timestamp, author_email)| {
+                    let short_hash = git2::Oid::from_str(&hash)
+                        .map(|oid| client.short_hash(oid))
+                        .unwrap_or_default();
+                    CommitInfo {
+                        tags: tags.remove(&hash).unwrap_or_default(),
+                        hash,
+                        author,
+                        author_email,
+                        date,
+                        message,
+                        files_changed: files,
+                        timestamp,
+                        short_hash,
+                    }
+                },
+            )
+            .collect()
+    }
+
     fn perform_branch_switch(&mut self) {
         let branch_info = self
             .branch_manager
             .selected_branch()
-            .map(|b| (b.name.clone(), b.is_current));
+            .map(|b| (b.name.clone(), b.is_current, b.is_tag));
 
-        if let Some((name, is_current)) = branch_info {
-            if is_current {
-                self.status_message = "Already on this branch".into();
-                return;
-            }
-
-            if let Some(client) = &self.git_client {
-                match client.checkout_branch(&name) {
-                    Ok(()) => {
-                        self.status_message = success(&format!("Switched to branch: {}", name));
-                        // Refresh branch list
-                        self.refresh_view_cache();
-                        // Update project branch info
-                        if let Some(project) =
-                            self.store.projects.get_mut(self.dashboard.selected_index)
-                        {
-                            project.branch = name;
-                        }
-                    }
-                    Err(e) => {
-                        self.status_message = error(&format!("Failed to switch branch: {}", e));
-                    }
-                }
-            }
+        match branch_info {
+            Some((name, _, true)) => self.checkout_detached(&format!("refs/tags/{}", name)),
+            Some((name, is_current, false)) => self.switch_to_branch(name, is_current),
+            None => {}
         }
     }
 
-    fn perform_branch_create(&mut self) {
-        let branch_name = self.branch_manager.get_input_value();
-        if let Some(client) = &self.git_client {
-            match client.create_branch(branch_name) {
-                Ok(()) => {
-                    self.status_message = success(&format!("Created branch: {}", branch_name));
-                    self.branch_manager.exit_create_mode();
-                    // Refresh branch list
-                    self.refresh_view_cache();
-                }
-                Err(e) => {
-                    self.status_message = error(&format!("Failed to create branch: {}", e));
-                }
+    fn perform_open_branch_picker(&mut self) {
+        let Some(client) = &self.git_client else {
+            return;
+        };
+        match client.list_branches(true, false) {
+            Ok(branches) if branches.is_empty() => {
+                self.status_message = "No local branches".into();
+            }
+            Ok(branches) => self.branch_picker = Some(BranchPickerState::new(branches)),
+            Err(e) => self.status_message = error(&format!("Failed to list branches: {}", e)),
+        }
+    }
+
+    fn perform_open_worktrees(&mut self) {
+        let (Some(client), Some(workdir)) = (&self.git_client, &self.git_workdir) else {
+            return;
+        };
+        match client.worktrees() {
+            Ok(list) if list.is_empty() => self.status_message = "No worktrees".into(),
+            Ok(list) => self.worktrees = Some(WorktreeListState::new(list, workdir)),
+            Err(e) => self.status_message = error(&format!("Failed to list worktrees: {}", e)),
+        }
+    }
+
+    /// Save the open repository's data, then load `client`'s in its place
+    /// with selections back at the top
+    fn switch_repo(&mut self, client: git::GitClient) -> Result<(), String> {
+        if let (Some(wd), Some(dir)) = (self.git_workdir.as_ref(), self.state_dir()) {
+            self.store
+                .save_if_dirty(wd, &dir, self.settings.autosync)
+                .map_err(|e| format!("Could not save before switching: {}", e))?;
+        }
+
+        self.attach_repo(client);
+        self.dashboard.selected_index = 0;
+        self.clamp_selections_for_project();
+        self.refresh_view_cache();
+        Ok(())
+    }
+
+    /// Re-point the app at the selected worktree, saving the current one's data first
+    fn perform_worktree_switch(&mut self) {
+        let Some(list) = self.worktrees.take() else {
+            return;
+        };
+        if list.current == Some(list.selected) {
+            self.status_message = "Already in this worktree".into();
+            return;
+        }
+        let Some(path) = list.selected_path().cloned() else {
+            return;
+        };
+        let client = match git::GitClient::discover(&path) {
+            Ok(client) => client,
+            Err(e) => {
+                self.status_message = error(&format!("Failed to open worktree: {}", e));
+                return;
+            }
+        };
+        if let Err(e) = self.switch_repo(client) {
+            self.status_message = warning(&e);
+            return;
+        }
+        self.action_log
+            .record("Worktree", true, format!("Switched to {}", path.display()));
+        self.status_message = success(&format!("Switched to worktree {}", path.display()));
+    }
+
+    fn perform_open_reflog(&mut self) {
+        let Some(client) = &self.git_client else {
+            return;
+        };
+        match client.reflog(REFLOG_LIMIT) {
+            Ok(entries) => self.reflog = Some(ReflogState::new(entries)),
+            Err(e) => self.status_message = error(&format!("Failed to read reflog: {}", e)),
+        }
+    }
+
+    fn perform_reflog_checkout(&mut self) {
+        let Some(hash) = self
+            .reflog
+            .take()
+            .and_then(|r| r.selected_hash().map(str::to_string))
+        else {
+            return;
+        };
+        self.checkout_detached(&hash);
+        self.refresh_view_cache();
+    }
+
+    /// Move the current branch to the selected reflog entry, keeping the working tree
+    fn perform_reflog_reset(&mut self) {
+        let Some(hash) = self
+            .reflog
+            .take()
+            .and_then(|r| r.selected_hash().map(str::to_string))
+        else {
+            return;
+        };
+        let Some(client) = &self.git_client else {
+            return;
+        };
+        match client.reset(&hash, git2::ResetType::Mixed) {
+            Ok(()) => {
+                self.action_log
+                    .record("Reset", true, format!("Moved HEAD to {}", hash));
+                self.status_message = success(&format!("Reset to {}", hash));
+                let changes = client.list_changes().unwrap_or_default();
+                if let Some(project) = self.store.projects.get_mut(self.dashboard.selected_index) {
+                    self.changes.replace_changes(&mut project.changes, changes);
+                }
+                self.clamp_selections_for_project();
+                self.refresh_view_cache();
+            }
+            Err(e) => {
+                self.action_log.record("Reset", false, e.to_string());
+                self.status_message = error(&format!("Failed to reset: {}", e));
+            }
+        }
+    }
+
+    fn perform_branch_picker_checkout(&mut self) {
+        let Some(picker) = &self.branch_picker else {
+            return;
+        };
+        let Some((name, is_current)) = picker
+            .selected_branch()
+            .map(|(name, is_current)| (name.to_string(), is_current))
+        else {
+            self.status_message = "No matching branch".into();
+            return;
+        };
+        self.branch_picker = None;
+        self.switch_to_branch(name, is_current);
+    }
+
+    /// Check out a local branch and refresh everything that depends on HEAD
+    fn switch_to_branch(&mut self, name: String, is_current: bool) {
+        if is_current {
+            self.status_message = "Already on this branch".into();
+            return;
+        }
+
+        if let Some(client) = &self.git_client {
+            match client.checkout_branch(&name) {
+                Ok(()) => {
+                    self.action_log
+                        .record("Checkout", true, format!("Switched to {}", name));
+                    self.status_message = success(&format!("Switched to branch: {}", name));
+                    self.head = client.head_branch();
+                    self.dashboard.tracked_files = client.tracked_file_count().ok();
+                    // Refresh branch list
+                    self.refresh_view_cache();
+                    // Update project branch info
+                    if let Some(project) =
+                        self.store.projects.get_mut(self.dashboard.selected_index)
+                    {
+                        project.branch = name;
+                    }
+                }
+                Err(e) => {
+                    self.action_log
+                        .record("Checkout", false, format!("{}: {}", name, e));
+                    self.status_message = error(&format!("Failed to switch branch: {}", e));
+                }
+            }
+        }
+    }
+
+    fn perform_commit_checkout(&mut self) {
+        let Some(hash) = self
+            .commit_history
+            .selected_commit()
+            .map(|c| c.hash.clone())
+        else {
+            return;
+        };
+        self.checkout_detached(&hash);
+    }
+
+    /// Squash every commit from HEAD down to the selected one into a single commit
+    fn perform_squash(&mut self, message: &str) {
+        let Some(hash) = self
+            .commit_history
+            .selected_commit()
+            .map(|c| c.hash.clone())
+        else {
+            return;
+        };
+        let Some(client) = &self.git_client else {
+            return;
+        };
+        // The selected commit is squashed too, along with everything above it.
+        // squash_last follows first parents, so count the same way
+        let count = match client.first_parent_distance(&hash) {
+            Ok(newer) => newer + 1,
+            Err(e) => {
+                self.action_log.record("Squash", false, e.to_string());
+                self.status_message = error(&format!("Failed to squash: {}", e));
+                return;
+            }
+        };
+        match client.squash_last(count, message) {
+            Ok(oid) => {
+                let short = client.short_hash(oid);
+                self.action_log
+                    .record("Squash", true, format!("{} commits into {}", count, short));
+                self.status_message =
+                    success(&format!("Squashed {} commits into {}", count, short));
+                self.refresh_view_cache();
+            }
+            Err(e) => {
+                self.action_log.record("Squash", false, e.to_string());
+                self.status_message = error(&format!("Failed to squash: {}", e));
+            }
+        }
+    }
+
+    /// Clone `url` into a directory named after it under the current
+    /// directory, opening it once the background clone finishes
+    fn perform_clone_repo(&mut self, url: &str) {
+        match std::env::current_dir() {
+            Ok(cwd) => self.clone_repo_into(url, &cwd),
+            Err(e) => self.status_message = error(&format!("Cannot clone: {}", e)),
+        }
+    }
+
+    fn clone_repo_into(&mut self, url: &str, parent: &std::path::Path) {
+        let dest = parent.join(git::clone_dir_name(url));
+        if dest.exists() {
+            self.status_message = error(&format!("{} already exists", dest.display()));
+            return;
+        }
+        self.spawn_git_operation(dest.clone(), GitOperation::Clone(url.to_string(), dest));
+    }
+
+    /// Select the commit `rev` resolves to in Commit History, paging in
+    /// older commits until it is found
+    fn perform_go_to_ref(&mut self, rev: &str) {
+        let Some(client) = &self.git_client else {
+            return;
+        };
+        let oid = match client.resolve_commit(rev) {
+            Ok(oid) => oid,
+            Err(e) => {
+                self.status_message = error(&format!("Unknown revision '{}': {}", rev, e));
+                return;
+            }
+        };
+        let short = client.short_hash(oid);
+        self.on_enter_view(AppMode::CommitHistory);
+        self.focus = Focus::View;
+        let hash = oid.to_string();
+        while !self.commit_history.select_hash(&hash) && self.commit_history.has_more {
+            self.load_more_commits();
+        }
+        if self.commit_history.select_hash(&hash) {
+            self.status_message = info(&format!("Jumped to {} ({})", short, rev));
+        } else {
+            self.status_message = warning(&format!("{} ({}) is not in HEAD's history", short, rev));
+        }
+    }
+
+    /// Change the selected commit's message, replaying the commits after it
+    fn perform_reword(&mut self, message: &str) {
+        let Some(hash) = self
+            .commit_history
+            .selected_commit()
+            .map(|c| c.hash.clone())
+        else {
+            return;
+        };
+        let Some(client) = &self.git_client else {
+            return;
+        };
+        let old_short = client
+            .resolve_commit(&hash)
+            .map_or_else(|_| hash.clone(), |oid| client.short_hash(oid));
+        match client.reword_commit(&hash, message) {
+            Ok(oid) => {
+                let short = client.short_hash(oid);
+                self.action_log
+                    .record("Reword", true, format!("{} is now {}", old_short, short));
+                self.status_message = success(&format!("Reworded commit, now {}", short));
+                self.refresh_view_cache();
+            }
+            Err(e) => {
+                self.action_log.record("Reword", false, e.to_string());
+                self.status_message = error(&format!("Failed to reword: {}", e));
+            }
+        }
+    }
+
+    /// Detach HEAD at `hash` and refresh everything that depends on HEAD
+    fn checkout_detached(&mut self, hash: &str) {
+        let Some(client) = &self.git_client else {
+            return;
+        };
+
+        match client.checkout_commit(hash) {
+            Ok(()) => {
+                let short = client
+                    .resolve_commit(hash)
+                    .map_or_else(|_| hash.to_string(), |oid| client.short_hash(oid));
+                self.action_log
+                    .record("Checkout", true, format!("Detached at {}", short));
+                self.status_message = success(&format!("Checked out {} (detached HEAD)", short));
+                self.head = client.head_branch();
+                self.dashboard.tracked_files = client.tracked_file_count().ok();
+                self.repo_dirty = client.is_clean().is_ok_and(|clean| !clean);
+                self.uncommitted = client.uncommitted_stats().ok();
+                let branch = self.head.label();
+                let changes = client.list_changes().unwrap_or_default();
+                if let Some(project) = self.store.projects.get_mut(self.dashboard.selected_index) {
+                    project.branch = branch;
+                    self.changes.replace_changes(&mut project.changes, changes);
+                }
+                self.clamp_selections_for_project();
+            }
+            Err(e) => {
+                self.action_log.record("Checkout", false, e.to_string());
+                self.status_message = error(&format!("Failed to check out commit: {}", e));
+            }
+        }
+    }
+
+    fn perform_toggle_my_commits(&mut self) {
+        // The email tells apart authors whose names overlap
+        let me = self
+            .git_client
+            .as_ref()
+            .and_then(|c| c.user_email().or_else(|| c.user_name()));
+        let Some(name) = me else {
+            self.status_message = warning("No user.name or user.email configured");
+            return;
+        };
+        self.commit_history.toggle_author_filter(&name);
+        self.status_message = if self.commit_history.author_filter.is_some() {
+            info(&format!(
+                "Showing {} commits by {}",
+                self.commit_history.commit_count(),
+                name
+            ))
+        } else {
+            info("Showing all commits")
+        };
+    }
+
+    fn perform_export_commits(&mut self) {
+        let Some(workdir) = self.git_workdir.clone() else {
+            return;
+        };
+        let commits = &self.commit_history.cached_commits;
+        if commits.is_empty() {
+            self.status_message = "No commits to export".into();
+            return;
+        }
+
+        let dir = workdir.join(".forge");
+        let result = std::fs::create_dir_all(&dir)
+            .and_then(|()| std::fs::write(dir.join("commits.json"), export_commits_json(commits)));
+        self.status_message = match result {
+            Ok(()) => success(&format!(
+                "Exported {} commits to .forge/commits.json",
+                commits.len()
+            )),
+            Err(e) => error(&format!("Failed to export commits: {}", e)),
+        };
+    }
+
+    /// Write release notes for the loaded commits to `.forge/CHANGELOG.md`,
+    /// covering everything since the latest tag
+    fn perform_write_changelog(&mut self) {
+        let Some(workdir) = self.git_workdir.clone() else {
+            return;
+        };
+        // A tag on the newest commit names the release being described, so
+        // page in older commits until an earlier tag or the root is loaded
+        let tagged =
+            |commits: &[CommitInfo]| commits.iter().skip(1).any(|commit| !commit.tags.is_empty());
+        while !tagged(self.commit_history.all_commits()) && self.commit_history.has_more {
+            let loaded = self.commit_history.all_commits().len();
+            self.load_more_commits();
+            if self.commit_history.all_commits().len() == loaded {
+                break;
+            }
+        }
+
+        // The author filter only narrows the view; the changelog covers everyone
+        let commits = self.commit_history.all_commits();
+        if commits.is_empty() {
+            self.status_message = "No commits to write a changelog from".into();
+            return;
+        }
+        let since_tag = commits
+            .iter()
+            .skip(1)
+            .find_map(|commit| commit.tags.first())
+            .map(String::as_str);
+        let dir = workdir.join(".forge");
+        let result = std::fs::create_dir_all(&dir).and_then(|()| {
+            std::fs::write(
+                dir.join("CHANGELOG.md"),
+                generate_changelog(commits, since_tag),
+            )
+        });
+        self.status_message = match (result, since_tag) {
+            (Ok(()), Some(tag)) => success(&format!(
+                "Wrote changes since {} to .forge/CHANGELOG.md",
+                tag
+            )),
+            (Ok(()), None) => success("Wrote changelog to .forge/CHANGELOG.md"),
+            (Err(e), _) => error(&format!("Failed to write changelog: {}", e)),
+        };
+    }
+
+    /// Write the porcelain status to `.forge/status.txt` for comparing with `git status`
+    fn perform_dump_status(&mut self) {
+        let (Some(client), Some(workdir)) = (&self.git_client, &self.git_workdir) else {
+            return;
+        };
+        let dir = workdir.join(".forge");
+        let result = client.status_porcelain().and_then(|status| {
+            std::fs::create_dir_all(&dir)?;
+            std::fs::write(dir.join("status.txt"), &status)?;
+            Ok(status.lines().count())
+        });
+        self.status_message = match result {
+            Ok(count) => success(&format!(
+                "Wrote {} status entries to .forge/status.txt",
+                count
+            )),
+            Err(e) => error(&format!("Failed to dump status: {}", e)),
+        };
+    }
+
+    fn perform_branch_create(&mut self) {
+        let branch_name = self.branch_manager.get_input_value();
+        if let Some(client) = &self.git_client {
+            match client.create_branch(branch_name) {
+                Ok(()) => {
+                    self.action_log
+                        .record("Branch", true, format!("Created {}", branch_name));
+                    self.status_message = success(&format!("Created branch: {}", branch_name));
+                    self.branch_manager.exit_create_mode();
+                    // Refresh branch list
+                    self.refresh_view_cache();
+                }
+                Err(e) => {
+                    self.action_log
+                        .record("Branch", false, format!("{}: {}", branch_name, e));
+                    self.status_message = error(&format!("Failed to create branch: {}", e));
+                }
             }
         }
     }
@@ -1115,9 +2990,11 @@ impl App {
         let branch_info = self
             .branch_manager
             .selected_branch()
-            .map(|b| (b.name.clone(), b.is_current));
+            .map(|b| (b.name.clone(), b.is_current, b.is_tag));
 
-        if let Some((name, is_current)) = branch_info {
+        if let Some((name, _, true)) = branch_info {
+            self.perform_tag_delete(&name);
+        } else if let Some((name, is_current, _)) = branch_info {
             if is_current {
                 self.status_message = "Cannot delete current branch".into();
                 return;
@@ -1126,11 +3003,15 @@ impl App {
             if let Some(client) = &self.git_client {
                 match client.delete_branch(&name) {
                     Ok(()) => {
+                        self.action_log
+                            .record("Branch", true, format!("Deleted {}", name));
                         self.status_message = success(&format!("Deleted branch: {}", name));
                         // Refresh branch list
                         self.refresh_view_cache();
                     }
                     Err(e) => {
+                        self.action_log
+                            .record("Branch", false, format!("{}: {}", name, e));
                         self.status_message = error(&format!("Failed to delete branch: {}", e));
                     }
                 }
@@ -1138,6 +3019,25 @@ impl App {
         }
     }
 
+    fn perform_tag_delete(&mut self, name: &str) {
+        let Some(client) = &self.git_client else {
+            return;
+        };
+        match client.delete_tag(name) {
+            Ok(()) => {
+                self.action_log
+                    .record("Tag", true, format!("Deleted {}", name));
+                self.status_message = success(&format!("Deleted tag: {}", name));
+                self.refresh_view_cache();
+            }
+            Err(e) => {
+                self.action_log
+                    .record("Tag", false, format!("{}: {}", name, e));
+                self.status_message = error(&format!("Failed to delete tag: {}", e));
+            }
+        }
+    }
+
     fn load_selected_module_for_edit(&mut self) {
         if let Some(project) = self.store.projects.get(self.dashboard.selected_index) {
             if let Some(module) = project.modules.get(self.module_manager.selected_module) {
@@ -1155,9 +3055,7 @@ impl App {
         {
             self.status_message = success(&format!("Created module: {}", module_name));
             self.module_manager.exit_current_mode();
-            if let Some(wd) = self.git_workdir.as_ref() {
-                let _ = self.store.save_to_json(wd);
-            }
+            self.save_store_json();
         } else {
             self.status_message = error("Failed to create module");
         }
@@ -1173,9 +3071,7 @@ impl App {
             ) {
                 self.status_message = success(&format!("Updated module: {}", module_name));
                 self.module_manager.exit_current_mode();
-                if let Some(wd) = self.git_workdir.as_ref() {
-                    let _ = self.store.save_to_json(wd);
-                }
+                self.save_store_json();
             } else {
                 self.status_message = error("Failed to update module");
             }
@@ -1197,9 +3093,7 @@ impl App {
                         .modules
                         .len();
                     self.module_manager.clamp_selections(new_count, 0);
-                    if let Some(wd) = self.git_workdir.as_ref() {
-                        let _ = self.store.save_to_json(wd);
-                    }
+                    self.save_store_json();
                 } else {
                     self.status_message = error("Failed to delete module");
                 }
@@ -1215,14 +3109,55 @@ impl App {
         {
             self.status_message = success(&format!("Created developer: {}", developer_name));
             self.module_manager.exit_current_mode();
-            if let Some(wd) = self.git_workdir.as_ref() {
-                let _ = self.store.save_to_json(wd);
-            }
+            self.save_store_json();
         } else {
             self.status_message = error("Failed to create developer");
         }
     }
 
+    /// Turns the selected module or developer row into an editable name
+    fn start_inline_rename(&mut self) {
+        let Some(project) = self.store.projects.get(self.dashboard.selected_index) else {
+            return;
+        };
+        let selected = if self.module_manager.is_developer_list() {
+            project
+                .developers
+                .get(self.module_manager.selected_developer)
+                .map(|d| (d.id, d.name.as_str()))
+        } else {
+            project
+                .modules
+                .get(self.module_manager.selected_module)
+                .map(|m| (m.id, m.name.as_str()))
+        };
+        if let Some((id, name)) = selected {
+            self.module_manager.enter_rename(id, name);
+        }
+    }
+
+    fn perform_inline_rename(&mut self) {
+        let Some(id) = self.module_manager.rename_target else {
+            return;
+        };
+        let name = self.module_manager.get_input_value().to_string();
+        let project_idx = self.dashboard.selected_index;
+        let (kind, renamed) = if self.module_manager.is_developer_list() {
+            let renamed = self.store.update_developer(project_idx, id, name.clone());
+            ("developer", renamed)
+        } else {
+            let renamed = self.store.update_module(project_idx, id, name.clone());
+            ("module", renamed)
+        };
+        if renamed {
+            self.status_message = success(&format!("Renamed {}: {}", kind, name));
+            self.module_manager.leave_rename();
+            self.save_store_json();
+        } else {
+            self.status_message = error(&format!("Failed to rename {}", kind));
+        }
+    }
+
     fn perform_developer_delete(&mut self) {
         if let Some(project) = self.store.projects.get(self.dashboard.selected_index) {
             if let Some(developer) = project
@@ -1242,9 +3177,7 @@ impl App {
                         .developers
                         .len();
                     self.module_manager.clamp_selections(0, new_count);
-                    if let Some(wd) = self.git_workdir.as_ref() {
-                        let _ = self.store.save_to_json(wd);
-                    }
+                    self.save_store_json();
                 } else {
                     self.status_message = error("Failed to delete developer");
                 }
@@ -1257,19 +3190,26 @@ impl App {
             if let Some(change) = project.changes.get(self.changes.selected_index) {
                 let path = change.path.clone();
                 let is_staged = change.staged;
+                // A rename is staged as its old and new paths together
+                let mut paths = vec![path.clone()];
+                if let data::FileStatus::Renamed { from, .. } = &change.status {
+                    paths.push(from.clone());
+                }
 
                 if let Some(client) = &self.git_client {
-                    let result = if is_staged {
-                        client.unstage_file(&path)
-                    } else {
-                        client.stage_file(&path)
-                    };
+                    let result = paths.iter().try_for_each(|p| {
+                        if is_staged {
+                            client.unstage_file(p)
+                        } else {
+                            client.stage_file(p)
+                        }
+                    });
 
                     match result {
                         Ok(()) => {
                             // Refresh changes to update staging status
                             if let Ok(changes) = client.list_changes() {
-                                project.changes = changes;
+                                self.changes.replace_changes(&mut project.changes, changes);
                                 self.status_message = if is_staged {
                                     success(&format!("Unstaged: {}", path))
                                 } else {
@@ -1291,52 +3231,438 @@ impl App {
         }
     }
 
+    /// Stage the selected file unless it already is, then move on to the next unstaged file
+    fn perform_stage_and_advance(&mut self) {
+        let selected = self
+            .store
+            .projects
+            .get(self.dashboard.selected_index)
+            .and_then(|p| p.changes.get(self.changes.selected_index))
+            .map(|c| (c.path.clone(), c.staged));
+        let Some((path, staged)) = selected else {
+            return;
+        };
+        if !staged {
+            self.toggle_file_staging();
+        }
+        let Some(project) = self.store.projects.get(self.dashboard.selected_index) else {
+            return;
+        };
+        // Staging refreshes the list, so pick up from wherever the file now is
+        match project.changes.iter().position(|c| c.path == path) {
+            Some(index) if project.changes[index].staged => self.changes.selected_index = index,
+            // Staging failed and the status bar says why
+            _ => return,
+        }
+        if !self.changes.select_next_unstaged(&project.changes) {
+            self.status_message = success(&format!("Staged: {} (all changes staged)", path));
+        }
+    }
+
+    /// Stage every file ticked in multi-select mode, then untick them
+    fn perform_stage_selected(&mut self) {
+        let (Some(client), Some(project)) = (
+            &self.git_client,
+            self.store.projects.get_mut(self.dashboard.selected_index),
+        ) else {
+            return;
+        };
+        let paths = self.changes.selected_paths(&project.changes);
+        if paths.is_empty() {
+            self.status_message = warning("No files ticked; Space ticks the selected file");
+            return;
+        }
+        let specs: Vec<&str> = paths.iter().map(String::as_str).collect();
+        match client.stage_paths(&specs) {
+            Ok(count) => {
+                self.changes.clear_row_selection();
+                if let Ok(changes) = client.list_changes() {
+                    self.changes.replace_changes(&mut project.changes, changes);
+                }
+                self.status_message = success(&format!("Staged {} file(s)", count));
+            }
+            Err(e) => self.status_message = error(&format!("Failed to stage files: {}", e)),
+        }
+    }
+
     fn perform_fetch(&mut self) {
-        self.enqueue_git_operation(GitOperation::Fetch("origin".to_string()));
+        let remote = self.settings.default_remote.clone();
+        self.enqueue_git_operation(GitOperation::Fetch(remote));
+    }
+
+    fn perform_fetch_all(&mut self) {
+        self.enqueue_git_operation(GitOperation::FetchAll);
     }
 
     fn perform_push(&mut self) {
-        self.enqueue_git_operation(GitOperation::Push("origin".to_string()));
+        let remote = self.settings.default_remote.clone();
+        self.enqueue_git_operation(GitOperation::Push(remote));
     }
 
     fn perform_pull(&mut self) {
-        self.enqueue_git_operation(GitOperation::Pull("origin".to_string()));
+        let remote = self.settings.default_remote.clone();
+        self.enqueue_git_operation(GitOperation::Pull(remote));
     }
 
-    fn perform_module_assignment(&mut self) {
-        if let Some(project) = self.store.projects.get_mut(self.dashboard.selected_index) {
-            if let Some(module) = project.modules.get(self.module_manager.selected_module) {
-                let module_id = module.id;
-                if let Some(developer) = project
-                    .developers
-                    .get(self.module_manager.selected_developer)
-                {
-                    let developer_id = developer.id;
-                    let developer_name = developer.name.clone();
-                    if self.store.assign_module_owner(
-                        self.dashboard.selected_index,
-                        module_id,
-                        Some(developer_id),
-                    ) {
-                        self.status_message =
-                            success(&format!("Assigned {} to module", developer_name));
-                        self.module_manager.assign_mode = false;
-                        if let Some(wd) = self.git_workdir.as_ref() {
-                            let _ = self.store.save_to_json(wd);
-                        }
-                    } else {
-                        self.status_message = error("Failed to assign developer");
-                    }
-                }
-            }
-        }
+    /// Re-sorts the project list, keeping the selected project and merge
+    /// resolutions attached to the same projects.
+    fn perform_cycle_project_sort(&mut self) {
+        let mode = self.dashboard.cycle_sort();
+        let old_ids: Vec<uuid::Uuid> = self.store.projects.iter().map(|p| p.id).collect();
+        crate::data::sort_projects(&mut self.store.projects, mode);
+
+        let new_index = |old: usize| {
+            old_ids
+                .get(old)
+                .and_then(|id| self.store.projects.iter().position(|p| p.id == *id))
+                .unwrap_or(old)
+        };
+        let selected = new_index(self.dashboard.selected_index);
+        self.merge.resolutions = std::mem::take(&mut self.merge.resolutions)
+            .into_iter()
+            .map(|((project, file), pane)| ((new_index(project), file), pane))
+            .collect();
+        self.dashboard.selected_index = selected;
+        crate::ui_utils::auto_scroll(selected, &mut self.dashboard.scroll, WINDOW_SIZE);
+
+        self.status_message = info(&format!("Projects sorted by {}", mode.label()));
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum AppMode {
-    Dashboard,
-    Changes,
+    fn perform_assign_all_unassigned(&mut self) {
+        let Some(developer) = self
+            .store
+            .projects
+            .get(self.dashboard.selected_index)
+            .and_then(|p| p.developers.get(self.module_manager.selected_developer))
+            .map(|d| (d.id, d.name.clone()))
+        else {
+            self.status_message = error("No developer selected");
+            return;
+        };
+        let (developer_id, developer_name) = developer;
+
+        let count = self
+            .store
+            .assign_all_unassigned(self.dashboard.selected_index, developer_id);
+        self.status_message = success(&format!(
+            "Assigned {} unowned module(s) to {}",
+            count, developer_name
+        ));
+        if count > 0 {
+            self.save_store_json();
+        }
+    }
+
+    /// Blame the selected change for the diff annotations, reusing the cached
+    /// result while its diff is unchanged
+    fn refresh_changes_blame(&mut self) {
+        if !self.changes.show_blame || self.current_view != AppMode::Changes {
+            return;
+        }
+        let (Some(client), Some(project)) = (
+            &self.git_client,
+            self.store.projects.get(self.dashboard.selected_index),
+        ) else {
+            return;
+        };
+        let Some(change) = project.changes.get(self.changes.selected_index) else {
+            self.changes.blame_cache = None;
+            return;
+        };
+        if self
+            .changes
+            .blame_cache
+            .as_ref()
+            .is_some_and(|(diff, _)| *diff == change.diff_preview)
+        {
+            return;
+        }
+        // A working-tree diff of a staged file is taken against the index,
+        // so blame what is staged; otherwise the old side is HEAD
+        let blame = if change.staged && change.local_preview.is_some() {
+            client.blame_staged(&change.path)
+        } else {
+            let path = match &change.status {
+                data::FileStatus::Renamed { from, .. } => from,
+                _ => &change.path,
+            };
+            client.blame_file(path)
+        };
+        // Files that were never committed have nothing to attribute
+        let blame = blame.unwrap_or_default();
+        self.changes.blame_cache = Some((change.diff_preview.clone(), blame));
+    }
+
+    /// Add modules from the task list at `path` (relative to the repository root)
+    fn perform_import_modules(&mut self, path: &str) {
+        let full_path = match &self.git_workdir {
+            Some(workdir) => workdir.join(path),
+            None => PathBuf::from(path),
+        };
+        let text = match std::fs::read_to_string(&full_path) {
+            Ok(text) => text,
+            Err(e) => {
+                self.status_message = error(&format!("Cannot read {}: {}", path, e));
+                return;
+            }
+        };
+        let result = match self
+            .store
+            .import_modules_csv(self.dashboard.selected_index, &text)
+        {
+            Ok(result) => result,
+            Err(e) => {
+                self.status_message = error(&format!("Cannot import {}: {}", path, e));
+                return;
+            }
+        };
+
+        let message = format!("Imported {} module(s) from {}", result.imported, path);
+        self.status_message = if !result.warnings.is_empty() {
+            warning(&format!("{} ({})", message, result.warnings.join("; ")))
+        } else {
+            success(&message)
+        };
+        if result.imported > 0 {
+            self.save_store_json();
+            self.save_store_progress();
+        }
+    }
+
+    /// Module highlighted in the Module Manager's list
+    fn selected_module(&self) -> Option<&Module> {
+        self.store
+            .projects
+            .get(self.dashboard.selected_index)?
+            .modules
+            .get(self.module_manager.selected_module)
+    }
+
+    fn perform_set_module_notes(&mut self, notes: &str) {
+        let Some(module) = self.selected_module() else {
+            self.status_message = error("No module selected");
+            return;
+        };
+        let (id, name) = (module.id, module.name.clone());
+        self.store
+            .set_module_notes(self.dashboard.selected_index, id, notes.to_string());
+        self.status_message = if notes.is_empty() {
+            success(&format!("Cleared notes for {}", name))
+        } else {
+            success(&format!("Updated notes for {}", name))
+        };
+        self.save_store_json();
+    }
+
+    /// Set the selected module's progress from the typed percentage
+    fn perform_set_module_progress(&mut self, input: &str) {
+        let Ok(score) = input.trim_end_matches('%').parse::<u32>() else {
+            self.status_message = error("Progress must be a number from 0 to 100");
+            return;
+        };
+        let Some(module) = self.selected_module() else {
+            self.status_message = error("No module selected");
+            return;
+        };
+        let (id, name) = (module.id, module.name.clone());
+        let score = score.min(100) as u8;
+        self.store
+            .set_module_progress(self.dashboard.selected_index, id, score);
+        self.status_message = success(&format!("{} is {}% done", name, score));
+        self.save_module_progress();
+    }
+
+    /// Nudge the selected module's progress up or down
+    fn perform_adjust_module_progress(&mut self, delta: i16) {
+        let Some(module) = self.selected_module() else {
+            self.status_message = error("No module selected");
+            return;
+        };
+        let (id, name) = (module.id, module.name.clone());
+        if let Some(score) =
+            self.store
+                .adjust_module_progress(self.dashboard.selected_index, id, delta)
+        {
+            self.status_message = success(&format!("{} is {}% done", name, score));
+            self.save_module_progress();
+        }
+    }
+
+    /// Persist a progress change to both modules.json and progress.txt
+    fn save_module_progress(&mut self) {
+        self.save_store_json();
+        self.save_store_progress();
+    }
+
+    /// Replace the selected file's truncated preview with its full diff
+    fn perform_expand_diff(&mut self) {
+        let (Some(client), Some(project)) = (
+            &self.git_client,
+            self.store.projects.get_mut(self.dashboard.selected_index),
+        ) else {
+            return;
+        };
+        let Some(change) = project.changes.get_mut(self.changes.selected_index) else {
+            return;
+        };
+        if change.hidden_lines == 0 {
+            self.status_message = info("Diff is already shown in full");
+            return;
+        }
+        let hidden = change.hidden_lines;
+        client.expand_change(change);
+        self.status_message = info(&format!("Showing {} more diff lines", hidden));
+    }
+
+    /// Copy the selected file's diff (or every file's diff) to the clipboard
+    fn perform_copy_diff(&mut self, whole_changeset: bool) {
+        let Some(project) = self.store.projects.get(self.dashboard.selected_index) else {
+            return;
+        };
+        let (text, label) = if whole_changeset {
+            if project.changes.is_empty() {
+                self.status_message = "No changes to copy".into();
+                return;
+            }
+            (
+                ChangesState::changeset_diff(&project.changes),
+                format!("diff of {} files", project.changes.len()),
+            )
+        } else {
+            match (
+                self.changes.selected_diff(&project.changes),
+                project.changes.get(self.changes.selected_index),
+            ) {
+                (Some(diff), Some(change)) => {
+                    (diff.to_string(), format!("diff of {}", change.path))
+                }
+                _ => {
+                    self.status_message = "No file selected".into();
+                    return;
+                }
+            }
+        };
+
+        self.status_message = match clipboard::copy(&text) {
+            Ok(()) => success(&format!("Copied {} to clipboard", label)),
+            Err(e) => error(&format!("Copy failed: {}", e)),
+        };
+    }
+
+    fn perform_open_quick_view(&mut self) {
+        let project = self.store.projects.get(self.dashboard.selected_index);
+        let view = match self.current_view {
+            AppMode::Changes => project
+                .and_then(|p| p.changes.get(self.changes.selected_index))
+                .map(|c| QuickViewState::new(c.path.as_str(), c.diff_preview.as_str())),
+            AppMode::MergeVisualizer => project
+                .and_then(|p| p.changes.get(self.merge.selected_file_index))
+                .map(|c| QuickViewState::new(c.path.as_str(), c.diff_preview.as_str())),
+            AppMode::CommitHistory => {
+                let Some(commit) = self.commit_history.selected_commit() else {
+                    self.status_message = "No commit selected".into();
+                    return;
+                };
+                let Some(client) = &self.git_client else {
+                    return;
+                };
+                match client.commit_diff(&commit.hash) {
+                    Ok(diff) => Some(QuickViewState::new(
+                        format!("{} {}", commit.short(), commit.message),
+                        diff,
+                    )),
+                    Err(e) => {
+                        self.status_message = error(&format!("Failed to load diff: {}", e));
+                        return;
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        match view {
+            Some(view) => self.quick_view = Some(view),
+            None => self.status_message = "No file selected".into(),
+        }
+    }
+
+    fn perform_toggle_module_dependency(&mut self) {
+        let project_idx = self.dashboard.selected_index;
+        let Some(target_id) = self.module_manager.dependency_target else {
+            return;
+        };
+        let Some(project) = self.store.projects.get(project_idx) else {
+            return;
+        };
+        let (Some(target), Some(dependency)) = (
+            project.modules.iter().find(|m| m.id == target_id),
+            project.modules.get(self.module_manager.selected_module),
+        ) else {
+            return;
+        };
+        let (target_name, dependency_id, dependency_name) =
+            (target.name.clone(), dependency.id, dependency.name.clone());
+
+        self.status_message = if target.depends_on.contains(&dependency_id) {
+            self.store
+                .remove_dependency(project_idx, target_id, dependency_id);
+            success(&format!(
+                "{} no longer depends on {}",
+                target_name, dependency_name
+            ))
+        } else if dependency_id == target_id {
+            warning("A module cannot depend on itself")
+        } else if self
+            .store
+            .add_dependency(project_idx, target_id, dependency_id)
+        {
+            success(&format!(
+                "{} now depends on {}",
+                target_name, dependency_name
+            ))
+        } else {
+            warning(&format!(
+                "{} already depends on {}; that would be a cycle",
+                dependency_name, target_name
+            ))
+        };
+        self.save_store_json();
+    }
+
+    fn perform_module_assignment(&mut self) {
+        if let Some(project) = self.store.projects.get(self.dashboard.selected_index) {
+            if let Some(module) = project.modules.get(self.module_manager.selected_module) {
+                let module_id = module.id;
+                let Some(index) = self.module_manager.assign_selection(&project.developers) else {
+                    self.status_message = warning("No developer matches the filter");
+                    return;
+                };
+                if let Some(developer) = project.developers.get(index) {
+                    let developer_id = developer.id;
+                    let developer_name = developer.name.clone();
+                    if self.store.assign_module_owner(
+                        self.dashboard.selected_index,
+                        module_id,
+                        Some(developer_id),
+                    ) {
+                        self.status_message =
+                            success(&format!("Assigned {} to module", developer_name));
+                        let developers = self.store.developers(self.dashboard.selected_index);
+                        self.module_manager.leave_assign_mode(developers);
+                        self.save_store_json();
+                    } else {
+                        self.status_message = error("Failed to assign developer");
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum AppMode {
+    Dashboard,
+    Changes,
     CommitHistory,
     BranchManager,
     MergeVisualizer,
@@ -1346,6 +3672,14 @@ pub enum AppMode {
 }
 
 impl App {
+    fn autosave_label(secs: u64) -> String {
+        match secs {
+            0 => "Off".to_string(),
+            s if s % 60 == 0 => format!("every {}m", s / 60),
+            s => format!("every {}s", s),
+        }
+    }
+
     fn settings_options(&self) -> Vec<String> {
         vec![
             format!(
@@ -1364,9 +3698,59 @@ impl App {
                 }
             ),
             format!(
-                "Autosync: {} (placeholder)",
+                "Autosync: {}",
                 if self.settings.autosync { "On" } else { "Off" }
             ),
+            format!(
+                "Sign commits: {}",
+                if self.settings.sign_commits {
+                    "On"
+                } else {
+                    "Off"
+                }
+            ),
+            format!(
+                "Autosave: {}",
+                Self::autosave_label(self.settings.autosave_secs)
+            ),
+            format!(
+                "Show ignored files: {}",
+                Self::on_off(self.settings.git_options.include_ignored)
+            ),
+            format!(
+                "Show untracked files: {}",
+                Self::on_off(self.settings.git_options.include_untracked)
+            ),
+            format!(
+                "Expand untracked folders: {}",
+                Self::on_off(self.settings.git_options.recurse_untracked)
+            ),
+            format!(
+                "Commit dates: {}",
+                Self::dates_label(self.settings.relative_dates)
+            ),
+            format!("Initial history: {} commits", self.settings.history_limit),
+            format!(
+                "Commit key: {}",
+                Self::commit_key_label(self.settings.commit_requires_modifier)
+            ),
+            format!(
+                "Run commit hooks: {}",
+                Self::on_off(self.settings.run_hooks)
+            ),
+            format!(
+                "Max diff size: {}",
+                Self::diff_size_label(self.settings.git_options.max_diff_bytes)
+            ),
+            format!("Default remote: {}", self.settings.default_remote),
+            format!(
+                "Prune on fetch: {}",
+                Self::on_off(self.settings.git_options.prune_on_fetch)
+            ),
+            format!(
+                "Fetch on startup: {}",
+                Self::on_off(self.settings.auto_fetch)
+            ),
         ]
     }
 }
@@ -1386,6 +3770,23 @@ impl AppMode {
         }
     }
 
+    /// The view listed at `index` in the side menu
+    pub fn from_menu_index(index: usize) -> Option<Self> {
+        use AppMode::*;
+        [
+            Dashboard,
+            Changes,
+            CommitHistory,
+            BranchManager,
+            MergeVisualizer,
+            ProjectBoard,
+            ModuleManager,
+            Settings,
+        ]
+        .get(index)
+        .copied()
+    }
+
     pub fn menu_index(self) -> usize {
         match self {
             AppMode::Dashboard => 0,
@@ -1399,3 +3800,1724 @@ impl AppMode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_panic_hook_restores_terminal() {
+        static RESTORED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+        fn restore() {
+            RESTORED.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+
+        install_panic_restore(restore);
+        let result = std::panic::catch_unwind(|| panic!("render failed"));
+        // Put the default hook back for the rest of the suite
+        let _ = std::panic::take_hook();
+
+        assert!(result.is_err());
+        assert!(RESTORED.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_commit_appends_action_log_entry() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        std::fs::write(dir.path().join("notes.txt"), "hello").expect("Failed to write");
+
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        client.stage_all().expect("Failed to stage");
+
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        app.changes.commit_message = "Add notes".into();
+        app.perform_commit();
+
+        let entry = app.action_log.newest_first().next().expect("No log entry");
+        assert_eq!(entry.action, "Commit");
+        assert!(entry.success, "commit failed: {}", entry.detail);
+        assert_eq!(entry.detail, "Add notes");
+    }
+
+    #[test]
+    fn test_commit_with_nothing_staged_since_reports_nothing_to_commit() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        std::fs::write(dir.path().join("notes.txt"), "hello").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+        let head = client.commit_all("Add notes").expect("Failed to commit");
+        std::fs::write(dir.path().join("notes.txt"), "edited").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        assert!(app.store.projects[0].changes[0].staged);
+        // Reverted behind Forge's back, so the listed change is stale
+        std::fs::write(dir.path().join("notes.txt"), "hello").expect("Failed to write");
+        let client = app.git_client.as_ref().unwrap();
+        client.stage_file("notes.txt").expect("Failed to stage");
+
+        app.changes.commit_message = "Edit notes".into();
+        app.perform_commit();
+        assert_eq!(app.status_message, warning("Nothing to commit"));
+        assert_eq!(app.changes.commit_message, "Edit notes");
+        assert!(!app.store.projects[0].changes.iter().any(|c| c.staged));
+        let client = app.git_client.as_ref().unwrap();
+        assert_eq!(client.resolve_commit("HEAD").unwrap(), head);
+    }
+
+    #[test]
+    fn test_branch_filter_lists_remote_branches_only_when_included() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        let repo = git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let oid = repo
+            .commit(Some("HEAD"), &sig, &sig, "Initial", &tree, &[])
+            .unwrap();
+        repo.reference("refs/remotes/origin/feature", oid, false, "test")
+            .unwrap();
+
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app.current_view = AppMode::BranchManager;
+        let listed = |app: &App| -> Vec<(String, bool)> {
+            app.branch_manager
+                .cached_branches
+                .iter()
+                .map(|b| (b.name.clone(), b.is_remote))
+                .collect()
+        };
+        let local = (app.head.label(), false);
+        let remote = ("origin/feature".to_string(), true);
+
+        app.refresh_view_cache();
+        assert_eq!(listed(&app), std::slice::from_ref(&local));
+
+        app.handle_action(KeyAction::InputChar('R'));
+        assert_eq!(listed(&app), std::slice::from_ref(&remote));
+        assert!(app.status_message.starts_with("Branches (remote)"));
+
+        app.handle_action(KeyAction::InputChar('R'));
+        assert_eq!(listed(&app), [local.clone(), remote]);
+
+        app.handle_action(KeyAction::InputChar('R'));
+        assert!(listed(&app).is_empty());
+        assert!(app.status_message.starts_with("Tags: 0"));
+
+        app.handle_action(KeyAction::InputChar('R'));
+        assert_eq!(listed(&app), [local]);
+    }
+
+    #[test]
+    fn test_changelog_covers_commits_since_the_last_tag() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        for message in [
+            "feat: first",
+            "fix: released",
+            "feat: second",
+            "chore: newest",
+        ] {
+            std::fs::write(dir.path().join("log.txt"), message).expect("Failed to write");
+            client.stage_all().expect("Failed to stage");
+            client.commit_all(message).expect("Failed to commit");
+        }
+        let repo = git2::Repository::open(dir.path()).unwrap();
+        for (name, rev) in [("v1.0", "HEAD~2"), ("v2.0", "HEAD")] {
+            let target = repo.revparse_single(rev).unwrap();
+            repo.tag_lightweight(name, &target, false).unwrap();
+        }
+
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        app.current_view = AppMode::CommitHistory;
+        app.refresh_view_cache();
+        assert_eq!(app.commit_history.cached_commits[0].tags, ["v2.0"]);
+        app.perform_write_changelog();
+        assert!(app.status_message.contains("since v1.0"));
+
+        let changelog = std::fs::read_to_string(dir.path().join(".forge/CHANGELOG.md"))
+            .expect("Failed to read changelog");
+        assert!(changelog.contains("## Features") && changelog.contains("- second ("));
+        assert!(changelog.contains("## Other") && changelog.contains("- chore: newest ("));
+        assert!(!changelog.contains("released") && !changelog.contains("first"));
+    }
+
+    #[test]
+    fn test_changelog_ignores_the_author_filter_and_pages_to_the_tag() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        for message in ["fix: released", "feat: second", "chore: newest"] {
+            std::fs::write(dir.path().join("log.txt"), message).expect("Failed to write");
+            client.stage_all().expect("Failed to stage");
+            client.commit_all(message).expect("Failed to commit");
+        }
+        let repo = git2::Repository::open(dir.path()).unwrap();
+        let target = repo.revparse_single("HEAD~2").unwrap();
+        repo.tag_lightweight("v1.0", &target, false).unwrap();
+
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        // Only the newest commit is loaded, and the filter hides it
+        app.settings.history_limit = 1;
+        app.current_view = AppMode::CommitHistory;
+        app.refresh_view_cache();
+        app.commit_history
+            .set_author_filter(Some("someone else".to_string()));
+        assert_eq!(app.commit_history.commit_count(), 0);
+
+        app.perform_write_changelog();
+        assert!(app.status_message.contains("since v1.0"));
+        let changelog = std::fs::read_to_string(dir.path().join(".forge/CHANGELOG.md"))
+            .expect("Failed to read changelog");
+        assert!(changelog.contains("- second (") && changelog.contains("- chore: newest ("));
+        assert!(!changelog.contains("released"));
+    }
+
+    #[test]
+    fn test_tags_filter_checks_out_and_deletes_tags() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        for i in 0..2 {
+            std::fs::write(dir.path().join("log.txt"), i.to_string()).expect("Failed to write");
+            client.stage_all().expect("Failed to stage");
+            client
+                .commit_all(&format!("Commit {}", i))
+                .expect("Failed to commit");
+        }
+        let repo = git2::Repository::open(dir.path()).unwrap();
+        let first = repo.revparse_single("HEAD~1").unwrap();
+        repo.tag_lightweight("v0.1", &first, false).unwrap();
+
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        app.current_view = AppMode::BranchManager;
+        app.branch_manager.filter = BranchFilter::Tags;
+        app.refresh_view_cache();
+        let tag = app.branch_manager.selected_branch().unwrap();
+        assert_eq!(tag.name, "v0.1");
+        assert!(tag.is_tag);
+
+        app.perform_branch_switch();
+        assert!(app.status_message.contains("detached HEAD"));
+        assert!(repo.head_detached().unwrap());
+        assert_eq!(repo.head().unwrap().target(), Some(first.id()));
+
+        app.perform_branch_delete();
+        assert!(app.status_message.contains("Deleted tag: v0.1"));
+        assert!(app.branch_manager.cached_branches.is_empty());
+        assert!(repo.tag_names(None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_tracked_file_count_refreshes_on_commit() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        std::fs::write(dir.path().join("a.txt"), "a").expect("Failed to write");
+        std::fs::write(dir.path().join("b.txt"), "b").expect("Failed to write");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        client.stage_all().expect("Failed to stage");
+
+        let mut app = App::without_repo();
+        assert_eq!(app.dashboard.tracked_files, None);
+        app.attach_repo(client);
+        assert_eq!(app.dashboard.tracked_files, Some(0));
+
+        app.changes.commit_message = "Add files".into();
+        app.perform_commit();
+        assert_eq!(app.dashboard.tracked_files, Some(2));
+    }
+
+    #[test]
+    fn test_uncommitted_stats_cleared_by_commit() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        std::fs::write(dir.path().join("a.txt"), "1\n2\n3\n").expect("Failed to write");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        client.stage_all().expect("Failed to stage");
+
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        assert_eq!(app.uncommitted, Some((3, 0)));
+
+        app.changes.commit_message = "Add a".into();
+        app.perform_commit();
+        assert_eq!(app.uncommitted, Some((0, 0)));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_run_hooks_setting_blocks_commit_on_failing_hook() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let hook = dir.path().join(".git/hooks/pre-commit");
+        std::fs::create_dir_all(hook.parent().unwrap()).expect("Failed to create hooks dir");
+        std::fs::write(&hook, "#!/bin/sh\necho 'tests failed'\nexit 1\n").expect("Failed to write");
+        std::fs::set_permissions(&hook, std::fs::Permissions::from_mode(0o755))
+            .expect("Failed to make hook executable");
+        std::fs::write(dir.path().join("notes.txt"), "hello").expect("Failed to write");
+
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        client.stage_all().expect("Failed to stage");
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        app.toggle_setting(11);
+        assert!(app.settings.run_hooks);
+
+        app.changes.commit_message = "Add notes".into();
+        app.perform_commit();
+        assert!(app.status_message.contains("tests failed"));
+        assert_eq!(app.changes.commit_message, "Add notes");
+        let entry = app.action_log.newest_first().next().expect("No log entry");
+        assert!(!entry.success);
+    }
+
+    #[test]
+    fn test_assign_picker_assigns_filtered_selection() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app.store.add_module(0, "Parser".into()).unwrap();
+        for name in ["Alice", "Bob", "Carol", "Alicia"] {
+            app.store.add_developer(0, name.into()).unwrap();
+        }
+        app.current_view = AppMode::ModuleManager;
+
+        app.handle_action(KeyAction::InputChar('a'));
+        assert!(app.module_manager.assign_mode);
+        for c in "ali".chars() {
+            app.handle_action(KeyAction::InputChar(c));
+        }
+        assert_eq!(app.module_manager.assign_query, "ali");
+
+        // Down moves within the two matches, never past them
+        app.handle_action(KeyAction::NavigateDown);
+        app.handle_action(KeyAction::NavigateDown);
+        assert_eq!(app.module_manager.selected_developer, 1);
+
+        app.handle_action(KeyAction::Select);
+        let project = &app.store.projects[0];
+        assert_eq!(project.modules[0].owner, Some(project.developers[3].id));
+        assert!(!app.module_manager.assign_mode);
+        assert_eq!(app.module_manager.selected_developer, 3);
+    }
+
+    #[test]
+    fn test_inline_rename_persists_module_and_developer_names() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app.store.add_module(0, "Parser".into()).unwrap();
+        app.store.add_developer(0, "Al".into()).unwrap();
+        app.current_view = AppMode::ModuleManager;
+
+        app.handle_action(KeyAction::Rename);
+        assert!(app.module_manager.rename_target.is_some());
+        assert_eq!(app.module_manager.input_buffer, "Parser");
+        app.handle_action(KeyAction::InputChar('s'));
+        app.handle_action(KeyAction::Select);
+        assert_eq!(app.store.projects[0].modules[0].name, "Parsers");
+        assert!(app.module_manager.rename_target.is_none());
+
+        // Esc leaves the name alone
+        app.handle_action(KeyAction::Rename);
+        app.handle_action(KeyAction::Backspace);
+        app.handle_action(KeyAction::Back);
+        assert_eq!(app.store.projects[0].modules[0].name, "Parsers");
+        assert!(app.module_manager.rename_target.is_none());
+
+        app.handle_action(KeyAction::NavigateRight);
+        assert!(app.module_manager.is_developer_list());
+        app.handle_action(KeyAction::Rename);
+        for c in "ice".chars() {
+            app.handle_action(KeyAction::InputChar(c));
+        }
+        app.handle_action(KeyAction::Select);
+        assert_eq!(app.store.projects[0].developers[0].name, "Alice");
+        let forge = dir.path().join(".forge");
+        let modules = std::fs::read_to_string(forge.join("modules.json")).unwrap();
+        let developers = std::fs::read_to_string(forge.join("developers.json")).unwrap();
+        assert!(modules.contains("Parsers"));
+        assert!(developers.contains("Alice"));
+    }
+
+    #[test]
+    fn test_editor_command_appends_selected_path() {
+        let workdir = std::path::Path::new("/tmp/repo");
+        let command = App::editor_command("fake-editor --wait -n", workdir, "src/lib.rs")
+            .expect("editor is set");
+        assert_eq!(command.get_program(), "fake-editor");
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args, ["--wait", "-n", "src/lib.rs"]);
+        assert_eq!(command.get_current_dir(), Some(workdir));
+
+        // Paths with spaces stay a single argument
+        let command = App::editor_command("vi", workdir, "docs/read me.md").unwrap();
+        assert_eq!(command.get_args().collect::<Vec<_>>(), ["docs/read me.md"]);
+
+        assert!(App::editor_command("", workdir, "a.rs").is_none());
+        assert!(App::editor_command("   ", workdir, "a.rs").is_none());
+    }
+
+    #[test]
+    fn test_open_in_editor_queues_selected_change() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        std::fs::write(dir.path().join("notes.txt"), "hello").expect("Failed to write");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app.current_view = AppMode::Changes;
+
+        app.editor = "fake-editor --wait".into();
+        app.perform_open_in_editor();
+        let command = app.editor_command.take().expect("editor queued");
+        assert_eq!(command.get_program(), "fake-editor");
+        assert_eq!(command.get_args().last().unwrap(), "notes.txt");
+
+        app.editor = String::new();
+        app.perform_open_in_editor();
+        assert!(app.editor_command.is_none());
+        assert!(app.status_message.contains("$EDITOR"));
+    }
+
+    #[test]
+    fn test_refresh_keeps_the_same_file_and_branch_selected() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        std::fs::write(dir.path().join("m.txt"), "m").expect("Failed to write");
+        std::fs::write(dir.path().join("n.txt"), "n").expect("Failed to write");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        client.stage_file("m.txt").expect("Failed to stage");
+        client
+            .commit_all("Initial commit")
+            .expect("Failed to commit");
+        client.create_branch("m-branch").expect("Failed to branch");
+        client.create_branch("n-branch").expect("Failed to branch");
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        let selected_path = |app: &App| {
+            app.store.projects[0].changes[app.changes.selected_index]
+                .path
+                .clone()
+        };
+
+        app.current_view = AppMode::Changes;
+        app.refresh_view_cache();
+        let n = app.store.projects[0]
+            .changes
+            .iter()
+            .position(|c| c.path == "n.txt")
+            .unwrap();
+        app.changes.selected_index = n;
+        std::fs::write(dir.path().join("a.txt"), "a").expect("Failed to write");
+        app.refresh_view_cache();
+        assert_eq!(selected_path(&app), "n.txt");
+        assert_ne!(app.changes.selected_index, n);
+
+        app.current_view = AppMode::BranchManager;
+        app.refresh_view_cache();
+        while app.branch_manager.selected_branch_name() != Some("n-branch") {
+            assert!(app.branch_manager.navigate_down());
+        }
+        let n = app.branch_manager.selected_index;
+        let client = app.git_client.as_ref().unwrap();
+        client.create_branch("a-branch").expect("Failed to branch");
+        app.refresh_view_cache();
+        assert_eq!(app.branch_manager.selected_branch_name(), Some("n-branch"));
+        assert_eq!(app.branch_manager.selected_index, n + 1);
+    }
+
+    #[test]
+    fn test_copy_path_follows_view_selection() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        std::fs::write(dir.path().join("a.txt"), "a").expect("Failed to write");
+        std::fs::write(dir.path().join("b.txt"), "b").expect("Failed to write");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+
+        app.current_view = AppMode::Changes;
+        app.changes.selected_index = 1;
+        assert_eq!(app.selected_file_path(), Some("b.txt"));
+
+        app.current_view = AppMode::MergeVisualizer;
+        app.merge.selected_file_index = 0;
+        assert_eq!(app.selected_file_path(), Some("a.txt"));
+
+        app.merge.selected_file_index = 5;
+        app.perform_copy_path();
+        assert!(app.status_message.contains("No file selected"));
+
+        let client = app.git_client.as_ref().unwrap();
+        client.stage_all().expect("Failed to stage");
+        client.commit_all("Add files").expect("Failed to commit");
+        app.current_view = AppMode::CommitHistory;
+        app.refresh_view_cache();
+        let copied = app.paths_to_copy().expect("commit has files");
+        assert!(copied.lines().any(|line| line == "a.txt"));
+        assert!(copied.lines().any(|line| line == "b.txt"));
+    }
+
+    #[test]
+    fn test_accept_both_combines_sides_and_clears_conflict() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        let repo = git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let commit = |refname: &str, text: &str, parents: &[&git2::Commit]| {
+            std::fs::write(dir.path().join("notes.txt"), text).expect("Failed to write");
+            let mut index = repo.index().unwrap();
+            index.add_path(std::path::Path::new("notes.txt")).unwrap();
+            index.write().unwrap();
+            let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+            let oid = repo
+                .commit(Some(refname), &sig, &sig, text, &tree, parents)
+                .unwrap();
+            repo.find_commit(oid).unwrap()
+        };
+        let base = commit("HEAD", "base\n", &[]);
+        repo.branch("other", &base, false).unwrap();
+        commit("HEAD", "local line\n", &[&base]);
+        let incoming = commit("refs/heads/other", "incoming line\n", &[&base]);
+        repo.checkout_head(Some(git2::build::CheckoutBuilder::new().force()))
+            .unwrap();
+        let annotated = repo.find_annotated_commit(incoming.id()).unwrap();
+        repo.merge(&[&annotated], None, None).unwrap();
+
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app.current_view = AppMode::MergeVisualizer;
+        assert_eq!(app.selected_file_path(), Some("notes.txt"));
+
+        app.perform_accept_both();
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("notes.txt")).unwrap(),
+            "local line\nincoming line\n"
+        );
+        let client = app.git_client.as_ref().unwrap();
+        assert!(client.list_conflicts().unwrap().is_empty());
+        assert_eq!(app.merge.get_resolution(0, 0), Some(MergePaneFocus::Both));
+        assert_eq!(app.status_message, success("Kept both sides of notes.txt"));
+    }
+
+    #[test]
+    fn test_stage_and_advance_skips_staged_files() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        for name in ["a.txt", "b.txt", "c.txt"] {
+            std::fs::write(dir.path().join(name), name).expect("Failed to write");
+        }
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        client.stage_file("b.txt").expect("Failed to stage");
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        app.current_view = AppMode::Changes;
+        let staged = |app: &App| -> Vec<bool> {
+            app.store.projects[0]
+                .changes
+                .iter()
+                .map(|c| c.staged)
+                .collect()
+        };
+        assert_eq!(app.selected_file_path(), Some("a.txt"));
+
+        app.perform_stage_and_advance();
+        assert_eq!(staged(&app), [true, true, false]);
+        assert_eq!(app.selected_file_path(), Some("c.txt"));
+
+        app.perform_stage_and_advance();
+        assert_eq!(staged(&app), [true, true, true]);
+        assert_eq!(app.selected_file_path(), Some("c.txt"));
+        assert!(app.status_message.contains("all changes staged"));
+    }
+
+    #[test]
+    fn test_multi_select_stages_ticked_files() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        for name in ["a.txt", "b.txt", "c.txt"] {
+            std::fs::write(dir.path().join(name), name).expect("Failed to write");
+        }
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        app.current_view = AppMode::Changes;
+        app.focus = Focus::View;
+        let staged = |app: &App| -> Vec<bool> {
+            app.store.projects[0]
+                .changes
+                .iter()
+                .map(|c| c.staged)
+                .collect()
+        };
+
+        app.handle_action(KeyAction::InputChar('V'));
+        app.handle_action(KeyAction::ToggleStaging);
+        app.handle_action(KeyAction::NavigateDown);
+        app.handle_action(KeyAction::NavigateDown);
+        app.handle_action(KeyAction::ToggleStaging);
+        assert!(app.status_message.contains("2 ticked"));
+        // Ticking stages nothing until Enter
+        assert_eq!(staged(&app), [false, false, false]);
+
+        app.perform_stage_selected();
+        assert_eq!(staged(&app), [true, false, true]);
+        assert_eq!(app.status_message, success("Staged 2 file(s)"));
+        assert!(app.changes.multi_select.as_ref().unwrap().is_empty());
+
+        app.perform_stage_selected();
+        assert!(app.status_message.contains("No files ticked"));
+
+        app.handle_action(KeyAction::Back);
+        assert_eq!(app.changes.multi_select, None);
+        assert_eq!(app.focus, Focus::View);
+    }
+
+    #[test]
+    fn test_ticked_files_follow_their_paths_across_a_reload() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        for name in ["b.txt", "c.txt"] {
+            std::fs::write(dir.path().join(name), name).expect("Failed to write");
+        }
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app.current_view = AppMode::Changes;
+        app.focus = Focus::View;
+        app.handle_action(KeyAction::InputChar('V'));
+        app.handle_action(KeyAction::NavigateDown);
+        app.handle_action(KeyAction::ToggleStaging);
+
+        // A new file sorts ahead of the ticked one, shifting it down a row
+        std::fs::write(dir.path().join("a.txt"), "a").expect("Failed to write");
+        app.refresh_view_cache();
+        app.perform_stage_selected();
+        let staged: Vec<&str> = app.store.projects[0]
+            .changes
+            .iter()
+            .filter(|c| c.staged)
+            .map(|c| c.path.as_str())
+            .collect();
+        assert_eq!(staged, ["c.txt"]);
+
+        // Ticks never carry over into another repository's list
+        let ticked = |app: &App| app.changes.selected_paths(&app.store.projects[0].changes);
+        app.handle_action(KeyAction::ToggleStaging);
+        assert_eq!(ticked(&app).len(), 1);
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        assert!(ticked(&app).is_empty());
+    }
+
+    #[test]
+    fn test_entering_view_from_menu_clamps_stale_selection() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        std::fs::write(dir.path().join("a.txt"), "a").expect("Failed to write");
+        std::fs::write(dir.path().join("b.txt"), "b").expect("Failed to write");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app.changes.selected_index = 7;
+        app.merge.selected_file_index = 9;
+
+        app.focus = Focus::Menu;
+        app.menu_selected_index = AppMode::Changes.menu_index();
+        app.handle_action(KeyAction::Select);
+        assert_eq!(app.current_view, AppMode::Changes);
+        assert_eq!(app.focus, Focus::View);
+        assert_eq!(app.changes.selected_index, 1);
+        assert_eq!(app.merge.selected_file_index, 1);
+        assert_eq!(app.selected_file_path(), Some("b.txt"));
+
+        app.store.add_module(0, "Parser".into()).unwrap();
+        app.module_manager.selected_module = 4;
+        app.focus = Focus::Menu;
+        app.menu_selected_index = AppMode::ModuleManager.menu_index();
+        app.handle_action(KeyAction::Select);
+        assert_eq!(app.current_view, AppMode::ModuleManager);
+        assert_eq!(app.module_manager.selected_module, 0);
+    }
+
+    #[test]
+    fn test_board_grouped_by_developer() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        let first = app.store.add_module(0, "Parser".into()).unwrap();
+        let second = app.store.add_module(0, "Lexer".into()).unwrap();
+        let alice = app.store.add_developer(0, "Alice".into()).unwrap();
+        app.store.assign_module_owner(0, second, Some(alice));
+        app.current_view = AppMode::ProjectBoard;
+        app.focus = Focus::View;
+
+        app.handle_action(KeyAction::InputChar('g'));
+        assert_eq!(app.board.group_by, state::GroupBy::Developer);
+        let developer_count = app.store.projects[0].developers.len();
+        assert_eq!(app.board_column_lens().len(), developer_count + 1);
+
+        // Left from the first column wraps to "Unassigned", which holds Parser
+        app.handle_action(KeyAction::NavigateLeft);
+        assert_eq!(app.board.selected_column, developer_count);
+        assert!(app.status_message.contains("Unassigned"));
+        app.handle_action(KeyAction::Select);
+        let project = &app.store.projects[0];
+        let parser = project.modules.iter().find(|m| m.id == first).unwrap();
+        let lexer = project.modules.iter().find(|m| m.id == second).unwrap();
+        assert_eq!(parser.status, ModuleStatus::Current);
+        assert_eq!(lexer.status, ModuleStatus::Pending);
+        // The move is recorded in the module's timeline
+        let timeline: Vec<ModuleStatus> = project
+            .module_timeline(first)
+            .map(|(status, _)| status)
+            .collect();
+        assert_eq!(timeline, [ModuleStatus::Current]);
+        assert_eq!(project.module_timeline(second).count(), 0);
+    }
+
+    #[test]
+    fn test_board_move_timeline_survives_a_restart() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        assert!(!app.settings.autosync);
+        let parser = app.store.add_module(0, "Parser".into()).unwrap();
+        app.current_view = AppMode::ProjectBoard;
+        app.focus = Focus::View;
+        app.board.selected_column = 0;
+        app.handle_action(KeyAction::Select);
+
+        let mut reopened = App::without_repo();
+        reopened.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        let timeline: Vec<ModuleStatus> = reopened.store.projects[0]
+            .module_timeline(parser)
+            .map(|(status, _)| status)
+            .collect();
+        assert_eq!(timeline, [ModuleStatus::Current]);
+    }
+
+    #[test]
+    fn test_module_progress_edit_persists() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app.store.add_module(0, "Parser".into()).unwrap();
+        app.current_view = AppMode::ModuleManager;
+
+        app.handle_action(KeyAction::InputChar('+'));
+        assert_eq!(app.store.projects[0].modules[0].progress_score, 5);
+
+        app.apply_action_updates(ActionStateUpdate {
+            text_prompt_open: Some(PromptKind::ModuleProgress),
+            ..Default::default()
+        });
+        assert_eq!(app.prompt.as_ref().unwrap().input, "5");
+        app.prompt.as_mut().unwrap().input = "72".into();
+        app.apply_action_updates(ActionStateUpdate {
+            text_prompt_submit: Some(()),
+            ..Default::default()
+        });
+        assert_eq!(app.store.projects[0].modules[0].progress_score, 72);
+
+        let mut reloaded = data::Store::new();
+        reloaded.projects.push(app.store.projects[0].clone());
+        reloaded.projects[0].modules[0].progress_score = 0;
+        reloaded.load_from_json(dir.path()).unwrap();
+        assert_eq!(reloaded.projects[0].modules[0].progress_score, 72);
+
+        // Non-numbers are rejected without touching the value
+        app.perform_set_module_progress("lots");
+        assert!(app.status_message.contains("number from 0 to 100"));
+        assert_eq!(app.store.projects[0].modules[0].progress_score, 72);
+    }
+
+    #[test]
+    fn test_last_view_restores_board_selection() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let open_app = || {
+            let mut app = App::without_repo();
+            app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+            for name in ["Parser", "Lexer"] {
+                let id = app.store.add_module(0, name.into()).unwrap();
+                app.store.set_module_status(0, id, ModuleStatus::Completed);
+            }
+            app
+        };
+
+        let mut app = open_app();
+        app.current_view = AppMode::ProjectBoard;
+        app.board.selected_column = 2;
+        app.board.selected_item = 1;
+        app.save_last_view();
+
+        let mut reopened = open_app();
+        reopened.restore_last_view();
+        assert_eq!(reopened.current_view, AppMode::ProjectBoard);
+        assert_eq!(
+            reopened.menu_selected_index,
+            AppMode::ProjectBoard.menu_index()
+        );
+        assert_eq!(reopened.board.selected_column, 2);
+        assert_eq!(reopened.board.selected_item, 1);
+
+        // Data that shrank since the last session clamps the stale indices
+        LastView {
+            view: AppMode::ProjectBoard,
+            project: 4,
+            column: 7,
+            item: 9,
+        }
+        .save(&dir.path().join(".git/forge"))
+        .unwrap();
+        let mut reopened = open_app();
+        reopened.restore_last_view();
+        assert_eq!(reopened.current_view, AppMode::ProjectBoard);
+        assert_eq!(reopened.dashboard.selected_index, 0);
+        assert_eq!(reopened.board.selected_column, 0);
+        assert_eq!(reopened.board.selected_item, 0);
+    }
+
+    #[test]
+    fn test_my_commits_toggle_prefers_configured_email() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        let repo = git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Alice").unwrap();
+        config.set_str("user.email", "alice@example.com").unwrap();
+
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        let commit = |hash: &str, author: &str, email: &str| CommitInfo {
+            hash: hash.into(),
+            author: author.into(),
+            author_email: email.into(),
+            date: "2026-01-01".into(),
+            message: "Change".into(),
+            files_changed: Vec::new(),
+            timestamp: 0,
+            short_hash: String::new(),
+            tags: Vec::new(),
+        };
+        app.commit_history.update_commits(vec![
+            commit("a1", "Alice", "alice@example.com"),
+            commit("b2", "Alice", "alice@elsewhere.org"),
+            commit("a3", "A. Smith", "ALICE@example.com"),
+        ]);
+
+        app.perform_toggle_my_commits();
+        let hashes: Vec<&str> = app
+            .commit_history
+            .cached_commits
+            .iter()
+            .map(|c| c.hash.as_str())
+            .collect();
+        assert_eq!(hashes, ["a1", "a3"]);
+    }
+
+    #[test]
+    fn test_my_commits_toggle_uses_configured_name() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        let repo = git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Alice").unwrap();
+        // A blank email shadows any global one, so the name is matched
+        config.set_str("user.email", "").unwrap();
+
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        let commit = |hash: &str, author: &str| CommitInfo {
+            hash: hash.into(),
+            author: author.into(),
+            author_email: String::new(),
+            date: "2026-01-01".into(),
+            message: "Change".into(),
+            files_changed: Vec::new(),
+            timestamp: 0,
+            short_hash: String::new(),
+            tags: Vec::new(),
+        };
+        app.commit_history.update_commits(vec![
+            commit("a1", "Alice"),
+            commit("b2", "Bob"),
+            commit("a3", "Alice Smith"),
+        ]);
+        app.commit_history.selected_index = 2;
+
+        app.perform_toggle_my_commits();
+        assert_eq!(app.commit_history.author_filter.as_deref(), Some("Alice"));
+        assert_eq!(app.commit_history.commit_count(), 2);
+        assert_eq!(app.commit_history.selected_index, 1);
+
+        app.perform_toggle_my_commits();
+        assert_eq!(app.commit_history.commit_count(), 3);
+    }
+
+    #[test]
+    fn test_resize_reclamps_scroll_offset() {
+        let mut app = App::without_repo();
+        app.current_view = AppMode::BranchManager;
+        app.branch_manager.cached_branches = (0..30)
+            .map(|i| BranchInfo {
+                name: format!("branch-{}", i),
+                is_current: i == 0,
+                is_remote: false,
+                is_unpushed: false,
+                is_tag: false,
+                ahead_behind: None,
+            })
+            .collect();
+        app.branch_manager.selected_index = 20;
+        app.branch_manager.scroll = 11;
+        app.no_repo_banner_dismissed = true;
+
+        // 12 rows leave room for 7 branches, so 20 is only visible from 14 on
+        app.handle_action(KeyAction::Resize(80, 12));
+        assert_eq!(app.branch_manager.scroll, 14);
+
+        // Growing again keeps the selection where it is
+        app.fit_scroll_to_height(40);
+        assert_eq!(app.branch_manager.scroll, 14);
+
+        // The no-repository banner costs the list a row
+        app.no_repo_banner_dismissed = false;
+        app.fit_scroll_to_height(12);
+        assert_eq!(app.branch_manager.scroll, 15);
+    }
+
+    #[test]
+    fn test_resize_fits_commit_history_to_its_density() {
+        let mut app = App::without_repo();
+        app.no_repo_banner_dismissed = true;
+        app.current_view = AppMode::CommitHistory;
+        app.commit_history.update_commits(
+            (0..30)
+                .map(|i| CommitInfo {
+                    hash: format!("{:07}", i),
+                    author: "Ada".into(),
+                    author_email: String::new(),
+                    date: "2026-01-01".into(),
+                    message: "Change".into(),
+                    files_changed: Vec::new(),
+                    timestamp: 0,
+                    short_hash: String::new(),
+                    tags: Vec::new(),
+                })
+                .collect(),
+        );
+        app.commit_history.selected_index = 20;
+
+        // 13 rows hold 8 lines: four two-line commits, or eight compact ones
+        app.fit_scroll_to_height(13);
+        assert_eq!(app.commit_history.scroll, 17);
+
+        app.settings.compact_commits = true;
+        app.commit_history.scroll = 0;
+        app.fit_scroll_to_height(13);
+        assert_eq!(app.commit_history.scroll, 13);
+    }
+
+    #[test]
+    fn test_squash_from_commit_history() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        for i in 0..4 {
+            std::fs::write(dir.path().join("log.txt"), i.to_string()).expect("Failed to write");
+            client.stage_all().expect("Failed to stage");
+            client
+                .commit_all(&format!("Commit {}", i))
+                .expect("Failed to commit");
+        }
+
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        app.current_view = AppMode::CommitHistory;
+        app.refresh_view_cache();
+        // Newest first: select "Commit 1" to squash it with the two above it
+        app.commit_history.selected_index = 2;
+
+        app.apply_action_updates(ActionStateUpdate {
+            text_prompt_open: Some(PromptKind::SquashCommits),
+            ..Default::default()
+        });
+        assert_eq!(app.prompt.as_ref().unwrap().input, "Commit 1");
+        app.apply_action_updates(ActionStateUpdate {
+            text_prompt_submit: Some(()),
+            ..Default::default()
+        });
+
+        let messages: Vec<&str> = app
+            .commit_history
+            .cached_commits
+            .iter()
+            .map(|c| c.message.as_str())
+            .collect();
+        assert_eq!(messages, ["Commit 1", "Commit 0"]);
+        assert!(app.status_message.contains("Squashed 3 commits"));
+    }
+
+    #[test]
+    fn test_go_to_revision_pages_in_commit() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        for i in 0..4 {
+            std::fs::write(dir.path().join("log.txt"), i.to_string()).expect("Failed to write");
+            client.stage_all().expect("Failed to stage");
+            client
+                .commit_all(&format!("Commit {}", i))
+                .expect("Failed to commit");
+        }
+
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        // Only the newest commit is loaded up front
+        app.settings.history_limit = 1;
+        app.current_view = AppMode::Dashboard;
+
+        app.perform_go_to_ref("HEAD~3");
+        assert_eq!(app.current_view, AppMode::CommitHistory);
+        assert_eq!(app.previous_view, Some(AppMode::Dashboard));
+        let selected = app.commit_history.selected_commit().unwrap();
+        assert_eq!(selected.message, "Commit 0");
+        assert!(app.status_message.contains("Jumped to"));
+
+        app.perform_go_to_ref("no-such-ref");
+        assert_eq!(
+            app.commit_history.selected_commit().unwrap().message,
+            "Commit 0"
+        );
+        assert!(app
+            .status_message
+            .contains("Unknown revision 'no-such-ref'"));
+    }
+
+    #[test]
+    fn test_reword_from_commit_history() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        for i in 0..3 {
+            std::fs::write(dir.path().join("log.txt"), i.to_string()).expect("Failed to write");
+            client.stage_all().expect("Failed to stage");
+            client
+                .commit_all(&format!("Commit {}", i))
+                .expect("Failed to commit");
+        }
+
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        app.current_view = AppMode::CommitHistory;
+        app.refresh_view_cache();
+        app.commit_history.selected_index = 1;
+
+        app.apply_action_updates(ActionStateUpdate {
+            text_prompt_open: Some(PromptKind::RewordCommit),
+            ..Default::default()
+        });
+        assert_eq!(app.prompt.as_ref().unwrap().input, "Commit 1");
+        app.prompt.as_mut().unwrap().input = "Fixed message".into();
+        app.apply_action_updates(ActionStateUpdate {
+            text_prompt_submit: Some(()),
+            ..Default::default()
+        });
+
+        let messages: Vec<&str> = app
+            .commit_history
+            .cached_commits
+            .iter()
+            .map(|c| c.message.as_str())
+            .collect();
+        assert_eq!(messages, ["Commit 2", "Fixed message", "Commit 0"]);
+        assert!(app.status_message.contains("Reworded commit"));
+    }
+
+    #[test]
+    fn test_history_limit_bounds_initial_fetch() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        for i in 0..15 {
+            std::fs::write(dir.path().join("log.txt"), i.to_string()).expect("Failed to write");
+            client.stage_all().expect("Failed to stage");
+            client
+                .commit_all(&format!("Commit {}", i))
+                .expect("Failed to commit");
+        }
+
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        assert_eq!(app.settings.history_limit, 100);
+        // 100 -> 250 -> 500 -> 1000 -> 10
+        for _ in 0..4 {
+            app.toggle_setting(9);
+        }
+        assert_eq!(app.settings.history_limit, 10);
+
+        app.current_view = AppMode::CommitHistory;
+        app.refresh_view_cache();
+        assert_eq!(app.commit_history.cached_commits.len(), 10);
+        assert!(app.commit_history.has_more);
+
+        // The choice is saved, and hand-edited values are pulled into range
+        let state_dir = dir.path().join(".git/forge");
+        let saved = AppSettings::load(&state_dir).expect("settings were saved");
+        assert_eq!(saved.history_limit, 10);
+        let path = AppSettings::path(&state_dir);
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(
+            &path,
+            text.replace("\"history_limit\": 10", "\"history_limit\": 5000"),
+        )
+        .unwrap();
+        assert_eq!(AppSettings::load(&state_dir).unwrap().history_limit, 1000);
+    }
+
+    #[test]
+    fn test_max_diff_size_setting_relists_changes() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let client = git::GitClient::discover(dir.path()).expect("Failed to open repo");
+        std::fs::write(dir.path().join("data.txt"), "small\n").expect("Failed to write");
+        client.stage_all().expect("Failed to stage");
+        client.commit_all("Add data").expect("Failed to commit");
+        std::fs::write(dir.path().join("data.txt"), "row\n".repeat(100_000))
+            .expect("Failed to write");
+
+        let mut app = App::without_repo();
+        app.attach_repo(client);
+        let preview = |app: &App| {
+            app.store.projects[0]
+                .changes
+                .iter()
+                .find(|change| change.path == "data.txt")
+                .map(|change| change.diff_preview.clone())
+                .unwrap_or_default()
+        };
+        assert!(app.settings_options()[12].ends_with("1 MB"));
+        assert!(preview(&app).contains("+row"));
+
+        // 1 MB -> 4 MB -> 16 MB -> Unlimited -> 256 KB
+        for _ in 0..4 {
+            app.toggle_setting(12);
+        }
+        assert!(app.settings_options()[12].ends_with("256 KB"));
+        assert_eq!(preview(&app), "File too large to diff (400000 bytes)");
+    }
+
+    /// App attached to a fresh repository with `remotes` configured, pointing
+    /// at paths that do not exist so fetches fail quickly
+    fn app_with_remotes(dir: &TempDir, remotes: &[&str]) -> App {
+        let repo = git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        for name in remotes {
+            let url = dir.path().join("missing").join(name);
+            repo.remote(name, &url.to_string_lossy())
+                .expect("Failed to add remote");
+        }
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app
+    }
+
+    #[test]
+    fn test_auto_fetch_setting_gates_startup_fetch() {
+        let dir = TempDir::new().expect("Failed to create temp dir");
+        let mut app = app_with_remotes(&dir, &["origin"]);
+        app.auto_fetch_on_startup();
+        assert!(app.pending_git_ops.is_empty());
+
+        app.toggle_setting(15);
+        assert!(app.settings_options()[15].ends_with("On"));
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        assert!(app.settings.auto_fetch);
+        app.auto_fetch_on_startup();
+        assert_eq!(
+            app.pending_git_ops,
+            vec![GitOperation::Fetch("origin".to_string())]
+        );
+        // Let the fetch fail before the repository is deleted
+        while app.task_manager.try_recv().is_none() {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn test_auto_fetch_skips_a_missing_default_remote() {
+        let dir = TempDir::new().expect("Failed to create temp dir");
+        let mut app = app_with_remotes(&dir, &[]);
+        app.settings.auto_fetch = true;
+        app.auto_fetch_on_startup();
+        assert!(app.pending_git_ops.is_empty());
+    }
+
+    #[test]
+    fn test_clone_opens_the_cloned_repository() {
+        let source = TempDir::new().expect("Failed to create temp dir");
+        git2::Repository::init(source.path()).expect("Failed to initialize repo");
+        std::fs::write(source.path().join("README.md"), "hello").expect("Failed to write");
+        let client = git::GitClient::discover(source.path()).expect("Failed to open repo");
+        client.stage_all().expect("Failed to stage");
+        client
+            .commit_all("Initial commit")
+            .expect("Failed to commit");
+
+        // Unsaved work in the repository open before the clone
+        let home = TempDir::new().expect("Failed to create temp dir");
+        git2::Repository::init(home.path()).expect("Failed to initialize repo");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(home.path()).expect("Failed to open repo"));
+        app.store.add_module(0, "Parser".into()).unwrap();
+        assert!(app.store.is_dirty());
+        app.dashboard.selected_index = 2;
+
+        let target = TempDir::new().expect("Failed to create temp dir");
+        let url = source.path().join(".git").to_string_lossy().into_owned();
+        app.clone_repo_into(&url, target.path());
+        assert_eq!(app.pending_git_ops.len(), 1);
+        while !app.pending_git_ops.is_empty() {
+            std::thread::sleep(Duration::from_millis(10));
+            app.poll_background_tasks();
+        }
+
+        let dest = target.path().join(git::clone_dir_name(&url));
+        assert_eq!(app.git_workdir.as_deref(), Some(dest.as_path()));
+        assert!(app.git_client.is_some());
+        assert!(dest.join("README.md").exists());
+        assert_eq!(app.dashboard.selected_index, 0);
+        let saved = std::fs::read_to_string(home.path().join(".git/forge/progress.txt"))
+            .expect("Failed to read progress");
+        assert!(saved.contains("Parser"));
+
+        // A second clone would overwrite the first, so it is refused
+        app.clone_repo_into(&url, target.path());
+        assert!(app.pending_git_ops.is_empty());
+        assert!(app.status_message.contains("already exists"));
+    }
+
+    #[test]
+    fn test_default_remote_cycles_through_configured_remotes() {
+        let dir = TempDir::new().expect("Failed to create temp dir");
+        let mut app = app_with_remotes(&dir, &["origin", "upstream"]);
+        assert!(app.settings_options()[13].ends_with("origin"));
+
+        app.toggle_setting(13);
+        assert_eq!(app.settings.default_remote, "upstream");
+        app.toggle_setting(14);
+        assert!(app.settings.git_options.prune_on_fetch);
+
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        assert_eq!(app.settings.default_remote, "upstream");
+        assert!(app.settings_options()[14].ends_with("On"));
+        app.toggle_setting(13);
+        assert_eq!(app.settings.default_remote, "origin");
+    }
+
+    #[test]
+    fn test_no_repo_banner_shows_only_without_a_repository() {
+        let rendered = |app: &mut App| ui_utils::render_to_text(100, 30, |frame| app.render(frame));
+
+        let mut app = App::without_repo();
+        for view in [AppMode::Dashboard, AppMode::ProjectBoard, AppMode::Settings] {
+            app.current_view = view;
+            let text = rendered(&mut app);
+            assert!(
+                text.contains(screen::NO_REPO_BANNER),
+                "no banner in {:?}",
+                view
+            );
+        }
+        app.handle_action(KeyAction::DismissBanner);
+        assert!(!rendered(&mut app).contains(screen::NO_REPO_BANNER));
+
+        let dir = TempDir::new().expect("Failed to create temp dir");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        assert!(!rendered(&mut app).contains(screen::NO_REPO_BANNER));
+    }
+
+    #[test]
+    fn test_alternate_view_toggles_last_two_views() {
+        let mut app = App::without_repo();
+        for view in [AppMode::ProjectBoard, AppMode::Changes] {
+            app.apply_action_updates(ActionStateUpdate {
+                current_view: Some(view),
+                ..Default::default()
+            });
+        }
+
+        app.handle_action(KeyAction::AlternateView);
+        assert_eq!(app.current_view, AppMode::ProjectBoard);
+        assert_eq!(app.menu_selected_index, AppMode::ProjectBoard.menu_index());
+        app.handle_action(KeyAction::AlternateView);
+        assert_eq!(app.current_view, AppMode::Changes);
+        app.handle_action(KeyAction::AlternateView);
+        assert_eq!(app.current_view, AppMode::ProjectBoard);
+    }
+
+    #[test]
+    fn test_count_prefix_repeats_navigation() {
+        let mut app = App::without_repo();
+        app.current_view = AppMode::Settings;
+        app.focus = Focus::View;
+
+        app.handle_action(KeyAction::InputChar('3'));
+        app.handle_action(KeyAction::NavigateDown);
+        assert_eq!(app.selected_setting_index, 3);
+
+        // The count is used up by one move
+        app.handle_action(KeyAction::NavigateDown);
+        assert_eq!(app.selected_setting_index, 4);
+
+        // Multi-digit counts, and ticks don't interrupt them
+        app.handle_action(KeyAction::InputChar('1'));
+        app.handle_action(KeyAction::Tick);
+        app.handle_action(KeyAction::InputChar('0'));
+        app.handle_action(KeyAction::NavigateUp);
+        assert_eq!(app.selected_setting_index, 0);
+
+        // Any other key drops a half-typed count
+        app.handle_action(KeyAction::InputChar('5'));
+        app.handle_action(KeyAction::Help);
+        app.handle_action(KeyAction::NavigateDown);
+        assert_eq!(app.selected_setting_index, 1);
+    }
+
+    #[test]
+    fn test_commit_key_setting_guards_enter() {
+        let mut app = App::without_repo();
+        app.current_view = AppMode::Changes;
+        app.focus = Focus::View;
+        app.changes.commit_message = "wip".into();
+
+        app.toggle_setting(10);
+        assert!(app.settings.commit_requires_modifier);
+        assert!(app.settings_options()[10].ends_with("Ctrl+Enter"));
+
+        app.handle_action(KeyAction::Select);
+        assert_eq!(app.changes.commit_message, "wip");
+
+        // Without a repository the commit is a mock that clears the message
+        app.handle_action(KeyAction::QuickCommit);
+        assert!(app.changes.commit_message.is_empty());
+    }
+
+    #[test]
+    fn test_commit_dates_toggle_uses_stored_timestamp() {
+        let mut app = App::without_repo();
+        app.current_view = AppMode::CommitHistory;
+        app.apply_action_updates(ActionStateUpdate {
+            commit_dates_toggle: Some(()),
+            ..Default::default()
+        });
+        assert!(app.settings.relative_dates);
+        assert!(app.settings_options()[8].ends_with("Relative"));
+
+        // 2024-01-01 00:00:00 UTC, viewed three hours later
+        let commit = CommitInfo {
+            hash: "a1".into(),
+            author: "Alice".into(),
+            author_email: String::new(),
+            date: "2024-01-01 00:00:00".into(),
+            message: "Change".into(),
+            files_changed: Vec::new(),
+            timestamp: 1_704_067_200,
+            short_hash: String::new(),
+            tags: Vec::new(),
+        };
+        let now = commit.timestamp + 3 * 3_600;
+        assert_eq!(
+            commit.display_date(app.settings.relative_dates, now),
+            "3h ago"
+        );
+
+        app.toggle_setting(8);
+        assert!(!app.settings.relative_dates);
+        assert_eq!(
+            commit.display_date(app.settings.relative_dates, now),
+            "2024-01-01 00:00:00"
+        );
+    }
+
+    #[test]
+    fn test_commit_density_toggle_is_saved() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        assert!(!app.settings.compact_commits);
+
+        app.apply_action_updates(ActionStateUpdate {
+            commit_density_toggle: Some(()),
+            ..Default::default()
+        });
+        assert!(app.settings.compact_commits);
+        let saved = AppSettings::load(&dir.path().join(".git/forge")).unwrap();
+        assert!(saved.compact_commits);
+    }
+
+    #[test]
+    fn test_setting_hotkeys_toggle_their_setting() {
+        use pages::settings::{COMMIT_DATES_SETTING, NOTIFICATIONS_SETTING, THEME_SETTING};
+
+        let mut app = App::without_repo();
+        app.current_view = AppMode::Dashboard;
+        app.selected_setting_index = 5;
+        let before = app.settings.clone();
+
+        app.handle_action(KeyAction::ToggleSetting(THEME_SETTING));
+        assert_eq!(app.settings.theme, Theme::HighContrast);
+        app.handle_action(KeyAction::ToggleSetting(NOTIFICATIONS_SETTING));
+        assert_eq!(app.settings.notifications, !before.notifications);
+        app.handle_action(KeyAction::ToggleSetting(COMMIT_DATES_SETTING));
+        assert_eq!(app.settings.relative_dates, !before.relative_dates);
+
+        // The highlighted Settings row is left alone
+        assert_eq!(
+            app.settings.git_options.include_ignored,
+            before.git_options.include_ignored
+        );
+        assert_eq!(app.selected_setting_index, 5);
+        assert_eq!(app.current_view, AppMode::Dashboard);
+    }
+
+    #[test]
+    fn test_show_ignored_setting_relists_changes() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        std::fs::write(dir.path().join(".gitignore"), "*.log\n").expect("Failed to write");
+        std::fs::write(dir.path().join("debug.log"), "noise").expect("Failed to write");
+
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        let paths = |app: &App| -> Vec<String> {
+            app.store.projects[0]
+                .changes
+                .iter()
+                .map(|c| c.path.clone())
+                .collect()
+        };
+        assert_eq!(paths(&app), [".gitignore"]);
+
+        app.toggle_setting(5);
+        assert!(app.settings.git_options.include_ignored);
+        assert!(paths(&app).contains(&"debug.log".to_string()));
+        assert!(app.settings_options()[5].ends_with("On"));
+
+        app.toggle_setting(5);
+        assert_eq!(paths(&app), [".gitignore"]);
+    }
+
+    #[test]
+    fn test_branch_picker_checks_out_filtered_branch() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        let repo = git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let oid = repo
+            .commit(Some("HEAD"), &sig, &sig, "Initial", &tree, &[])
+            .unwrap();
+        let head = repo.find_commit(oid).unwrap();
+        for name in ["feature/login", "fix/typo"] {
+            repo.branch(name, &head, false).unwrap();
+        }
+
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app.current_view = AppMode::Settings;
+
+        app.apply_action_updates(ActionStateUpdate {
+            branch_picker_requested: Some(()),
+            ..Default::default()
+        });
+        assert_eq!(app.branch_picker.as_ref().unwrap().branches.len(), 3);
+        for c in "fxt".chars() {
+            app.apply_action_updates(ActionStateUpdate {
+                branch_picker_append: Some(c),
+                ..Default::default()
+            });
+        }
+        app.apply_action_updates(ActionStateUpdate {
+            branch_picker_checkout: Some(()),
+            ..Default::default()
+        });
+
+        assert!(app.branch_picker.is_none());
+        let entry = app.action_log.newest_first().next().expect("No log entry");
+        assert!(entry.success, "checkout failed: {}", entry.detail);
+        assert_eq!(repo.head().unwrap().shorthand(), Some("fix/typo"));
+        assert_eq!(app.current_view, AppMode::Settings);
+    }
+
+    #[test]
+    fn test_switch_to_linked_worktree() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        let repo = git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "Initial", &tree, &[])
+            .unwrap();
+        let linked_dir = TempDir::new().expect("Failed to create temp directory");
+        let linked_path = linked_dir.path().join("hotfix");
+        repo.worktree("hotfix", &linked_path, None)
+            .expect("Failed to add worktree");
+
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app.apply_action_updates(ActionStateUpdate {
+            worktrees_requested: Some(()),
+            ..Default::default()
+        });
+        let list = app.worktrees.as_ref().expect("worktree list");
+        assert_eq!(list.worktrees.len(), 2);
+        assert_eq!(list.current, Some(0));
+
+        app.apply_action_updates(ActionStateUpdate {
+            worktrees_move: Some(1),
+            ..Default::default()
+        });
+        app.apply_action_updates(ActionStateUpdate {
+            worktree_switch: Some(()),
+            ..Default::default()
+        });
+
+        assert!(app.worktrees.is_none());
+        assert_eq!(
+            app.git_workdir.as_ref().unwrap().canonicalize().unwrap(),
+            linked_path.canonicalize().unwrap()
+        );
+        assert_eq!(app.store.projects[0].branch, "hotfix");
+    }
+
+    #[test]
+    fn test_linked_worktree_saves_progress_in_common_dir() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        let repo = git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "Initial", &tree, &[])
+            .unwrap();
+        let linked_dir = TempDir::new().expect("Failed to create temp directory");
+        let linked_path = linked_dir.path().join("hotfix");
+        repo.worktree("hotfix", &linked_path, None)
+            .expect("Failed to add worktree");
+        // A linked worktree's .git is a file pointing back at the main repository
+        assert!(linked_path.join(".git").is_file());
+
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(&linked_path).expect("Failed to open repo"));
+        let id = app.store.add_module(0, "Parser".into()).unwrap();
+        app.store.set_module_status(0, id, ModuleStatus::Current);
+        app.status_message.clear();
+        app.save_store_progress();
+        assert!(app.status_message.is_empty(), "{}", app.status_message);
+
+        let common = dir.path().join(".git/forge");
+        let progress = std::fs::read_to_string(common.join("progress.txt")).unwrap();
+        assert!(progress.contains("|Parser|Current|"));
+        // Merge resolutions and the last view stay with the worktree
+        let worktree_dir = app.worktree_state_dir().unwrap();
+        assert_eq!(worktree_dir.file_name().unwrap(), "forge");
+        assert_eq!(
+            worktree_dir.parent().unwrap().canonicalize().unwrap(),
+            dir.path()
+                .join(".git/worktrees/hotfix")
+                .canonicalize()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_detached_checkout_labels_branch() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        let repo = git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        assert_eq!(app.head, git::HeadRef::Unborn);
+        assert_eq!(app.store.projects[0].branch, "(no commits yet)");
+
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let oid = repo
+            .commit(Some("HEAD"), &sig, &sig, "Initial", &tree, &[])
+            .unwrap();
+        let short = oid.to_string()[..7].to_string();
+
+        app.checkout_detached(&oid.to_string());
+        assert_eq!(app.head, git::HeadRef::Detached(short.clone()));
+        assert_eq!(
+            app.store.projects[0].branch,
+            format!("(detached at {})", short)
+        );
+    }
+
+    #[test]
+    fn test_reflog_reset_restores_dropped_commit() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        let repo = git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let first = repo
+            .commit(Some("HEAD"), &sig, &sig, "Initial", &tree, &[])
+            .unwrap();
+        let parent = repo.find_commit(first).unwrap();
+        let second = repo
+            .commit(Some("HEAD"), &sig, &sig, "Second", &tree, &[&parent])
+            .unwrap();
+        repo.reset(parent.as_object(), git2::ResetType::Soft, None)
+            .unwrap();
+
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        app.apply_action_updates(ActionStateUpdate {
+            reflog_requested: Some(()),
+            ..Default::default()
+        });
+        // Newest entry is the reset; the one before it is the dropped commit
+        app.apply_action_updates(ActionStateUpdate {
+            reflog_move: Some(1),
+            ..Default::default()
+        });
+        app.apply_action_updates(ActionStateUpdate {
+            reflog_reset: Some(()),
+            ..Default::default()
+        });
+
+        assert!(app.reflog.is_none());
+        assert_eq!(repo.head().unwrap().target(), Some(second));
+        assert!(matches!(app.head, git::HeadRef::Branch(_)));
+    }
+
+    #[test]
+    fn test_quick_commit_stages_and_commits_from_any_view() {
+        for view in [AppMode::Dashboard, AppMode::Settings] {
+            let dir = TempDir::new().expect("Failed to create temp directory");
+            git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+            std::fs::write(dir.path().join("notes.txt"), "hello").expect("Failed to write");
+
+            let mut app = App::without_repo();
+            app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+            app.current_view = view;
+            app.changes.commit_message = "Draft".into();
+
+            app.apply_action_updates(ActionStateUpdate {
+                text_prompt_open: Some(PromptKind::QuickCommit),
+                ..Default::default()
+            });
+            for c in "Quick".chars() {
+                app.apply_action_updates(ActionStateUpdate {
+                    text_prompt_append: Some(c),
+                    ..Default::default()
+                });
+            }
+            app.apply_action_updates(ActionStateUpdate {
+                text_prompt_submit: Some(()),
+                ..Default::default()
+            });
+
+            let entry = app.action_log.newest_first().next().expect("No log entry");
+            assert!(entry.success, "commit failed: {}", entry.detail);
+            assert_eq!(entry.detail, "Quick");
+            assert!(app.prompt.is_none());
+            assert_eq!(app.current_view, view);
+            // A draft message in the Changes view is left alone
+            assert_eq!(app.changes.commit_message, "Draft");
+            let repo = git2::Repository::open(dir.path()).unwrap();
+            assert!(repo.statuses(None).unwrap().is_empty());
+            // The Commit History header count follows new commits
+            assert_eq!(app.commit_history.total_commits, Some(1));
+        }
+    }
+
+    #[test]
+    fn test_prompt_takes_vim_keys_quit_and_help_as_text() {
+        let mut app = App::without_repo();
+        app.apply_action_updates(ActionStateUpdate {
+            text_prompt_open: Some(PromptKind::GoToRef),
+            ..Default::default()
+        });
+        for c in "hjklq?".chars() {
+            let key = crossterm::event::KeyEvent::from(crossterm::event::KeyCode::Char(c));
+            assert!(app.text_input_active());
+            let action = app.key_handler.on_text_key_event(key);
+            assert!(!app.handle_action(action), "{} quit the app", c);
+        }
+        assert_eq!(app.prompt.as_ref().expect("prompt open").input, "hjklq?");
+        assert!(!app.show_help);
+    }
+
+    #[test]
+    fn test_failed_quick_commit_keeps_the_typed_message() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        std::fs::write(dir.path().join("notes.txt"), "hello").expect("Failed to write");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+
+        app.apply_action_updates(ActionStateUpdate {
+            text_prompt_open: Some(PromptKind::QuickCommit),
+            ..Default::default()
+        });
+        for c in "Retry me".chars() {
+            app.apply_action_updates(ActionStateUpdate {
+                text_prompt_append: Some(c),
+                ..Default::default()
+            });
+        }
+        // Another process holding the index makes staging fail
+        std::fs::write(dir.path().join(".git").join("index.lock"), "").expect("Failed to lock");
+        app.apply_action_updates(ActionStateUpdate {
+            text_prompt_submit: Some(()),
+            ..Default::default()
+        });
+
+        let prompt = app.prompt.as_ref().expect("prompt stays open");
+        assert_eq!(prompt.kind, PromptKind::QuickCommit);
+        assert_eq!(prompt.input, "Retry me");
+    }
+
+    #[test]
+    fn test_save_failure_sets_warning_status() {
+        let dir = TempDir::new().expect("Failed to create temp directory");
+        git2::Repository::init(dir.path()).expect("Failed to initialize repo");
+        let mut app = App::without_repo();
+        app.attach_repo(git::GitClient::discover(dir.path()).expect("Failed to open repo"));
+        // Files where the persistence directories should be make every write fail
+        for path in [dir.path().join(".forge"), dir.path().join(".git/forge")] {
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::write(&path, "").unwrap();
+        }
+
+        app.status_message.clear();
+        app.save_store_json();
+        assert!(app
+            .status_message
+            .starts_with(&status_symbols::warning("Could not save project data")));
+
+        app.status_message.clear();
+        app.save_store_progress();
+        assert!(app
+            .status_message
+            .starts_with(&status_symbols::warning("Could not save progress")));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::state::ActionLog;
+use crate::status_symbols::{ERROR, SUCCESS};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem},
+    Frame,
+};
+
+/// Parameters for ActionLogPanel rendering
+#[derive(Debug, Clone)]
+pub struct ActionLogParams<'a> {
+    pub area: Rect,
+    pub log: &'a ActionLog,
+}
+
+/// Overlay listing performed git operations, newest first
+#[derive(Debug)]
+pub struct ActionLogPanel;
+
+impl Default for ActionLogPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ActionLogPanel {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(&self, frame: &mut Frame, params: ActionLogParams) {
+        let items: Vec<ListItem> = if params.log.is_empty() {
+            vec![ListItem::new(Span::styled(
+                "No git operations yet",
+                Style::new().gray(),
+            ))]
+        } else {
+            params
+                .log
+                .newest_first()
+                .map(|entry| {
+                    let (symbol, color) = if entry.success {
+                        (SUCCESS, Color::Green)
+                    } else {
+                        (ERROR, Color::Red)
+                    };
+                    ListItem::new(Line::from(vec![
+                        Span::styled(
+                            entry.timestamp.format("%H:%M:%S ").to_string(),
+                            Style::new().gray(),
+                        ),
+                        Span::styled(format!("{} ", symbol), Style::new().fg(color)),
+                        Span::styled(format!("{:<10}", entry.action), Style::new().bold()),
+                        Span::raw(entry.detail.clone()),
+                    ]))
+                })
+                .collect()
+        };
+
+        frame.render_widget(Clear, params.area);
+        frame.render_widget(
+            List::new(items).block(
+                Block::bordered()
+                    .title("Git Activity (Ctrl+G / Esc to close)")
+                    .border_style(Style::new().cyan())
+                    .style(Style::new().bg(Color::Black)),
+            ),
+            params.area,
+        );
+    }
+}
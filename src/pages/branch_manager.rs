@@ -1,4 +1,9 @@
-use crate::ui_utils::{create_list_state, render_input_form};
+use crate::pages::help::HelpEntry;
+use crate::state::BranchFilter;
+use crate::ui_utils::{
+    create_list_state, render_empty_state, render_input_form, truncate_middle, LIST_CHROME_WIDTH,
+};
+use crate::AppMode;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
@@ -12,6 +17,12 @@ pub struct BranchInfo {
     pub name: String,
     pub is_current: bool,
     pub is_remote: bool,
+    /// Local branch with no published copy on any remote
+    pub is_unpushed: bool,
+    /// A tag rather than a branch; Enter detaches HEAD at it
+    pub is_tag: bool,
+    /// Commits (ahead, behind) relative to the current branch
+    pub ahead_behind: Option<(usize, usize)>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -30,8 +41,28 @@ pub struct BranchManagerParams<'a> {
     pub scroll: usize,
     pub mode: BranchManagerMode,
     pub input_buffer: &'a str,
+    /// Which branches are listed, shown in the title
+    pub filter: BranchFilter,
 }
 
+/// Shortcuts listed in the help overlay for this view
+pub const HELP_ENTRIES: &[HelpEntry] = &[
+    HelpEntry::view(
+        AppMode::BranchManager,
+        "Enter",
+        "Switch to branch / check out tag",
+    )
+    .with_hint("Switch"),
+    HelpEntry::view(AppMode::BranchManager, "n", "New branch").with_hint("New"),
+    HelpEntry::view(AppMode::BranchManager, "d", "Delete branch or tag").with_hint("Delete"),
+    HelpEntry::view(AppMode::BranchManager, "F", "Fetch from all remotes"),
+    HelpEntry::view(
+        AppMode::BranchManager,
+        "R",
+        "Show local / remote / all branches / tags",
+    ),
+];
+
 #[derive(Debug)]
 pub struct BranchManager;
 
@@ -55,6 +86,7 @@ impl BranchManager {
                     params.branches,
                     params.selected,
                     params.scroll,
+                    params.filter,
                 );
             }
             BranchManagerMode::CreateBranch => {
@@ -69,6 +101,7 @@ impl BranchManager {
                     params.branches,
                     params.selected,
                     params.scroll,
+                    params.filter,
                 );
                 self.render_create_form(frame, layout[1], params.input_buffer);
             }
@@ -82,7 +115,28 @@ impl BranchManager {
         branches: &[BranchInfo],
         selected: usize,
         scroll: usize,
+        filter: BranchFilter,
     ) {
+        let enter = if filter == BranchFilter::Tags {
+            "Checkout"
+        } else {
+            "Switch"
+        };
+        let block = Block::bordered().title(format!(
+            "Branches [{}] | ↵ {} | n New | d Delete | F Fetch all | R Filter",
+            filter.label(),
+            enter
+        ));
+        if branches.is_empty() {
+            let message = match filter {
+                BranchFilter::Remote => "No remote branches — press F to fetch",
+                BranchFilter::Tags => "No tags",
+                _ => "No branches yet — make a first commit",
+            };
+            render_empty_state(frame, area, block, message);
+            return;
+        }
+
         let items: Vec<ListItem> = branches
             .iter()
             .map(|b| {
@@ -94,12 +148,36 @@ impl BranchManager {
 
                 let branch_type = if b.is_remote {
                     Span::styled(" [remote]", Style::new().fg(Color::Cyan))
+                } else if b.is_tag {
+                    Span::styled(" [tag]", Style::new().fg(Color::Blue))
+                } else {
+                    Span::raw("")
+                };
+
+                let unpushed = if b.is_unpushed {
+                    Span::styled(" (unpushed)", Style::new().fg(Color::Yellow))
                 } else {
                     Span::raw("")
                 };
 
+                let divergence = match b.ahead_behind {
+                    Some((0, 0)) | None => Span::raw(""),
+                    Some((ahead, behind)) => Span::styled(
+                        format!(" ↑{} ↓{}", ahead, behind),
+                        Style::new().fg(Color::Magenta),
+                    ),
+                };
+
+                // The name gives way so the badges after it stay visible
+                let badges = [&branch_type, &divergence, &unpushed]
+                    .iter()
+                    .map(|span| span.width())
+                    .sum::<usize>();
+                let name_width = (area.width.saturating_sub(LIST_CHROME_WIDTH) as usize)
+                    .saturating_sub(prefix.width() + badges);
+
                 let name = Span::styled(
-                    &b.name,
+                    truncate_middle(&b.name, name_width),
                     if b.is_current {
                         Style::new().fg(Color::Green).bold()
                     } else {
@@ -107,7 +185,13 @@ impl BranchManager {
                     },
                 );
 
-                ListItem::new(Line::from(vec![prefix, name, branch_type]))
+                ListItem::new(Line::from(vec![
+                    prefix,
+                    name,
+                    branch_type,
+                    divergence,
+                    unpushed,
+                ]))
             })
             .collect();
 
@@ -115,7 +199,7 @@ impl BranchManager {
 
         frame.render_stateful_widget(
             List::new(items)
-                .block(Block::bordered().title("Branches | ↵ Switch | n New | d Delete"))
+                .block(block)
                 .highlight_style(Style::new().reversed())
                 .highlight_symbol(">> "),
             area,
@@ -127,3 +211,90 @@ impl BranchManager {
         render_input_form(frame, area, "Create New Branch", "Branch name", input);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui_utils::render_to_text;
+
+    #[test]
+    fn test_no_branches_shows_hint() {
+        let text = render_to_text(80, 6, |frame| {
+            BranchManager::new().render(
+                frame,
+                BranchManagerParams {
+                    area: frame.area(),
+                    branches: &[],
+                    selected: 0,
+                    scroll: 0,
+                    mode: BranchManagerMode::List,
+                    input_buffer: "",
+                    filter: BranchFilter::Local,
+                },
+            )
+        });
+        assert!(text.contains("No branches yet"));
+    }
+
+    #[test]
+    fn test_tags_filter_marks_rows_and_title() {
+        let tags = [BranchInfo {
+            name: "v1.0".into(),
+            is_current: false,
+            is_remote: false,
+            is_unpushed: false,
+            is_tag: true,
+            ahead_behind: None,
+        }];
+        let render = |branches: &[BranchInfo]| {
+            render_to_text(80, 6, |frame| {
+                BranchManager::new().render(
+                    frame,
+                    BranchManagerParams {
+                        area: frame.area(),
+                        branches,
+                        selected: 0,
+                        scroll: 0,
+                        mode: BranchManagerMode::List,
+                        input_buffer: "",
+                        filter: BranchFilter::Tags,
+                    },
+                )
+            })
+        };
+        let text = render(&tags);
+        assert!(text.contains("v1.0 [tag]"));
+        assert!(text.contains("↵ Checkout"));
+
+        assert!(render(&[]).contains("No tags"));
+    }
+
+    #[test]
+    fn test_long_branch_name_keeps_badges_visible() {
+        let branches = [BranchInfo {
+            name: "feature/a-really-long-branch-name-that-never-ends".into(),
+            is_current: false,
+            is_remote: false,
+            is_unpushed: true,
+            is_tag: false,
+            ahead_behind: None,
+        }];
+        let text = render_to_text(40, 6, |frame| {
+            BranchManager::new().render(
+                frame,
+                BranchManagerParams {
+                    area: frame.area(),
+                    branches: &branches,
+                    selected: 0,
+                    scroll: 0,
+                    mode: BranchManagerMode::List,
+                    input_buffer: "",
+                    filter: BranchFilter::Local,
+                },
+            )
+        });
+        assert!(text.contains("feature/"));
+        assert!(text.contains("…"));
+        assert!(text.contains("(unpushed)"));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::state::BranchPickerState;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Parameters for BranchPicker rendering
+#[derive(Debug, Clone)]
+pub struct BranchPickerParams<'a> {
+    pub area: Rect,
+    pub picker: &'a BranchPickerState,
+}
+
+/// Popup listing local branches under a fuzzy filter input
+#[derive(Debug)]
+pub struct BranchPicker;
+
+impl Default for BranchPicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BranchPicker {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(&self, frame: &mut Frame, params: BranchPickerParams) {
+        frame.render_widget(Clear, params.area);
+        let block = Block::bordered()
+            .title("Switch Branch | ↑↓ Select | Enter Checkout | Esc Close")
+            .border_style(Style::new().cyan())
+            .style(Style::new().bg(Color::Black));
+        let inner = block.inner(params.area);
+        frame.render_widget(block, params.area);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(0)])
+            .split(inner);
+
+        frame.render_widget(
+            Paragraph::new(format!("> {}", params.picker.query)),
+            layout[0],
+        );
+
+        let matches = params.picker.matches();
+        if matches.is_empty() {
+            frame.render_widget(Paragraph::new("No matching branches"), layout[1]);
+            return;
+        }
+        let items: Vec<ListItem> = matches
+            .iter()
+            .enumerate()
+            .map(|(i, (name, is_current))| {
+                let marker = if *is_current { "* " } else { "  " };
+                let style = if i == params.picker.selected {
+                    Style::new().reversed()
+                } else {
+                    Style::new()
+                };
+                ListItem::new(format!("{}{}", marker, name)).style(style)
+            })
+            .collect();
+        frame.render_widget(List::new(items), layout[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn rendered_text(picker: &BranchPickerState) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(60, 8)).unwrap();
+        terminal
+            .draw(|frame| {
+                BranchPicker::new().render(
+                    frame,
+                    BranchPickerParams {
+                        area: frame.area(),
+                        picker,
+                    },
+                )
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_renders_filtered_branches() {
+        let mut picker =
+            BranchPickerState::new(vec![("main".into(), true), ("feature/login".into(), false)]);
+        let text = rendered_text(&picker);
+        assert!(text.contains("* main"));
+        assert!(text.contains("  feature/login"));
+
+        picker.push_char('f');
+        let text = rendered_text(&picker);
+        assert!(text.contains("> f"));
+        assert!(!text.contains("main"));
+
+        picker.push_char('z');
+        assert!(rendered_text(&picker).contains("No matching branches"));
+    }
+}
@@ -1,7 +1,14 @@
-use crate::data::{Change, Project};
-use crate::ui_utils::create_list_state;
+use std::collections::HashSet;
+
+use crate::data::{Change, FileStatus, Project};
+use crate::git::{align_blame, BlameLine};
+use crate::pages::help::HelpEntry;
+use crate::ui_utils::{create_list_state, pane_direction, styled_diff_lines};
+use crate::AppMode;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
     widgets::{Block, List, ListItem, Paragraph},
     Frame,
 };
@@ -15,8 +22,42 @@ pub struct ChangesParams<'a> {
     pub commit_msg: &'a str,
     pub scroll: usize,
     pub pane_ratio: u16,
+    /// Blame of the selected file's committed lines, when annotations are on
+    pub blame: Option<&'a [BlameLine]>,
+    /// Lines of the diff preview scrolled past the top
+    pub diff_scroll: u16,
+    /// Ctrl+Enter rather than Enter commits the message
+    pub commit_requires_modifier: bool,
+    /// Ticked rows, when in multi-select mode
+    pub multi_select: Option<&'a HashSet<usize>>,
 }
 
+/// Shortcuts listed in the help overlay for this view
+pub const HELP_ENTRIES: &[HelpEntry] = &[
+    HelpEntry::view(AppMode::Changes, "Space", "Stage/unstage file").with_hint("Stage"),
+    HelpEntry::view(
+        AppMode::Changes,
+        "S",
+        "Stage file and select the next unstaged",
+    ),
+    HelpEntry::view(AppMode::Changes, "Enter", "Commit staged changes").with_hint("Commit"),
+    HelpEntry::view(
+        AppMode::Changes,
+        "V",
+        "Multi-select: Space ticks files, Enter stages them",
+    ),
+    HelpEntry::view(AppMode::Changes, "f / p", "Fetch / Push (empty message)")
+        .with_hint("Fetch / Push"),
+    HelpEntry::view(AppMode::Changes, "v", "Quick view diff (empty message)").with_hint("View"),
+    HelpEntry::view(AppMode::Changes, "Y", "Copy selected diff").with_hint("Copy diff"),
+    HelpEntry::view(AppMode::Changes, "B", "Toggle blame annotations"),
+    HelpEntry::view(AppMode::Changes, "o", "Open file in $EDITOR"),
+    HelpEntry::view(AppMode::Changes, "P", "Copy file path"),
+    HelpEntry::view(AppMode::Changes, "+", "Show all of a truncated diff"),
+    HelpEntry::view(AppMode::Changes, "Ctrl+Y", "Copy whole changeset"),
+    HelpEntry::view(AppMode::Changes, "Ctrl+P", "Apply a patch file"),
+];
+
 #[derive(Debug)]
 pub struct ChangesPage;
 
@@ -40,7 +81,7 @@ impl ChangesPage {
         let left = params.pane_ratio.clamp(20, 80);
         let right = 100u16.saturating_sub(left);
         let cols = Layout::default()
-            .direction(Direction::Horizontal)
+            .direction(pane_direction(layout[0]))
             .constraints([Constraint::Percentage(left), Constraint::Percentage(right)])
             .split(layout[0]);
 
@@ -49,15 +90,21 @@ impl ChangesPage {
             .project
             .changes
             .iter()
-            .map(|c| ListItem::new(Self::fmt_change(c)))
+            .enumerate()
+            .map(|(i, c)| {
+                let ticked = params.multi_select.map(|rows| rows.contains(&i));
+                ListItem::new(Self::fmt_change(c, ticked))
+            })
             .collect();
         let mut state = create_list_state(params.selected, params.scroll, items.len());
+        let mut title = Self::summary_spans(status_counts(&params.project.changes));
+        title.push(Span::raw(format!(
+            "Branch: {} | Space: stage/unstage | f: fetch | p: push | Ctrl+l: pull",
+            params.project.branch
+        )));
         frame.render_stateful_widget(
             List::new(items)
-                .block(Block::bordered().title(format!(
-                    "Branch: {} | Space: stage/unstage | f: fetch | p: push | Ctrl+l: pull",
-                    params.project.branch
-                )))
+                .block(Block::bordered().title(Line::from(title)))
                 .highlight_style(ratatui::style::Style::new().reversed())
                 .highlight_symbol(">> ")
                 .repeat_highlight_symbol(true),
@@ -66,32 +113,232 @@ impl ChangesPage {
         );
 
         // Right: diff preview for selected
-        let preview = params
-            .project
-            .changes
-            .get(params.selected)
-            .map(|c| c.diff_preview.clone())
-            .unwrap_or_else(|| "Select a file".into());
+        let preview = match params.project.changes.get(params.selected) {
+            Some(c) if c.is_binary => Paragraph::new(vec![
+                Line::from(Span::styled(" BINARY ", Style::new().black().on_magenta())),
+                Line::from(""),
+                Line::from(c.diff_preview.as_str()),
+            ]),
+            Some(c) => {
+                let mut lines = styled_diff_lines(&c.diff_preview);
+                if let Some(blame) = params.blame {
+                    for (line, entry) in lines.iter_mut().zip(align_blame(&c.diff_preview, blame)) {
+                        line.spans.insert(0, Self::blame_gutter(entry));
+                    }
+                }
+                Paragraph::new(lines)
+            }
+            None => Paragraph::new("Select a file"),
+        };
+        let title = if params.blame.is_some() {
+            "Diff Preview (blame)"
+        } else {
+            "Diff Preview"
+        };
         frame.render_widget(
-            Paragraph::new(preview).block(Block::bordered().title("Diff Preview")),
+            preview
+                .scroll((params.diff_scroll, 0))
+                .block(Block::bordered().title(title)),
             cols[1],
         );
 
         // Bottom: commit message input
+        let commit_title = if params.commit_requires_modifier {
+            "Type and press Ctrl+Enter to commit"
+        } else {
+            "Type and press Enter to commit"
+        };
         frame.render_widget(
             Paragraph::new(format!("Commit message: {}", params.commit_msg))
-                .block(Block::bordered().title("Type and press Enter to commit")),
+                .block(Block::bordered().title(commit_title)),
             layout[1],
         );
     }
 
-    fn fmt_change(c: &Change) -> String {
-        let status = match c.status {
-            crate::data::FileStatus::Modified => "M",
-            crate::data::FileStatus::Added => "A",
-            crate::data::FileStatus::Deleted => "D",
+    /// "3M 1A 2D | " with each count in its status colour; statuses with no
+    /// files are left out, and nothing is shown for a clean tree
+    fn summary_spans(counts: (usize, usize, usize)) -> Vec<Span<'static>> {
+        let (modified, added, deleted) = counts;
+        let mut spans = Vec::new();
+        for (count, letter, style) in [
+            (modified, 'M', Style::new().yellow()),
+            (added, 'A', Style::new().green()),
+            (deleted, 'D', Style::new().red()),
+        ] {
+            if count > 0 {
+                if !spans.is_empty() {
+                    spans.push(Span::raw(" "));
+                }
+                spans.push(Span::styled(format!("{}{}", count, letter), style));
+            }
+        }
+        if !spans.is_empty() {
+            spans.push(Span::raw(" | "));
+        }
+        spans
+    }
+
+    /// Fixed-width "hash author │" column; blank for lines with no history
+    fn blame_gutter(entry: Option<&BlameLine>) -> Span<'static> {
+        let text = match entry {
+            Some(b) => format!("{:7} {:12.12} │ ", b.short_hash, b.author),
+            None => format!("{:20} │ ", ""),
+        };
+        Span::styled(text, Style::new().dark_gray())
+    }
+
+    /// `ticked` is `Some` in multi-select mode, adding a checkbox
+    fn fmt_change(c: &Change, ticked: Option<bool>) -> String {
+        let checkbox = match ticked {
+            Some(true) => "☑ ",
+            Some(false) => "☐ ",
+            None => "",
         };
         let staged_marker = if c.staged { "✓" } else { " " };
-        format!("[{staged_marker}] [{status}] {}", c.path)
+        let binary_badge = if c.is_binary { " [BIN]" } else { "" };
+        let submodule_badge = if c.is_submodule { " (submodule)" } else { "" };
+        let (status, path) = match &c.status {
+            crate::data::FileStatus::Modified => ("M", c.path.clone()),
+            crate::data::FileStatus::Added => ("A", c.path.clone()),
+            crate::data::FileStatus::Deleted => ("D", c.path.clone()),
+            crate::data::FileStatus::Renamed { from, to } => ("R", format!("{from} -> {to}")),
+        };
+        format!("{checkbox}[{staged_marker}] [{status}] {path}{binary_badge}{submodule_badge}")
+    }
+}
+
+/// Number of (modified, added, deleted) files; renames count as modified
+fn status_counts(changes: &[Change]) -> (usize, usize, usize) {
+    changes
+        .iter()
+        .fold((0, 0, 0), |(m, a, d), change| match change.status {
+            FileStatus::Modified | FileStatus::Renamed { .. } => (m + 1, a, d),
+            FileStatus::Added => (m, a + 1, d),
+            FileStatus::Deleted => (m, a, d + 1),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui_utils::demo_project;
+    use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+
+    fn render_buffer(width: u16, pane_ratio: u16) -> Buffer {
+        let project = demo_project();
+        let mut terminal = Terminal::new(TestBackend::new(width, 10)).unwrap();
+        terminal
+            .draw(|frame| {
+                ChangesPage::new().render(
+                    frame,
+                    ChangesParams {
+                        area: frame.area(),
+                        project: &project,
+                        selected: 0,
+                        commit_msg: "",
+                        scroll: 0,
+                        pane_ratio,
+                        blame: None,
+                        diff_scroll: 0,
+                        commit_requires_modifier: false,
+                        multi_select: None,
+                    },
+                )
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    /// Column of the diff preview's left border for the given split ratio
+    fn split_column(pane_ratio: u16) -> u16 {
+        let buffer = render_buffer(100, pane_ratio);
+        (1..buffer.area.width)
+            .find(|&x| buffer[(x, 0)].symbol() == "┌")
+            .expect("preview pane border")
+    }
+
+    fn change(path: &str, status: FileStatus) -> Change {
+        Change {
+            path: path.into(),
+            status,
+            diff_preview: String::new(),
+            local_preview: None,
+            incoming_preview: None,
+            staged: false,
+            is_binary: false,
+            hidden_lines: 0,
+            is_submodule: false,
+        }
+    }
+
+    #[test]
+    fn test_status_counts_mixed_changes() {
+        let changes = vec![
+            change("a.rs", FileStatus::Modified),
+            change("b.rs", FileStatus::Modified),
+            change("c.rs", FileStatus::Added),
+            change("d.rs", FileStatus::Deleted),
+            change("e.rs", FileStatus::Deleted),
+            change(
+                "g.rs",
+                FileStatus::Renamed {
+                    from: "f.rs".into(),
+                    to: "g.rs".into(),
+                },
+            ),
+        ];
+        assert_eq!(status_counts(&changes), (3, 1, 2));
+        assert_eq!(status_counts(&[]), (0, 0, 0));
+
+        let text: String = ChangesPage::summary_spans((3, 0, 2))
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(text, "3M 2D | ");
+        assert!(ChangesPage::summary_spans((0, 0, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_submodule_entries_are_tagged() {
+        let mut lib = change("lib", FileStatus::Modified);
+        assert_eq!(ChangesPage::fmt_change(&lib, None), "[ ] [M] lib");
+        lib.is_submodule = true;
+        assert_eq!(
+            ChangesPage::fmt_change(&lib, None),
+            "[ ] [M] lib (submodule)"
+        );
+    }
+
+    #[test]
+    fn test_multi_select_rows_show_checkboxes() {
+        let lib = change("lib.rs", FileStatus::Modified);
+        assert_eq!(
+            ChangesPage::fmt_change(&lib, Some(true)),
+            "☑ [ ] [M] lib.rs"
+        );
+        assert_eq!(
+            ChangesPage::fmt_change(&lib, Some(false)),
+            "☐ [ ] [M] lib.rs"
+        );
+    }
+
+    #[test]
+    fn test_pane_ratio_moves_split() {
+        assert_eq!(split_column(50), 50);
+        assert_eq!(split_column(30), 30);
+        assert_eq!(split_column(70), 70);
+        // Ratios outside the supported range are clamped
+        assert_eq!(split_column(95), 80);
+    }
+
+    #[test]
+    fn test_narrow_area_stacks_preview_under_list() {
+        let wide = render_buffer(120, 50);
+        assert_eq!(wide[(60, 0)].symbol(), "┌");
+
+        // Rows 7 and below hold the commit message box
+        let narrow = render_buffer(60, 50);
+        assert!((1..60).all(|x| narrow[(x, 0)].symbol() != "┌"));
+        assert!((1..7).any(|y| narrow[(0, y)].symbol() == "┌"));
     }
 }
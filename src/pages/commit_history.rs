@@ -1,31 +1,119 @@
-use crate::ui_utils::create_list_state;
+use crate::pages::help::HelpEntry;
+use crate::ui_utils::{
+    author_badge, create_list_state, humanize_time, pane_direction, render_empty_state,
+};
+use crate::AppMode;
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Constraint, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, List, ListItem, Paragraph, Wrap},
     Frame,
 };
+use serde::{Deserialize, Serialize};
 
 /// Parameters for CommitHistory page rendering
 #[derive(Debug, Clone)]
 pub struct CommitHistoryParams<'a> {
     pub area: Rect,
     pub commits: &'a [CommitInfo],
+    pub author_filter: Option<&'a str>,
+    /// Commits reachable from HEAD and the branch name, for the header
+    pub total_commits: Option<(usize, &'a str)>,
     pub selected: usize,
     pub scroll: usize,
     pub pane_ratio: u16,
+    /// Show dates as "time ago" instead of timestamps
+    pub relative_dates: bool,
+    /// One `hash subject — author` line per commit instead of two lines
+    pub compact: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CommitInfo {
     pub hash: String,
     pub author: String,
+    /// Empty for commits exported before emails were recorded
+    #[serde(default)]
+    pub author_email: String,
     pub date: String,
     pub message: String,
     pub files_changed: Vec<String>,
+    /// Commit time in seconds since the Unix epoch; `date` is derived from it
+    #[serde(default)]
+    pub timestamp: i64,
+    /// Repository-unique abbreviation of `hash`; empty when unknown
+    #[serde(skip)]
+    pub short_hash: String,
+    /// Tags pointing at this commit, sorted
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl CommitInfo {
+    /// Abbreviated hash for display: the repository-unique one when known,
+    /// otherwise the first seven characters
+    pub fn short(&self) -> &str {
+        if self.short_hash.is_empty() {
+            &self.hash[..self.hash.len().min(7)]
+        } else {
+            &self.short_hash
+        }
+    }
+
+    /// The commit time as "2h ago" when `relative`, otherwise as a UTC
+    /// timestamp; `now` is in seconds since the epoch
+    pub fn display_date(&self, relative: bool, now: i64) -> String {
+        if relative {
+            humanize_time(self.timestamp, now)
+        } else {
+            chrono::DateTime::from_timestamp(self.timestamp, 0)
+                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_else(|| self.date.clone())
+        }
+    }
 }
 
+/// Shortcuts listed in the help overlay for this view
+pub const HELP_ENTRIES: &[HelpEntry] = &[
+    HelpEntry::view(AppMode::CommitHistory, "↑↓", "Browse commits").with_hint("Navigate"),
+    HelpEntry::view(AppMode::CommitHistory, "Space", "Quick view commit diff").with_hint("View"),
+    HelpEntry::view(AppMode::CommitHistory, "c", "Check out commit (detached)")
+        .with_hint("Checkout"),
+    HelpEntry::view(
+        AppMode::CommitHistory,
+        "s",
+        "Squash commits up to HEAD into this one",
+    ),
+    HelpEntry::view(AppMode::CommitHistory, "r", "Reword commit message"),
+    HelpEntry::view(
+        AppMode::CommitHistory,
+        "T",
+        "Toggle relative / absolute dates",
+    ),
+    HelpEntry::view(
+        AppMode::CommitHistory,
+        "z",
+        "Toggle one-line / two-line commits",
+    ),
+    HelpEntry::view(
+        AppMode::CommitHistory,
+        "E",
+        "Export history to .forge/commits.json",
+    ),
+    HelpEntry::view(
+        AppMode::CommitHistory,
+        "g",
+        "Write changelog since the last tag to .forge/CHANGELOG.md",
+    ),
+    HelpEntry::view(
+        AppMode::CommitHistory,
+        "p",
+        "Copy the commit's changed file paths",
+    ),
+    HelpEntry::view(AppMode::CommitHistory, "m", "Show only my commits").with_hint("Mine"),
+];
+
 #[derive(Debug)]
 pub struct CommitHistory;
 
@@ -44,44 +132,60 @@ impl CommitHistory {
         let left = params.pane_ratio.clamp(20, 80);
         let right = 100u16.saturating_sub(left);
         let layout = Layout::default()
-            .direction(Direction::Horizontal)
+            .direction(pane_direction(params.area))
             .constraints([Constraint::Percentage(left), Constraint::Percentage(right)])
             .split(params.area);
 
+        let now = chrono::Utc::now().timestamp();
+
         // Left: commit list
-        self.render_commit_list(
-            frame,
-            layout[0],
-            params.commits,
-            params.selected,
-            params.scroll,
-        );
+        self.render_commit_list(frame, layout[0], &params, now);
 
         // Right: commit details
         if let Some(commit) = params.commits.get(params.selected) {
-            self.render_commit_details(frame, layout[1], commit);
+            let date = commit.display_date(params.relative_dates, now);
+            self.render_commit_details(frame, layout[1], commit, &date);
         } else {
             frame.render_widget(Block::bordered().title("Commit Details"), layout[1]);
         }
     }
 
+    /// List title: commit count on the branch, active author filter and shortcuts
+    fn list_title(author_filter: Option<&str>, total_commits: Option<(usize, &str)>) -> String {
+        let heading = match total_commits {
+            Some((1, branch)) => format!("Commit History \u{2014} 1 commit on {}", branch),
+            Some((count, branch)) => {
+                format!("Commit History \u{2014} {} commits on {}", count, branch)
+            }
+            None => "Commit History".to_string(),
+        };
+        match author_filter {
+            Some(author) => format!("{} (author: {}) | m: mine", heading, author),
+            None => format!("{} (c: checkout) | m: mine", heading),
+        }
+    }
+
     fn render_commit_list(
         &self,
         frame: &mut Frame,
         area: Rect,
-        commits: &[CommitInfo],
-        selected: usize,
-        scroll: usize,
+        params: &CommitHistoryParams,
+        now: i64,
     ) {
-        let items: Vec<ListItem> = commits
+        let title = Self::list_title(params.author_filter, params.total_commits);
+        if params.commits.is_empty() {
+            let message = match params.author_filter {
+                Some(author) => format!("No commits by {}", author),
+                None => "No commits yet".to_string(),
+            };
+            render_empty_state(frame, area, Block::bordered().title(title), &message);
+            return;
+        }
+
+        let items: Vec<ListItem> = params
+            .commits
             .iter()
             .map(|c| {
-                let hash_short = if c.hash.len() > 7 {
-                    c.hash[0..7].to_string()
-                } else {
-                    c.hash.clone()
-                };
-
                 let message_oneline = c.message.lines().next().unwrap_or("");
                 let message_display = if message_oneline.len() > 50 {
                     format!("{}...", &message_oneline[0..47])
@@ -90,14 +194,30 @@ impl CommitHistory {
                 };
 
                 let author_display = c.author.clone();
-                let date_display = format!(" on {}", c.date);
+                let date_display = if params.relative_dates {
+                    format!(", {}", c.display_date(true, now))
+                } else {
+                    format!(" on {}", c.display_date(false, now))
+                };
 
+                let (initials, badge_color) = author_badge(&c.author);
+                let mut summary = vec![
+                    Span::styled(
+                        format!(" {} ", initials),
+                        Style::new().fg(Color::Black).bg(badge_color),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(c.short(), Style::new().fg(Color::Yellow).bold()),
+                    Span::raw(" "),
+                    Span::raw(message_display),
+                ];
+                if params.compact {
+                    summary.push(Span::styled(" — ", Style::new().gray()));
+                    summary.push(Span::styled(author_display, Style::new().cyan()));
+                    return ListItem::new(Line::from(summary));
+                }
                 ListItem::new(vec![
-                    Line::from(vec![
-                        Span::styled(hash_short, Style::new().fg(Color::Yellow).bold()),
-                        Span::raw(" "),
-                        Span::raw(message_display),
-                    ]),
+                    Line::from(summary),
                     Line::from(vec![
                         Span::styled("  by ", Style::new().gray()),
                         Span::styled(author_display, Style::new().cyan()),
@@ -107,11 +227,11 @@ impl CommitHistory {
             })
             .collect();
 
-        let mut state = create_list_state(selected, scroll, items.len());
+        let mut state = create_list_state(params.selected, params.scroll, items.len());
 
         frame.render_stateful_widget(
             List::new(items)
-                .block(Block::bordered().title("Commit History"))
+                .block(Block::bordered().title(title))
                 .highlight_style(Style::new().reversed())
                 .highlight_symbol(">> "),
             area,
@@ -119,7 +239,13 @@ impl CommitHistory {
         );
     }
 
-    fn render_commit_details(&self, frame: &mut Frame, area: Rect, commit: &CommitInfo) {
+    fn render_commit_details(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        commit: &CommitInfo,
+        date: &str,
+    ) {
         let mut lines = vec![
             Line::from(vec![
                 Span::styled("Commit: ", Style::new().bold()),
@@ -131,7 +257,7 @@ impl CommitHistory {
             ]),
             Line::from(vec![
                 Span::styled("Date:   ", Style::new().bold()),
-                Span::raw(&commit.date),
+                Span::raw(date),
             ]),
             Line::from(""),
         ];
@@ -167,3 +293,113 @@ impl CommitHistory {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui_utils::render_to_text;
+
+    fn rendered_text(author_filter: Option<&str>) -> String {
+        rendered_text_at(100, author_filter)
+    }
+
+    fn rendered_text_at(width: u16, author_filter: Option<&str>) -> String {
+        render_to_text(width, 6, |frame| {
+            CommitHistory::new().render(
+                frame,
+                CommitHistoryParams {
+                    area: frame.area(),
+                    commits: &[],
+                    author_filter,
+                    total_commits: None,
+                    selected: 0,
+                    scroll: 0,
+                    pane_ratio: 50,
+                    relative_dates: false,
+                    compact: false,
+                },
+            )
+        })
+    }
+
+    #[test]
+    fn test_empty_history_shows_hint() {
+        assert!(rendered_text(None).contains("No commits yet"));
+        assert!(rendered_text(Some("Ada")).contains("No commits by Ada"));
+    }
+
+    #[test]
+    fn test_details_pane_moves_below_list_when_narrow() {
+        let first_line = |text: &str| text.lines().next().unwrap_or_default().to_string();
+
+        let wide = rendered_text_at(120, None);
+        assert!(first_line(&wide).contains("Commit Details"));
+
+        let narrow = rendered_text_at(60, None);
+        assert!(!first_line(&narrow).contains("Commit Details"));
+        assert!(narrow.contains("Commit Details"));
+    }
+
+    /// Renders commits "abc0000" to "abc0003" by Ada Smith, one row per line
+    fn rendered_commits(compact: bool) -> Vec<String> {
+        let commits: Vec<CommitInfo> = (0..4)
+            .map(|i| CommitInfo {
+                hash: format!("abc000{}def", i),
+                author: "Ada Smith".into(),
+                author_email: String::new(),
+                date: "2026-01-01 00:00:00".into(),
+                message: format!("Fix parser {}", i),
+                files_changed: Vec::new(),
+                timestamp: 0,
+                short_hash: String::new(),
+                tags: Vec::new(),
+            })
+            .collect();
+        render_to_text(100, 12, |frame| {
+            CommitHistory::new().render(
+                frame,
+                CommitHistoryParams {
+                    area: frame.area(),
+                    commits: &commits,
+                    author_filter: None,
+                    total_commits: None,
+                    selected: 0,
+                    scroll: 0,
+                    pane_ratio: 50,
+                    relative_dates: false,
+                    compact,
+                },
+            )
+        })
+        .lines()
+        .map(str::to_string)
+        .collect()
+    }
+
+    #[test]
+    fn test_commit_rows_start_with_author_badge() {
+        let lines = rendered_commits(false);
+        assert!(lines[1].contains(">>  AS  abc0000 Fix parser 0"));
+    }
+
+    #[test]
+    fn test_compact_mode_renders_one_line_per_commit() {
+        // Line 0 is the border; each commit's hash sits on its first line
+        let hash_rows = |lines: &[String]| -> Vec<usize> {
+            (0..4)
+                .map(|i| {
+                    let hash = format!("abc000{}", i);
+                    lines.iter().position(|l| l.contains(&hash)).unwrap()
+                })
+                .collect()
+        };
+
+        let two_line = rendered_commits(false);
+        assert_eq!(hash_rows(&two_line), [1, 3, 5, 7]);
+        assert!(two_line[2].contains("by Ada Smith on"));
+
+        let compact = rendered_commits(true);
+        assert_eq!(hash_rows(&compact), [1, 2, 3, 4]);
+        assert!(compact[1].contains("abc0000 Fix parser 0 — Ada Smith"));
+    }
+}
@@ -0,0 +1,55 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+
+/// Parameters for ConfirmDialog rendering
+#[derive(Debug, Clone)]
+pub struct ConfirmDialogParams<'a> {
+    pub area: Rect,
+    pub message: &'a str,
+}
+
+/// Small modal asking the user to confirm a destructive action (y/n)
+#[derive(Debug)]
+pub struct ConfirmDialog;
+
+impl Default for ConfirmDialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConfirmDialog {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(&self, frame: &mut Frame, params: ConfirmDialogParams) {
+        let lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(params.message, Style::new().bold())),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("y", Style::new().bold().green()),
+                Span::raw(": Yes    "),
+                Span::styled("n / Esc", Style::new().bold().red()),
+                Span::raw(": No"),
+            ]),
+        ];
+
+        frame.render_widget(Clear, params.area);
+        frame.render_widget(
+            Paragraph::new(lines).alignment(Alignment::Center).block(
+                Block::bordered()
+                    .title("Confirm")
+                    .border_style(Style::new().yellow())
+                    .style(Style::new().bg(Color::Black)),
+            ),
+            params.area,
+        );
+    }
+}
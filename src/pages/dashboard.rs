@@ -1,5 +1,10 @@
-use crate::data::Project;
-use crate::ui_utils::create_list_state;
+use crate::data::{Project, ProjectSort};
+use crate::pages::help::HelpEntry;
+use crate::ui_utils::{
+    activity_bars, create_list_state, pane_direction, progress_bar, truncate_middle,
+    LIST_CHROME_WIDTH,
+};
+use crate::AppMode;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     widgets::{Block, List, ListItem, Paragraph},
@@ -15,10 +20,24 @@ pub struct DashboardParams<'a> {
     pub scroll: usize,
     pub search_active: bool,
     pub search_buffer: &'a str,
+    pub sort: Option<ProjectSort>,
     pub total_count: usize,
     pub pane_ratio: u16,
+    /// Commits per day, oldest first; the activity panel is hidden when empty
+    pub activity: &'a [(String, usize)],
+    /// Files in the repository's HEAD tree, if known
+    pub tracked_files: Option<usize>,
 }
 
+/// Shortcuts listed in the help overlay for this view
+pub const HELP_ENTRIES: &[HelpEntry] = &[
+    HelpEntry::view(AppMode::Dashboard, "Enter", "Open Changes view").with_hint("Open"),
+    HelpEntry::view(AppMode::Dashboard, "o", "Cycle project sort order").with_hint("Sort"),
+    HelpEntry::view(AppMode::Dashboard, "f", "Fetch from origin").with_hint("Fetch"),
+    HelpEntry::view(AppMode::Dashboard, "c", "Clone a repository and open it"),
+    HelpEntry::view(AppMode::Dashboard, "Ctrl+F", "Search projects").with_hint("Search"),
+];
+
 #[derive(Debug)]
 pub struct Dashboard;
 
@@ -34,10 +53,10 @@ impl Dashboard {
     }
 
     pub fn render(&self, frame: &mut Frame, params: DashboardParams) {
-        let left_pct = params.pane_ratio;
-        let right_pct = 100 - left_pct;
+        let left_pct = params.pane_ratio.clamp(20, 80);
+        let right_pct = 100u16.saturating_sub(left_pct);
         let cols = Layout::default()
-            .direction(Direction::Horizontal)
+            .direction(pane_direction(params.area))
             .constraints([
                 Constraint::Percentage(left_pct),
                 Constraint::Percentage(right_pct),
@@ -45,10 +64,11 @@ impl Dashboard {
             .split(params.area);
 
         // Left: project list with scrolling
+        let name_width = cols[0].width.saturating_sub(LIST_CHROME_WIDTH) as usize;
         let items: Vec<ListItem> = params
             .projects
             .iter()
-            .map(|p| ListItem::new(p.name.clone()))
+            .map(|p| ListItem::new(truncate_middle(&p.name, name_width)))
             .collect();
         let mut state = create_list_state(params.selected, params.scroll, items.len());
 
@@ -59,8 +79,13 @@ impl Dashboard {
                 params.projects.len(),
                 params.total_count
             )
+        } else if let Some(sort) = params.sort {
+            format!(
+                "Projects [{}] (Ctrl+F: search, o: sort, f: fetch)",
+                sort.label()
+            )
         } else {
-            "Projects (Ctrl+F: search, f: fetch)".to_string()
+            "Projects (Ctrl+F: search, o: sort, f: fetch)".to_string()
         };
 
         frame.render_stateful_widget(
@@ -78,19 +103,58 @@ impl Dashboard {
             .projects
             .get(params.selected)
             .map(|p| {
+                let progress = match p.progress() {
+                    Some(pct) => format!("{}% {}", pct, progress_bar(pct, 20)),
+                    None => "N/A".to_string(),
+                };
+                let tracked = params
+                    .tracked_files
+                    .map(|n| format!("\n{} tracked files", n))
+                    .unwrap_or_default();
                 format!(
-                    "Name: {}\nBranch: {}\n\nModules: {}\nDevelopers: {}\n\n{}",
+                    "Name: {}\nBranch: {}{}\n\nModules: {}\nDevelopers: {}\nOverall progress: {}\n\n{}",
                     p.name,
                     p.branch,
+                    tracked,
                     p.modules.len(),
                     p.developers.len(),
+                    progress,
                     p.description
                 )
             })
             .unwrap_or_else(|| "No project".into());
+        let right = if params.activity.is_empty() {
+            vec![cols[1]]
+        } else {
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(4)])
+                .split(cols[1])
+                .to_vec()
+        };
         frame.render_widget(
             Paragraph::new(details).block(Block::bordered().title("Info")),
-            cols[1],
+            right[0],
+        );
+        if let Some(&area) = right.get(1) {
+            self.render_activity(frame, area, params.activity);
+        }
+    }
+
+    fn render_activity(&self, frame: &mut Frame, area: Rect, activity: &[(String, usize)]) {
+        let counts: Vec<usize> = activity.iter().map(|(_, count)| *count).collect();
+        let total: usize = counts.iter().sum();
+        let peak = counts.iter().copied().max().unwrap_or(0);
+        let text = format!(
+            "{}\n{} commits · peak {}/day",
+            activity_bars(&counts),
+            total,
+            peak
+        );
+        frame.render_widget(
+            Paragraph::new(text)
+                .block(Block::bordered().title(format!("Activity (last {} days)", activity.len()))),
+            area,
         );
     }
 }
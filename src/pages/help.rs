@@ -1,3 +1,8 @@
+use crate::pages::{
+    branch_manager, changes, commit_history, dashboard, merge_visualizer, module_manager,
+    project_board, settings,
+};
+use crate::AppMode;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
@@ -6,6 +11,131 @@ use ratatui::{
     Frame,
 };
 
+/// A shortcut shown in the help overlay.
+///
+/// Global entries have no mode; view entries are contributed by each page
+/// through its `HELP_ENTRIES` constant and collected by [`registry`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HelpEntry {
+    pub keys: &'static str,
+    pub description: &'static str,
+    pub mode: Option<AppMode>,
+    /// Short label for the status bar cheat-sheet; `None` keeps the entry
+    /// to the help overlay
+    pub hint: Option<&'static str>,
+}
+
+impl HelpEntry {
+    pub const fn global(keys: &'static str, description: &'static str) -> Self {
+        Self {
+            keys,
+            description,
+            mode: None,
+            hint: None,
+        }
+    }
+
+    pub const fn view(mode: AppMode, keys: &'static str, description: &'static str) -> Self {
+        Self {
+            keys,
+            description,
+            mode: Some(mode),
+            hint: None,
+        }
+    }
+
+    /// Also lists the entry in the status bar, labelled `hint`
+    pub const fn with_hint(self, hint: &'static str) -> Self {
+        Self {
+            hint: Some(hint),
+            ..self
+        }
+    }
+}
+
+/// Shortcuts that work from any view
+pub const GLOBAL_ENTRIES: &[HelpEntry] = &[
+    HelpEntry::global("↑↓ / k j", "Navigate items"),
+    HelpEntry::global("← → / h l", "Change columns/panes"),
+    HelpEntry::global("5j / 5↓", "Repeat a move (count prefix)"),
+    HelpEntry::global("Ctrl+← →", "Resize split panes"),
+    HelpEntry::global("Tab", "Cycle through views"),
+    HelpEntry::global("Ctrl+^ / Ctrl+6", "Back to the previous view"),
+    HelpEntry::global("Enter / ↵", "Select/Confirm action"),
+    HelpEntry::global("Esc", "Back to menu"),
+    HelpEntry::global("Ctrl+L", "Pull from origin"),
+    HelpEntry::global("?", "Toggle this help"),
+    HelpEntry::global("Ctrl+G", "Show git activity log"),
+    HelpEntry::global("Ctrl+Enter / Ctrl+J", "Stage all and commit"),
+    HelpEntry::global("Ctrl+B", "Switch branch (fuzzy picker)"),
+    HelpEntry::global("Ctrl+R", "Reflog: checkout or reset to a lost commit"),
+    HelpEntry::global(":", "Go to a revision in Commit History"),
+    HelpEntry::global("Ctrl+W", "Switch worktree"),
+    HelpEntry::global("Ctrl+T", "Cycle theme"),
+    HelpEntry::global("Ctrl+N", "Toggle notifications"),
+    HelpEntry::global("Ctrl+D", "Toggle relative commit dates"),
+    HelpEntry::global("Ctrl+X", "Hide the no-repository banner"),
+    HelpEntry::global("q / Ctrl-C", "Quit"),
+];
+
+/// Order in which views are listed in the Actions section
+const VIEW_ORDER: [AppMode; 8] = [
+    AppMode::Dashboard,
+    AppMode::Changes,
+    AppMode::CommitHistory,
+    AppMode::BranchManager,
+    AppMode::MergeVisualizer,
+    AppMode::ProjectBoard,
+    AppMode::ModuleManager,
+    AppMode::Settings,
+];
+
+/// Every help entry: global shortcuts followed by each page's contribution
+pub fn registry() -> Vec<HelpEntry> {
+    [
+        GLOBAL_ENTRIES,
+        dashboard::HELP_ENTRIES,
+        changes::HELP_ENTRIES,
+        commit_history::HELP_ENTRIES,
+        branch_manager::HELP_ENTRIES,
+        merge_visualizer::HELP_ENTRIES,
+        project_board::HELP_ENTRIES,
+        module_manager::HELP_ENTRIES,
+        settings::HELP_ENTRIES,
+    ]
+    .concat()
+}
+
+/// Entries for one view, or the global ones when `mode` is `None`
+pub fn entries_for(mode: Option<AppMode>) -> Vec<HelpEntry> {
+    registry()
+        .into_iter()
+        .filter(|entry| entry.mode == mode)
+        .collect()
+}
+
+/// Status bar cheat-sheet for `mode`: its hinted entries, in registry order
+pub fn view_hints(mode: AppMode) -> String {
+    entries_for(Some(mode))
+        .iter()
+        .filter_map(|entry| Some(format!("{} {}", entry.keys, entry.hint?)))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+fn mode_label(mode: AppMode) -> &'static str {
+    match mode {
+        AppMode::Dashboard => "Dashboard",
+        AppMode::Changes => "Changes",
+        AppMode::CommitHistory => "History",
+        AppMode::BranchManager => "Branches",
+        AppMode::MergeVisualizer => "Merge",
+        AppMode::ProjectBoard => "Board",
+        AppMode::ModuleManager => "Modules",
+        AppMode::Settings => "Settings",
+    }
+}
+
 #[derive(Debug)]
 pub struct HelpPage;
 
@@ -20,13 +150,17 @@ impl HelpPage {
         Self
     }
 
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
+    /// Draws the overlay with the shortcuts of `current`, the view it was
+    /// opened from, expanded at the top; the other views follow under Actions
+    pub fn render(&self, frame: &mut Frame, area: Rect, current: AppMode) {
+        let current_entries = entries_for(Some(current));
         let sections = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(8),
+                Constraint::Length(current_entries.len() as u16 + 2),
+                Constraint::Length(entries_for(None).len() as u16 + 2),
                 Constraint::Length(9),
-                Constraint::Length(8),
+                Constraint::Length(VIEW_ORDER.len() as u16 + 1),
                 Constraint::Min(0),
             ])
             .split(area);
@@ -34,38 +168,38 @@ impl HelpPage {
         // Fill the overlay to avoid a transparent background bleeding through
         frame.render_widget(Block::default().style(Style::new().bg(Color::Black)), area);
 
+        // Current view section, one shortcut per line
+        let current_help: Vec<Line> = current_entries
+            .into_iter()
+            .map(|entry| {
+                Line::from(vec![
+                    Span::styled(format!("{:<12}", entry.keys), Style::new().bold().magenta()),
+                    Span::raw(entry.description),
+                ])
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(current_help)
+                .block(Block::bordered().title(format!("{} shortcuts", mode_label(current))))
+                .style(Style::new()),
+            sections[0],
+        );
+
         // Navigation section
-        let nav_help = vec![
-            Line::from(vec![
-                Span::styled("↑↓ / k j", Style::new().bold().cyan()),
-                Span::raw("     Navigate items"),
-            ]),
-            Line::from(vec![
-                Span::styled("← → / h l", Style::new().bold().cyan()),
-                Span::raw("     Change columns/panes"),
-            ]),
-            Line::from(vec![
-                Span::styled("Tab", Style::new().bold().cyan()),
-                Span::raw("          Cycle through views"),
-            ]),
-            Line::from(vec![
-                Span::styled("Enter / ↵", Style::new().bold().cyan()),
-                Span::raw("     Select/Confirm action"),
-            ]),
-            Line::from(vec![
-                Span::styled("Esc", Style::new().bold().cyan()),
-                Span::raw("          Back to menu"),
-            ]),
-            Line::from(vec![
-                Span::styled("q / Ctrl-C", Style::new().bold().cyan()),
-                Span::raw("     Quit"),
-            ]),
-        ];
+        let nav_help: Vec<Line> = entries_for(None)
+            .into_iter()
+            .map(|entry| {
+                Line::from(vec![
+                    Span::styled(format!("{:<12}", entry.keys), Style::new().bold().cyan()),
+                    Span::raw(entry.description),
+                ])
+            })
+            .collect();
         frame.render_widget(
             Paragraph::new(nav_help)
                 .block(Block::bordered().title("Navigation"))
                 .style(Style::new()),
-            sections[0],
+            sections[1],
         );
 
         // View-specific section
@@ -95,53 +229,33 @@ impl HelpPage {
             Paragraph::new(view_help)
                 .block(Block::bordered().title("Views"))
                 .style(Style::new()),
-            sections[1],
+            sections[2],
         );
 
-        // Actions section
-        let action_help = vec![
-            Line::from(vec![
-                Span::styled("Dashboard", Style::new().bold().magenta()),
-                Span::raw("  Press "),
-                Span::styled("Enter", Style::new().bold()),
-                Span::raw(" to open Changes view"),
-            ]),
-            Line::from(vec![
-                Span::styled("Changes", Style::new().bold().magenta()),
-                Span::raw("    Press "),
-                Span::styled("Space", Style::new().bold()),
-                Span::raw(" to stage/unstage file"),
-            ]),
-            Line::from(vec![
-                Span::styled("Remote", Style::new().bold().magenta()),
-                Span::raw("      "),
-                Span::styled("f", Style::new().bold()),
-                Span::raw(": Fetch,  "),
-                Span::styled("p", Style::new().bold()),
-                Span::raw(": Push,  "),
-                Span::styled("Ctrl+l", Style::new().bold()),
-                Span::raw(": Pull"),
-            ]),
-            Line::from(vec![
-                Span::styled("Branches", Style::new().bold().magenta()),
-                Span::raw("   n: New, d: Delete, Enter: Switch"),
-            ]),
-            Line::from(vec![
-                Span::styled("Modules", Style::new().bold().magenta()),
-                Span::raw("    n: New, e: Edit, d: Delete, a: Assign"),
-            ]),
-            Line::from(vec![
-                Span::styled("Board", Style::new().bold().magenta()),
-                Span::raw("       Press "),
-                Span::styled("Enter", Style::new().bold()),
-                Span::raw(" to move item to next column"),
-            ]),
-        ];
+        // Actions section, one line per other view
+        let action_help: Vec<Line> = VIEW_ORDER
+            .iter()
+            .filter(|&&mode| mode != current)
+            .map(|&mode| {
+                let shortcuts = entries_for(Some(mode))
+                    .iter()
+                    .map(|entry| format!("{}: {}", entry.keys, entry.description))
+                    .collect::<Vec<_>>()
+                    .join(",  ");
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<12}", mode_label(mode)),
+                        Style::new().bold().magenta(),
+                    ),
+                    Span::raw(shortcuts),
+                ])
+            })
+            .collect();
         frame.render_widget(
             Paragraph::new(action_help)
-                .block(Block::bordered().title("Actions"))
+                .block(Block::bordered().title("Other views"))
                 .style(Style::new()),
-            sections[2],
+            sections[3],
         );
 
         // Tips section
@@ -154,7 +268,83 @@ impl HelpPage {
             Paragraph::new(tips)
                 .block(Block::bordered().title("Tips"))
                 .style(Style::new()),
-            sections[3],
+            sections[4],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_has_core_navigation() {
+        let global = entries_for(None);
+        for keys in ["↑↓ / k j", "Tab", "Esc", "q / Ctrl-C"] {
+            assert!(
+                global.iter().any(|entry| entry.keys == keys),
+                "missing global entry for {}",
+                keys
+            );
+        }
+        assert!(registry().len() > global.len());
+    }
+
+    #[test]
+    fn test_view_hints_come_from_the_registry() {
+        assert_eq!(
+            view_hints(AppMode::Changes),
+            "Space Stage  Enter Commit  f / p Fetch / Push  v View  Y Copy diff"
+        );
+        assert_eq!(
+            view_hints(AppMode::CommitHistory),
+            "↑↓ Navigate  Space View  c Checkout  m Mine"
         );
+        assert_eq!(view_hints(AppMode::Settings), "↑↓ Select  Enter Toggle");
+        for mode in VIEW_ORDER {
+            assert!(!view_hints(mode).is_empty(), "{:?} has no hints", mode);
+        }
+    }
+
+    #[test]
+    fn test_entries_for_mode_are_view_specific() {
+        for mode in VIEW_ORDER {
+            let entries = entries_for(Some(mode));
+            assert!(!entries.is_empty(), "no help entries for {:?}", mode);
+            assert!(entries.iter().all(|entry| entry.mode == Some(mode)));
+        }
+
+        let changes = entries_for(Some(AppMode::Changes));
+        assert!(changes.iter().any(|entry| entry.keys == "Space"));
+        assert!(!changes.iter().any(|entry| entry.keys == "Tab"));
+    }
+
+    #[test]
+    fn test_current_view_section_comes_first() {
+        let text = crate::ui_utils::render_to_text(120, 80, |frame| {
+            HelpPage::new().render(frame, frame.area(), AppMode::ProjectBoard)
+        });
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].contains("Board shortcuts"));
+        let board = entries_for(Some(AppMode::ProjectBoard));
+        for (line, entry) in lines[1..].iter().zip(&board) {
+            assert!(
+                line.contains(entry.description),
+                "{:?} not in {:?}",
+                entry,
+                line
+            );
+        }
+        assert!(lines[board.len() + 2].contains("Navigation"));
+
+        // The board is not repeated among the other views
+        let others = text
+            .lines()
+            .skip_while(|line| !line.contains("Other views"))
+            .take(VIEW_ORDER.len())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(others.contains("Changes"));
+        assert!(!others.contains("Board "));
     }
 }
@@ -7,9 +7,24 @@ use ratatui::{
 
 use crate::Focus;
 
+/// Menu labels, in `AppMode` order
+const LABELS: [&str; 8] = [
+    "Dashboard",
+    "Changes",
+    "History",
+    "Branches",
+    "Merge",
+    "Board",
+    "Modules",
+    "Settings",
+];
+
+/// Position of the Merge entry in [`LABELS`]
+const MERGE_INDEX: usize = 4;
+
 #[derive(Debug)]
 pub struct MainMenu {
-    pub menu_items: Vec<&'static str>,
+    pub menu_items: Vec<String>,
 }
 
 impl Default for MainMenu {
@@ -21,19 +36,20 @@ impl Default for MainMenu {
 impl MainMenu {
     pub fn new() -> Self {
         Self {
-            menu_items: vec![
-                "Dashboard",
-                "Changes",
-                "History",
-                "Branches",
-                "Merge",
-                "Board",
-                "Modules",
-                "Settings",
-            ],
+            menu_items: LABELS.iter().map(|label| label.to_string()).collect(),
         }
     }
 
+    /// Shows the number of unresolved conflicts on the Merge entry, or the
+    /// plain label when there are none
+    pub fn set_conflict_count(&mut self, count: usize) {
+        self.menu_items[MERGE_INDEX] = if count > 0 {
+            format!("{} ({})", LABELS[MERGE_INDEX], count)
+        } else {
+            LABELS[MERGE_INDEX].to_string()
+        };
+    }
+
     pub fn render(&self, frame: &mut Frame, area: Rect, selected_index: usize, focus: Focus) {
         let mut state = ListState::default().with_selected(Some(selected_index));
 
@@ -54,3 +70,21 @@ impl MainMenu {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_label_shows_conflict_count() {
+        let mut menu = MainMenu::new();
+        assert_eq!(menu.menu_items[MERGE_INDEX], "Merge");
+
+        menu.set_conflict_count(3);
+        assert_eq!(menu.menu_items[MERGE_INDEX], "Merge (3)");
+        assert_eq!(menu.menu_items.len(), LABELS.len());
+
+        menu.set_conflict_count(0);
+        assert_eq!(menu.menu_items[MERGE_INDEX], "Merge");
+    }
+}
@@ -1,17 +1,25 @@
 use crate::data::Project;
+use crate::pages::help::HelpEntry;
+use crate::ui_utils::render_empty_state;
+use crate::AppMode;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::Style,
+    text::{Line, Span},
     widgets::{Block, List, ListItem, ListState, Paragraph},
     Frame,
 };
+use std::collections::HashMap;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub enum MergePaneFocus {
     #[default]
     Files,
     Local,
     Incoming,
+    /// Not a pane: the resolution keeping local then incoming. Focus never
+    /// lands here; cycling away from it returns to the file list.
+    Both,
 }
 
 impl MergePaneFocus {
@@ -19,14 +27,14 @@ impl MergePaneFocus {
         match self {
             MergePaneFocus::Files => MergePaneFocus::Local,
             MergePaneFocus::Local => MergePaneFocus::Incoming,
-            MergePaneFocus::Incoming => MergePaneFocus::Files,
+            MergePaneFocus::Incoming | MergePaneFocus::Both => MergePaneFocus::Files,
         }
     }
 
     pub fn prev(self) -> Self {
         match self {
             MergePaneFocus::Files => MergePaneFocus::Incoming,
-            MergePaneFocus::Local => MergePaneFocus::Files,
+            MergePaneFocus::Local | MergePaneFocus::Both => MergePaneFocus::Files,
             MergePaneFocus::Incoming => MergePaneFocus::Local,
         }
     }
@@ -41,8 +49,26 @@ pub struct MergeVisualizerParams<'a> {
     pub pane_focus: MergePaneFocus,
     pub scroll: usize,
     pub accepted: Option<MergePaneFocus>,
+    /// Index of `project` in the store, the first half of a resolution key
+    pub project_index: usize,
+    /// Accepted pane per (project index, file index)
+    pub resolutions: &'a HashMap<(usize, usize), MergePaneFocus>,
 }
 
+/// Shortcuts listed in the help overlay for this view
+pub const HELP_ENTRIES: &[HelpEntry] = &[
+    HelpEntry::view(AppMode::MergeVisualizer, "← →", "Switch pane").with_hint("Pane"),
+    HelpEntry::view(AppMode::MergeVisualizer, "Enter", "Accept focused pane").with_hint("Accept"),
+    HelpEntry::view(
+        AppMode::MergeVisualizer,
+        "b",
+        "Accept both: local then incoming",
+    ),
+    HelpEntry::view(AppMode::MergeVisualizer, "Space", "Quick view file diff").with_hint("View"),
+    HelpEntry::view(AppMode::MergeVisualizer, "o", "Open file in $EDITOR"),
+    HelpEntry::view(AppMode::MergeVisualizer, "p", "Copy file path"),
+];
+
 #[derive(Debug)]
 pub struct MergeVisualizer;
 
@@ -72,7 +98,26 @@ impl MergeVisualizer {
             .project
             .changes
             .iter()
-            .map(|c| ListItem::new(format!("{} ({:?})", c.path, c.status)))
+            .enumerate()
+            .map(|(i, c)| {
+                let binary_badge = if c.is_binary { " [BIN]" } else { "" };
+                let status = match &c.status {
+                    crate::data::FileStatus::Renamed { from, .. } => format!("Renamed from {from}"),
+                    other => format!("{other:?}"),
+                };
+                let marker = match params.resolutions.get(&(params.project_index, i)) {
+                    Some(MergePaneFocus::Local) => Span::styled("✓ local ", Style::new().green()),
+                    Some(MergePaneFocus::Incoming) => {
+                        Span::styled("✓ incoming ", Style::new().green())
+                    }
+                    Some(MergePaneFocus::Both) => Span::styled("✓ both ", Style::new().green()),
+                    _ => Span::styled("● ", Style::new().red()),
+                };
+                ListItem::new(Line::from(vec![
+                    marker,
+                    Span::raw(format!("{} ({}){}", c.path, status, binary_badge)),
+                ]))
+            })
             .collect();
         let mut state = ListState::default()
             .with_selected(Some(
@@ -85,15 +130,19 @@ impl MergeVisualizer {
         } else {
             files_block
         };
-        frame.render_stateful_widget(
-            List::new(file_items)
-                .block(files_block)
-                .highlight_style(Style::new().reversed())
-                .highlight_symbol(">> ")
-                .repeat_highlight_symbol(true),
-            cols[0],
-            &mut state,
-        );
+        if file_items.is_empty() {
+            render_empty_state(frame, cols[0], files_block, "No conflicts");
+        } else {
+            frame.render_stateful_widget(
+                List::new(file_items)
+                    .block(files_block)
+                    .highlight_style(Style::new().reversed())
+                    .highlight_symbol(">> ")
+                    .repeat_highlight_symbol(true),
+                cols[0],
+                &mut state,
+            );
+        }
 
         // Local / Incoming panes
         let local_block = Block::bordered().title("Local change");
@@ -141,3 +190,70 @@ impl MergeVisualizer {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{Change, FileStatus};
+    use crate::ui_utils::{demo_project, render_to_text};
+
+    fn project(paths: &[&str]) -> Project {
+        Project {
+            changes: paths
+                .iter()
+                .map(|path| Change {
+                    path: path.to_string(),
+                    status: FileStatus::Modified,
+                    diff_preview: String::new(),
+                    local_preview: None,
+                    incoming_preview: None,
+                    staged: false,
+                    is_binary: false,
+                    hidden_lines: 0,
+                    is_submodule: false,
+                })
+                .collect(),
+            ..demo_project()
+        }
+    }
+
+    fn rendered_text(
+        project: &Project,
+        resolutions: &HashMap<(usize, usize), MergePaneFocus>,
+    ) -> String {
+        render_to_text(120, 6, |frame| {
+            MergeVisualizer::new().render(
+                frame,
+                MergeVisualizerParams {
+                    area: frame.area(),
+                    project,
+                    selected_file: 0,
+                    pane_focus: MergePaneFocus::Files,
+                    scroll: 0,
+                    accepted: None,
+                    project_index: 0,
+                    resolutions,
+                },
+            )
+        })
+    }
+
+    #[test]
+    fn test_no_changes_shows_no_conflicts() {
+        let text = rendered_text(&project(&[]), &HashMap::new());
+        assert!(text.contains("No conflicts"));
+    }
+
+    #[test]
+    fn test_files_show_resolution_markers() {
+        let project = project(&["a.rs", "b.rs"]);
+        let mut resolutions = HashMap::new();
+        resolutions.insert((0, 0), MergePaneFocus::Incoming);
+        // Another project's resolution for the same file index is ignored
+        resolutions.insert((1, 1), MergePaneFocus::Local);
+
+        let text = rendered_text(&project, &resolutions);
+        assert!(text.contains("✓ incoming a.rs"));
+        assert!(text.contains("● b.rs"));
+    }
+}
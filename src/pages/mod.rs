@@ -1,10 +1,16 @@
+pub mod action_log;
 pub mod branch_manager;
+pub mod branch_picker;
 pub mod changes;
 pub mod commit_history;
+pub mod confirm_dialog;
 pub mod dashboard;
 pub mod help;
 pub mod main_menu;
 pub mod merge_visualizer;
 pub mod module_manager;
 pub mod project_board;
+pub mod quick_view;
+pub mod reflog;
 pub mod settings;
+pub mod worktrees;
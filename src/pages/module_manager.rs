@@ -1,12 +1,16 @@
 use crate::data::{Developer, Module, ModuleStatus, Project};
-use crate::ui_utils::{create_list_state, render_input_form};
+use crate::pages::help::HelpEntry;
+use crate::state::developer_matches;
+use crate::ui_utils::{create_list_state, focused_block, render_empty_state, render_input_form};
+use crate::AppMode;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, List, ListItem},
+    widgets::{List, ListItem},
     Frame,
 };
+use uuid::Uuid;
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum ModuleManagerMode {
@@ -29,6 +33,13 @@ pub struct ModuleManagerParams<'a> {
     pub input_buffer: &'a str,
     pub scroll: usize,
     pub pane_ratio: u16,
+    /// Module whose dependencies are being picked
+    pub dependency_target: Option<Uuid>,
+    /// Developer filter while assigning an owner; the developer list shows
+    /// only matches and `selected_developer` indexes them
+    pub assign_query: Option<&'a str>,
+    /// Module or developer whose row is being renamed to `input_buffer`
+    pub rename_target: Option<Uuid>,
 }
 
 /// Parameters for ModuleList rendering
@@ -40,6 +51,57 @@ pub struct ModuleListParams<'a> {
     pub selected: usize,
     pub scroll: usize,
     pub is_focused: bool,
+    pub dependency_target: Option<Uuid>,
+    /// Row being renamed in place, with the name typed so far
+    pub rename: Option<(Uuid, &'a str)>,
+}
+
+/// Parameters for DeveloperList rendering
+#[derive(Debug, Clone)]
+pub struct DeveloperListParams<'a> {
+    pub area: Rect,
+    pub developers: &'a [Developer],
+    pub selected: usize,
+    pub scroll: usize,
+    pub is_focused: bool,
+    /// Row being renamed in place, with the name typed so far
+    pub rename: Option<(Uuid, &'a str)>,
+}
+
+/// Shortcuts listed in the help overlay for this view
+pub const HELP_ENTRIES: &[HelpEntry] = &[
+    HelpEntry::view(AppMode::ModuleManager, "n / e / d", "New / Edit / Delete")
+        .with_hint("New / Edit / Delete"),
+    HelpEntry::view(AppMode::ModuleManager, "F2", "Rename selected row in place"),
+    HelpEntry::view(AppMode::ModuleManager, "a", "Assign owner (type to filter)")
+        .with_hint("Assign"),
+    HelpEntry::view(AppMode::ModuleManager, "A", "Assign all unowned modules"),
+    HelpEntry::view(AppMode::ModuleManager, "N", "Edit module notes"),
+    HelpEntry::view(AppMode::ModuleManager, "+ / -", "Progress up / down 5%"),
+    HelpEntry::view(AppMode::ModuleManager, "P", "Set progress exactly"),
+    HelpEntry::view(
+        AppMode::ModuleManager,
+        "I",
+        "Import modules from a task list",
+    ),
+    HelpEntry::view(
+        AppMode::ModuleManager,
+        "D",
+        "Pick dependencies (Enter toggles)",
+    )
+    .with_hint("Deps"),
+    HelpEntry::view(AppMode::ModuleManager, "Shift+← →", "Resize panes"),
+];
+
+/// A list row's bold name, or the edited name with a cursor while the row
+/// is being renamed
+fn name_span<'a>(name: &'a str, id: Uuid, rename: Option<(Uuid, &'a str)>) -> Span<'a> {
+    match rename {
+        Some((target, typed)) if target == id => {
+            Span::styled(format!("{}▏", typed), Style::new().bold().underlined())
+        }
+        _ => Span::styled(name, Style::new().bold()),
+    }
 }
 
 #[derive(Debug)]
@@ -64,6 +126,8 @@ impl ModuleManager {
             .constraints([Constraint::Percentage(left), Constraint::Percentage(right)])
             .split(params.area);
 
+        let rename = params.rename_target.map(|id| (id, params.input_buffer));
+
         // Left: Module list
         let list_params = ModuleListParams {
             area: layout[0],
@@ -72,6 +136,8 @@ impl ModuleManager {
             selected: params.selected_module,
             scroll: params.scroll,
             is_focused: params.mode == ModuleManagerMode::ModuleList,
+            dependency_target: params.dependency_target,
+            rename,
         };
         self.render_module_list(frame, list_params);
 
@@ -83,20 +149,48 @@ impl ModuleManager {
             ModuleManagerMode::CreateDeveloper => {
                 self.render_developer_form(frame, layout[1], params.input_buffer);
             }
-            _ => {
-                self.render_developer_list(
+            _ => match params.assign_query {
+                Some(query) => self.render_assign_picker(
                     frame,
                     layout[1],
                     &params.project.developers,
+                    query,
                     params.selected_developer,
-                    0,
-                    params.mode == ModuleManagerMode::DeveloperList,
-                );
-            }
+                ),
+                None => self.render_developer_list(
+                    frame,
+                    DeveloperListParams {
+                        area: layout[1],
+                        developers: &params.project.developers,
+                        selected: params.selected_developer,
+                        scroll: 0,
+                        is_focused: params.mode == ModuleManagerMode::DeveloperList,
+                        rename,
+                    },
+                ),
+            },
         }
     }
 
     fn render_module_list(&self, frame: &mut Frame, params: ModuleListParams) {
+        if params.modules.is_empty() {
+            let title = if params.is_focused {
+                "Modules [FOCUSED]"
+            } else {
+                "Modules"
+            };
+            render_empty_state(
+                frame,
+                params.area,
+                focused_block(title, params.is_focused),
+                "No modules yet — press n to create one",
+            );
+            return;
+        }
+
+        let target = params
+            .dependency_target
+            .and_then(|id| params.modules.iter().find(|m| m.id == id));
         let items: Vec<ListItem> = params
             .modules
             .iter()
@@ -113,8 +207,17 @@ impl ModuleManager {
                     ModuleStatus::Completed => "✓",
                 };
 
-                ListItem::new(vec![
+                let is_dependency = target.is_some_and(|t| t.depends_on.contains(&m.id));
+                let dependency_names: Vec<&str> = m
+                    .depends_on
+                    .iter()
+                    .filter_map(|id| params.modules.iter().find(|d| d.id == *id))
+                    .map(|d| d.name.as_str())
+                    .collect();
+
+                let mut lines = vec![
                     Line::from(vec![
+                        Span::raw(if is_dependency { "⛓ " } else { "" }),
                         Span::styled(
                             format!("{} ", status_icon),
                             Style::new().fg(match m.status {
@@ -123,32 +226,43 @@ impl ModuleManager {
                                 ModuleStatus::Completed => Color::Green,
                             }),
                         ),
-                        Span::styled(&m.name, Style::new().bold()),
+                        name_span(&m.name, m.id, params.rename),
                     ]),
                     Line::from(vec![
                         Span::raw("  Owner: "),
                         Span::styled(owner_name, Style::new().cyan()),
                         Span::raw(format!(" | Progress: {}%", m.progress_score)),
+                        Span::styled(
+                            if m.notes.is_empty() {
+                                String::new()
+                            } else {
+                                format!(" | {}", m.notes)
+                            },
+                            Style::new().italic().gray(),
+                        ),
                     ]),
-                ])
+                ];
+                if !dependency_names.is_empty() {
+                    lines.push(Line::from(Span::styled(
+                        format!("  Needs: {}", dependency_names.join(", ")),
+                        Style::new().gray(),
+                    )));
+                }
+                ListItem::new(lines)
             })
             .collect();
 
         let mut state = create_list_state(params.selected, params.scroll, items.len());
 
-        let title = if params.is_focused {
-            "Modules [FOCUSED]"
-        } else {
-            "Modules"
+        let title = match target {
+            Some(t) => format!("Dependencies of {} (Enter: toggle, Esc: done)", t.name),
+            None if params.is_focused => "Modules [FOCUSED]".to_string(),
+            None => "Modules".to_string(),
         };
 
         frame.render_stateful_widget(
             List::new(items)
-                .block(Block::bordered().title(title).style(if params.is_focused {
-                    Style::new().fg(Color::Cyan)
-                } else {
-                    Style::new()
-                }))
+                .block(focused_block(&title, params.is_focused))
                 .highlight_style(Style::new().reversed())
                 .highlight_symbol(">> "),
             params.area,
@@ -156,20 +270,36 @@ impl ModuleManager {
         );
     }
 
-    fn render_developer_list(
-        &self,
-        frame: &mut Frame,
-        area: Rect,
-        developers: &[Developer],
-        selected: usize,
-        scroll: usize,
-        is_focused: bool,
-    ) {
+    fn render_developer_list(&self, frame: &mut Frame, params: DeveloperListParams) {
+        let DeveloperListParams {
+            area,
+            developers,
+            selected,
+            scroll,
+            is_focused,
+            rename,
+        } = params;
+        let title = if is_focused {
+            "Developers [FOCUSED]"
+        } else {
+            "Developers"
+        };
+
+        if developers.is_empty() {
+            render_empty_state(
+                frame,
+                area,
+                focused_block(title, is_focused),
+                "No developers yet — press n here to add one",
+            );
+            return;
+        }
+
         let items: Vec<ListItem> = developers
             .iter()
             .map(|d| {
                 ListItem::new(vec![
-                    Line::from(Span::styled(&d.name, Style::new().bold())),
+                    Line::from(name_span(&d.name, d.id, rename)),
                     Line::from(Span::styled(format!("  ID: {}", d.id), Style::new().gray())),
                 ])
             })
@@ -177,19 +307,9 @@ impl ModuleManager {
 
         let mut state = create_list_state(selected, scroll, items.len());
 
-        let title = if is_focused {
-            "Developers [FOCUSED]"
-        } else {
-            "Developers"
-        };
-
         frame.render_stateful_widget(
             List::new(items)
-                .block(Block::bordered().title(title).style(if is_focused {
-                    Style::new().fg(Color::Cyan)
-                } else {
-                    Style::new()
-                }))
+                .block(focused_block(title, is_focused))
                 .highlight_style(Style::new().reversed())
                 .highlight_symbol(">> "),
             area,
@@ -213,7 +333,143 @@ impl ModuleManager {
         render_input_form(frame, area, title, "Enter module name", input);
     }
 
+    /// Developer list narrowed by the assignment filter, with the filter
+    /// shown in the title
+    fn render_assign_picker(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        developers: &[Developer],
+        query: &str,
+        selected: usize,
+    ) {
+        let title = format!("Assign owner: {}▏", query);
+        let matches = developer_matches(query, developers);
+        if matches.is_empty() {
+            let message = if developers.is_empty() {
+                "No developers yet — press n here to add one"
+            } else {
+                "No developers match"
+            };
+            render_empty_state(frame, area, focused_block(&title, true), message);
+            return;
+        }
+
+        let items: Vec<ListItem> = matches
+            .iter()
+            .map(|&i| ListItem::new(Span::styled(&developers[i].name, Style::new().bold())))
+            .collect();
+        let mut state = create_list_state(selected, 0, items.len());
+
+        frame.render_stateful_widget(
+            List::new(items)
+                .block(focused_block(&title, true))
+                .highlight_style(Style::new().reversed())
+                .highlight_symbol(">> "),
+            area,
+            &mut state,
+        );
+    }
+
     fn render_developer_form(&self, frame: &mut Frame, area: Rect, input: &str) {
         render_input_form(frame, area, "Create New Developer", "Enter developer name", input);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui_utils::{demo_project, render_to_text};
+
+    #[test]
+    fn test_empty_project_shows_hints() {
+        let project = demo_project();
+        let text = render_to_text(140, 6, |frame| {
+            ModuleManager::new().render(
+                frame,
+                ModuleManagerParams {
+                    area: frame.area(),
+                    project: &project,
+                    mode: ModuleManagerMode::ModuleList,
+                    selected_module: 0,
+                    selected_developer: 0,
+                    input_buffer: "",
+                    scroll: 0,
+                    pane_ratio: 50,
+                    dependency_target: None,
+                    assign_query: None,
+                    rename_target: None,
+                },
+            )
+        });
+        assert!(text.contains("No modules yet — press n to create one"));
+        assert!(text.contains("No developers yet"));
+    }
+
+    #[test]
+    fn test_assign_picker_lists_only_matches() {
+        let developers = ["Alice", "Bob", "Alicia"]
+            .iter()
+            .map(|name| Developer {
+                id: uuid::Uuid::new_v4(),
+                name: name.to_string(),
+            })
+            .collect();
+        let project = Project {
+            developers,
+            ..demo_project()
+        };
+        let text = render_to_text(100, 8, |frame| {
+            ModuleManager::new().render(
+                frame,
+                ModuleManagerParams {
+                    area: frame.area(),
+                    project: &project,
+                    mode: ModuleManagerMode::ModuleList,
+                    selected_module: 0,
+                    selected_developer: 0,
+                    input_buffer: "",
+                    scroll: 0,
+                    pane_ratio: 50,
+                    dependency_target: None,
+                    assign_query: Some("ali"),
+                    rename_target: None,
+                },
+            )
+        });
+        assert!(text.contains("Assign owner: ali"));
+        assert!(text.contains("Alicia"));
+        assert!(!text.contains("Bob"));
+    }
+
+    #[test]
+    fn test_renamed_row_shows_typed_name() {
+        let developer = Developer {
+            id: uuid::Uuid::new_v4(),
+            name: "Al".into(),
+        };
+        let project = Project {
+            developers: vec![developer.clone()],
+            ..demo_project()
+        };
+        let text = render_to_text(100, 8, |frame| {
+            ModuleManager::new().render(
+                frame,
+                ModuleManagerParams {
+                    area: frame.area(),
+                    project: &project,
+                    mode: ModuleManagerMode::DeveloperList,
+                    selected_module: 0,
+                    selected_developer: 0,
+                    input_buffer: "Alice",
+                    scroll: 0,
+                    pane_ratio: 50,
+                    dependency_target: None,
+                    assign_query: None,
+                    rename_target: Some(developer.id),
+                },
+            )
+        });
+        assert!(text.contains("Alice▏"));
+    }
+}
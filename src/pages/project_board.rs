@@ -1,12 +1,18 @@
-use crate::data::{ModuleStatus, Project};
-use crate::ui_utils::{create_list_state, focused_block};
+use crate::data::Project;
+use crate::pages::help::HelpEntry;
+use crate::state::{board_columns, GroupBy};
+use crate::ui_utils::{create_list_state, focused_block, render_empty_state};
+use crate::AppMode;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::Style,
-    widgets::{List, ListItem},
+    widgets::{Block, List, ListItem, Paragraph},
     Frame,
 };
 
+/// Most recent status changes shown under the board for the selected module
+const TIMELINE_ROWS: usize = 5;
+
 /// Parameters for ProjectBoard page rendering
 #[derive(Debug, Clone)]
 pub struct ProjectBoardParams<'a> {
@@ -15,8 +21,22 @@ pub struct ProjectBoardParams<'a> {
     pub selected_column: usize,
     pub selected_item: usize,
     pub scroll: usize,
+    /// Columns are statuses or developers
+    pub group_by: GroupBy,
 }
 
+/// Shortcuts listed in the help overlay for this view
+pub const HELP_ENTRIES: &[HelpEntry] = &[
+    HelpEntry::view(AppMode::ProjectBoard, "← →", "Switch column").with_hint("Column"),
+    HelpEntry::view(AppMode::ProjectBoard, "Enter", "Move item to next status")
+        .with_hint("Advance"),
+    HelpEntry::view(
+        AppMode::ProjectBoard,
+        "g",
+        "Group columns by status / developer",
+    ),
+];
+
 #[derive(Debug)]
 pub struct ProjectBoard;
 
@@ -32,86 +52,201 @@ impl ProjectBoard {
     }
 
     pub fn render(&self, frame: &mut Frame, params: ProjectBoardParams) {
-        let cols = Layout::default()
+        let columns = board_columns(params.project, params.group_by);
+
+        // The selected module's status changes, newest last, below the columns
+        let selected = columns
+            .get(params.selected_column)
+            .and_then(|column| column.modules.get(params.selected_item))
+            .map(|&i| &params.project.modules[i]);
+        let timeline: Vec<_> = selected
+            .map(|m| params.project.module_timeline(m.id).collect())
+            .unwrap_or_default();
+        let board_area = match selected {
+            Some(module) if !timeline.is_empty() => {
+                let shown = timeline.len().min(TIMELINE_ROWS);
+                let rows = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(0), Constraint::Length(shown as u16 + 2)])
+                    .split(params.area);
+                let lines: Vec<String> = timeline[timeline.len() - shown..]
+                    .iter()
+                    .map(|&(status, at)| {
+                        let at = chrono::DateTime::<chrono::Local>::from(at);
+                        format!("{}  \u{2192} {:?}", at.format("%Y-%m-%d %H:%M"), status)
+                    })
+                    .collect();
+                frame.render_widget(
+                    Paragraph::new(lines.join("\n"))
+                        .block(Block::bordered().title(format!("{} history", module.name))),
+                    rows[1],
+                );
+                rows[0]
+            }
+            _ => params.area,
+        };
+
+        let areas = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(33),
-                Constraint::Percentage(34),
-                Constraint::Percentage(33),
+            .constraints(vec![
+                Constraint::Ratio(1, columns.len() as u32);
+                columns.len()
             ])
-            .split(params.area);
+            .split(board_area);
+
+        for (index, (column, &area)) in columns.iter().zip(areas.iter()).enumerate() {
+            let is_selected = params.selected_column == index;
+            let block = focused_block(&column.title, is_selected);
+            if params.project.modules.is_empty() {
+                if index == 0 {
+                    render_empty_state(
+                        frame,
+                        area,
+                        block,
+                        "No modules yet — press n in Module Manager to create one",
+                    );
+                } else {
+                    frame.render_widget(block, area);
+                }
+                continue;
+            }
 
-        let mk = |status: ModuleStatus| -> Vec<ListItem> {
-            params
-                .project
+            let items: Vec<ListItem> = column
                 .modules
                 .iter()
-                .filter(|m| m.status == status)
-                .map(|m| {
-                    let owner_name = m
-                        .owner
-                        .and_then(|oid| params.project.developers.iter().find(|d| d.id == oid))
-                        .map(|d| d.name.clone())
-                        .unwrap_or_else(|| "unassigned".to_string());
-                    ListItem::new(format!(
-                        "{} ({}) - {}%",
-                        m.name, owner_name, m.progress_score
-                    ))
+                .map(|&i| {
+                    let m = &params.project.modules[i];
+                    // The column already says who or what state; show the other
+                    let detail = match params.group_by {
+                        GroupBy::Status => m
+                            .owner
+                            .and_then(|oid| params.project.developers.iter().find(|d| d.id == oid))
+                            .map(|d| d.name.clone())
+                            .unwrap_or_else(|| "unassigned".to_string()),
+                        GroupBy::Developer => format!("{:?}", m.status),
+                    };
+                    ListItem::new(format!("{} ({}) - {}%", m.name, detail, m.progress_score))
                 })
-                .collect()
-        };
+                .collect();
 
-        let pending_items = mk(ModuleStatus::Pending);
-        let current_items = mk(ModuleStatus::Current);
-        let done_items = mk(ModuleStatus::Completed);
+            // Only the active column shows a selection
+            let mut state = if is_selected {
+                create_list_state(params.selected_item, params.scroll, items.len())
+            } else {
+                create_list_state(0, params.scroll, 0)
+            };
 
-        // Create states using utility - only select if this is the active column
-        let mut pending_state = if params.selected_column == 0 {
-            create_list_state(params.selected_item, params.scroll, pending_items.len())
-        } else {
-            create_list_state(0, params.scroll, 0)
-        };
+            frame.render_stateful_widget(
+                List::new(items)
+                    .block(block)
+                    .highlight_symbol(">> ")
+                    .repeat_highlight_symbol(true)
+                    .highlight_style(Style::new().reversed()),
+                area,
+                &mut state,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::ModuleStatus;
+    use crate::ui_utils::{demo_project, render_to_text};
+
+    #[test]
+    fn test_empty_board_shows_hint() {
+        let project = demo_project();
+        let text = render_to_text(120, 6, |frame| {
+            ProjectBoard::new().render(
+                frame,
+                ProjectBoardParams {
+                    area: frame.area(),
+                    project: &project,
+                    selected_column: 0,
+                    selected_item: 0,
+                    scroll: 0,
+                    group_by: GroupBy::Status,
+                },
+            )
+        });
+        assert!(text.contains("No modules yet"));
+        assert!(text.contains("Completed"));
+    }
 
-        let mut current_state = if params.selected_column == 1 {
-            create_list_state(params.selected_item, params.scroll, current_items.len())
-        } else {
-            create_list_state(0, params.scroll, 0)
+    #[test]
+    fn test_developer_columns_render() {
+        let alice = crate::data::Developer {
+            id: uuid::Uuid::new_v4(),
+            name: "Alice".into(),
+        };
+        let project = Project {
+            modules: vec![crate::data::Module {
+                id: uuid::Uuid::new_v4(),
+                name: "Parser".into(),
+                owner: Some(alice.id),
+                status: crate::data::ModuleStatus::Current,
+                progress_score: 40,
+                depends_on: Vec::new(),
+                notes: String::new(),
+            }],
+            developers: vec![alice],
+            ..demo_project()
         };
+        let text = render_to_text(80, 6, |frame| {
+            ProjectBoard::new().render(
+                frame,
+                ProjectBoardParams {
+                    area: frame.area(),
+                    project: &project,
+                    selected_column: 0,
+                    selected_item: 0,
+                    scroll: 0,
+                    group_by: GroupBy::Developer,
+                },
+            )
+        });
+        assert!(text.contains("Alice"));
+        assert!(text.contains("Unassigned"));
+        assert!(text.contains("Parser (Current) - 40%"));
+        assert!(!text.contains("Pending"));
+        assert!(!text.contains("history"));
+    }
+
+    #[test]
+    fn test_selected_module_shows_its_status_history() {
+        let mut store = crate::data::Store::new();
+        store.projects.push(demo_project());
+        let parser = store.add_module(0, "Parser".into()).unwrap();
+        let lexer = store.add_module(0, "Lexer".into()).unwrap();
+        store.set_module_status(0, parser, ModuleStatus::Current);
+        store.set_module_status(0, parser, ModuleStatus::Completed);
+        store.set_module_status(0, lexer, ModuleStatus::Current);
 
-        let mut done_state = if params.selected_column == 2 {
-            create_list_state(params.selected_item, params.scroll, done_items.len())
-        } else {
-            create_list_state(0, params.scroll, 0)
+        let render = |column: usize| {
+            render_to_text(80, 14, |frame| {
+                ProjectBoard::new().render(
+                    frame,
+                    ProjectBoardParams {
+                        area: frame.area(),
+                        project: &store.projects[0],
+                        selected_column: column,
+                        selected_item: 0,
+                        scroll: 0,
+                        group_by: GroupBy::Status,
+                    },
+                )
+            })
         };
+        // Columns are Pending, Current, Completed
+        let text = render(2);
+        assert!(text.contains("Parser history"));
+        let current = text.find("\u{2192} Current").expect("no Current entry");
+        let completed = text.find("\u{2192} Completed").expect("no Completed entry");
+        assert!(current < completed);
 
-        frame.render_stateful_widget(
-            List::new(pending_items)
-                .block(focused_block("Pending", params.selected_column == 0))
-                .highlight_symbol(">> ")
-                .repeat_highlight_symbol(true)
-                .highlight_style(Style::new().reversed()),
-            cols[0],
-            &mut pending_state,
-        );
-
-        frame.render_stateful_widget(
-            List::new(current_items)
-                .block(focused_block("Current", params.selected_column == 1))
-                .highlight_symbol(">> ")
-                .repeat_highlight_symbol(true)
-                .highlight_style(Style::new().reversed()),
-            cols[1],
-            &mut current_state,
-        );
-
-        frame.render_stateful_widget(
-            List::new(done_items)
-                .block(focused_block("Completed", params.selected_column == 2))
-                .highlight_symbol(">> ")
-                .repeat_highlight_symbol(true)
-                .highlight_style(Style::new().reversed()),
-            cols[2],
-            &mut done_state,
-        );
+        assert!(render(1).contains("Lexer history"));
+        assert!(!render(0).contains("history"));
     }
 }
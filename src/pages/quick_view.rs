@@ -0,0 +1,181 @@
+use crate::state::QuickViewState;
+use crate::ui_utils::styled_diff_lines;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+
+/// Popup size as a percentage of the screen's (width, height)
+pub const QUICK_VIEW_PERCENT: (u16, u16) = (90, 85);
+
+/// Parameters for QuickView rendering
+#[derive(Debug, Clone)]
+pub struct QuickViewParams<'a> {
+    pub area: Rect,
+    pub view: &'a QuickViewState,
+}
+
+/// Large popup showing a full diff over the current view
+#[derive(Debug)]
+pub struct QuickView;
+
+impl Default for QuickView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QuickView {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(&self, frame: &mut Frame, params: QuickViewParams) {
+        let view = params.view;
+        let body = if view.content.is_empty() {
+            Paragraph::new("No changes")
+        } else {
+            let mut lines = styled_diff_lines(&view.content);
+            let len = view.query.chars().count();
+            for (i, &(line_no, col)) in view.matches.iter().enumerate() {
+                if let Some(line) = lines.get_mut(line_no) {
+                    let style = if i == view.current_match {
+                        Style::new().black().on_magenta()
+                    } else {
+                        Style::new().black().on_yellow()
+                    };
+                    Self::highlight(line, col, len, style);
+                }
+            }
+            Paragraph::new(lines)
+        };
+
+        let search = match &view.search_input {
+            Some(input) => format!(" | /{}", input),
+            None if view.matches.is_empty() => String::new(),
+            None => format!(
+                " | {} [{}/{}]",
+                view.query,
+                view.current_match + 1,
+                view.matches.len()
+            ),
+        };
+        frame.render_widget(Clear, params.area);
+        frame.render_widget(
+            body.scroll((view.scroll, 0)).block(
+                Block::bordered()
+                    .title(format!(
+                        "{}{} | ↑↓ Scroll | / Search | n/N Next/Prev | Esc Close",
+                        view.title, search
+                    ))
+                    .border_style(Style::new().cyan())
+                    .style(Style::new().bg(Color::Black)),
+            ),
+            params.area,
+        );
+    }
+
+    /// Restyles `len` characters from column `col` of a line, splitting
+    /// whichever span they fall in
+    fn highlight(line: &mut Line, col: usize, len: usize, style: Style) {
+        let mut start = 0;
+        let mut spans = Vec::with_capacity(line.spans.len() + 2);
+        for span in line.spans.drain(..) {
+            let chars = span.content.chars().count();
+            let (from, to) = (col.max(start), (col + len).min(start + chars));
+            if from >= to {
+                spans.push(span);
+            } else {
+                let text: Vec<char> = span.content.chars().collect();
+                let piece =
+                    |a: usize, b: usize| text[a - start..b - start].iter().collect::<String>();
+                if from > start {
+                    spans.push(Span::styled(piece(start, from), span.style));
+                }
+                spans.push(Span::styled(piece(from, to), span.style.patch(style)));
+                if to < start + chars {
+                    spans.push(Span::styled(piece(to, start + chars), span.style));
+                }
+            }
+            start += chars;
+        }
+        line.spans = spans;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn rendered_text(view: &QuickViewState) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        terminal
+            .draw(|frame| {
+                QuickView::new().render(
+                    frame,
+                    QuickViewParams {
+                        area: frame.area(),
+                        view,
+                    },
+                )
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_renders_selected_diff() {
+        let view = QuickViewState::new("src/lib.rs", "@@ -1 +1 @@\n-old line\n+new line");
+        let text = rendered_text(&view);
+        assert!(text.contains("src/lib.rs"));
+        assert!(text.contains("-old line"));
+        assert!(text.contains("+new line"));
+    }
+
+    #[test]
+    fn test_search_highlights_matches() {
+        let mut view = QuickViewState::new("a.rs", "+let foo = 1;\n-foo()");
+        view.start_search();
+        view.search_input.as_mut().unwrap().push_str("foo");
+        assert!(rendered_text(&view).contains("/foo"));
+        view.submit_search();
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        terminal
+            .draw(|frame| {
+                QuickView::new().render(
+                    frame,
+                    QuickViewParams {
+                        area: frame.area(),
+                        view: &view,
+                    },
+                )
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        // Row 1 is the first diff line inside the border; "foo" starts at column 6
+        assert_eq!(buffer[(6, 1)].bg, Color::Magenta);
+        assert_eq!(buffer[(5, 1)].bg, Color::Black);
+        assert_eq!(buffer[(2, 2)].bg, Color::Yellow);
+        assert!(rendered_text(&view).contains("foo [1/2]"));
+    }
+
+    #[test]
+    fn test_scroll_hides_leading_lines() {
+        let mut view = QuickViewState::new("a.rs", "first\nsecond");
+        view.scroll = 1;
+        let text = rendered_text(&view);
+        assert!(!text.contains("first"));
+        assert!(text.contains("second"));
+    }
+}
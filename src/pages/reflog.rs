@@ -0,0 +1,92 @@
+use crate::state::ReflogState;
+use crate::ui_utils::create_list_state;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Parameters for Reflog rendering
+#[derive(Debug, Clone)]
+pub struct ReflogParams<'a> {
+    pub area: Rect,
+    pub reflog: &'a ReflogState,
+}
+
+/// Popup listing recent positions of HEAD
+#[derive(Debug)]
+pub struct Reflog;
+
+impl Default for Reflog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reflog {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(&self, frame: &mut Frame, params: ReflogParams) {
+        frame.render_widget(Clear, params.area);
+        let block = Block::bordered()
+            .title("Reflog | ↑↓ Select | Enter Checkout | r Reset | Esc Close")
+            .border_style(Style::new().cyan())
+            .style(Style::new().bg(Color::Black));
+        let inner = block.inner(params.area);
+        frame.render_widget(block, params.area);
+
+        if params.reflog.entries.is_empty() {
+            frame.render_widget(Paragraph::new("Reflog is empty"), inner);
+            return;
+        }
+        let items: Vec<ListItem> = params
+            .reflog
+            .entries
+            .iter()
+            .map(|(hash, message)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{} ", hash), Style::new().yellow()),
+                    Span::raw(message.as_str()),
+                ]))
+            })
+            .collect();
+        // Stateful so the list scrolls to keep the selection in view
+        let mut state = create_list_state(params.reflog.selected, 0, items.len());
+        frame.render_stateful_widget(
+            List::new(items).highlight_style(Style::new().reversed()),
+            inner,
+            &mut state,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui_utils::render_to_text;
+
+    #[test]
+    fn test_selection_scrolls_into_view() {
+        let entries = (0..100)
+            .map(|i| (format!("{:07}", i), format!("commit: change {}", i)))
+            .collect();
+        let mut reflog = ReflogState::new(entries);
+        reflog.move_selection(80);
+
+        let text = render_to_text(60, 12, |frame| {
+            Reflog::new().render(
+                frame,
+                ReflogParams {
+                    area: frame.area(),
+                    reflog: &reflog,
+                },
+            )
+        });
+        assert!(text.contains("commit: change 80"));
+        assert!(!text.contains("0000000 "));
+    }
+}
@@ -1,4 +1,6 @@
+use crate::pages::help::HelpEntry;
 use crate::ui_utils::create_list_state;
+use crate::AppMode;
 use ratatui::{
     layout::Rect,
     style::Style,
@@ -15,6 +17,19 @@ pub struct SettingsParams<'a> {
     pub options: &'a [String],
 }
 
+/// Row of the theme setting in the Settings list
+pub const THEME_SETTING: usize = 0;
+/// Row of the notifications setting in the Settings list
+pub const NOTIFICATIONS_SETTING: usize = 1;
+/// Row of the commit date format setting in the Settings list
+pub const COMMIT_DATES_SETTING: usize = 8;
+
+/// Shortcuts listed in the help overlay for this view
+pub const HELP_ENTRIES: &[HelpEntry] = &[
+    HelpEntry::view(AppMode::Settings, "↑↓", "Select setting").with_hint("Select"),
+    HelpEntry::view(AppMode::Settings, "Enter", "Toggle setting").with_hint("Toggle"),
+];
+
 #[derive(Debug)]
 pub struct SettingsPage;
 
@@ -0,0 +1,66 @@
+use crate::state::WorktreeListState;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem},
+    Frame,
+};
+
+/// Parameters for WorktreeList rendering
+#[derive(Debug, Clone)]
+pub struct WorktreeListParams<'a> {
+    pub area: Rect,
+    pub list: &'a WorktreeListState,
+}
+
+/// Popup listing the repository's working trees
+#[derive(Debug)]
+pub struct WorktreeList;
+
+impl Default for WorktreeList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorktreeList {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(&self, frame: &mut Frame, params: WorktreeListParams) {
+        frame.render_widget(Clear, params.area);
+        let block = Block::bordered()
+            .title("Worktrees | ↑↓ Select | Enter Switch | Esc Close")
+            .border_style(Style::new().cyan())
+            .style(Style::new().bg(Color::Black));
+        let inner = block.inner(params.area);
+        frame.render_widget(block, params.area);
+
+        let items: Vec<ListItem> = params
+            .list
+            .worktrees
+            .iter()
+            .enumerate()
+            .map(|(i, (name, path))| {
+                let marker = if params.list.current == Some(i) {
+                    "* "
+                } else {
+                    "  "
+                };
+                let style = if i == params.list.selected {
+                    Style::new().reversed()
+                } else {
+                    Style::new()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{}{} ", marker, name)),
+                    Span::styled(path.display().to_string(), Style::new().dark_gray()),
+                ]))
+                .style(style)
+            })
+            .collect();
+        frame.render_widget(List::new(items), inner);
+    }
+}
@@ -1,24 +1,58 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::Stylize,
+    style::{Color, Style, Stylize},
     text::{Line, Span},
     widgets::{Block, Clear},
     Frame,
 };
 use throbber_widgets_tui::{Throbber, ThrobberState};
 
+use crate::pages::action_log::{ActionLogPanel, ActionLogParams};
 use crate::pages::branch_manager::BranchManager;
+use crate::pages::branch_picker::{BranchPicker, BranchPickerParams};
 use crate::pages::changes::ChangesPage;
 use crate::pages::commit_history::CommitHistory;
+use crate::pages::confirm_dialog::{ConfirmDialog, ConfirmDialogParams};
 use crate::pages::dashboard::Dashboard;
-use crate::pages::help::HelpPage;
+use crate::pages::help::{view_hints, HelpPage};
 use crate::pages::main_menu::MainMenu;
 use crate::pages::merge_visualizer::MergeVisualizer;
 use crate::pages::module_manager::ModuleManager;
 use crate::pages::project_board::ProjectBoard;
+use crate::pages::quick_view::{QuickView, QuickViewParams, QUICK_VIEW_PERCENT};
+use crate::pages::reflog::{Reflog, ReflogParams};
 use crate::pages::settings::SettingsPage;
+use crate::pages::worktrees::{WorktreeList, WorktreeListParams};
+use crate::ui_utils::{humanize_duration, render_empty_state, render_input_form};
 use crate::{AppMode, AppSettings, Focus, Theme};
 
+/// Shown by project-scoped views when there is no project to display
+const NO_PROJECT: &str = "No project — open a git repository to get started";
+
+/// Top border title: the app name, then the repository name followed by a
+/// `●` marker when `dirty` (uncommitted changes)
+fn title_line(repo: Option<(bool, &str)>, theme: Theme) -> Line<'_> {
+    let mut spans = vec![Span::raw("Forge - Git Aware Project Management")];
+    if let Some((dirty, name)) = repo {
+        spans.push(Span::raw(format!(" \u{2014} {}", name)));
+        if dirty {
+            let color = match theme {
+                Theme::HighContrast => Color::LightRed,
+                Theme::Default => Color::Yellow,
+            };
+            spans.push(Span::styled(" \u{25CF}", Style::new().fg(color)));
+        }
+    }
+    Line::from(spans).bold().blue().left_aligned()
+}
+
+/// Remote data older than this is highlighted as stale in the status bar
+const FETCH_STALE_AFTER: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// Shown above every view while no repository is open
+pub const NO_REPO_BANNER: &str =
+    "\u{26A0} Not inside a Git repository \u{2014} changes are mock-only";
+
 /// Context for rendering the UI
 ///
 /// Bundles all parameters needed for rendering to reduce function signature complexity
@@ -34,24 +68,61 @@ pub struct RenderContext<'a> {
     pub changes_pane_ratio: u16,
     pub commit_pane_ratio: u16,
     pub dashboard_pane_ratio: u16,
+    /// Commits per day for the dashboard activity panel
+    pub dashboard_activity: &'a [(String, usize)],
+    /// Files in HEAD's tree, for the dashboard info pane
+    pub tracked_files: Option<usize>,
     pub menu_selected_index: usize,
     pub focus: Focus,
     pub selected_board_column: usize,
     pub selected_board_item: usize,
+    pub board_group_by: crate::state::GroupBy,
     pub merge_file_index: usize,
     pub merge_focus: crate::pages::merge_visualizer::MergePaneFocus,
     pub selected_setting: usize,
     pub show_help: bool,
+    pub confirm_message: Option<&'a str>,
+    /// Git activity log, when its overlay is open
+    pub action_log: Option<&'a crate::state::ActionLog>,
+    /// Diff quick view popup, when open
+    pub quick_view: Option<&'a crate::state::QuickViewState>,
+    /// Branch-switch picker popup, when open
+    pub branch_picker: Option<&'a crate::state::BranchPickerState>,
+    /// HEAD reflog popup, when open
+    pub reflog: Option<&'a crate::state::ReflogState>,
+    /// Worktree switcher popup, when open
+    pub worktrees: Option<&'a crate::state::WorktreeListState>,
+    /// Single-line text prompt, when open
+    pub prompt: Option<&'a crate::state::TextPrompt>,
     pub project_scroll: usize,
     pub changes_scroll: usize,
+    /// Commits reachable from HEAD, for the Commit History header
+    pub total_commits: Option<usize>,
+    /// Commit History shows "time ago" rather than timestamps
+    pub relative_dates: bool,
+    /// Commit History shows one line per commit
+    pub compact_commits: bool,
+    /// Only Ctrl+Enter commits from the Changes view
+    pub commit_requires_modifier: bool,
+    /// Lines of the Changes diff preview scrolled past the top
+    pub changes_diff_scroll: u16,
+    /// Ticked Changes rows, in multi-select mode
+    pub changes_multi_select: Option<&'a std::collections::HashSet<usize>>,
+    /// Blame of the selected change, when annotations are on
+    pub changes_blame: Option<&'a [crate::git::BlameLine]>,
     pub merge_scroll: usize,
     pub search_active: bool,
     pub search_buffer: &'a str,
+    pub project_sort: Option<crate::data::ProjectSort>,
     pub filtered_projects: &'a [&'a crate::data::Project],
     pub settings_options: &'a [String],
     pub total_projects: usize,
     pub settings: &'a AppSettings,
     pub accepted_merge: Option<crate::pages::merge_visualizer::MergePaneFocus>,
+    pub merge_resolutions: &'a std::collections::HashMap<
+        (usize, usize),
+        crate::pages::merge_visualizer::MergePaneFocus,
+    >,
     pub workdir: Option<&'a std::path::Path>,
     pub module_manager_mode: crate::pages::module_manager::ModuleManagerMode,
     pub selected_module: usize,
@@ -59,15 +130,36 @@ pub struct RenderContext<'a> {
     pub module_input_buffer: &'a str,
     pub module_scroll: usize,
     pub module_pane_ratio: u16,
+    /// Module whose dependencies are being picked
+    pub module_dependency_target: Option<uuid::Uuid>,
+    /// Module or developer being renamed in place
+    pub module_rename_target: Option<uuid::Uuid>,
+    /// Developer filter while assigning an owner
+    pub module_assign_query: Option<&'a str>,
     pub branch_manager_mode: crate::pages::branch_manager::BranchManagerMode,
     pub selected_branch: usize,
     pub branch_input_buffer: &'a str,
     pub branch_scroll: usize,
+    pub branch_filter: crate::state::BranchFilter,
     pub cached_branches: &'a [crate::pages::branch_manager::BranchInfo],
     pub selected_commit: usize,
     pub commit_scroll: usize,
     pub cached_commits: &'a [crate::pages::commit_history::CommitInfo],
+    /// Author substring the commit list is filtered by
+    pub commit_author_filter: Option<&'a str>,
     pub pending_git_ops_count: usize,
+    /// Time since `origin` was last fetched, if ever
+    pub last_fetch_age: Option<std::time::Duration>,
+    /// Abbreviated hash HEAD is detached at; shows a warning banner
+    pub detached_at: Option<&'a str>,
+    /// Working tree has uncommitted changes; `None` without a repository
+    pub repo_dirty: Option<bool>,
+    /// Lines inserted and deleted since HEAD; `None` without a repository
+    pub uncommitted: Option<(usize, usize)>,
+    /// Unresolved merge conflicts, counted on the Merge menu entry
+    pub conflict_count: usize,
+    /// Warn above every view that no repository is open, so nothing persists
+    pub no_repo_banner: bool,
 }
 
 #[derive(Debug)]
@@ -82,6 +174,12 @@ pub struct Screen {
     module_manager: ModuleManager,
     settings: SettingsPage,
     help: HelpPage,
+    confirm_dialog: ConfirmDialog,
+    action_log: ActionLogPanel,
+    quick_view: QuickView,
+    branch_picker: BranchPicker,
+    reflog: Reflog,
+    worktrees: WorktreeList,
     spinner_state: ThrobberState,
 }
 
@@ -104,6 +202,12 @@ impl Screen {
             module_manager: ModuleManager::new(),
             settings: SettingsPage::new(),
             help: HelpPage::new(),
+            confirm_dialog: ConfirmDialog::new(),
+            action_log: ActionLogPanel::new(),
+            quick_view: QuickView::new(),
+            branch_picker: BranchPicker::new(),
+            reflog: Reflog::new(),
+            worktrees: WorktreeList::new(),
             spinner_state: ThrobberState::default(),
         }
     }
@@ -115,11 +219,13 @@ impl Screen {
         }
 
         let area = frame.area();
-        let title = Line::from("Forge - Git Aware Project Management")
-            .bold()
-            .blue()
-            .left_aligned();
-        let block = Block::bordered().title(title);
+        let repo = ctx.repo_dirty.zip(
+            ctx.store
+                .projects
+                .get(ctx.selected_project)
+                .map(|p| p.name.as_str()),
+        );
+        let block = Block::bordered().title(title_line(repo, ctx.settings.theme));
         let inner_area = block.inner(area);
         frame.render_widget(block, area);
 
@@ -138,6 +244,7 @@ impl Screen {
             ratatui::style::Style::new()
         };
 
+        self.main_menu.set_conflict_count(ctx.conflict_count);
         for (idx, item) in self.main_menu.menu_items.iter().enumerate() {
             if idx == ctx.menu_selected_index {
                 menu_line.push(Span::styled(
@@ -159,10 +266,24 @@ impl Screen {
             }
         }
 
+        let main_area = if ctx.no_repo_banner {
+            let rows = Layout::new(
+                Direction::Vertical,
+                [Constraint::Length(1), Constraint::Min(0)],
+            )
+            .split(vlayout[0]);
+            let banner = Line::from(format!(" {} (Ctrl+X Dismiss)", NO_REPO_BANNER))
+                .style(ratatui::style::Style::new().on_yellow().black().bold());
+            frame.render_widget(banner, rows[0]);
+            rows[1]
+        } else {
+            vlayout[0]
+        };
+
         let content_title = Line::from(menu_line).left_aligned();
         let content_block = Block::bordered().title(content_title);
-        let content_area = content_block.inner(vlayout[0]);
-        frame.render_widget(content_block, vlayout[0]);
+        let content_area = content_block.inner(main_area);
+        frame.render_widget(content_block, main_area);
 
         // Render the content page based on mode
         match ctx.mode {
@@ -174,8 +295,11 @@ impl Screen {
                     scroll: ctx.project_scroll,
                     search_active: ctx.search_active,
                     search_buffer: ctx.search_buffer,
+                    sort: ctx.project_sort,
                     total_count: ctx.total_projects,
                     pane_ratio: ctx.dashboard_pane_ratio,
+                    activity: ctx.dashboard_activity,
+                    tracked_files: ctx.tracked_files,
                 };
                 self.dashboard.render(frame, params);
             }
@@ -189,17 +313,32 @@ impl Screen {
                         commit_msg: ctx.commit_msg,
                         scroll: ctx.changes_scroll,
                         pane_ratio: ctx.changes_pane_ratio,
+                        blame: ctx.changes_blame,
+                        commit_requires_modifier: ctx.commit_requires_modifier,
+                        diff_scroll: ctx.changes_diff_scroll,
+                        multi_select: ctx.changes_multi_select,
                     };
                     self.changes.render(frame, params);
+                } else {
+                    render_empty_state(frame, content_area, Block::bordered(), NO_PROJECT);
                 }
             }
             AppMode::CommitHistory => {
                 let params = crate::pages::commit_history::CommitHistoryParams {
                     area: content_area,
                     commits: ctx.cached_commits,
+                    author_filter: ctx.commit_author_filter,
+                    total_commits: ctx.total_commits.zip(
+                        ctx.store
+                            .projects
+                            .get(ctx.selected_project)
+                            .map(|p| p.branch.as_str()),
+                    ),
                     selected: ctx.selected_commit,
                     scroll: ctx.commit_scroll,
                     pane_ratio: ctx.commit_pane_ratio,
+                    relative_dates: ctx.relative_dates,
+                    compact: ctx.compact_commits,
                 };
                 self.commit_history.render(frame, params);
             }
@@ -211,6 +350,7 @@ impl Screen {
                     scroll: ctx.branch_scroll,
                     mode: ctx.branch_manager_mode,
                     input_buffer: ctx.branch_input_buffer,
+                    filter: ctx.branch_filter,
                 };
                 self.branch_manager.render(frame, params);
             }
@@ -224,8 +364,12 @@ impl Screen {
                         pane_focus: ctx.merge_focus,
                         scroll: ctx.merge_scroll,
                         accepted: ctx.accepted_merge,
+                        project_index: ctx.selected_project,
+                        resolutions: ctx.merge_resolutions,
                     };
                     self.merge.render(frame, params);
+                } else {
+                    render_empty_state(frame, content_area, Block::bordered(), NO_PROJECT);
                 }
             }
             AppMode::ProjectBoard => {
@@ -237,8 +381,11 @@ impl Screen {
                         selected_column: ctx.selected_board_column,
                         selected_item: ctx.selected_board_item,
                         scroll: ctx.project_scroll,
+                        group_by: ctx.board_group_by,
                     };
                     self.board.render(frame, params);
+                } else {
+                    render_empty_state(frame, content_area, Block::bordered(), NO_PROJECT);
                 }
             }
             AppMode::ModuleManager => {
@@ -253,8 +400,13 @@ impl Screen {
                         input_buffer: ctx.module_input_buffer,
                         scroll: ctx.module_scroll,
                         pane_ratio: ctx.module_pane_ratio,
+                        dependency_target: ctx.module_dependency_target,
+                        rename_target: ctx.module_rename_target,
+                        assign_query: ctx.module_assign_query,
                     };
                     self.module_manager.render(frame, params);
+                } else {
+                    render_empty_state(frame, content_area, Block::bordered(), NO_PROJECT);
                 }
             }
             AppMode::Settings => {
@@ -274,10 +426,34 @@ impl Screen {
             .map(|p| format!("Repo: {}", p.display()))
             .unwrap_or_else(|| "Repo: n/a".to_string());
 
-        let status_text = format!(
-            "{}  |  {}  |  Tab: Switch View  Enter: Open  ?: Help  Esc/q: Quit",
-            ctx.status, repo_badge
-        );
+        let fetch_span = match ctx.last_fetch_age {
+            Some(age) if age > FETCH_STALE_AFTER => Span::styled(
+                format!("  |  fetched {}", humanize_duration(age)),
+                ratatui::style::Style::new().red().bold(),
+            ),
+            Some(age) => Span::raw(format!("  |  fetched {}", humanize_duration(age))),
+            None if ctx.workdir.is_some() => Span::raw("  |  never fetched"),
+            None => Span::raw(""),
+        };
+
+        let uncommitted_span = match ctx.uncommitted {
+            Some((insertions, deletions)) if insertions + deletions > 0 => Span::raw(format!(
+                "  |  +{} \u{2212}{} uncommitted",
+                insertions, deletions
+            )),
+            _ => Span::raw(""),
+        };
+
+        let detached_span = match ctx.detached_at {
+            Some(short) => Span::styled(
+                format!(" HEAD (detached at {}) ", short),
+                ratatui::style::Style::new().on_red().white().bold(),
+            ),
+            None => Span::raw(""),
+        };
+
+        let status_text = format!("{}  |  {}", ctx.status, repo_badge);
+        let hints = format!("  |  {}  Tab View  ? Help  q Quit", view_hints(ctx.mode));
 
         if ctx.pending_git_ops_count > 0 {
             let status_layout = Layout::default()
@@ -299,14 +475,26 @@ impl Screen {
                 .style(spinner_style);
             frame.render_stateful_widget(spinner_widget, status_layout[0], &mut self.spinner_state);
 
-            let status_line = Line::from(status_text);
+            let status_line = Line::from(vec![
+                detached_span,
+                Span::raw(status_text),
+                fetch_span,
+                uncommitted_span,
+                Span::raw(hints),
+            ]);
             let status_line = match ctx.settings.theme {
                 Theme::HighContrast => status_line.on_yellow().black(),
                 Theme::Default => status_line.on_dark_gray().white(),
             };
             frame.render_widget(status_line, status_layout[1]);
         } else {
-            let status_line = Line::from(status_text);
+            let status_line = Line::from(vec![
+                detached_span,
+                Span::raw(status_text),
+                fetch_span,
+                uncommitted_span,
+                Span::raw(hints),
+            ]);
             let status_line = match ctx.settings.theme {
                 Theme::HighContrast => status_line.on_yellow().black(),
                 Theme::Default => status_line.on_dark_gray().white(),
@@ -325,7 +513,87 @@ impl Screen {
             );
             let inner = Block::bordered().inner(popup_area);
             frame.render_widget(Clear, inner);
-            self.help.render(frame, inner);
+            self.help.render(frame, inner, ctx.mode);
+        }
+
+        if let Some(log) = ctx.action_log {
+            let popup_area = self.centered_rect(80, 70, frame.area());
+            self.action_log.render(
+                frame,
+                ActionLogParams {
+                    area: popup_area,
+                    log,
+                },
+            );
+        }
+
+        if let Some(view) = ctx.quick_view {
+            let (width, height) = QUICK_VIEW_PERCENT;
+            let popup_area = self.centered_rect(width, height, frame.area());
+            self.quick_view.render(
+                frame,
+                QuickViewParams {
+                    area: popup_area,
+                    view,
+                },
+            );
+        }
+
+        if let Some(picker) = ctx.branch_picker {
+            let popup_area = self.centered_rect(50, 50, frame.area());
+            self.branch_picker.render(
+                frame,
+                BranchPickerParams {
+                    area: popup_area,
+                    picker,
+                },
+            );
+        }
+
+        if let Some(reflog) = ctx.reflog {
+            let popup_area = self.centered_rect(70, 60, frame.area());
+            self.reflog.render(
+                frame,
+                ReflogParams {
+                    area: popup_area,
+                    reflog,
+                },
+            );
+        }
+
+        if let Some(list) = ctx.worktrees {
+            let popup_area = self.centered_rect(70, 50, frame.area());
+            self.worktrees.render(
+                frame,
+                WorktreeListParams {
+                    area: popup_area,
+                    list,
+                },
+            );
+        }
+
+        if let Some(prompt) = ctx.prompt {
+            let popup_area = self.centered_rect(60, 35, frame.area());
+            frame.render_widget(Clear, popup_area);
+            render_input_form(
+                frame,
+                popup_area,
+                prompt.kind.title(),
+                prompt.kind.label(),
+                &prompt.input,
+            );
+        }
+
+        // Confirmation dialog sits above everything else
+        if let Some(message) = ctx.confirm_message {
+            let popup_area = self.centered_rect(50, 25, frame.area());
+            self.confirm_dialog.render(
+                frame,
+                ConfirmDialogParams {
+                    area: popup_area,
+                    message,
+                },
+            );
         }
     }
 
@@ -354,3 +622,38 @@ impl Screen {
             .split(popup_layout[1])[1]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    /// Top row of a bordered block titled for the given repository state
+    fn rendered_title(repo: Option<(bool, &str)>) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(70, 3)).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget(
+                    Block::bordered().title(title_line(repo, Theme::Default)),
+                    frame.area(),
+                )
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.width)
+            .map(|x| buffer[(x, 0)].symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_title_marks_dirty_repository() {
+        let dirty = rendered_title(Some((true, "forge")));
+        assert!(dirty.contains("forge \u{25CF}"), "{}", dirty);
+
+        let clean = rendered_title(Some((false, "forge")));
+        assert!(clean.contains("\u{2014} forge"));
+        assert!(!clean.contains('\u{25CF}'));
+
+        assert!(!rendered_title(None).contains('\u{2014}'));
+    }
+}
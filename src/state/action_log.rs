@@ -0,0 +1,121 @@
+//! Action log state.
+//!
+//! Keeps a bounded history of the git operations forge has performed.
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Local};
+
+/// Default number of entries kept before the oldest are dropped.
+pub const DEFAULT_LOG_CAPACITY: usize = 200;
+
+/// One recorded git operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    /// When the operation finished.
+    pub timestamp: DateTime<Local>,
+    /// Short operation name, e.g. "Commit" or "Fetch".
+    pub action: String,
+    /// Whether the operation succeeded.
+    pub success: bool,
+    /// Outcome detail: what was done, or the error message.
+    pub detail: String,
+}
+
+/// Ring buffer of performed git operations, oldest dropped first.
+#[derive(Debug, Clone)]
+pub struct ActionLog {
+    /// Recorded entries, oldest at the front.
+    entries: VecDeque<LogEntry>,
+    /// Maximum number of entries retained.
+    capacity: usize,
+}
+
+impl Default for ActionLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_LOG_CAPACITY)
+    }
+}
+
+impl ActionLog {
+    /// Creates an empty log holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Records an operation outcome, evicting the oldest entry when full.
+    pub fn record(&mut self, action: &str, success: bool, detail: impl Into<String>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(LogEntry {
+            timestamp: Local::now(),
+            action: action.to_string(),
+            success,
+            detail: detail.into(),
+        });
+    }
+
+    /// Iterates entries from most recent to oldest.
+    pub fn newest_first(&self) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter().rev()
+    }
+
+    /// Returns the number of recorded entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_log_is_empty() {
+        let log = ActionLog::default();
+        assert!(log.is_empty());
+        assert_eq!(log.newest_first().count(), 0);
+    }
+
+    #[test]
+    fn test_record_newest_first() {
+        let mut log = ActionLog::new(5);
+        log.record("Fetch", true, "Fetched from origin");
+        log.record("Push", false, "rejected");
+
+        let actions: Vec<&str> = log.newest_first().map(|e| e.action.as_str()).collect();
+        assert_eq!(actions, ["Push", "Fetch"]);
+        assert!(!log.newest_first().next().unwrap().success);
+    }
+
+    #[test]
+    fn test_capacity_evicts_oldest() {
+        let mut log = ActionLog::new(3);
+        for i in 0..5 {
+            log.record("Commit", true, format!("commit {}", i));
+        }
+
+        assert_eq!(log.len(), 3);
+        let details: Vec<&str> = log.newest_first().map(|e| e.detail.as_str()).collect();
+        assert_eq!(details, ["commit 4", "commit 3", "commit 2"]);
+    }
+
+    #[test]
+    fn test_zero_capacity_records_nothing() {
+        let mut log = ActionLog::new(0);
+        log.record("Commit", true, "ignored");
+        assert!(log.is_empty());
+    }
+}
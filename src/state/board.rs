@@ -2,17 +2,93 @@
 //!
 //! Manages Kanban board column and item navigation.
 
-use crate::data::ModuleStatus;
+use crate::data::{ModuleStatus, Project};
+
+/// How the board splits modules into columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupBy {
+    /// Pending, Current and Completed columns
+    #[default]
+    Status,
+    /// One column per developer, then "Unassigned"
+    Developer,
+}
+
+impl GroupBy {
+    pub fn toggle(self) -> Self {
+        match self {
+            GroupBy::Status => GroupBy::Developer,
+            GroupBy::Developer => GroupBy::Status,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GroupBy::Status => "status",
+            GroupBy::Developer => "developer",
+        }
+    }
+}
+
+/// One board column: its title and the indices of its modules in the project
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardColumn {
+    pub title: String,
+    pub modules: Vec<usize>,
+}
+
+/// Splits `project`'s modules into board columns; modules keep their project
+/// order within a column.
+///
+/// Developer grouping puts modules whose owner is no longer a developer of
+/// the project under "Unassigned".
+pub fn board_columns(project: &Project, group_by: GroupBy) -> Vec<BoardColumn> {
+    let column = |title: String, keep: &dyn Fn(&crate::data::Module) -> bool| BoardColumn {
+        title,
+        modules: project
+            .modules
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| keep(m))
+            .map(|(i, _)| i)
+            .collect(),
+    };
+    match group_by {
+        GroupBy::Status => [
+            ModuleStatus::Pending,
+            ModuleStatus::Current,
+            ModuleStatus::Completed,
+        ]
+        .into_iter()
+        .map(|status| column(format!("{:?}", status), &|m| m.status == status))
+        .collect(),
+        GroupBy::Developer => {
+            let mut columns: Vec<BoardColumn> = project
+                .developers
+                .iter()
+                .map(|d| column(d.name.clone(), &|m| m.owner == Some(d.id)))
+                .collect();
+            let is_developer = |id| project.developers.iter().any(|d| d.id == id);
+            columns.push(column("Unassigned".into(), &|m| {
+                !m.owner.is_some_and(is_developer)
+            }));
+            columns
+        }
+    }
+}
 
 /// State for the Project Board view (Kanban board).
 ///
-/// Handles navigation between columns (Pending, Current, Completed) and items.
+/// Handles navigation between columns and items. Columns are Pending,
+/// Current and Completed, or one per developer when grouped by developer.
 #[derive(Debug, Clone, Default)]
 pub struct BoardState {
-    /// Currently selected column (0=Pending, 1=Current, 2=Completed).
+    /// Currently selected column, an index into [`board_columns`].
     pub selected_column: usize,
     /// Currently selected item within the column.
     pub selected_item: usize,
+    /// Whether columns are statuses or developers.
+    pub group_by: GroupBy,
 }
 
 impl BoardState {
@@ -23,9 +99,21 @@ impl BoardState {
         Self {
             selected_column: 1, // Start in "Current" column
             selected_item: 0,
+            group_by: GroupBy::Status,
         }
     }
 
+    /// Switches between status and developer columns, starting over at the
+    /// first developer or the Current column.
+    pub fn toggle_group_by(&mut self) {
+        self.group_by = self.group_by.toggle();
+        self.selected_column = match self.group_by {
+            GroupBy::Status => 1,
+            GroupBy::Developer => 0,
+        };
+        self.selected_item = 0;
+    }
+
     /// Navigates to the previous item in the current column.
     ///
     /// Wraps to the last item if at the top.
@@ -50,44 +138,24 @@ impl BoardState {
         }
     }
 
-    /// Navigates to the previous column.
+    /// Navigates to the previous column, given every column's length.
     ///
-    /// Wraps from Pending (0) to Completed (2).
+    /// Wraps from the first column to the last.
     /// Clamps the item selection to the new column's length.
-    pub fn navigate_left(&mut self, new_column_len: usize) {
-        if self.selected_column == 0 {
-            self.selected_column = 2;
-        } else {
-            self.selected_column -= 1;
-        }
-        self.clamp_item_to_column(new_column_len);
+    pub fn navigate_left(&mut self, column_lens: &[usize]) {
+        let count = column_lens.len().max(1);
+        self.selected_column = (self.selected_column.min(count - 1) + count - 1) % count;
+        self.clamp_item_to_column(column_lens.get(self.selected_column).copied().unwrap_or(0));
     }
 
-    /// Navigates to the next column.
+    /// Navigates to the next column, given every column's length.
     ///
-    /// Wraps from Completed (2) to Pending (0).
+    /// Wraps from the last column to the first.
     /// Clamps the item selection to the new column's length.
-    pub fn navigate_right(&mut self, new_column_len: usize) {
-        self.selected_column = (self.selected_column + 1) % 3;
-        self.clamp_item_to_column(new_column_len);
-    }
-
-    /// Returns the `ModuleStatus` corresponding to the current column.
-    pub fn current_status(&self) -> ModuleStatus {
-        match self.selected_column {
-            0 => ModuleStatus::Pending,
-            1 => ModuleStatus::Current,
-            _ => ModuleStatus::Completed,
-        }
-    }
-
-    /// Returns the name of the current column.
-    pub fn current_column_name(&self) -> &'static str {
-        match self.selected_column {
-            0 => "Pending",
-            1 => "Current",
-            _ => "Completed",
-        }
+    pub fn navigate_right(&mut self, column_lens: &[usize]) {
+        let count = column_lens.len().max(1);
+        self.selected_column = (self.selected_column + 1) % count;
+        self.clamp_item_to_column(column_lens.get(self.selected_column).copied().unwrap_or(0));
     }
 
     /// Clamps the item selection to the new column's length.
@@ -108,6 +176,105 @@ impl BoardState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data::{Developer, Module};
+    use uuid::Uuid;
+
+    /// Alice owns modules 0 and 2, Bob owns 1, module 3 is unowned and
+    /// module 4's owner has left the project
+    fn team_project() -> Project {
+        let alice = Developer {
+            id: Uuid::new_v4(),
+            name: "Alice".into(),
+        };
+        let bob = Developer {
+            id: Uuid::new_v4(),
+            name: "Bob".into(),
+        };
+        let owners = [
+            Some(alice.id),
+            Some(bob.id),
+            Some(alice.id),
+            None,
+            Some(Uuid::new_v4()),
+        ];
+        Project {
+            id: Uuid::new_v4(),
+            name: "team".into(),
+            description: String::new(),
+            branch: "main".into(),
+            changes: Vec::new(),
+            modules: owners
+                .iter()
+                .enumerate()
+                .map(|(i, &owner)| Module {
+                    id: Uuid::new_v4(),
+                    name: format!("Module {}", i),
+                    owner,
+                    status: ModuleStatus::Pending,
+                    progress_score: 0,
+                    depends_on: Vec::new(),
+                    notes: String::new(),
+                })
+                .collect(),
+            developers: vec![alice, bob],
+            status_history: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_status_columns() {
+        let columns = board_columns(&team_project(), GroupBy::Status);
+        let titles: Vec<&str> = columns.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, ["Pending", "Current", "Completed"]);
+        assert_eq!(columns[0].modules.len(), 5);
+        assert!(columns[1].modules.is_empty());
+    }
+
+    #[test]
+    fn test_developer_columns() {
+        let columns = board_columns(&team_project(), GroupBy::Developer);
+        let titles: Vec<&str> = columns.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, ["Alice", "Bob", "Unassigned"]);
+        assert_eq!(columns[0].modules, [0, 2]);
+        assert_eq!(columns[1].modules, [1]);
+        assert_eq!(columns[2].modules, [3, 4]);
+    }
+
+    #[test]
+    fn test_developer_columns_without_developers() {
+        let mut project = team_project();
+        project.developers.clear();
+        let columns = board_columns(&project, GroupBy::Developer);
+        assert_eq!(columns.len(), 1);
+        assert_eq!(columns[0].modules.len(), 5);
+    }
+
+    #[test]
+    fn test_navigation_follows_column_count() {
+        let mut state = BoardState::new();
+        state.toggle_group_by();
+        assert_eq!(state.group_by, GroupBy::Developer);
+        assert_eq!(state.selected_column, 0);
+
+        let lens = [2, 1, 2];
+        state.selected_item = 1;
+        state.navigate_left(&lens);
+        assert_eq!(state.selected_column, 2);
+        state.navigate_right(&lens);
+        assert_eq!(state.selected_column, 0);
+        state.navigate_right(&lens);
+        assert_eq!(state.selected_column, 1);
+        assert_eq!(state.selected_item, 0);
+
+        // A stale column past the end steps back onto the board
+        state.selected_column = 7;
+        state.navigate_left(&lens);
+        assert_eq!(state.selected_column, 1);
+
+        state.toggle_group_by();
+        assert_eq!(state.group_by, GroupBy::Status);
+        assert_eq!(state.selected_column, 1);
+    }
 
     #[test]
     fn test_new_default_values() {
@@ -121,6 +288,7 @@ mod tests {
         let mut state = BoardState {
             selected_column: 1,
             selected_item: 3,
+            ..Default::default()
         };
         state.navigate_up(5);
         assert_eq!(state.selected_item, 2);
@@ -131,6 +299,7 @@ mod tests {
         let mut state = BoardState {
             selected_column: 1,
             selected_item: 0,
+            ..Default::default()
         };
         state.navigate_up(5);
         assert_eq!(state.selected_item, 4);
@@ -141,6 +310,7 @@ mod tests {
         let mut state = BoardState {
             selected_column: 1,
             selected_item: 0,
+            ..Default::default()
         };
         state.navigate_up(0);
         assert_eq!(state.selected_item, 0);
@@ -151,6 +321,7 @@ mod tests {
         let mut state = BoardState {
             selected_column: 1,
             selected_item: 2,
+            ..Default::default()
         };
         state.navigate_down(5);
         assert_eq!(state.selected_item, 3);
@@ -161,6 +332,7 @@ mod tests {
         let mut state = BoardState {
             selected_column: 1,
             selected_item: 4,
+            ..Default::default()
         };
         state.navigate_down(5);
         assert_eq!(state.selected_item, 4); // Stays at bottom
@@ -171,6 +343,7 @@ mod tests {
         let mut state = BoardState {
             selected_column: 1,
             selected_item: 0,
+            ..Default::default()
         };
         state.navigate_down(0);
         assert_eq!(state.selected_item, 0);
@@ -181,8 +354,9 @@ mod tests {
         let mut state = BoardState {
             selected_column: 1,
             selected_item: 3,
+            ..Default::default()
         };
-        state.navigate_left(2);
+        state.navigate_left(&[2, 5, 5]);
         assert_eq!(state.selected_column, 0);
         assert_eq!(state.selected_item, 1); // Clamped to new column length
     }
@@ -192,8 +366,9 @@ mod tests {
         let mut state = BoardState {
             selected_column: 0,
             selected_item: 0,
+            ..Default::default()
         };
-        state.navigate_left(5);
+        state.navigate_left(&[5, 5, 5]);
         assert_eq!(state.selected_column, 2);
     }
 
@@ -202,8 +377,9 @@ mod tests {
         let mut state = BoardState {
             selected_column: 1,
             selected_item: 3,
+            ..Default::default()
         };
-        state.navigate_right(2);
+        state.navigate_right(&[5, 5, 2]);
         assert_eq!(state.selected_column, 2);
         assert_eq!(state.selected_item, 1); // Clamped to new column length
     }
@@ -213,44 +389,18 @@ mod tests {
         let mut state = BoardState {
             selected_column: 2,
             selected_item: 0,
+            ..Default::default()
         };
-        state.navigate_right(5);
+        state.navigate_right(&[5, 5, 5]);
         assert_eq!(state.selected_column, 0);
     }
 
-    #[test]
-    fn test_current_status() {
-        let mut state = BoardState::new();
-
-        state.selected_column = 0;
-        assert_eq!(state.current_status(), ModuleStatus::Pending);
-
-        state.selected_column = 1;
-        assert_eq!(state.current_status(), ModuleStatus::Current);
-
-        state.selected_column = 2;
-        assert_eq!(state.current_status(), ModuleStatus::Completed);
-    }
-
-    #[test]
-    fn test_current_column_name() {
-        let mut state = BoardState::new();
-
-        state.selected_column = 0;
-        assert_eq!(state.current_column_name(), "Pending");
-
-        state.selected_column = 1;
-        assert_eq!(state.current_column_name(), "Current");
-
-        state.selected_column = 2;
-        assert_eq!(state.current_column_name(), "Completed");
-    }
-
     #[test]
     fn test_clamp_selection() {
         let mut state = BoardState {
             selected_column: 1,
             selected_item: 10,
+            ..Default::default()
         };
         state.clamp_selection(3);
         assert_eq!(state.selected_item, 2);
@@ -261,6 +411,7 @@ mod tests {
         let mut state = BoardState {
             selected_column: 1,
             selected_item: 5,
+            ..Default::default()
         };
         state.clamp_selection(0);
         assert_eq!(state.selected_item, 0);
@@ -4,6 +4,48 @@
 
 use crate::pages::branch_manager::{BranchInfo, BranchManagerMode};
 
+/// Which branches the Branch Manager lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BranchFilter {
+    #[default]
+    Local,
+    Remote,
+    All,
+    /// Tags instead of branches
+    Tags,
+}
+
+impl BranchFilter {
+    /// Local, then remote, then all, then tags, then back to local.
+    pub fn next(self) -> Self {
+        match self {
+            BranchFilter::Local => BranchFilter::Remote,
+            BranchFilter::Remote => BranchFilter::All,
+            BranchFilter::All => BranchFilter::Tags,
+            BranchFilter::Tags => BranchFilter::Local,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            BranchFilter::Local => "local",
+            BranchFilter::Remote => "remote",
+            BranchFilter::All => "all",
+            BranchFilter::Tags => "tags",
+        }
+    }
+
+    /// The `(local, remote)` flags for [`crate::git::GitClient::list_branches`].
+    pub fn includes(self) -> (bool, bool) {
+        match self {
+            BranchFilter::Local => (true, false),
+            BranchFilter::Remote => (false, true),
+            BranchFilter::All => (true, true),
+            BranchFilter::Tags => (false, false),
+        }
+    }
+}
+
 /// State for the Branch Manager view.
 ///
 /// Handles branch list navigation, creation mode, and branch operations.
@@ -19,6 +61,8 @@ pub struct BranchManagerState {
     pub scroll: usize,
     /// Cached list of branches.
     pub cached_branches: Vec<BranchInfo>,
+    /// Local, remote or all branches, or tags.
+    pub filter: BranchFilter,
 }
 
 impl BranchManagerState {
@@ -30,6 +74,7 @@ impl BranchManagerState {
             input_buffer: String::new(),
             scroll: 0,
             cached_branches: Vec::new(),
+            filter: BranchFilter::Local,
         }
     }
 
@@ -119,11 +164,28 @@ impl BranchManagerState {
         self.selected_branch().is_some_and(|b| b.is_current)
     }
 
-    /// Updates the cached branches and resets selection.
+    /// Updates the cached branches, keeping the selected branch selected by
+    /// name. Clamps if it is gone, and starts at the top if nothing was
+    /// selected.
     pub fn update_branches(&mut self, branches: Vec<BranchInfo>) {
+        let selected = self.selected_branch_name().map(str::to_string);
         self.cached_branches = branches;
-        self.selected_index = 0;
-        self.scroll = 0;
+        let Some(name) = selected else {
+            self.selected_index = 0;
+            self.scroll = 0;
+            return;
+        };
+        match self.cached_branches.iter().position(|b| b.name == name) {
+            Some(index) => self.selected_index = index,
+            None => self.clamp_selection(),
+        }
+        self.ensure_visible();
+    }
+
+    /// Moves to the next branch filter; the caller re-queries the branches.
+    pub fn cycle_filter(&mut self) -> BranchFilter {
+        self.filter = self.filter.next();
+        self.filter
     }
 
     /// Returns the number of cached branches.
@@ -159,16 +221,25 @@ mod tests {
                 name: "main".to_string(),
                 is_current: true,
                 is_remote: false,
+                is_unpushed: false,
+                is_tag: false,
+                ahead_behind: None,
             },
             BranchInfo {
                 name: "develop".to_string(),
                 is_current: false,
                 is_remote: false,
+                is_unpushed: false,
+                is_tag: false,
+                ahead_behind: None,
             },
             BranchInfo {
                 name: "feature/test".to_string(),
                 is_current: false,
                 is_remote: false,
+                is_unpushed: false,
+                is_tag: false,
+                ahead_behind: None,
             },
         ]
     }
@@ -182,6 +253,19 @@ mod tests {
         assert!(state.cached_branches.is_empty());
     }
 
+    #[test]
+    fn test_cycle_filter_walks_include_flags() {
+        let mut state = BranchManagerState::new();
+        assert_eq!(state.filter.includes(), (true, false));
+        assert_eq!(state.cycle_filter(), BranchFilter::Remote);
+        assert_eq!(state.filter.includes(), (false, true));
+        assert_eq!(state.cycle_filter(), BranchFilter::All);
+        assert_eq!(state.filter.includes(), (true, true));
+        assert_eq!(state.cycle_filter(), BranchFilter::Tags);
+        assert_eq!(state.filter.includes(), (false, false));
+        assert_eq!(state.cycle_filter(), BranchFilter::Local);
+    }
+
     #[test]
     fn test_create_mode_toggle() {
         let mut state = BranchManagerState::new();
@@ -294,6 +378,33 @@ mod tests {
         assert_eq!(state.scroll, 0);
     }
 
+    #[test]
+    fn test_update_branches_keeps_the_selected_branch() {
+        let mut state = BranchManagerState::new();
+        state.update_branches(sample_branches());
+        state.navigate_down();
+        let name = state.selected_branch_name().unwrap().to_string();
+
+        // A branch listed ahead of the selection pushes it down a row
+        let mut branches = sample_branches();
+        branches.insert(
+            0,
+            BranchInfo {
+                name: "aaa-new".into(),
+                ..branches[1].clone()
+            },
+        );
+        state.update_branches(branches);
+        assert_eq!(state.selected_index, 2);
+        assert_eq!(state.selected_branch_name(), Some(name.as_str()));
+
+        // Once it is gone the selection clamps to what is left
+        let mut branches = sample_branches();
+        branches.truncate(1);
+        state.update_branches(branches);
+        assert_eq!(state.selected_index, 0);
+    }
+
     #[test]
     fn test_clamp_selection() {
         let mut state = BranchManagerState::new();
@@ -0,0 +1,131 @@
+//! Branch picker popup state.
+//!
+//! A fuzzy-filtered list of local branches for quick switching from any view.
+
+use crate::ui_utils::fuzzy_score;
+
+/// State for the branch-switch picker popup.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BranchPickerState {
+    /// Local branches as (name, is_current).
+    pub branches: Vec<(String, bool)>,
+    /// Fuzzy filter typed so far.
+    pub query: String,
+    /// Index into the filtered matches.
+    pub selected: usize,
+}
+
+impl BranchPickerState {
+    /// Opens the picker over `branches` with an empty filter.
+    pub fn new(branches: Vec<(String, bool)>) -> Self {
+        Self {
+            branches,
+            query: String::new(),
+            selected: 0,
+        }
+    }
+
+    /// Branches matching the filter, best match first; ties keep list order.
+    pub fn matches(&self) -> Vec<&(String, bool)> {
+        let mut scored: Vec<(i64, &(String, bool))> = self
+            .branches
+            .iter()
+            .filter_map(|b| fuzzy_score(&self.query, &b.0).map(|score| (score, b)))
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, b)| b).collect()
+    }
+
+    /// Name of the highlighted match and whether it is checked out, if any.
+    pub fn selected_branch(&self) -> Option<(&str, bool)> {
+        self.matches()
+            .get(self.selected)
+            .map(|(name, is_current)| (name.as_str(), *is_current))
+    }
+
+    /// Moves the highlight by `delta`, staying within the matches.
+    pub fn move_selection(&mut self, delta: i32) {
+        let max = self.matches().len().saturating_sub(1) as i32;
+        self.selected = (self.selected as i32 + delta).clamp(0, max) as usize;
+    }
+
+    /// Appends to the filter and returns to the best match.
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    /// Removes the last filter character and returns to the best match.
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picker() -> BranchPickerState {
+        BranchPickerState::new(
+            [
+                "main",
+                "feature/login",
+                "feature/logout",
+                "fix/typo",
+                "release-1.0",
+            ]
+            .iter()
+            .map(|name| (name.to_string(), *name == "main"))
+            .collect(),
+        )
+    }
+
+    fn names(picker: &BranchPickerState) -> Vec<&str> {
+        picker.matches().iter().map(|(n, _)| n.as_str()).collect()
+    }
+
+    #[test]
+    fn test_empty_filter_lists_everything() {
+        let picker = picker();
+        assert_eq!(names(&picker).len(), 5);
+        assert_eq!(picker.selected_branch(), Some(("main", true)));
+    }
+
+    #[test]
+    fn test_fuzzy_filter_ranks_matches() {
+        let mut picker = picker();
+        for c in "fl".chars() {
+            picker.push_char(c);
+        }
+        assert_eq!(names(&picker), ["feature/login", "feature/logout"]);
+
+        picker.push_char('o');
+        picker.push_char('u');
+        assert_eq!(names(&picker), ["feature/logout"]);
+
+        picker.pop_char();
+        picker.pop_char();
+        picker.pop_char();
+        picker.push_char('x');
+        assert_eq!(picker.selected_branch(), Some(("fix/typo", false)));
+
+        picker.push_char('z');
+        assert!(names(&picker).is_empty());
+        assert_eq!(picker.selected_branch(), None);
+    }
+
+    #[test]
+    fn test_selection_stays_within_matches() {
+        let mut picker = picker();
+        picker.push_char('f');
+        picker.move_selection(10);
+        assert_eq!(picker.selected, names(&picker).len() - 1);
+        picker.move_selection(-10);
+        assert_eq!(picker.selected, 0);
+
+        picker.move_selection(1);
+        picker.push_char('i');
+        assert_eq!(picker.selected, 0);
+    }
+}
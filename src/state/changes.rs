@@ -2,6 +2,12 @@
 //!
 //! Manages Git staging interface and commit message input.
 
+use std::collections::HashSet;
+
+use crate::data::{Change, FileStatus};
+use crate::git::BlameLine;
+use crate::ui_utils::first_hunk_line;
+
 /// State for the Changes view (Git staging/commit interface).
 ///
 /// Handles file selection, staging status, and commit message composition.
@@ -17,6 +23,16 @@ pub struct ChangesState {
     pub changes_pane_ratio: u16,
     /// Pane ratio for commit message area (percentage).
     pub commit_pane_ratio: u16,
+    /// Whether blame annotations are shown beside the diff preview.
+    pub show_blame: bool,
+    /// Blame of the selected file, keyed by the diff text it was computed for.
+    pub blame_cache: Option<(String, Vec<BlameLine>)>,
+    /// Lines of the diff preview scrolled past the top.
+    pub diff_scroll: u16,
+    /// Path of the file `diff_scroll` was positioned for.
+    pub diff_scroll_path: Option<String>,
+    /// Rows ticked for staging together; `None` outside multi-select mode.
+    pub multi_select: Option<HashSet<usize>>,
 }
 
 impl ChangesState {
@@ -28,6 +44,11 @@ impl ChangesState {
             commit_message: String::new(),
             changes_pane_ratio: 35,
             commit_pane_ratio: 50,
+            show_blame: false,
+            blame_cache: None,
+            diff_scroll: 0,
+            diff_scroll_path: None,
+            multi_select: None,
         }
     }
 
@@ -92,6 +113,22 @@ impl ChangesState {
         self.commit_message.trim().is_empty()
     }
 
+    /// Returns the diff of the selected change, if any, for copying.
+    pub fn selected_diff<'a>(&self, changes: &'a [Change]) -> Option<&'a str> {
+        changes
+            .get(self.selected_index)
+            .map(|c| c.diff_preview.as_str())
+    }
+
+    /// Concatenates the diffs of every change, each headed by its path.
+    pub fn changeset_diff(changes: &[Change]) -> String {
+        changes
+            .iter()
+            .map(|c| format!("# {}\n{}", c.path, c.diff_preview))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Adjusts the changes pane ratio.
     ///
     /// # Returns
@@ -122,16 +159,195 @@ impl ChangesState {
         }
     }
 
+    /// Selects the next unstaged file after the current one, wrapping
+    /// around. Returns `false`, leaving the selection alone, if every file is staged.
+    pub fn select_next_unstaged(&mut self, changes: &[Change]) -> bool {
+        let start = self.selected_index + 1;
+        let next = (start..changes.len())
+            .chain(0..start.min(changes.len()))
+            .find(|&i| !changes[i].staged);
+        match next {
+            Some(index) => {
+                self.selected_index = index;
+                self.ensure_visible();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Enters or leaves multi-select mode, dropping any ticked rows.
+    ///
+    /// Returns `true` if the mode is now on.
+    pub fn toggle_multi_select(&mut self) -> bool {
+        self.multi_select = match self.multi_select {
+            Some(_) => None,
+            None => Some(HashSet::new()),
+        };
+        self.multi_select.is_some()
+    }
+
+    /// Ticks or unticks the current row. Returns `true` if it is now ticked;
+    /// does nothing outside multi-select mode.
+    pub fn toggle_row_selection(&mut self) -> bool {
+        let Some(rows) = &mut self.multi_select else {
+            return false;
+        };
+        if rows.remove(&self.selected_index) {
+            false
+        } else {
+            rows.insert(self.selected_index)
+        }
+    }
+
+    /// Returns `true` if `index` is ticked in multi-select mode.
+    pub fn is_row_selected(&self, index: usize) -> bool {
+        self.multi_select
+            .as_ref()
+            .is_some_and(|rows| rows.contains(&index))
+    }
+
+    /// Paths of the ticked rows in list order, with both sides of a rename.
+    /// Rows past the end of `changes` are skipped.
+    pub fn selected_paths(&self, changes: &[Change]) -> Vec<String> {
+        let mut paths = Vec::new();
+        for (index, change) in changes.iter().enumerate() {
+            if !self.is_row_selected(index) {
+                continue;
+            }
+            if let FileStatus::Renamed { from, .. } = &change.status {
+                paths.push(from.clone());
+            }
+            paths.push(change.path.clone());
+        }
+        paths
+    }
+
+    /// Unticks every row, staying in multi-select mode.
+    pub fn clear_row_selection(&mut self) {
+        if let Some(rows) = &mut self.multi_select {
+            rows.clear();
+        }
+    }
+
+    /// Replaces `changes` with `refreshed`, keeping the selected and ticked
+    /// files selected by path so rows added or removed above them do not
+    /// shift the highlight. A selected file that is gone clamps instead.
+    pub fn replace_changes(&mut self, changes: &mut Vec<Change>, refreshed: Vec<Change>) {
+        let selected = changes.get(self.selected_index).map(|c| c.path.clone());
+        let ticked: Option<HashSet<String>> = self.multi_select.as_ref().map(|rows| {
+            rows.iter()
+                .filter_map(|&i| changes.get(i).map(|c| c.path.clone()))
+                .collect()
+        });
+        *changes = refreshed;
+
+        match selected.and_then(|path| changes.iter().position(|c| c.path == path)) {
+            Some(index) => self.selected_index = index,
+            None => self.clamp_selection(changes.len()),
+        }
+        if let Some(paths) = ticked {
+            self.multi_select = Some(
+                changes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| paths.contains(&c.path))
+                    .map(|(i, _)| i)
+                    .collect(),
+            );
+        }
+        self.ensure_visible();
+    }
+
     /// Resets selection to valid range for the given item count.
     pub fn clamp_selection(&mut self, max_items: usize) {
         self.selected_index = self.selected_index.min(max_items.saturating_sub(1));
     }
+
+    /// Scrolls the diff preview to the first hunk whenever a different file
+    /// becomes selected; the position is kept while the same file stays selected.
+    pub fn sync_diff_scroll(&mut self, changes: &[Change]) {
+        let selected = changes.get(self.selected_index);
+        if selected.map(|c| &c.path) == self.diff_scroll_path.as_ref() {
+            return;
+        }
+        self.diff_scroll = selected
+            .map(|c| first_hunk_line(&c.diff_preview) as u16)
+            .unwrap_or(0);
+        self.diff_scroll_path = selected.map(|c| c.path.clone());
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn sample_changes() -> Vec<Change> {
+        ["a.rs", "b.rs"]
+            .iter()
+            .map(|path| Change {
+                path: path.to_string(),
+                status: FileStatus::Modified,
+                diff_preview: format!("diff of {}", path),
+                local_preview: None,
+                incoming_preview: None,
+                staged: false,
+                is_binary: false,
+                hidden_lines: 0,
+                is_submodule: false,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_select_next_unstaged_skips_staged_and_wraps() {
+        let mut changes = sample_changes();
+        changes.extend(sample_changes());
+        changes[1].staged = true;
+        changes[2].staged = true;
+        let mut state = ChangesState::new();
+
+        assert!(state.select_next_unstaged(&changes));
+        assert_eq!(state.selected_index, 3);
+        assert!(state.select_next_unstaged(&changes));
+        assert_eq!(state.selected_index, 0);
+
+        changes.iter_mut().for_each(|c| c.staged = true);
+        assert!(!state.select_next_unstaged(&changes));
+        assert_eq!(state.selected_index, 0);
+    }
+
+    #[test]
+    fn test_row_selection_toggles_only_in_multi_select_mode() {
+        let mut changes = sample_changes();
+        changes[1].status = FileStatus::Renamed {
+            from: "old.rs".into(),
+            to: "b.rs".into(),
+        };
+        let mut state = ChangesState::new();
+        assert!(!state.toggle_row_selection());
+        assert!(state.selected_paths(&changes).is_empty());
+
+        assert!(state.toggle_multi_select());
+        assert!(state.toggle_row_selection());
+        state.navigate_down(changes.len());
+        assert!(state.toggle_row_selection());
+        assert_eq!(state.selected_paths(&changes), ["a.rs", "old.rs", "b.rs"]);
+
+        // Toggling again unticks just that row
+        assert!(!state.toggle_row_selection());
+        assert!(state.is_row_selected(0));
+        assert!(!state.is_row_selected(1));
+
+        state.clear_row_selection();
+        assert!(state.selected_paths(&changes).is_empty());
+        assert!(state.multi_select.is_some());
+
+        state.toggle_row_selection();
+        assert!(!state.toggle_multi_select());
+        assert!(!state.is_row_selected(1));
+    }
+
     #[test]
     fn test_new_default_values() {
         let state = ChangesState::new();
@@ -142,6 +358,26 @@ mod tests {
         assert_eq!(state.commit_pane_ratio, 50);
     }
 
+    #[test]
+    fn test_diff_scroll_jumps_to_first_hunk_on_selection_change() {
+        let mut changes = sample_changes();
+        changes[1].diff_preview =
+            "diff --git a/b.rs b/b.rs\n--- a/b.rs\n+++ b/b.rs\n@@ -1 +1 @@".into();
+        let mut state = ChangesState::new();
+
+        state.sync_diff_scroll(&changes);
+        assert_eq!(state.diff_scroll, 0);
+
+        state.navigate_down(changes.len());
+        state.sync_diff_scroll(&changes);
+        assert_eq!(state.diff_scroll, 3);
+
+        // Same file still selected: position is left alone
+        state.diff_scroll = 1;
+        state.sync_diff_scroll(&changes);
+        assert_eq!(state.diff_scroll, 1);
+    }
+
     #[test]
     fn test_navigate_up() {
         let mut state = ChangesState {
@@ -251,4 +487,55 @@ mod tests {
         state.clamp_selection(10);
         assert_eq!(state.selected_index, 9);
     }
+
+    #[test]
+    fn test_replace_changes_keeps_the_selected_file() {
+        let mut changes = sample_changes();
+        let mut state = ChangesState::new();
+        state.toggle_multi_select();
+        state.navigate_down(changes.len());
+        state.toggle_row_selection();
+        assert_eq!(changes[state.selected_index].path, "b.rs");
+
+        // A file sorted ahead of the selection pushes it down a row
+        let mut refreshed = sample_changes();
+        refreshed.insert(
+            0,
+            Change {
+                path: "0.rs".into(),
+                ..refreshed[0].clone()
+            },
+        );
+        state.replace_changes(&mut changes, refreshed);
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[state.selected_index].path, "b.rs");
+        assert_eq!(state.selected_paths(&changes), ["b.rs"]);
+
+        // Once it is gone the selection clamps to what is left
+        let mut refreshed = sample_changes();
+        refreshed.pop();
+        state.replace_changes(&mut changes, refreshed);
+        assert_eq!(state.selected_index, 0);
+        assert!(state.selected_paths(&changes).is_empty());
+    }
+
+    #[test]
+    fn test_selected_diff_follows_selection() {
+        let changes = sample_changes();
+        let mut state = ChangesState::new();
+        assert_eq!(state.selected_diff(&changes), Some("diff of a.rs"));
+
+        state.selected_index = 1;
+        assert_eq!(state.selected_diff(&changes), Some("diff of b.rs"));
+
+        state.selected_index = 5;
+        assert_eq!(state.selected_diff(&changes), None);
+    }
+
+    #[test]
+    fn test_changeset_diff_includes_every_file() {
+        let text = ChangesState::changeset_diff(&sample_changes());
+        assert_eq!(text, "# a.rs\ndiff of a.rs\n# b.rs\ndiff of b.rs");
+        assert!(ChangesState::changeset_diff(&[]).is_empty());
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Manages commit history list navigation and display.
 
+use crate::git::HistoryCursor;
 use crate::pages::commit_history::CommitInfo;
 
 /// State for the Commit History view.
@@ -13,8 +14,19 @@ pub struct CommitHistoryState {
     pub selected_index: usize,
     /// Scroll offset for commit list.
     pub scroll: usize,
-    /// Cached list of commits.
+    /// Cached list of commits matching the author filter.
     pub cached_commits: Vec<CommitInfo>,
+    /// Every loaded commit, before filtering.
+    all_commits: Vec<CommitInfo>,
+    /// Case-insensitive author substring, or exact author email, the list
+    /// is narrowed to.
+    pub author_filter: Option<String>,
+    /// Whether older commits may still be available to page in.
+    pub has_more: bool,
+    /// Where the walk from HEAD resumes for the next page of older commits.
+    pub cursor: HistoryCursor,
+    /// Commits reachable from HEAD, when known.
+    pub total_commits: Option<usize>,
 }
 
 impl CommitHistoryState {
@@ -24,6 +36,11 @@ impl CommitHistoryState {
             selected_index: 0,
             scroll: 0,
             cached_commits: Vec::new(),
+            all_commits: Vec::new(),
+            author_filter: None,
+            has_more: false,
+            cursor: HistoryCursor::default(),
+            total_commits: None,
         }
     }
 
@@ -72,11 +89,114 @@ impl CommitHistoryState {
         self.cached_commits.get(self.selected_index)
     }
 
-    /// Updates the cached commits and resets selection.
+    /// Updates the cached commits, keeping the selected commit selected by
+    /// hash. Clamps if it is gone, and starts at the top if nothing was
+    /// selected.
     pub fn update_commits(&mut self, commits: Vec<CommitInfo>) {
-        self.cached_commits = commits;
-        self.selected_index = 0;
-        self.scroll = 0;
+        let selected = self.selected_commit().map(|c| c.hash.clone());
+        self.has_more = !commits.is_empty();
+        self.all_commits = commits;
+        self.apply_filter();
+        let Some(hash) = selected else {
+            self.selected_index = 0;
+            self.scroll = 0;
+            return;
+        };
+        match self.cached_commits.iter().position(|c| c.hash == hash) {
+            Some(index) => self.selected_index = index,
+            None => self.clamp_selection(),
+        }
+        self.ensure_visible();
+    }
+
+    /// Every loaded commit, ignoring the author filter.
+    pub fn all_commits(&self) -> &[CommitInfo] {
+        &self.all_commits
+    }
+
+    /// Appends an older page of commits, keeping the current selection.
+    ///
+    /// An empty page marks the history as exhausted.
+    pub fn append_commits(&mut self, commits: Vec<CommitInfo>) {
+        self.has_more = !commits.is_empty();
+        let matching: Vec<CommitInfo> = commits
+            .iter()
+            .filter(|c| self.matches_filter(c))
+            .cloned()
+            .collect();
+        self.all_commits.extend(commits);
+        self.cached_commits.extend(matching);
+    }
+
+    /// Narrows the list to commits whose author contains `filter`,
+    /// clamping the selection to the shorter list. `None` shows everything.
+    pub fn set_author_filter(&mut self, filter: Option<String>) {
+        let filter = filter.filter(|f| !f.is_empty());
+        if filter == self.author_filter {
+            return;
+        }
+        self.author_filter = filter;
+        self.apply_filter();
+        self.clamp_selection();
+        self.ensure_visible();
+    }
+
+    /// Toggles between showing only `author`'s commits and showing all.
+    pub fn toggle_author_filter(&mut self, author: &str) {
+        if self.author_filter.as_deref() == Some(author) {
+            self.set_author_filter(None);
+        } else {
+            self.set_author_filter(Some(author.to_string()));
+        }
+    }
+
+    fn matches_filter(&self, commit: &CommitInfo) -> bool {
+        self.author_filter.as_ref().is_none_or(|filter| {
+            commit.author_email.eq_ignore_ascii_case(filter)
+                || commit
+                    .author
+                    .to_lowercase()
+                    .contains(&filter.to_lowercase())
+        })
+    }
+
+    fn apply_filter(&mut self) {
+        self.cached_commits = self
+            .all_commits
+            .iter()
+            .filter(|c| self.matches_filter(c))
+            .cloned()
+            .collect();
+    }
+
+    /// Selects the loaded commit with `hash`, clearing an author filter that
+    /// hides it. Returns `false` if it has not been loaded.
+    pub fn select_hash(&mut self, hash: &str) -> bool {
+        if !self.all_commits.iter().any(|c| c.hash == hash) {
+            return false;
+        }
+        if !self.cached_commits.iter().any(|c| c.hash == hash) {
+            self.set_author_filter(None);
+        }
+        match self.cached_commits.iter().position(|c| c.hash == hash) {
+            Some(index) => {
+                self.selected_index = index;
+                self.ensure_visible();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` when the selection is within `threshold` commits of the
+    /// end of the cache and another page may exist.
+    pub fn needs_more(&self, threshold: usize) -> bool {
+        self.has_more && self.selected_index + threshold >= self.cached_commits.len()
+    }
+
+    /// Hash of the oldest loaded commit.
+    pub fn last_hash(&self) -> Option<&str> {
+        self.all_commits.last().map(|c| c.hash.as_str())
     }
 
     /// Returns the number of cached commits.
@@ -102,6 +222,69 @@ impl CommitHistoryState {
     }
 }
 
+/// Serializes commits as pretty-printed JSON for `.forge/commits.json`.
+pub fn export_commits_json(commits: &[CommitInfo]) -> String {
+    serde_json::to_string_pretty(commits).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Markdown release notes for `commits` (newest first), with each subject
+/// filed under Features, Fixes or Other by its Conventional Commits type.
+/// With `since_tag`, stops at the commit carrying that tag, leaving it out.
+pub fn generate_changelog(commits: &[CommitInfo], since_tag: Option<&str>) -> String {
+    let mut sections: [(&str, Vec<String>); 3] = [
+        ("Features", Vec::new()),
+        ("Fixes", Vec::new()),
+        ("Other", Vec::new()),
+    ];
+    for commit in commits {
+        if since_tag.is_some_and(|tag| commit.tags.iter().any(|t| t == tag)) {
+            break;
+        }
+        let subject = commit.message.lines().next().unwrap_or_default().trim();
+        let (section, text) = match conventional_subject(subject) {
+            Some((kind, text)) if kind == "feat" => (0, text),
+            Some((kind, text)) if kind == "fix" => (1, text),
+            _ => (2, subject.to_string()),
+        };
+        sections[section]
+            .1
+            .push(format!("- {} ({})", text, commit.short()));
+    }
+
+    let mut changelog = String::from("# Changelog\n");
+    if let Some(tag) = since_tag {
+        changelog.push_str(&format!("\nChanges since {}.\n", tag));
+    }
+    if sections.iter().all(|(_, entries)| entries.is_empty()) {
+        changelog.push_str("\nNo changes.\n");
+    }
+    for (title, entries) in sections.iter().filter(|(_, entries)| !entries.is_empty()) {
+        changelog.push_str(&format!("\n## {}\n\n{}\n", title, entries.join("\n")));
+    }
+    changelog
+}
+
+/// Splits a Conventional Commits subject such as `feat(ui)!: add x` into its
+/// lowercased type and the rest (`ui: add x`); `None` for other subjects.
+fn conventional_subject(subject: &str) -> Option<(String, String)> {
+    let (head, description) = subject.split_once(':')?;
+    let head = head.strip_suffix('!').unwrap_or(head);
+    let (kind, scope) = match head.split_once('(') {
+        Some((kind, scope)) => (kind, scope.strip_suffix(')')?),
+        None => (head, ""),
+    };
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let description = description.trim();
+    let text = if scope.is_empty() {
+        description.to_string()
+    } else {
+        format!("{}: {}", scope, description)
+    };
+    Some((kind.to_ascii_lowercase(), text))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,27 +294,173 @@ mod tests {
             CommitInfo {
                 hash: "abc123".to_string(),
                 author: "Alice".to_string(),
+                author_email: String::new(),
                 date: "2026-01-27".to_string(),
                 message: "Initial commit".to_string(),
                 files_changed: vec!["file1.rs".to_string(), "file2.rs".to_string()],
+                timestamp: 0,
+                short_hash: String::new(),
+                tags: Vec::new(),
             },
             CommitInfo {
                 hash: "def456".to_string(),
                 author: "Bob".to_string(),
+                author_email: String::new(),
                 date: "2026-01-26".to_string(),
                 message: "Add feature".to_string(),
                 files_changed: vec!["src/main.rs".to_string()],
+                timestamp: 0,
+                short_hash: String::new(),
+                tags: Vec::new(),
             },
             CommitInfo {
                 hash: "ghi789".to_string(),
                 author: "Charlie".to_string(),
+                author_email: String::new(),
                 date: "2026-01-25".to_string(),
                 message: "Fix bug".to_string(),
                 files_changed: vec!["src/lib.rs".to_string()],
+                timestamp: 0,
+                short_hash: String::new(),
+                tags: Vec::new(),
             },
         ]
     }
 
+    #[test]
+    fn test_export_commits_json_round_trips() {
+        let commits = sample_commits();
+        let json = export_commits_json(&commits);
+        let parsed: Vec<CommitInfo> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, commits);
+        assert!(json.contains("\"files_changed\""));
+    }
+
+    fn commit(hash: &str, message: &str, tags: &[&str]) -> CommitInfo {
+        CommitInfo {
+            hash: hash.to_string(),
+            author: "Alice".to_string(),
+            author_email: String::new(),
+            date: "2026-01-27".to_string(),
+            message: message.to_string(),
+            files_changed: Vec::new(),
+            timestamp: 0,
+            short_hash: String::new(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_changelog_groups_subjects_by_type() {
+        let commits = vec![
+            commit(
+                "aaaaaaa1",
+                "feat(ui): add a changelog key\n\nLong body",
+                &[],
+            ),
+            commit("bbbbbbb2", "fix: stop crashing on empty repos", &[]),
+            commit("ccccccc3", "Feat!: drop the old export", &[]),
+            commit("ddddddd4", "docs: explain themes", &[]),
+            commit("eeeeeee5", "Tidy up: whitespace", &[]),
+            commit("fffffff6", "Merge branch 'main'", &[]),
+        ];
+        let changelog = generate_changelog(&commits, None);
+        assert_eq!(
+            changelog,
+            "# Changelog\n\
+             \n## Features\n\n\
+             - ui: add a changelog key (aaaaaaa)\n\
+             - drop the old export (ccccccc)\n\
+             \n## Fixes\n\n\
+             - stop crashing on empty repos (bbbbbbb)\n\
+             \n## Other\n\n\
+             - docs: explain themes (ddddddd)\n\
+             - Tidy up: whitespace (eeeeeee)\n\
+             - Merge branch 'main' (fffffff)\n"
+        );
+    }
+
+    #[test]
+    fn test_changelog_stops_at_since_tag() {
+        let commits = vec![
+            commit("aaaaaaa1", "feat: newest", &["v2.0"]),
+            commit("bbbbbbb2", "fix: after the release", &[]),
+            commit("ccccccc3", "feat: released", &["v1.0", "v1.0-final"]),
+            commit("ddddddd4", "fix: long ago", &[]),
+        ];
+        let changelog = generate_changelog(&commits, Some("v1.0"));
+        assert!(changelog.contains("Changes since v1.0."));
+        assert!(changelog.contains("- newest (aaaaaaa)"));
+        assert!(changelog.contains("- after the release (bbbbbbb)"));
+        assert!(!changelog.contains("released"));
+        assert!(!changelog.contains("long ago"));
+
+        // An unknown tag covers every commit; the newest commit's tag covers none
+        assert!(generate_changelog(&commits, Some("v0.1")).contains("long ago"));
+        assert!(generate_changelog(&commits, Some("v2.0")).ends_with("No changes.\n"));
+    }
+
+    #[test]
+    fn test_author_filter_narrows_list() {
+        let mut state = CommitHistoryState::new();
+        state.update_commits(sample_commits());
+        state.selected_index = 2;
+
+        state.set_author_filter(Some("bo".to_string()));
+        assert_eq!(state.commit_count(), 1);
+        assert_eq!(state.cached_commits[0].author, "Bob");
+        assert_eq!(state.selected_index, 0);
+
+        // Paged-in commits are filtered too, but paging continues from the oldest loaded
+        state.append_commits(vec![CommitInfo {
+            hash: "jkl012".to_string(),
+            author: "BOB".to_string(),
+            author_email: String::new(),
+            date: "2026-01-24".to_string(),
+            message: "Older".to_string(),
+            files_changed: vec![],
+            timestamp: 0,
+            short_hash: String::new(),
+            tags: Vec::new(),
+        }]);
+        assert_eq!(state.commit_count(), 2);
+        assert_eq!(state.last_hash(), Some("jkl012"));
+
+        state.set_author_filter(None);
+        assert_eq!(state.commit_count(), 4);
+    }
+
+    #[test]
+    fn test_toggle_author_filter() {
+        let mut state = CommitHistoryState::new();
+        state.update_commits(sample_commits());
+
+        state.toggle_author_filter("Alice");
+        assert_eq!(state.author_filter.as_deref(), Some("Alice"));
+        assert_eq!(state.commit_count(), 1);
+
+        state.toggle_author_filter("Alice");
+        assert_eq!(state.author_filter, None);
+        assert_eq!(state.commit_count(), 3);
+    }
+
+    #[test]
+    fn test_select_hash_clears_hiding_filter() {
+        let mut state = CommitHistoryState::new();
+        state.update_commits(sample_commits());
+
+        assert!(state.select_hash("ghi789"));
+        assert_eq!(state.selected_index, 2);
+
+        state.set_author_filter(Some("Alice".to_string()));
+        assert!(state.select_hash("def456"));
+        assert_eq!(state.author_filter, None);
+        assert_eq!(state.selected_commit().unwrap().author, "Bob");
+
+        assert!(!state.select_hash("missing"));
+        assert_eq!(state.selected_index, 1);
+    }
+
     #[test]
     fn test_new_default_values() {
         let state = CommitHistoryState::new();
@@ -207,9 +536,13 @@ mod tests {
             .map(|i| CommitInfo {
                 hash: format!("hash{}", i),
                 author: "Author".to_string(),
+                author_email: String::new(),
                 date: "2026-01-27".to_string(),
                 message: format!("Commit {}", i),
                 files_changed: vec![format!("file{}.rs", i)],
+                timestamp: 0,
+                short_hash: String::new(),
+                tags: Vec::new(),
             })
             .collect();
         
@@ -224,9 +557,13 @@ mod tests {
             .map(|i| CommitInfo {
                 hash: format!("hash{}", i),
                 author: "Author".to_string(),
+                author_email: String::new(),
                 date: "2026-01-27".to_string(),
                 message: format!("Commit {}", i),
                 files_changed: vec![format!("file{}.rs", i)],
+                timestamp: 0,
+                short_hash: String::new(),
+                tags: Vec::new(),
             })
             .collect();
         state.scroll = 3;
@@ -259,6 +596,49 @@ mod tests {
         assert_eq!(state.scroll, 0);
     }
 
+    #[test]
+    fn test_update_commits_keeps_the_selected_commit() {
+        let mut state = CommitHistoryState::new();
+        state.update_commits(sample_commits());
+        state.navigate_down();
+        let hash = state.selected_commit().unwrap().hash.clone();
+
+        // A new commit on top pushes the selection down a row
+        let mut commits = sample_commits();
+        commits.insert(
+            0,
+            CommitInfo {
+                hash: "new000".into(),
+                ..commits[0].clone()
+            },
+        );
+        state.update_commits(commits);
+        assert_eq!(state.selected_index, 2);
+        assert_eq!(state.selected_commit().unwrap().hash, hash);
+
+        // Once it is gone the selection clamps to what is left
+        state.update_commits(sample_commits()[..1].to_vec());
+        assert_eq!(state.selected_index, 0);
+    }
+
+    #[test]
+    fn test_append_commits_keeps_selection() {
+        let mut state = CommitHistoryState::new();
+        state.update_commits(sample_commits());
+        state.selected_index = 2;
+
+        assert!(state.needs_more(5));
+        state.append_commits(sample_commits());
+
+        assert_eq!(state.commit_count(), 6);
+        assert_eq!(state.selected_index, 2);
+        assert_eq!(state.last_hash(), Some("ghi789"));
+
+        state.append_commits(Vec::new());
+        assert!(!state.has_more);
+        assert!(!state.needs_more(5));
+    }
+
     #[test]
     fn test_clamp_selection() {
         let mut state = CommitHistoryState::new();
@@ -2,6 +2,8 @@
 //!
 //! Manages project list navigation and filtering.
 
+use crate::data::ProjectSort;
+
 /// State for the Dashboard view.
 ///
 /// The dashboard displays a list of projects and allows navigation/selection.
@@ -13,6 +15,13 @@ pub struct DashboardState {
     pub scroll: usize,
     /// Pane ratio for dashboard layout (percentage).
     pub pane_ratio: u16,
+    /// Active project ordering; `None` keeps insertion order.
+    pub sort_mode: Option<ProjectSort>,
+    /// Commits per day for the activity panel, oldest first.
+    pub activity: Vec<(String, usize)>,
+    /// Files in HEAD's tree, refreshed on commit and checkout; `None`
+    /// without a repository.
+    pub tracked_files: Option<usize>,
 }
 
 impl DashboardState {
@@ -22,9 +31,22 @@ impl DashboardState {
             selected_index: 0,
             scroll: 0,
             pane_ratio: 30,
+            sort_mode: None,
+            activity: Vec::new(),
+            tracked_files: None,
         }
     }
 
+    /// Advances to the next project ordering and returns it.
+    pub fn cycle_sort(&mut self) -> ProjectSort {
+        let next = self
+            .sort_mode
+            .map(ProjectSort::next)
+            .unwrap_or(ProjectSort::NameAsc);
+        self.sort_mode = Some(next);
+        next
+    }
+
     /// Navigates to the previous project in the list.
     ///
     /// Returns `true` if the selection changed.
@@ -240,4 +262,14 @@ mod tests {
         state.clamp_selection(0);
         assert_eq!(state.selected_index, 0);
     }
+
+    #[test]
+    fn test_cycle_sort() {
+        let mut state = DashboardState::new();
+        assert_eq!(state.sort_mode, None);
+        assert_eq!(state.cycle_sort(), ProjectSort::NameAsc);
+        assert_eq!(state.cycle_sort(), ProjectSort::NameDesc);
+        assert_eq!(state.cycle_sort(), ProjectSort::MostModules);
+        assert_eq!(state.cycle_sort(), ProjectSort::NameAsc);
+    }
 }
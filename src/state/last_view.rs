@@ -0,0 +1,70 @@
+//! Last view persistence.
+//!
+//! Remembers which view was open, and the selection within it, so the next
+//! launch in the same repository reopens where the user left off.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::AppMode;
+
+/// The view and selection open when forge last exited.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LastView {
+    /// View that had focus.
+    pub view: AppMode,
+    /// Selected project on the Dashboard.
+    pub project: usize,
+    /// Board column (0=Pending, 1=Current, 2=Completed); unused elsewhere.
+    pub column: usize,
+    /// Selected row in the view's list: board item, changed file or module.
+    pub item: usize,
+}
+
+impl LastView {
+    /// Location of the saved view inside a worktree's state directory.
+    fn path(state_dir: &Path) -> PathBuf {
+        state_dir.join("last_view.json")
+    }
+
+    /// Reads the saved view, or `None` if there is none or it is unreadable.
+    pub fn load(state_dir: &Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(Self::path(state_dir)).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Writes the view for the next launch.
+    pub fn save(&self, state_dir: &Path) -> std::io::Result<()> {
+        let path = Self::path(state_dir);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(LastView::load(dir.path()), None);
+
+        let view = LastView {
+            view: AppMode::ModuleManager,
+            project: 0,
+            column: 0,
+            item: 3,
+        };
+        view.save(dir.path()).unwrap();
+        assert_eq!(LastView::load(dir.path()), Some(view));
+
+        // A corrupt file is treated as no saved view
+        std::fs::write(LastView::path(dir.path()), "{").unwrap();
+        assert_eq!(LastView::load(dir.path()), None);
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! Manages 3-pane conflict resolution state.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
 use crate::pages::merge_visualizer::MergePaneFocus;
 
@@ -17,7 +18,8 @@ pub struct MergeState {
     pub focus: MergePaneFocus,
     /// Scroll offset for the file list.
     pub scroll: usize,
-    /// Map of (project_index, file_index) -> accepted pane for resolutions.
+    /// Map of (project_index, file_index) -> accepted pane for resolutions;
+    /// `Both` when the two sides were combined.
     pub resolutions: HashMap<(usize, usize), MergePaneFocus>,
 }
 
@@ -90,7 +92,7 @@ impl MergeState {
     /// A status message describing what was accepted, or `None` if Files pane is focused.
     pub fn accept_current_pane(&mut self, project_index: usize) -> Option<&'static str> {
         match self.focus {
-            MergePaneFocus::Files => None,
+            MergePaneFocus::Files | MergePaneFocus::Both => None,
             MergePaneFocus::Local => {
                 self.resolutions
                     .insert((project_index, self.selected_file_index), self.focus);
@@ -104,6 +106,14 @@ impl MergeState {
         }
     }
 
+    /// Records that the selected file was resolved by keeping both sides.
+    pub fn accept_both(&mut self, project_index: usize) {
+        self.resolutions.insert(
+            (project_index, self.selected_file_index),
+            MergePaneFocus::Both,
+        );
+    }
+
     /// Gets the accepted resolution for a specific file, if any.
     pub fn get_resolution(
         &self,
@@ -118,6 +128,70 @@ impl MergeState {
         self.resolutions.clear();
     }
 
+    /// Location of saved resolutions inside a worktree's state directory.
+    fn resolutions_path(state_dir: &Path) -> PathBuf {
+        state_dir.join("merge_resolutions.json")
+    }
+
+    /// Writes the resolutions of `project_index` keyed by file path, so a
+    /// restarted session can carry on with the merge. `paths` are the
+    /// project's files in list order.
+    pub fn save_resolutions(
+        &self,
+        state_dir: &Path,
+        project_index: usize,
+        paths: &[&str],
+    ) -> std::io::Result<()> {
+        let saved: BTreeMap<&str, MergePaneFocus> = self
+            .resolutions
+            .iter()
+            .filter(|((project, _), _)| *project == project_index)
+            .filter_map(|((_, file), &pane)| Some((*paths.get(*file)?, pane)))
+            .collect();
+        let path = Self::resolutions_path(state_dir);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(&saved)?)
+    }
+
+    /// Restores saved resolutions for `project_index`, dropping files that
+    /// are no longer in `conflicted`. Returns how many were restored; an
+    /// unreadable file restores none.
+    pub fn load_resolutions(
+        &mut self,
+        state_dir: &Path,
+        project_index: usize,
+        paths: &[&str],
+        conflicted: &[String],
+    ) -> usize {
+        let Ok(contents) = std::fs::read_to_string(Self::resolutions_path(state_dir)) else {
+            return 0;
+        };
+        let Ok(saved) = serde_json::from_str::<BTreeMap<String, MergePaneFocus>>(&contents) else {
+            return 0;
+        };
+        let mut restored = 0;
+        for (path, pane) in saved {
+            if !conflicted.contains(&path) {
+                continue;
+            }
+            if let Some(file) = paths.iter().position(|p| *p == path) {
+                self.resolutions.insert((project_index, file), pane);
+                restored += 1;
+            }
+        }
+        restored
+    }
+
+    /// Deletes saved resolutions, for when no merge is in progress.
+    pub fn discard_saved_resolutions(state_dir: &Path) -> std::io::Result<()> {
+        match std::fs::remove_file(Self::resolutions_path(state_dir)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
     /// Ensures the current selection is visible within the scroll window.
     fn ensure_visible(&mut self) {
         const WINDOW_SIZE: usize = 10;
@@ -137,6 +211,53 @@ impl MergeState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_resolutions_round_trip_by_path() {
+        let dir = TempDir::new().unwrap();
+        let mut state = MergeState::new();
+        state.resolutions.insert((0, 0), MergePaneFocus::Local);
+        state.resolutions.insert((0, 2), MergePaneFocus::Incoming);
+        state.resolutions.insert((1, 0), MergePaneFocus::Incoming);
+        state
+            .save_resolutions(dir.path(), 0, &["a.rs", "b.rs", "c.rs"])
+            .unwrap();
+
+        // The list order changed and a.rs is no longer conflicted
+        let paths = ["c.rs", "b.rs", "a.rs"];
+        let conflicted = vec!["b.rs".to_string(), "c.rs".to_string()];
+        let mut reloaded = MergeState::new();
+        let restored = reloaded.load_resolutions(dir.path(), 0, &paths, &conflicted);
+        assert_eq!(restored, 1);
+        assert_eq!(reloaded.resolutions.len(), 1);
+        assert_eq!(
+            reloaded.get_resolution(0, 0),
+            Some(MergePaneFocus::Incoming)
+        );
+
+        MergeState::discard_saved_resolutions(dir.path()).unwrap();
+        MergeState::discard_saved_resolutions(dir.path()).unwrap();
+        let mut fresh = MergeState::new();
+        assert_eq!(
+            fresh.load_resolutions(dir.path(), 0, &paths, &conflicted),
+            0
+        );
+    }
+
+    #[test]
+    fn test_load_resolutions_ignores_corrupt_file() {
+        let dir = TempDir::new().unwrap();
+        let path = MergeState::resolutions_path(dir.path());
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{").unwrap();
+
+        let mut state = MergeState::new();
+        assert_eq!(
+            state.load_resolutions(dir.path(), 0, &["a.rs"], &["a.rs".to_string()]),
+            0
+        );
+    }
 
     #[test]
     fn test_new_default_values() {
@@ -10,27 +10,48 @@
 //!
 //! ```text
 //! App
+//! ├── ActionLog           - History of performed git operations
 //! ├── DashboardState      - Project list navigation
+//! ├── LastView            - View reopened on the next launch
 //! ├── ChangesState        - Git staging and commit interface
 //! ├── BoardState          - Kanban board navigation
 //! ├── MergeState          - Conflict resolution state
 //! ├── ModuleManagerState  - Module/developer management
 //! ├── BranchManagerState  - Branch operations
-//! └── CommitHistoryState  - Commit history navigation
+//! ├── BranchPickerState   - Fuzzy branch-switch popup
+//! ├── CommitHistoryState  - Commit history navigation
+//! ├── QuickViewState      - Diff quick view popup
+//! ├── ReflogState         - HEAD reflog popup
+//! ├── WorktreeListState   - Worktree switcher popup
+//! └── TextPrompt          - Single-line input popup
 //! ```
 
+mod action_log;
 mod board;
 mod branch_manager;
+mod branch_picker;
 mod changes;
 mod commit_history;
 mod dashboard;
+mod last_view;
 mod merge;
 mod module_manager;
+mod prompt;
+mod quick_view;
+mod reflog;
+mod worktrees;
 
-pub use board::BoardState;
-pub use branch_manager::BranchManagerState;
+pub use action_log::{ActionLog, LogEntry};
+pub use board::{board_columns, BoardColumn, BoardState, GroupBy};
+pub use branch_manager::{BranchFilter, BranchManagerState};
+pub use branch_picker::BranchPickerState;
 pub use changes::ChangesState;
-pub use commit_history::CommitHistoryState;
+pub use commit_history::{export_commits_json, generate_changelog, CommitHistoryState};
 pub use dashboard::DashboardState;
+pub use last_view::LastView;
 pub use merge::MergeState;
-pub use module_manager::ModuleManagerState;
+pub use module_manager::{developer_matches, ModuleManagerState};
+pub use prompt::{PromptKind, TextPrompt};
+pub use quick_view::QuickViewState;
+pub use reflog::ReflogState;
+pub use worktrees::WorktreeListState;
@@ -2,7 +2,21 @@
 //!
 //! Manages module and developer lists, creation, editing, and assignment.
 
+use crate::data::Developer;
 use crate::pages::module_manager::ModuleManagerMode;
+use crate::ui_utils::fuzzy_score;
+
+/// Indices of developers whose names fuzzy-match `query`, best match first;
+/// ties keep list order.
+pub fn developer_matches(query: &str, developers: &[Developer]) -> Vec<usize> {
+    let mut scored: Vec<(i64, usize)> = developers
+        .iter()
+        .enumerate()
+        .filter_map(|(i, d)| fuzzy_score(query, &d.name).map(|score| (score, i)))
+        .collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().map(|(_, i)| i).collect()
+}
 
 /// State for the Module Manager view.
 ///
@@ -25,6 +39,14 @@ pub struct ModuleManagerState {
     pub editing_module_id: Option<uuid::Uuid>,
     /// Whether assignment mode is active.
     pub assign_mode: bool,
+    /// Fuzzy filter over developers while assigning; `selected_developer`
+    /// then indexes the filtered matches.
+    pub assign_query: String,
+    /// Module whose dependencies are being picked, if any.
+    pub dependency_target: Option<uuid::Uuid>,
+    /// Module or developer being renamed in place; the new name is typed
+    /// into `input_buffer`.
+    pub rename_target: Option<uuid::Uuid>,
     /// Pane ratio for module/developer split (percentage).
     pub pane_ratio: u16,
 }
@@ -41,6 +63,9 @@ impl ModuleManagerState {
             developer_scroll: 0,
             editing_module_id: None,
             assign_mode: false,
+            assign_query: String::new(),
+            dependency_target: None,
+            rename_target: None,
             pane_ratio: 50,
         }
     }
@@ -91,9 +116,66 @@ impl ModuleManagerState {
         self.input_buffer = module_name.to_string();
     }
 
-    /// Enters assignment mode.
+    /// Enters assignment mode with an empty filter.
     pub fn enter_assign_mode(&mut self) {
         self.assign_mode = true;
+        self.assign_query.clear();
+    }
+
+    /// Leaves assignment mode, keeping the highlighted developer selected.
+    pub fn leave_assign_mode(&mut self, developers: &[Developer]) {
+        if let Some(index) = self.assign_selection(developers) {
+            self.selected_developer = index;
+        }
+        self.assign_mode = false;
+        self.assign_query.clear();
+    }
+
+    /// Indices of developers matching the assignment filter.
+    pub fn assign_matches(&self, developers: &[Developer]) -> Vec<usize> {
+        developer_matches(&self.assign_query, developers)
+    }
+
+    /// Index into `developers` of the highlighted match, if any.
+    pub fn assign_selection(&self, developers: &[Developer]) -> Option<usize> {
+        self.assign_matches(developers)
+            .get(self.selected_developer)
+            .copied()
+    }
+
+    /// Appends to the assignment filter, keeping the selection on a match.
+    pub fn push_assign_char(&mut self, c: char, developers: &[Developer]) {
+        self.assign_query.push(c);
+        self.clamp_assign_selection(developers);
+    }
+
+    /// Removes the last assignment filter character.
+    pub fn pop_assign_char(&mut self, developers: &[Developer]) {
+        self.assign_query.pop();
+        self.clamp_assign_selection(developers);
+    }
+
+    fn clamp_assign_selection(&mut self, developers: &[Developer]) {
+        let matches = self.assign_matches(developers).len();
+        self.selected_developer = self.selected_developer.min(matches.saturating_sub(1));
+        self.developer_scroll = self.developer_scroll.min(self.selected_developer);
+    }
+
+    /// Starts renaming the given module or developer in its list row.
+    pub fn enter_rename(&mut self, id: uuid::Uuid, name: &str) {
+        self.rename_target = Some(id);
+        self.input_buffer = name.to_string();
+    }
+
+    /// Stops renaming in place, dropping the typed name.
+    pub fn leave_rename(&mut self) {
+        self.rename_target = None;
+        self.input_buffer.clear();
+    }
+
+    /// Enters dependency picking for the given module.
+    pub fn enter_dependency_mode(&mut self, module_id: uuid::Uuid) {
+        self.dependency_target = Some(module_id);
     }
 
     /// Exits current mode back to the appropriate list view.
@@ -110,6 +192,9 @@ impl ModuleManagerState {
         self.input_buffer.clear();
         self.editing_module_id = None;
         self.assign_mode = false;
+        self.assign_query.clear();
+        self.dependency_target = None;
+        self.rename_target = None;
     }
 
     /// Navigates up in the current list.
@@ -254,6 +339,23 @@ mod tests {
         assert!(state.is_create_mode());
     }
 
+    #[test]
+    fn test_rename_keeps_list_mode() {
+        let mut state = ModuleManagerState::new();
+        state.toggle_list();
+        let id = uuid::Uuid::new_v4();
+
+        state.enter_rename(id, "Alice");
+        assert_eq!(state.rename_target, Some(id));
+        assert_eq!(state.input_buffer, "Alice");
+        assert!(state.is_developer_list());
+
+        state.leave_rename();
+        assert_eq!(state.rename_target, None);
+        assert!(state.input_buffer.is_empty());
+        assert!(state.is_developer_list());
+    }
+
     #[test]
     fn test_enter_create_developer() {
         let mut state = ModuleManagerState::new();
@@ -402,6 +504,71 @@ mod tests {
         assert_eq!(state.selected_developer, 2);
     }
 
+    fn developers(names: &[&str]) -> Vec<Developer> {
+        names
+            .iter()
+            .map(|name| Developer {
+                id: uuid::Uuid::new_v4(),
+                name: name.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_assign_filter_narrows_developers() {
+        let devs = developers(&["Alice Smith", "Bob Jones", "Alicia Keys", "Carol"]);
+        let mut state = ModuleManagerState::new();
+        state.enter_assign_mode();
+        assert_eq!(state.assign_matches(&devs), [0, 1, 2, 3]);
+
+        state.push_assign_char('a', &devs);
+        state.push_assign_char('l', &devs);
+        state.push_assign_char('i', &devs);
+        assert_eq!(state.assign_matches(&devs), [0, 2]);
+
+        state.push_assign_char('k', &devs);
+        assert_eq!(state.assign_matches(&devs), [2]);
+        assert_eq!(state.assign_selection(&devs), Some(2));
+
+        state.pop_assign_char(&devs);
+        assert_eq!(state.assign_matches(&devs).len(), 2);
+    }
+
+    #[test]
+    fn test_assign_selection_clamps_as_matches_shrink() {
+        let devs = developers(&["Alice", "Bob", "Alicia", "Carol"]);
+        let mut state = ModuleManagerState::new();
+        state.enter_assign_mode();
+        state.selected_developer = 3;
+
+        state.push_assign_char('a', &devs);
+        state.push_assign_char('l', &devs);
+        assert_eq!(state.assign_matches(&devs).len(), 3);
+        assert_eq!(state.selected_developer, 2);
+
+        state.push_assign_char('i', &devs);
+        assert_eq!(state.selected_developer, 1);
+        assert_eq!(state.assign_selection(&devs), Some(2));
+
+        state.push_assign_char('z', &devs);
+        assert_eq!(state.selected_developer, 0);
+        assert_eq!(state.assign_selection(&devs), None);
+    }
+
+    #[test]
+    fn test_leave_assign_mode_selects_highlighted_developer() {
+        let devs = developers(&["Alice", "Bob", "Carol"]);
+        let mut state = ModuleManagerState::new();
+        state.enter_assign_mode();
+        state.push_assign_char('c', &devs);
+        assert_eq!(state.assign_selection(&devs), Some(2));
+
+        state.leave_assign_mode(&devs);
+        assert!(!state.assign_mode);
+        assert!(state.assign_query.is_empty());
+        assert_eq!(state.selected_developer, 2);
+    }
+
     #[test]
     fn test_assign_mode() {
         let mut state = ModuleManagerState::new();
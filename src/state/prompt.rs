@@ -0,0 +1,121 @@
+//! Text prompt state.
+//!
+//! A single-line input popup used by actions that need a value typed in
+//! from any view, such as the stage-and-commit message or a file path.
+
+/// What a submitted prompt is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptKind {
+    /// Stage everything and commit with the typed message.
+    QuickCommit,
+    /// Apply the patch file at the typed path.
+    ApplyPatch,
+    /// Import modules from the task list at the typed path.
+    ImportModules,
+    /// Replace the selected module's notes with the typed text.
+    ModuleNotes,
+    /// Squash HEAD down to the selected commit with the typed message.
+    SquashCommits,
+    /// Replace the selected commit's message, replaying the commits after it.
+    RewordCommit,
+    /// Set the selected module's progress to the typed percentage.
+    ModuleProgress,
+    /// Select the commit the typed revision resolves to in Commit History.
+    GoToRef,
+    /// Clone the typed URL into the current directory and open it.
+    CloneRepo,
+}
+
+impl PromptKind {
+    /// Popup title.
+    pub fn title(self) -> &'static str {
+        match self {
+            PromptKind::QuickCommit => "Stage All & Commit",
+            PromptKind::ApplyPatch => "Apply Patch",
+            PromptKind::ImportModules => "Import Modules",
+            PromptKind::ModuleNotes => "Module Notes",
+            PromptKind::SquashCommits => "Squash Into Selected Commit",
+            PromptKind::RewordCommit => "Reword Commit",
+            PromptKind::ModuleProgress => "Module Progress",
+            PromptKind::GoToRef => "Go To Revision",
+            PromptKind::CloneRepo => "Clone Repository",
+        }
+    }
+
+    /// Label shown above the input.
+    pub fn label(self) -> &'static str {
+        match self {
+            PromptKind::QuickCommit => "Commit message",
+            PromptKind::ApplyPatch => "Patch file (relative to the repository root)",
+            PromptKind::ImportModules => {
+                "CSV or markdown task list (relative to the repository root)"
+            }
+            PromptKind::ModuleNotes => "Notes (leave empty to clear)",
+            PromptKind::SquashCommits => "Message for the combined commit",
+            PromptKind::RewordCommit => "New commit message",
+            PromptKind::ModuleProgress => "Progress (0-100)",
+            PromptKind::GoToRef => "Revision (HEAD~3, tag, branch or hash)",
+            PromptKind::CloneRepo => "Repository URL or path",
+        }
+    }
+
+    /// Status shown when Enter is pressed with nothing typed.
+    pub fn empty_message(self) -> &'static str {
+        match self {
+            PromptKind::QuickCommit => "Commit message cannot be empty",
+            PromptKind::ApplyPatch => "Patch path cannot be empty",
+            PromptKind::ImportModules => "Task list path cannot be empty",
+            PromptKind::ModuleNotes => "",
+            PromptKind::SquashCommits | PromptKind::RewordCommit => {
+                "Commit message cannot be empty"
+            }
+            PromptKind::ModuleProgress => "Progress cannot be empty",
+            PromptKind::GoToRef => "Revision cannot be empty",
+            PromptKind::CloneRepo => "Repository URL cannot be empty",
+        }
+    }
+
+    /// Whether submitting nothing is meaningful, e.g. to clear a value.
+    pub fn allows_empty(self) -> bool {
+        matches!(self, PromptKind::ModuleNotes)
+    }
+}
+
+/// An open text prompt and what has been typed so far.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextPrompt {
+    pub kind: PromptKind,
+    pub input: String,
+}
+
+impl TextPrompt {
+    /// Opens an empty prompt of the given kind.
+    pub fn new(kind: PromptKind) -> Self {
+        Self {
+            kind,
+            input: String::new(),
+        }
+    }
+
+    /// Returns `true` if nothing but whitespace has been typed.
+    pub fn is_blank(&self) -> bool {
+        self.input.trim().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blank_input() {
+        let mut prompt = TextPrompt::new(PromptKind::ApplyPatch);
+        assert!(prompt.is_blank());
+
+        prompt.input.push_str("  ");
+        assert!(prompt.is_blank());
+
+        prompt.input.push_str("fix.patch");
+        assert!(!prompt.is_blank());
+    }
+}
@@ -0,0 +1,182 @@
+//! Quick view popup state.
+//!
+//! Holds the diff shown in the full-screen quick view, its scroll position,
+//! and any search within it.
+
+/// State for the diff quick view popup.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuickViewState {
+    /// Popup title, usually the file path or commit.
+    pub title: String,
+    /// Diff text being shown.
+    pub content: String,
+    /// Number of lines scrolled past the top.
+    pub scroll: u16,
+    /// Search text being typed after `/`, until Enter or Esc.
+    pub search_input: Option<String>,
+    /// Last submitted search.
+    pub query: String,
+    /// (line, column) of each match of `query`, in reading order.
+    pub matches: Vec<(usize, usize)>,
+    /// Index into `matches` of the match last jumped to.
+    pub current_match: usize,
+    /// Rows of content visible in the popup, used to centre matches.
+    pub viewport: u16,
+}
+
+impl QuickViewState {
+    /// Creates a quick view scrolled to the top.
+    pub fn new(title: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            content: content.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Scrolls by `delta` lines, staying within the content.
+    pub fn scroll_by(&mut self, delta: i16) {
+        let max = self.content.lines().count().saturating_sub(1) as i32;
+        self.scroll = (self.scroll as i32 + delta as i32).clamp(0, max) as u16;
+    }
+
+    /// Starts typing a new search.
+    pub fn start_search(&mut self) {
+        self.search_input = Some(String::new());
+    }
+
+    /// Searches for the typed text and jumps to the first match at or below
+    /// the top of the view. Returns the number of matches.
+    pub fn submit_search(&mut self) -> usize {
+        let Some(input) = self.search_input.take() else {
+            return self.matches.len();
+        };
+        self.matches = find_matches(&self.content, &input);
+        self.query = input;
+        self.current_match = self
+            .matches
+            .iter()
+            .position(|&(line, _)| line >= self.scroll as usize)
+            .unwrap_or(0);
+        self.center_current_match();
+        self.matches.len()
+    }
+
+    /// Moves `delta` matches forward or back, wrapping at either end.
+    pub fn jump_match(&mut self, delta: i32) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let count = self.matches.len() as i32;
+        self.current_match = (self.current_match as i32 + delta).rem_euclid(count) as usize;
+        self.center_current_match();
+    }
+
+    /// Scrolls so the current match sits in the middle of the popup.
+    fn center_current_match(&mut self) {
+        if let Some(&(line, _)) = self.matches.get(self.current_match) {
+            let max = self.content.lines().count().saturating_sub(1);
+            let top = line.saturating_sub(self.viewport as usize / 2);
+            self.scroll = top.min(max) as u16;
+        }
+    }
+}
+
+/// Case-insensitive positions of `query` in `text` as (line, column), where
+/// both count from zero and columns are in characters. Matches on one line
+/// do not overlap; an empty query matches nothing.
+pub fn find_matches(text: &str, query: &str) -> Vec<(usize, usize)> {
+    let fold = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let needle: Vec<char> = query.chars().map(fold).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let mut found = Vec::new();
+    for (line_no, line) in text.lines().enumerate() {
+        let hay: Vec<char> = line.chars().map(fold).collect();
+        let mut col = 0;
+        while col + needle.len() <= hay.len() {
+            if hay[col..col + needle.len()] == needle[..] {
+                found.push((line_no, col));
+                col += needle.len();
+            } else {
+                col += 1;
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_clamps_to_content() {
+        let mut view = QuickViewState::new("a.rs", "1\n2\n3");
+        view.scroll_by(-1);
+        assert_eq!(view.scroll, 0);
+
+        view.scroll_by(10);
+        assert_eq!(view.scroll, 2);
+
+        view.scroll_by(-1);
+        assert_eq!(view.scroll, 1);
+    }
+
+    #[test]
+    fn test_find_matches_multiple() {
+        let text = "+let foo = foo();\n context\n-foo";
+        assert_eq!(find_matches(text, "foo"), [(0, 5), (0, 11), (2, 1)]);
+        // Matches do not overlap
+        assert_eq!(find_matches("aaaa", "aa"), [(0, 0), (0, 2)]);
+    }
+
+    #[test]
+    fn test_find_matches_none() {
+        assert!(find_matches("+added\n-removed", "missing").is_empty());
+        assert!(find_matches("anything", "").is_empty());
+    }
+
+    #[test]
+    fn test_find_matches_ignores_case() {
+        assert_eq!(
+            find_matches("Error\nerror\nERROR", "eRRor"),
+            [(0, 0), (1, 0), (2, 0)]
+        );
+        // Columns count characters, not bytes
+        assert_eq!(find_matches("héllo World", "world"), [(0, 6)]);
+    }
+
+    #[test]
+    fn test_search_centres_and_wraps() {
+        let content: Vec<String> = (0..40)
+            .map(|i| {
+                if i % 10 == 5 {
+                    "match".into()
+                } else {
+                    format!("line {i}")
+                }
+            })
+            .collect();
+        let mut view = QuickViewState::new("a.rs", content.join("\n"));
+        view.viewport = 10;
+        view.scroll = 10;
+
+        view.start_search();
+        view.search_input.as_mut().unwrap().push_str("MATCH");
+        assert_eq!(view.submit_search(), 4);
+        // First match below the top of the view, centred
+        assert_eq!(view.current_match, 1);
+        assert_eq!(view.scroll, 10);
+
+        view.jump_match(1);
+        assert_eq!(view.scroll, 20);
+        view.jump_match(2);
+        assert_eq!(view.current_match, 0, "wraps past the last match");
+        assert_eq!(view.scroll, 0);
+        view.jump_match(-1);
+        assert_eq!(view.current_match, 3);
+    }
+}
@@ -0,0 +1,56 @@
+//! Reflog popup state.
+//!
+//! Recent positions of HEAD, for finding commits lost to a reset or rebase.
+
+/// State for the reflog popup.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReflogState {
+    /// Entries as (short hash, reflog message), newest first.
+    pub entries: Vec<(String, String)>,
+    /// Index of the highlighted entry.
+    pub selected: usize,
+}
+
+impl ReflogState {
+    /// Opens the popup with the newest entry highlighted.
+    pub fn new(entries: Vec<(String, String)>) -> Self {
+        Self {
+            entries,
+            selected: 0,
+        }
+    }
+
+    /// Hash of the highlighted entry, if any.
+    pub fn selected_hash(&self) -> Option<&str> {
+        self.entries
+            .get(self.selected)
+            .map(|(hash, _)| hash.as_str())
+    }
+
+    /// Moves the highlight by `delta`, staying within the entries.
+    pub fn move_selection(&mut self, delta: i32) {
+        let max = self.entries.len().saturating_sub(1) as i32;
+        self.selected = (self.selected as i32 + delta).clamp(0, max) as usize;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_stays_within_entries() {
+        let mut reflog = ReflogState::new(vec![
+            ("aaaaaaa".into(), "reset: moving to HEAD~1".into()),
+            ("bbbbbbb".into(), "commit: Add login".into()),
+        ]);
+        assert_eq!(reflog.selected_hash(), Some("aaaaaaa"));
+
+        reflog.move_selection(5);
+        assert_eq!(reflog.selected_hash(), Some("bbbbbbb"));
+        reflog.move_selection(-5);
+        assert_eq!(reflog.selected, 0);
+
+        assert_eq!(ReflogState::new(Vec::new()).selected_hash(), None);
+    }
+}
@@ -0,0 +1,68 @@
+//! Worktree switcher popup state.
+//!
+//! The repository's working trees, for re-pointing the app at another one.
+
+use std::path::PathBuf;
+
+/// State for the worktree switcher popup.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WorktreeListState {
+    /// Working trees as (name, path), main one first.
+    pub worktrees: Vec<(String, PathBuf)>,
+    /// Index of the worktree the app is attached to, if listed.
+    pub current: Option<usize>,
+    /// Index of the highlighted worktree.
+    pub selected: usize,
+}
+
+impl WorktreeListState {
+    /// Opens the popup with the worktree at `current_path` highlighted.
+    pub fn new(worktrees: Vec<(String, PathBuf)>, current_path: &std::path::Path) -> Self {
+        let same = |path: &PathBuf| match (path.canonicalize(), current_path.canonicalize()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => path == current_path,
+        };
+        let current = worktrees.iter().position(|(_, path)| same(path));
+        Self {
+            worktrees,
+            current,
+            selected: current.unwrap_or(0),
+        }
+    }
+
+    /// Path of the highlighted worktree, if any.
+    pub fn selected_path(&self) -> Option<&PathBuf> {
+        self.worktrees.get(self.selected).map(|(_, path)| path)
+    }
+
+    /// Moves the highlight by `delta`, staying within the list.
+    pub fn move_selection(&mut self, delta: i32) {
+        let max = self.worktrees.len().saturating_sub(1) as i32;
+        self.selected = (self.selected as i32 + delta).clamp(0, max) as usize;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_worktree_is_highlighted() {
+        let list = WorktreeListState::new(
+            vec![
+                ("main".into(), PathBuf::from("/repo")),
+                ("feature".into(), PathBuf::from("/work/feature")),
+            ],
+            std::path::Path::new("/work/feature"),
+        );
+        assert_eq!(list.current, Some(1));
+        assert_eq!(list.selected_path(), Some(&PathBuf::from("/work/feature")));
+
+        let mut list = WorktreeListState::new(list.worktrees, std::path::Path::new("/elsewhere"));
+        assert_eq!(list.current, None);
+        list.move_selection(-1);
+        assert_eq!(list.selected, 0);
+        list.move_selection(5);
+        assert_eq!(list.selected, 1);
+    }
+}
@@ -12,6 +12,9 @@ pub const PROGRESS: &str = "⟳";
 /// Information indicator (ℹ)
 pub const INFO: &str = "ℹ";
 
+/// Warning indicator (⚠)
+pub const WARNING: &str = "⚠";
+
 /// Helper function to format success messages
 pub fn success(msg: &str) -> String {
     format!("{} {}", SUCCESS, msg)
@@ -31,3 +34,8 @@ pub fn progress(msg: &str) -> String {
 pub fn info(msg: &str) -> String {
     format!("{} {}", INFO, msg)
 }
+
+/// Helper function to format warning messages
+pub fn warning(msg: &str) -> String {
+    format!("{} {}", WARNING, msg)
+}
@@ -1,6 +1,6 @@
 use ratatui::{
-    layout::Rect,
-    style::Style,
+    layout::{Direction, Rect},
+    style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, ListState, Paragraph},
     Frame,
@@ -41,6 +41,14 @@ pub fn render_input_form(frame: &mut Frame, area: Rect, title: &str, label: &str
     );
 }
 
+/// Renders `block` with a dimmed hint in place of a list that has no items
+pub fn render_empty_state(frame: &mut Frame, area: Rect, block: Block, message: &str) {
+    frame.render_widget(
+        Paragraph::new(Span::styled(message, Style::new().gray())).block(block),
+        area,
+    );
+}
+
 /// Auto-scrolls a view to keep the selected item visible
 /// Call this after changing selected index to adjust scroll position
 pub fn auto_scroll(selected: usize, scroll: &mut usize, window_size: usize) {
@@ -56,7 +64,560 @@ pub fn adjust_pane_ratio(current: u16, delta: i16) -> u16 {
     ((current as i16) + delta).clamp(20, 80) as u16
 }
 
+/// Narrowest view area that fits list and detail panes side by side; an
+/// 80-column terminal leaves 64 columns beside the 16-column menu
+pub const NARROW_WIDTH: u16 = 64;
+
+/// Returns `true` if `area` is too narrow for side-by-side panes
+pub fn is_narrow(area: Rect) -> bool {
+    area.width < NARROW_WIDTH
+}
+
+/// Splits list and detail panes side by side, or stacked when `area` is narrow
+pub fn pane_direction(area: Rect) -> Direction {
+    if is_narrow(area) {
+        Direction::Vertical
+    } else {
+        Direction::Horizontal
+    }
+}
+
 /// Safely decrements an index, returning the previous value or 0 if already at 0
 pub fn safe_decrement(index: usize) -> usize {
     index.saturating_sub(1)
 }
+
+/// Formats an elapsed duration compactly, e.g. "just now", "5m ago", "2h ago", "3d ago"
+pub fn humanize_duration(elapsed: std::time::Duration) -> String {
+    let secs = elapsed.as_secs();
+    match secs {
+        0..60 => "just now".to_string(),
+        60..3_600 => format!("{}m ago", secs / 60),
+        3_600..86_400 => format!("{}h ago", secs / 3_600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
+
+/// Formats an epoch timestamp relative to `now`, both in seconds; times in the
+/// future read as "just now"
+pub fn humanize_time(timestamp: i64, now: i64) -> String {
+    humanize_duration(std::time::Duration::from_secs(
+        now.saturating_sub(timestamp).max(0) as u64,
+    ))
+}
+
+/// Colours unified-diff text line by line: additions green, removals red,
+/// hunk headers cyan, file headers bold.
+///
+/// A run of removed lines followed by as many added lines is read as those
+/// lines being modified in place, and each pair gets [`word_diff`] highlighting.
+pub fn styled_diff_lines(diff: &str) -> Vec<Line<'_>> {
+    let lines: Vec<&str> = diff.lines().collect();
+    let is_removal = |line: &str| line.starts_with('-') && !line.starts_with("---");
+    let is_addition = |line: &str| line.starts_with('+') && !line.starts_with("+++");
+
+    let mut styled = Vec::with_capacity(lines.len());
+    let mut i = 0;
+    while i < lines.len() {
+        let removed = lines[i..].iter().take_while(|l| is_removal(l)).count();
+        let added = lines[i + removed..]
+            .iter()
+            .take_while(|l| is_addition(l))
+            .count();
+        if removed == 0 {
+            styled.push(styled_diff_line(lines[i]));
+            i += 1;
+            continue;
+        }
+
+        let (old, new) = lines[i..i + removed + added].split_at(removed);
+        if removed == added {
+            let mut new_lines = Vec::with_capacity(added);
+            for (old, new) in old.iter().zip(new) {
+                let (mut old_spans, mut new_spans) = word_diff(&old[1..], &new[1..]);
+                old_spans.insert(0, Span::styled(&old[..1], Style::new().red()));
+                new_spans.insert(0, Span::styled(&new[..1], Style::new().green()));
+                styled.push(Line::from(old_spans));
+                new_lines.push(Line::from(new_spans));
+            }
+            styled.extend(new_lines);
+        } else {
+            styled.extend(old.iter().chain(new).map(|line| styled_diff_line(line)));
+        }
+        i += removed + added;
+    }
+    styled
+}
+
+fn styled_diff_line(line: &str) -> Line<'_> {
+    let style = if line.starts_with("+++") || line.starts_with("---") {
+        Style::new().bold()
+    } else if line.starts_with('+') {
+        Style::new().green()
+    } else if line.starts_with('-') {
+        Style::new().red()
+    } else if line.starts_with("@@") {
+        Style::new().cyan()
+    } else if line.starts_with("diff ") {
+        Style::new().bold().yellow()
+    } else {
+        Style::new()
+    };
+    Line::from(Span::styled(line, style))
+}
+
+/// Longest line pair, in tokens multiplied together, that [`word_diff`] will
+/// compare; longer pairs are shown without word highlighting
+const WORD_DIFF_LIMIT: usize = 250_000;
+
+/// Splits a line into words, whitespace runs and single punctuation characters
+fn diff_tokens(line: &str) -> Vec<&str> {
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            0
+        } else if c.is_whitespace() {
+            1
+        } else {
+            2
+        }
+    };
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut chars = line.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        let kind = class(c);
+        let end = match chars.peek() {
+            Some(&(next_at, next)) if kind == 2 || class(next) != kind => next_at,
+            Some(_) => continue,
+            None => line.len(),
+        };
+        tokens.push(&line[start..end]);
+        start = end;
+    }
+    tokens
+}
+
+/// Word-level diff of a modified line: removed text red and added text green,
+/// with the words that actually changed on a bright background.
+///
+/// Lines with no words in common, and very long lines, come back as a
+/// single plain span each, since highlighting everything shows nothing.
+pub fn word_diff<'a>(old: &'a str, new: &'a str) -> (Vec<Span<'a>>, Vec<Span<'a>>) {
+    let (old_style, new_style) = (Style::new().red(), Style::new().green());
+    let (old_changed, new_changed) = (
+        Style::new().white().bold().on_red(),
+        Style::new().black().bold().on_green(),
+    );
+    let plain = || {
+        (
+            vec![Span::styled(old, old_style)],
+            vec![Span::styled(new, new_style)],
+        )
+    };
+
+    let a = diff_tokens(old);
+    let b = diff_tokens(new);
+    if a.len() * b.len() > WORD_DIFF_LIMIT {
+        return plain();
+    }
+
+    // Longest common subsequence of tokens, filled from the end
+    let mut lcs = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut a_kept, mut b_kept) = (vec![false; a.len()], vec![false; b.len()]);
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            a_kept[i] = true;
+            b_kept[j] = true;
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    let shares_words = a
+        .iter()
+        .zip(&a_kept)
+        .any(|(token, &kept)| kept && !token.trim().is_empty());
+    if !shares_words {
+        return plain();
+    }
+
+    // Adjacent tokens with the same state merge into one span
+    let spans = |line: &'a str, tokens: &[&str], kept: &[bool], style, changed| {
+        let mut spans: Vec<Span<'a>> = Vec::new();
+        let mut start = 0;
+        let mut at = 0;
+        for (index, token) in tokens.iter().enumerate() {
+            at += token.len();
+            if kept.get(index + 1) != Some(&kept[index]) {
+                let span_style = if kept[index] { style } else { changed };
+                spans.push(Span::styled(&line[start..at], span_style));
+                start = at;
+            }
+        }
+        spans
+    };
+    (
+        spans(old, &a, &a_kept, old_style, old_changed),
+        spans(new, &b, &b_kept, new_style, new_changed),
+    )
+}
+
+/// Index of the first hunk header (`@@`) in a unified diff, or 0 if there is none
+pub fn first_hunk_line(diff: &str) -> usize {
+    diff.lines()
+        .position(|line| line.starts_with("@@"))
+        .unwrap_or(0)
+}
+
+/// Columns a bordered list with a ">> " highlight symbol spends on chrome
+pub const LIST_CHROME_WIDTH: u16 = 5;
+
+/// Shortens `s` to at most `max` characters with an ellipsis, keeping both
+/// ends (e.g. "feature/ver…/thing") so similar names stay distinguishable.
+/// Very small limits keep only the start. Counts characters, so multibyte
+/// text is never split mid-character.
+pub fn truncate_middle(s: &str, max: usize) -> String {
+    let len = s.chars().count();
+    if len <= max {
+        return s.to_string();
+    }
+    if max == 0 {
+        return String::new();
+    }
+    let keep = max - 1;
+    // Below this a head…tail split leaves too little of either end to read
+    if max < 8 {
+        return s.chars().take(keep).chain(['…']).collect();
+    }
+    let head = keep.div_ceil(2);
+    let tail = keep / 2;
+    s.chars()
+        .take(head)
+        .chain(['…'])
+        .chain(s.chars().skip(len - tail))
+        .collect()
+}
+
+/// Renders a fixed-width text progress bar, e.g. "██████░░░░" for 60%
+pub fn progress_bar(percent: u8, width: usize) -> String {
+    let filled = (percent.min(100) as usize * width + 50) / 100;
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// Renders counts as a one-line bar chart scaled to the largest value,
+/// e.g. "░▂█▄"; zero counts are drawn as empty bars
+pub fn activity_bars(counts: &[usize]) -> String {
+    const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = counts.iter().copied().max().unwrap_or(0);
+    counts
+        .iter()
+        .map(|&count| {
+            if count == 0 {
+                '░'
+            } else {
+                LEVELS[(count * LEVELS.len()).div_ceil(max) - 1]
+            }
+        })
+        .collect()
+}
+
+/// Badge colours for authors; dark enough for black text to stay readable
+const BADGE_COLORS: [Color; 12] = [
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+];
+
+/// Two-letter initials and a colour for an author, e.g. "AS" for
+/// "Ada Smith". The colour comes from an FNV-1a hash of the name, so it
+/// is the same on every run and machine. One-word names use their first
+/// two letters; names without letters or digits give "??".
+pub fn author_badge(name: &str) -> (String, Color) {
+    let words: Vec<&str> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let initials: String = match words.as_slice() {
+        [] => "??".to_string(),
+        [word] => word.chars().take(2).collect(),
+        [first, .., last] => first.chars().take(1).chain(last.chars().take(1)).collect(),
+    };
+
+    let hash = name.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    let color = BADGE_COLORS[(hash % BADGE_COLORS.len() as u64) as usize];
+    (initials.to_uppercase(), color)
+}
+
+/// Scores how well `query` fuzzy-matches `candidate`, or `None` if its
+/// characters do not all appear in order. Case-insensitive; consecutive
+/// matches and matches at the start of a word score higher, skipped
+/// characters lower. An empty query matches everything with score 0.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let chars: Vec<char> = candidate.chars().collect();
+    let same = |a: char, b: char| a.to_lowercase().eq(b.to_lowercase());
+    let mut score = 0;
+    let mut pos = 0;
+    let mut last_match = None;
+
+    for q in query.chars() {
+        let found = (pos..chars.len()).find(|&i| same(chars[i], q))?;
+        score += 1;
+        if found > 0 && last_match == Some(found - 1) {
+            score += 5;
+        }
+        if found == 0 || matches!(chars[found - 1], '/' | '-' | '_' | '.') {
+            score += 3;
+        }
+        score -= (found - pos) as i64;
+        last_match = Some(found);
+        pos = found + 1;
+    }
+    Some(score)
+}
+
+/// An empty project named "demo" on `main`, for page rendering tests
+#[cfg(test)]
+pub fn demo_project() -> crate::data::Project {
+    crate::data::Project {
+        id: uuid::Uuid::new_v4(),
+        name: "demo".into(),
+        description: String::new(),
+        branch: "main".into(),
+        changes: Vec::new(),
+        modules: Vec::new(),
+        developers: Vec::new(),
+        status_history: Vec::new(),
+    }
+}
+
+/// Draws into a `width` x `height` test terminal and returns the screen as
+/// one string per row, joined with newlines
+#[cfg(test)]
+pub fn render_to_text(width: u16, height: u16, draw: impl FnOnce(&mut Frame)) -> String {
+    let mut terminal =
+        ratatui::Terminal::new(ratatui::backend::TestBackend::new(width, height)).unwrap();
+    terminal.draw(draw).unwrap();
+    let buffer = terminal.backend().buffer();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_narrow_areas_stack_panes() {
+        assert!(is_narrow(Rect::new(0, 0, 60, 20)));
+        assert_eq!(pane_direction(Rect::new(0, 0, 60, 20)), Direction::Vertical);
+        assert!(!is_narrow(Rect::new(0, 0, NARROW_WIDTH, 20)));
+        assert_eq!(
+            pane_direction(Rect::new(0, 0, 120, 20)),
+            Direction::Horizontal
+        );
+    }
+
+    #[test]
+    fn test_humanize_duration() {
+        assert_eq!(humanize_duration(Duration::from_secs(5)), "just now");
+        assert_eq!(humanize_duration(Duration::from_secs(5 * 60)), "5m ago");
+        assert_eq!(
+            humanize_duration(Duration::from_secs(2 * 3_600 + 59)),
+            "2h ago"
+        );
+        assert_eq!(humanize_duration(Duration::from_secs(3 * 86_400)), "3d ago");
+    }
+
+    #[test]
+    fn test_truncate_middle_fits() {
+        assert_eq!(truncate_middle("main", 10), "main");
+        assert_eq!(truncate_middle("exactly10!", 10), "exactly10!");
+        assert_eq!(truncate_middle("", 0), "");
+    }
+
+    #[test]
+    fn test_truncate_middle_short_limit_cuts_end() {
+        assert_eq!(truncate_middle("feature/login", 5), "feat…");
+        assert_eq!(truncate_middle("feature/login", 1), "…");
+        assert_eq!(truncate_middle("feature/login", 0), "");
+    }
+
+    #[test]
+    fn test_truncate_middle_keeps_both_ends() {
+        let name = "feature/very-long-branch-name/thing";
+        let short = truncate_middle(name, 20);
+        assert_eq!(short, "feature/ve…ame/thing");
+        assert_eq!(short.chars().count(), 20);
+
+        // Multibyte characters are kept whole
+        let short = truncate_middle("日本語のとても長いブランチ名です", 9);
+        assert_eq!(short, "日本語の…チ名です");
+        assert_eq!(short.chars().count(), 9);
+    }
+
+    #[test]
+    fn test_progress_bar() {
+        assert_eq!(progress_bar(0, 10), "░░░░░░░░░░");
+        assert_eq!(progress_bar(60, 10), "██████░░░░");
+        assert_eq!(progress_bar(100, 4), "████");
+        assert_eq!(progress_bar(250, 4), "████");
+    }
+
+    #[test]
+    fn test_activity_bars() {
+        assert_eq!(activity_bars(&[]), "");
+        assert_eq!(activity_bars(&[0, 0]), "░░");
+        assert_eq!(activity_bars(&[0, 1, 8, 4]), "░▁█▄");
+        assert_eq!(activity_bars(&[3, 3]), "██");
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(fuzzy_score("", "main"), Some(0));
+        assert!(fuzzy_score("fl", "feature/login").is_some());
+        assert!(fuzzy_score("FL", "feature/login").is_some());
+        assert_eq!(fuzzy_score("lf", "feature/login"), None);
+        // Consecutive characters beat scattered ones
+        assert!(fuzzy_score("log", "feature/login") > fuzzy_score("log", "feature/long-go"));
+        // Word starts beat mid-word matches
+        assert!(fuzzy_score("fix", "fix/typo") > fuzzy_score("fix", "prefix"));
+    }
+
+    #[test]
+    fn test_first_hunk_line() {
+        let diff = "diff --git a/x b/x\nindex 1..2 100644\n--- a/x\n+++ b/x\n@@ -1 +1 @@\n-old\n+new\n@@ -9 +9 @@\n-a\n+b";
+        assert_eq!(first_hunk_line(diff), 4);
+        assert_eq!(first_hunk_line("@@ -1 +1 @@\n+x"), 0);
+        assert_eq!(first_hunk_line("Binary file (12 bytes)"), 0);
+        assert_eq!(first_hunk_line(""), 0);
+    }
+
+    fn span_texts<'a>(spans: &'a [Span]) -> Vec<(&'a str, bool)> {
+        spans
+            .iter()
+            .map(|s| (s.content.as_ref(), s.style.bg.is_some()))
+            .collect()
+    }
+
+    #[test]
+    fn test_word_diff_single_word() {
+        let (old, new) = word_diff("let limit = 10;", "let limit = 20;");
+        assert_eq!(
+            span_texts(&old),
+            [("let limit = ", false), ("10", true), (";", false)]
+        );
+        assert_eq!(
+            span_texts(&new),
+            [("let limit = ", false), ("20", true), (";", false)]
+        );
+    }
+
+    #[test]
+    fn test_word_diff_prefix_change() {
+        let (old, new) = word_diff("hello brave world", "goodbye brave world");
+        assert_eq!(span_texts(&old), [("hello", true), (" brave world", false)]);
+        assert_eq!(
+            span_texts(&new),
+            [("goodbye", true), (" brave world", false)]
+        );
+
+        // Inserted words are only marked on the side that has them
+        let (old, new) = word_diff("fn run()", "pub fn run()");
+        assert_eq!(span_texts(&old), [("fn run()", false)]);
+        assert_eq!(span_texts(&new), [("pub ", true), ("fn run()", false)]);
+    }
+
+    #[test]
+    fn test_word_diff_whole_line_replacement() {
+        let (old, new) = word_diff("alpha beta", "gamma delta");
+        assert_eq!(span_texts(&old), [("alpha beta", false)]);
+        assert_eq!(span_texts(&new), [("gamma delta", false)]);
+        assert_eq!(old[0].style, Style::new().red());
+        assert_eq!(new[0].style, Style::new().green());
+    }
+
+    #[test]
+    fn test_word_diff_keeps_multibyte_text_whole() {
+        let (old, new) = word_diff("naïve café", "naïve thé");
+        assert_eq!(span_texts(&old), [("naïve ", false), ("café", true)]);
+        assert_eq!(span_texts(&new), [("naïve ", false), ("thé", true)]);
+    }
+
+    #[test]
+    fn test_styled_diff_lines_pairs_modified_lines() {
+        let lines = styled_diff_lines("@@ -1,3 +1,2 @@\n-a = 1\n-b = 2\n+a = 1\n+b = 3\n-gone");
+        assert_eq!(lines.len(), 6);
+        let text = |line: &Line| {
+            line.spans
+                .iter()
+                .map(|s| s.content.as_ref())
+                .collect::<String>()
+        };
+        assert_eq!(text(&lines[2]), "-b = 2");
+        assert_eq!(text(&lines[4]), "+b = 3");
+        assert!(lines[4]
+            .spans
+            .iter()
+            .any(|s| s.content == "3" && s.style.bg.is_some()));
+        // Unpaired removals keep plain line colouring
+        assert_eq!(lines[5].spans.len(), 1);
+    }
+
+    #[test]
+    fn test_styled_diff_lines() {
+        let lines = styled_diff_lines("--- a/x\n+++ b/x\n@@ -1 +1 @@\n-old\n+new\n same");
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[3].spans[0].style, Style::new().red());
+        assert_eq!(lines[4].spans[0].style, Style::new().green());
+        assert_eq!(lines[2].spans[0].style, Style::new().cyan());
+        assert_eq!(lines[0].spans[0].style, Style::new().bold());
+        assert_eq!(lines[5].spans[0].style, Style::new());
+    }
+
+    #[test]
+    fn test_author_badge_is_stable_per_author() {
+        assert_eq!(author_badge("Ada Smith"), author_badge("Ada Smith"));
+        assert_eq!(author_badge("Ada Smith").0, "AS");
+        assert_eq!(author_badge("Jean-Luc de la Croix").0, "JC");
+        assert_eq!(author_badge("linus").0, "LI");
+        assert_eq!(author_badge("Ω").0, "Ω");
+        assert_eq!(author_badge(" -- ").0, "??");
+
+        // Twelve colours, so a handful of authors cannot all share one
+        let authors = ["Alice", "Bob", "Carol", "Dave", "Erin", "Frank"];
+        let colors: std::collections::HashSet<Color> =
+            authors.iter().map(|a| author_badge(a).1).collect();
+        assert!(colors.len() > 1);
+        assert_ne!(author_badge("Alice"), author_badge("Bob"));
+    }
+}